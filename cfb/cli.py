@@ -1,16 +1,20 @@
 """Generate code from serialized flatbuffers schema in bfbs format.
 
 Usage:
-  cfbc [-o <dir>] <bfbs>
+  cfbc [--proptest] [--borrowed] [-o <dir>] <bfbs>
   cfbc -h | --help
   cfbc --version
 
 Options:
-  -o <dir>   Save all generated files in <dir>, instead of the directory containing <bfbs>.
-  <bfbs>     Load schema from <bfbs> which is generated by `flatc -b --schema <fbs>`.
-
-  -h --help  Show this screen.
-  --version  Show version.
+  -o <dir>    Save all generated files in <dir>, instead of the directory containing <bfbs>.
+  <bfbs>      Load schema from <bfbs> which is generated by `flatc -b --schema <fbs>`.
+  --proptest  Generate proptest strategies for builder types, enabled by the cargo
+              feature "proptest".
+  --borrowed  Also generate builder types which borrow their strings and vectors, named
+              like the owned ones with the suffix "Ref".
+
+  -h --help   Show this screen.
+  --version   Show version.
 """
 from docopt import docopt
 from cfb.generator import Generator
@@ -22,7 +26,8 @@ def parse_arguments(argv=None):
 
 
 def generate(arguments):
-    g = Generator(arguments['<bfbs>'])
+    g = Generator(arguments['<bfbs>'], proptest=arguments['--proptest'],
+                  borrowed=arguments['--borrowed'])
     g.generate(arguments['-o'])
 
 
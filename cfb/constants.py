@@ -1,6 +1,8 @@
 from cfb.reflection.BaseType import BaseType
 
 SIZE_OF_UOFFSET = 4
+SIZE_OF_UOFFSET64 = 8
+SIZE_OF_VOFFSET = 2
 
 BASE_TYPE_SIZE = dict([
     (BaseType.UType, 1),
@@ -39,9 +41,12 @@ BASE_TYPE_RUST_TYPE = dict([
     (BaseType.String, "String"),
 ])
 
+# The longest arrays for which the standard library implements `Default` and proptest `Arbitrary`.
+MAX_DERIVED_ARRAY_LEN = 32
+
 BASE_TYPE_DEFAULT = dict([
     (BaseType.Bool, 'false'),
-    (BaseType.Byte, '9i8'),
+    (BaseType.Byte, '0i8'),
     (BaseType.Short, '0i16'),
     (BaseType.Int, '0i32'),
     (BaseType.Long, '0i64'),
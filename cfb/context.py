@@ -1,29 +1,91 @@
+import math
 import re
 from cfb.namespace import Namespace
 from cfb.reflection.BaseType import BaseType
-from cfb.constants import SIZE_OF_UOFFSET, BASE_TYPE_SIZE, BASE_TYPE_RUST_TYPE, BASE_TYPE_DEFAULT, RESERVED_KEYWORDS
+from cfb.reflection.Enum import Enum
+from cfb.constants import SIZE_OF_UOFFSET, SIZE_OF_UOFFSET64, SIZE_OF_VOFFSET, BASE_TYPE_SIZE, BASE_TYPE_RUST_TYPE, BASE_TYPE_DEFAULT, RESERVED_KEYWORDS, MAX_DERIVED_ARRAY_LEN
 from cfb.struct import struct_padded_fields
 
 CAMEL_TO_SNAKE_RE = re.compile(r'(?<=[a-z])[A-Z]|[A-Z](?=[^A-Z])')
 
 
+def float_literal(value, rust_type):
+    if math.isnan(value):
+        return '{0}::NAN'.format(rust_type)
+    if math.isinf(value):
+        return '{0}::{1}INFINITY'.format(rust_type, 'NEG_' if value < 0 else '')
+    return '{0!r}{1}'.format(value, rust_type)
+
+
 class Context(object):
-    def __init__(self, basename, schema):
+    def __init__(self, basename, schema, proptest=False, borrowed=False):
         self.basename = basename
         self.schema = schema
+        self.proptest = proptest
+        self.borrowed = borrowed
         self.root = Namespace.from_schema(schema)
 
     def field_default(self, field):
+        """Returns the default value declared in the schema for a scalar or enum field."""
+        base_type = field.Type().BaseType()
         index = field.Type().Index()
-        if index == -1:
-            return BASE_TYPE_DEFAULT[field.Type().BaseType()]
-        return self.enum_default(self.schema.Enums(index))
+        if index != -1:
+            return self.enum_default(self.schema.Enums(index), field.DefaultInteger())
+
+        if base_type in (BaseType.Float, BaseType.Double):
+            value = field.DefaultReal()
+            if value == 0 and math.copysign(1.0, value) > 0:
+                return BASE_TYPE_DEFAULT[base_type]
+            return float_literal(value, self.rust_type(base_type))
+
+        if field.DefaultInteger() == 0:
+            return BASE_TYPE_DEFAULT[base_type]
+        if base_type == BaseType.Bool:
+            return 'true'
+        return '{0}{1}'.format(field.DefaultInteger(), self.rust_type(base_type))
+
+    def enum_default(self, enum, value=0):
+        if self.is_bit_flags(enum):
+            name = self.base_name(enum)
+            flags = [v for v in self.enum_values(enum) if v.Value() & value]
+            if not flags:
+                return '{0}::empty()'.format(name)
+            if len(flags) == 1:
+                return '{0}::{1}'.format(name, flags[0].Name().decode('utf-8'))
+            return '({0})'.format(' | '.join('{0}::{1}'.format(name, v.Name().decode('utf-8')) for v in flags))
 
-    def enum_default(self, enum):
         for i in range(enum.ValuesLength()):
             val = enum.Values(i)
-            if val.Value() == 0:
+            if val.Value() == value:
                 return '{0}::{1}'.format(self.base_name(enum), val.Name().decode('utf-8'))
+        return '{0}({1})'.format(self.base_name(enum), value)
+
+    def is_scalar(self, field):
+        return BaseType.Bool <= field.Type().BaseType() <= BaseType.Double
+
+    def is_optional(self, field):
+        """Tests whether the field is an `Option` in the builder and is present iff it is `Some`.
+
+        Scalars are optional when declared with `= null`, and struct fields when they have the
+        attribute `cfb_optional`.
+        """
+        if self.is_scalar(field):
+            return field.Optional()
+        if field.Type().BaseType() == BaseType.Obj and self.schema.Objects(field.Type().Index()).IsStruct():
+            return any(field.Attributes(i).Key() == b'cfb_optional' for i in range(field.AttributesLength()))
+        return False
+
+    def has_custom_default(self, field):
+        """Tests whether the scalar field declares a default other than zero."""
+        if not self.is_scalar(field) or field.Optional():
+            return False
+        base_type = field.Type().BaseType()
+        if base_type in (BaseType.Float, BaseType.Double):
+            return self.field_default(field) != BASE_TYPE_DEFAULT[base_type]
+        return field.DefaultInteger() != 0
+
+    def has_custom_defaults(self, obj):
+        return any(self.has_custom_default(f) for f in self.table_fields(obj))
 
     def field_nested_table(self, field):
         if field.Type().BaseType() != BaseType.Vector or field.Type().Element() != BaseType.UByte:
@@ -33,8 +95,61 @@ class Context(object):
             if attr.Key() == b'cfb_nested_flatbuffer':
                 return attr.Value().decode('utf-8')
 
-    def field_present(self, field, extract=None):
+    def length_bounds(self, field):
+        """The bounds `(min, max)` of the length of a string or vector set by the attributes
+        `cfb_min_len` and `cfb_max_len`, or None if it has neither."""
         base_type = field.Type().BaseType()
+        if base_type not in (BaseType.String, BaseType.Vector) or self.is_union_type(field):
+            return
+        if self.field_nested_table(field) is not None:
+            return
+
+        bounds = {b'cfb_min_len': '0', b'cfb_max_len': 'usize::MAX'}
+        found = False
+        for attr in (field.Attributes(i) for i in range(field.AttributesLength())):
+            if attr.Key() in bounds:
+                bounds[attr.Key()] = str(int(attr.Value()))
+                found = True
+        if found:
+            return bounds[b'cfb_min_len'], bounds[b'cfb_max_len']
+
+    def force_align(self, attributed):
+        """The alignment forced by the `force_align` attribute of a struct or a vector field, or 0."""
+        for attr in (attributed.Attributes(i) for i in range(attributed.AttributesLength())):
+            if attr.Key() == b'force_align':
+                return int(attr.Value())
+        return 0
+
+    def is_offset64(self, field):
+        """Vectors of scalars or structs referenced by a 64-bit uoffset, see the attribute `offset64`."""
+        if field.Type().BaseType() != BaseType.Vector or not self.is_element_scalar(field):
+            return False
+        if self.field_nested_table(field) is not None:
+            return False
+        return any(field.Attributes(i).Key() == b'offset64' for i in range(field.AttributesLength()))
+
+    def uoffset_follower(self, field):
+        """The verifier function following the uoffset stored in `field`."""
+        return 'try_follow_uoffset64' if self.is_offset64(field) else 'try_follow_uoffset'
+
+    def object_alignment(self, obj):
+        """Alignment of a struct, which is at least its `force_align` even if `Minalign` is not raised."""
+        return max(obj.Minalign(), self.force_align(obj))
+
+    def is_required(self, field):
+        """Required strings and vectors, which are always written."""
+        return field.Required() and field.Type().BaseType() in (BaseType.String, BaseType.Vector)
+
+    def field_present(self, field, extract=None, by_ref=False):
+        """Tests whether the builder writes the field, or binds the value of an optional field to
+        `extract`, by reference to the tables, unions and nested buffers if `by_ref` is set."""
+        base_type = field.Type().BaseType()
+        ref = '&' if by_ref else ''
+        if self.is_optional(field):
+            if extract is None:
+                return 'self.{0}.is_some()'.format(self.field_name(field))
+            return 'let Some({0}) = self.{1}'.format(extract, self.field_name(field))
+
         if base_type == BaseType.Obj:
             obj = self.schema.Objects(field.Type().Index())
             if obj.IsStruct():
@@ -42,35 +157,73 @@ class Context(object):
 
             if extract is None:
                 return 'self.{0}.is_some()'.format(self.field_name(field))
-            return 'let Some({0}) = self.{1}'.format(extract, self.field_name(field))
+            return 'let Some({0}) = {2}self.{1}'.format(extract, self.field_name(field), ref)
+        if base_type == BaseType.Array:
+            element = field.Type().Element()
+            if element == BaseType.Obj:
+                return 'self.{0}.iter().any({1}::is_present)'.format(self.field_name(field), self.array_element_type(field))
+            if element == BaseType.Bool:
+                return 'self.{0}.iter().any(|v| *v)'.format(self.field_name(field))
+            if field.Type().Index() != -1:
+                default = self.enum_default(self.schema.Enums(field.Type().Index()))
+            else:
+                default = BASE_TYPE_DEFAULT[element]
+            return 'self.{0}.iter().any(|v| *v != {1})'.format(self.field_name(field), default)
 
         if base_type == BaseType.Bool:
-            return 'self.{0}'.format(self.field_name(field))
+            return '{0}self.{1}'.format('!' if field.DefaultInteger() else '', self.field_name(field))
         if base_type == BaseType.String:
             return '!self.{0}.is_empty()'.format(self.field_name(field))
         if base_type == BaseType.Vector:
+            if field.Type().Element() == BaseType.UType:
+                if extract is None:
+                    return '!self.{0}.is_empty()'.format(self.field_name(field)[:-5])
+                return '!{0}.is_empty()'.format(self.field_name(field))
             nested = self.field_nested_table(field)
             if nested is None:
                 return '!self.{0}.is_empty()'.format(self.field_name(field))
             if extract is None:
                 return 'self.{0}.is_some()'.format(self.field_name(field))
-            return 'let Some({0}) = self.{1}'.format(extract, self.field_name(field))
+            return 'let Some({0}) = {2}self.{1}'.format(extract, self.field_name(field), ref)
 
         if base_type == BaseType.Union:
             if extract is None:
                 return 'self.{0}.is_some()'.format(self.field_name(field))
-            return 'let Some({0}) = self.{1}'.format(extract, self.field_name(field))
+            return 'let Some({0}) = {2}self.{1}'.format(extract, self.field_name(field), ref)
         if base_type == BaseType.UType:
             if extract is None:
                 return 'self.{0}.is_some()'.format(self.field_name(field)[:-5])
             return 'let Some({0}) = {1}'.format(extract, self.field_name(field))
 
-        return 'self.{0} != {1}'.format(self.field_name(field), self.field_default(field))
+        default = self.field_default(field)
+        if default.endswith('::NAN'):
+            return '!self.{0}.is_nan()'.format(self.field_name(field))
+        return 'self.{0} != {1}'.format(self.field_name(field), default)
+
+    def array_element_type(self, field):
+        """Type of the elements of a fixed-length array in a struct."""
+        ty = field.Type()
+        if ty.Element() == BaseType.Obj:
+            return self.base_name(self.schema.Objects(ty.Index()))
+        if ty.Index() != -1:
+            return self.base_name(self.schema.Enums(ty.Index()))
+        return self.rust_type(ty.Element())
+
+    def is_large_array(self, field):
+        """Tests whether the field is an array too long to derive `Default` and `Arbitrary` for."""
+        ty = field.Type()
+        return ty.BaseType() == BaseType.Array and ty.FixedLength() > MAX_DERIVED_ARRAY_LEN
+
+    def has_large_arrays(self, struct):
+        return any(self.is_large_array(f) for f in self.fields_sorted_by_offset(struct))
 
     def field_type(self, field):
         index = field.Type().Index()
         base_type = field.Type().BaseType()
 
+        if base_type == BaseType.Array:
+            return '[{0}; {1}]'.format(self.array_element_type(field), field.Type().FixedLength())
+
         if base_type == BaseType.Vector:
             if index == -1:
                 nested = self.field_nested_table(field)
@@ -86,11 +239,14 @@ class Context(object):
 
         if base_type == BaseType.Obj:
             obj = self.schema.Objects(index)
-            if obj.IsStruct():
+            if obj.IsStruct() and not self.is_optional(field):
                 return self.base_name(obj)
 
             return 'Option<{0}>'.format(self.base_name(obj))
 
+        if self.is_optional(field):
+            return 'Option<{0}>'.format(self.rust_type(base_type) if index == -1 else
+                                        self.base_name(self.schema.Enums(index)))
         if index == -1:
             return self.rust_type(base_type)
 
@@ -101,6 +257,103 @@ class Context(object):
             return 'Option<{0}>'.format(self.base_name(enum))
         return self.base_name(enum)
 
+    def field_strategy(self, field):
+        index = field.Type().Index()
+        base_type = field.Type().BaseType()
+
+        bounds = self.length_bounds(field)
+        if base_type == BaseType.Vector:
+            nested = self.field_nested_table(field)
+            if nested is not None:
+                return 'strategy::option(&config, any_with::<{0}>(config.clone()))'.format(nested)
+            element_type = self.field_type(field)[len('Vec<'):-1]
+            if self.is_element_string(field):
+                element, len_range = 'strategy::string(&config)', 'config.vector_len.clone()'
+            elif self.is_element_union(field) or self.is_element_table(field):
+                element, len_range = 'any_with::<{0}>(config.clone())'.format(element_type), 'config.vector_len.clone()'
+            else:
+                element, len_range = 'any::<{0}>()'.format(element_type), 'config.scalar_vector_len.clone()'
+
+            if self.element_key_field(field):
+                if bounds:
+                    return 'strategy::bounded_unique_vec({0}, {1}, {3}, {4}, {2}::cmp_by_key)'.format(
+                        element, len_range, element_type, *bounds)
+                return 'strategy::unique_vec({0}, {1}, {2}::cmp_by_key)'.format(element, len_range, element_type)
+            if bounds:
+                return 'strategy::bounded_vec({0}, {1}, {2}, {3})'.format(element, len_range, *bounds)
+            return 'vec({0}, {1})'.format(element, len_range)
+
+        if base_type == BaseType.String:
+            if bounds:
+                return 'strategy::bounded_string(&config, {0}, {1})'.format(*bounds)
+            return 'strategy::string(&config)'
+        if base_type == BaseType.Array:
+            if self.is_large_array(field):
+                return 'strategy::array::<_, {1}>(any::<{0}>())'.format(
+                    self.array_element_type(field), field.Type().FixedLength())
+            return 'any::<{0}>()'.format(self.field_type(field))
+        if base_type == BaseType.Obj:
+            obj = self.schema.Objects(index)
+            if obj.IsStruct():
+                if self.is_optional(field):
+                    return 'strategy::option(&config, any::<{0}>())'.format(self.base_name(obj))
+                return 'any::<{0}>()'.format(self.base_name(obj))
+            return 'strategy::option(&config, any_with::<{0}>(config.clone()))'.format(self.base_name(obj))
+        if base_type == BaseType.Union:
+            return 'strategy::option(&config, any_with::<{0}>(config.clone()))'.format(
+                self.base_name(self.schema.Enums(index)))
+
+        if self.is_optional(field):
+            return 'strategy::option(&config, any::<{0}>())'.format(self.field_type(field)[len('Option<'):-1])
+        return 'any::<{0}>()'.format(self.field_type(field))
+
+    def reader_type(self, field):
+        ty = field.Type()
+        base_type = ty.BaseType()
+        index = ty.Index()
+
+        if base_type == BaseType.Vector:
+            element = ty.Element()
+            if element == BaseType.String:
+                inner = "flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'a str>>"
+            elif element == BaseType.Union:
+                inner = "flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>"
+            elif element == BaseType.Obj:
+                obj = self.schema.Objects(index)
+                if obj.IsStruct():
+                    inner = "&'a [reader::{0}]".format(self.base_name(obj))
+                else:
+                    inner = "flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::{0}<'a>>>".format(
+                        self.base_name(obj))
+            elif index != -1:
+                inner = "flatbuffers::Vector<'a, reader::{0}>".format(
+                    self.base_name(self.schema.Enums(index)))
+            elif BASE_TYPE_SIZE[element] == 1:
+                inner = "&'a [{0}]".format(self.rust_type(element))
+            else:
+                inner = "flatbuffers::Vector<'a, {0}>".format(self.rust_type(element))
+        elif base_type == BaseType.String:
+            inner = "&'a str"
+        elif base_type == BaseType.Obj:
+            obj = self.schema.Objects(index)
+            if obj.IsStruct():
+                inner = "&'a reader::{0}".format(self.base_name(obj))
+            else:
+                inner = "reader::{0}<'a>".format(self.base_name(obj))
+        elif base_type == BaseType.Union:
+            inner = "flatbuffers::Table<'a>"
+        elif index != -1:
+            inner = 'reader::{0}'.format(self.base_name(self.schema.Enums(index)))
+        else:
+            inner = self.rust_type(base_type)
+
+        if field.Required() or (base_type <= BaseType.Double and not field.Optional()):
+            return inner
+        return 'Option<{0}>'.format(inner)
+
+    def strategies_use_config(self, fields):
+        return any('config' in self.field_strategy(f) for f in fields)
+
     def rust_type(self, cfb_type):
         return BASE_TYPE_RUST_TYPE[cfb_type]
 
@@ -118,7 +371,7 @@ class Context(object):
         if cfb_type == BaseType.Obj:
             obj = self.schema.Objects(index)
             if obj.IsStruct():
-                return obj.Minalign()
+                return self.object_alignment(obj)
 
             return SIZE_OF_UOFFSET
 
@@ -126,15 +379,24 @@ class Context(object):
 
     def field_size(self, field):
         ty = field.Type()
+        if self.is_offset64(field):
+            return SIZE_OF_UOFFSET64
+        if ty.BaseType() == BaseType.Array:
+            return self.type_size(ty.Element(), ty.Index()) * ty.FixedLength()
         return self.type_size(ty.BaseType(), ty.Index())
 
     def field_alignment(self, field):
         ty = field.Type()
+        if self.is_offset64(field):
+            return SIZE_OF_UOFFSET64
+        if ty.BaseType() == BaseType.Array:
+            return self.type_alignment(ty.Element(), ty.Index())
         return self.type_alignment(ty.BaseType(), ty.Index())
 
     def table_alignment(self, table):
-        if table.FieldsLength() > 0:
-            return max(self.field_alignment(table.Fields(i)) for i in range(table.FieldsLength()))
+        fields = self.table_fields(table)
+        if len(fields) > 0:
+            return max(self.field_alignment(f) for f in fields)
         return SIZE_OF_UOFFSET
 
     def element_size(self, field):
@@ -143,7 +405,7 @@ class Context(object):
 
     def element_aligment(self, field):
         ty = field.Type()
-        return self.type_alignment(ty.Element(), ty.Index())
+        return max(self.type_alignment(ty.Element(), ty.Index()), self.force_align(field))
 
     def full_name(self, object):
         return object.Name().decode('utf-8').replace('.', '::')
@@ -157,6 +419,72 @@ class Context(object):
     def field_union_enum(self, field):
         return self.schema.Enums(field.Type().Index())
 
+    def union_variants(self, enum):
+        """The values of a union except `NONE`."""
+        return [v for v in self.enum_values(enum) if v.Value() != 0]
+
+    def variant_object(self, val):
+        """The table or struct of a union variant, or None if the variant is a string."""
+        ty = val.UnionType()
+        if ty is not None and ty.BaseType() == BaseType.Obj:
+            return self.schema.Objects(ty.Index())
+        if ty is None or ty.BaseType() != BaseType.String:
+            return val.Object()
+
+    def is_string_variant(self, val):
+        return self.variant_object(val) is None
+
+    def is_struct_variant(self, val):
+        obj = self.variant_object(val)
+        return obj is not None and obj.IsStruct()
+
+    def variant_type(self, val):
+        """Type of the variant in the builder."""
+        if self.is_string_variant(val):
+            return 'String'
+        return self.base_name(self.variant_object(val))
+
+    def variant_strategy(self, val):
+        if self.is_string_variant(val):
+            return 'strategy::string(&config)'
+        if self.is_struct_variant(val):
+            return 'any::<{0}>()'.format(self.variant_type(val))
+        return 'any_with::<{0}>(config.clone())'.format(self.variant_type(val))
+
+    def variant_component(self, val, var):
+        if self.is_string_variant(val):
+            return 'StringComponent::new({0})'.format(var)
+        if self.is_struct_variant(val):
+            return 'StructComponent::new({0}, {1})'.format(var, self.object_alignment(self.variant_object(val)))
+        return var
+
+    def variant_reader_type(self, val):
+        if self.is_string_variant(val):
+            return "&'a str"
+        if self.is_struct_variant(val):
+            return "&'a reader::{0}".format(self.variant_type(val))
+        return "reader::{0}<'a>".format(self.variant_type(val))
+
+    def variant_reader(self, val):
+        """Converts the `flatbuffers::Table` returned by the union accessor to the variant."""
+        if self.is_string_variant(val):
+            return "|t| <&str>::follow(t.buf, t.loc)"
+        if self.is_struct_variant(val):
+            return "|t| <&reader::{0}>::follow(t.buf, t.loc)".format(self.variant_type(val))
+        return "reader::{0}::init_from_table".format(self.variant_type(val))
+
+    def variant_verify(self, val, loc, buf='buf'):
+        """Verifies the variant stored at `loc` in `buf`."""
+        if self.is_string_variant(val):
+            return 'StringVerifier::follow({0}, {1}).verify_in(verification)'.format(buf, loc)
+        if self.is_struct_variant(val):
+            obj = self.variant_object(val)
+            verify = 'verify_struct({0}, {1}, {2}, {3})'.format(buf, loc, obj.Bytesize(), self.object_alignment(obj))
+            if self.struct_floats(obj):
+                verify += '.and_then(|_| {0})'.format(self.struct_float_check(obj, buf, loc))
+            return verify + '.and_then(|_| verification.visit({0}, {0} + {1}))'.format(loc, obj.Bytesize())
+        return 'reader::{0}::follow({1}, {2}).verify_in(verification)'.format(self.variant_type(val), buf, loc)
+
     def is_table(self, field):
         return field.Type().BaseType() == BaseType.Obj and not self.schema.Objects(field.Type().Index()).IsStruct()
 
@@ -167,7 +495,12 @@ class Context(object):
         return field.Type().BaseType() == BaseType.Union
 
     def is_union_type(self, field):
-        return field.Type().BaseType() == BaseType.UType
+        """The type field of a union, or the vector of types of a vector of unions."""
+        ty = field.Type()
+        return ty.BaseType() == BaseType.UType or (ty.BaseType() == BaseType.Vector and ty.Element() == BaseType.UType)
+
+    def is_element_union(self, field):
+        return field.Type().BaseType() == BaseType.Vector and field.Type().Element() == BaseType.Union
 
     def is_vector(self, field):
         return field.Type().BaseType() == BaseType.Vector
@@ -198,15 +531,274 @@ class Context(object):
         return list(sorted((enum.Values(i) for i in range(enum.ValuesLength())),
                            key=lambda v: v.Value()))
 
+    def enum_pattern(self, enum):
+        values = sorted(v.Value() for v in self.enum_values(enum))
+        ranges = []
+        for value in values:
+            if ranges and ranges[-1][1] + 1 == value:
+                ranges[-1][1] = value
+            else:
+                ranges.append([value, value])
+
+        return ' | '.join(str(first) if first == last else '{0}..={1}'.format(first, last)
+                          for first, last in ranges)
+
+    def has_validate(self, obj):
+        """Tables with the attribute `cfb_validate`, whose verifier calls their `Validate` impl."""
+        return any(obj.Attributes(i).Key() == b'cfb_validate' for i in range(obj.AttributesLength()))
+
+    def is_bit_flags(self, enum):
+        return any(enum.Attributes(i).Key() == b'bit_flags' for i in range(enum.AttributesLength()))
+
+    def enum_mask(self, enum):
+        """All the bits declared by a `bit_flags` enum."""
+        mask = 0
+        for val in self.enum_values(enum):
+            mask |= val.Value()
+        return mask
+
+    def value_check(self, base_type, index):
+        """Returns the underlying type of an enum and the pattern of the declared values, or the mask
+        of the declared bits for `bit_flags` enums."""
+        if base_type == BaseType.Bool or index == -1 or base_type in (BaseType.Obj, BaseType.Union):
+            return None
+
+        enum = self.schema.Enums(index)
+        if self.is_bit_flags(enum):
+            return (self.rust_type(enum.UnderlyingType().BaseType()), None, self.enum_mask(enum))
+        return (self.rust_type(enum.UnderlyingType().BaseType()), self.enum_pattern(enum), None)
+
+    def value_is_declared(self, check, expr):
+        if check[2] is None:
+            return 'matches!({0}, {1})'.format(expr, check[1])
+        return '{0} & !{1} == 0'.format(expr, check[2])
+
+    def field_default_check(self, field):
+        """Returns the type of a non-optional scalar field and the condition testing whether its
+        `value` equals the default declared in the schema, or None for other fields."""
+        if not self.is_scalar(field) or field.Optional():
+            return None
+        base_type = field.Type().BaseType()
+        if base_type == BaseType.Bool:
+            return ('u8', 'value == {0}'.format(1 if field.DefaultInteger() else 0))
+        rust_type = self.rust_type(base_type)
+        if base_type in (BaseType.Float, BaseType.Double):
+            if math.isnan(field.DefaultReal()):
+                return (rust_type, 'value.is_nan()')
+            return (rust_type, 'value == {0}'.format(float_literal(field.DefaultReal(), rust_type)))
+        return (rust_type, 'value == {0}{1}'.format(field.DefaultInteger(), rust_type))
+
+    def field_value_check(self, field):
+        ty = field.Type()
+        return self.value_check(ty.BaseType(), ty.Index())
+
+    def element_value_check(self, field):
+        ty = field.Type()
+        return self.value_check(ty.Element(), ty.Index())
+
+    def struct_floats(self, struct, base=0):
+        """Byte offsets and Rust types of the floats in a struct, including nested structs and arrays."""
+        floats = []
+        for field in self.fields_sorted_by_offset(struct):
+            ty = field.Type()
+            base_type = ty.BaseType()
+            count = 1
+            if base_type == BaseType.Array:
+                base_type = ty.Element()
+                count = ty.FixedLength()
+            size = self.type_size(base_type, ty.Index())
+            for i in range(count):
+                offset = base + field.Offset() + i * size
+                if base_type in (BaseType.Float, BaseType.Double):
+                    floats.append((offset, self.rust_type(base_type)))
+                elif base_type == BaseType.Obj:
+                    floats.extend(self.struct_floats(self.schema.Objects(ty.Index()), offset))
+        return floats
+
+    def field_has_floats(self, field):
+        """Whether a struct field is a float, or a struct or an array containing floats."""
+        ty = field.Type()
+        base_type = ty.Element() if ty.BaseType() == BaseType.Array else ty.BaseType()
+        if base_type == BaseType.Obj:
+            return len(self.struct_floats(self.schema.Objects(ty.Index()))) > 0
+        return base_type in (BaseType.Float, BaseType.Double)
+
+    def float_check(self, base_type, index, buf, loc):
+        """Verifies that the floats in a scalar or struct at `loc` are canonical, or None if it has none."""
+        if base_type in (BaseType.Float, BaseType.Double):
+            return 'verify_float::<{0}>({1}, {2})'.format(self.rust_type(base_type), buf, loc)
+        if base_type == BaseType.Obj:
+            obj = self.schema.Objects(index)
+            if obj.IsStruct():
+                return self.struct_float_check(obj, buf, loc)
+
+    def struct_float_check(self, struct, buf, loc):
+        if self.struct_floats(struct):
+            return 'reader::{0}::verify_canonical_floats({1}, {2})'.format(self.base_name(struct), buf, loc)
+
+    def field_float_check(self, field, buf, loc):
+        ty = field.Type()
+        return self.float_check(ty.BaseType(), ty.Index(), buf, loc)
+
+    def element_float_check(self, field, buf, loc):
+        ty = field.Type()
+        return self.float_check(ty.Element(), ty.Index(), buf, loc)
+
+    def is_bool(self, field):
+        return field.Type().BaseType() == BaseType.Bool
+
+    def is_element_bool(self, field):
+        return field.Type().Element() == BaseType.Bool
+
     def fields_sorted_by_alignement(self, object):
-        fields = sorted((object.Fields(i) for i in range(
-            object.FieldsLength())), key=lambda f: f.Offset())
+        fields = self.table_fields(object)
 
         return list(sorted(fields, key=lambda f: (self.field_alignment(f), self.field_size(f)), reverse=True))
 
     def fields_sorted_by_offset(self, object):
         return list(sorted((object.Fields(i) for i in range(object.FieldsLength())), key=lambda f: f.Offset()))
 
+    def table_fields(self, object):
+        """Fields sorted by offset, without the deprecated ones which are never written."""
+        return [f for f in self.fields_sorted_by_offset(object) if not f.Deprecated()]
+
+    def deprecated_fields(self, object):
+        return [f for f in self.fields_sorted_by_offset(object) if f.Deprecated()]
+
+    def vtable_num_bytes(self, object):
+        """Size of the vtable holding every field of the schema, including the deprecated ones."""
+        return max([f.Offset() + SIZE_OF_VOFFSET for f in self.fields_sorted_by_offset(object)] + [2 * SIZE_OF_VOFFSET])
+
+    def key_field(self, object):
+        return next((f for f in self.table_fields(object) if f.Key()), None)
+
+    def element_key_field(self, field):
+        if self.is_element_table(field):
+            return self.key_field(self.schema.Objects(field.Type().Index()))
+
+    def key_type(self, field):
+        """Type of the key to look up in the reader."""
+        base_type = field.Type().BaseType()
+        if base_type == BaseType.String:
+            return '&str'
+        if field.Type().Index() != -1:
+            return 'reader::{0}'.format(self.base_name(self.schema.Enums(field.Type().Index())))
+        return self.rust_type(base_type)
+
+    def builder_key(self, field, var, borrowed=False):
+        if field.Type().BaseType() == BaseType.String and not borrowed:
+            return '{0}.{1}.as_str()'.format(var, self.field_name(field))
+        return '{0}.{1}'.format(var, self.field_name(field))
+
+    def reader_key(self, field, var):
+        if field.Type().BaseType() == BaseType.String and not field.Required():
+            return '{0}.{1}().unwrap_or_default()'.format(var, self.field_name(field))
+        return '{0}.{1}()'.format(var, self.field_name(field))
+
+    def key_cmp(self, field, lhs, rhs, builder=False):
+        """Compares two keys, floats are ordered by `total_cmp` and enums by the underlying values.
+
+        The enums of the builders are newtypes around the underlying values, while the readers use
+        Rust enums.
+        """
+        base_type = field.Type().BaseType()
+        if base_type == BaseType.String:
+            return '{0}.cmp({1})'.format(lhs, rhs)
+        if base_type in (BaseType.Float, BaseType.Double):
+            return '{0}.total_cmp(&{1})'.format(lhs, rhs)
+        if base_type != BaseType.String and field.Type().Index() != -1:
+            if self.is_bit_flags(self.schema.Enums(field.Type().Index())):
+                return '{0}.bits().cmp(&{1}.bits())'.format(lhs, rhs)
+            if builder:
+                return '{0}.0.cmp(&{1}.0)'.format(lhs, rhs)
+            rust_type = self.rust_type(base_type)
+            return '({0} as {2}).cmp(&({1} as {2}))'.format(lhs, rhs, rust_type)
+        return '{0}.cmp(&{1})'.format(lhs, rhs)
+
+    def ref_name(self, obj, lifetime="'a"):
+        """Name of the borrowed builder type of a table or union, with the lifetime if it has one."""
+        name = self.base_name(obj) + 'Ref'
+        if self.ref_has_lifetime(obj):
+            return '{0}<{1}>'.format(name, lifetime)
+        return name
+
+    def ref_has_lifetime(self, obj, visiting=()):
+        """Whether the borrowed builder type of a table or union borrows anything.
+
+        Tables without strings and vectors, even indirectly, are small enough to be copied, and
+        have no lifetime since Rust rejects unused lifetime parameters.
+        """
+        name = obj.Name()
+        if name in visiting:
+            return False
+        visiting = visiting + (name,)
+        if isinstance(obj, Enum):
+            return any(self.is_string_variant(v) or
+                       (not self.is_struct_variant(v) and self.ref_has_lifetime(self.variant_object(v), visiting))
+                       for v in self.union_variants(obj))
+
+        for field in self.builder_fields(obj):
+            base_type = field.Type().BaseType()
+            if base_type == BaseType.String:
+                return True
+            if base_type == BaseType.Vector:
+                nested = self.field_nested_table(field)
+                if nested is None or self.ref_has_lifetime(self.object_by_name(nested), visiting):
+                    return True
+            elif base_type == BaseType.Union:
+                if self.ref_has_lifetime(self.schema.Enums(field.Type().Index()), visiting):
+                    return True
+            elif base_type == BaseType.Obj:
+                target = self.schema.Objects(field.Type().Index())
+                if not target.IsStruct() and self.ref_has_lifetime(target, visiting):
+                    return True
+        return False
+
+    def object_by_name(self, name):
+        """The table named `name`, which may be qualified by its namespace."""
+        for i in range(self.schema.ObjectsLength()):
+            obj = self.schema.Objects(i)
+            full_name = obj.Name().decode('utf-8')
+            if name in (full_name, self.base_name(obj)):
+                return obj
+        raise KeyError(name)
+
+    def ref_field_type(self, field):
+        """Type of the field in the borrowed builder type, which borrows strings and vectors."""
+        index = field.Type().Index()
+        base_type = field.Type().BaseType()
+
+        if base_type == BaseType.String:
+            return "&'a str"
+        if base_type == BaseType.Vector:
+            nested = self.field_nested_table(field)
+            if nested is not None:
+                return 'Option<{0}>'.format(self.ref_name(self.object_by_name(nested)))
+            element = field.Type().Element()
+            if element == BaseType.String:
+                return "&'a [&'a str]"
+            if element == BaseType.Union:
+                return "&'a [{0}]".format(self.ref_name(self.schema.Enums(index)))
+            if element == BaseType.Obj and not self.schema.Objects(index).IsStruct():
+                return "&'a [{0}]".format(self.ref_name(self.schema.Objects(index)))
+            return "&'a [{0}]".format(self.field_type(field)[len('Vec<'):-1])
+        if base_type == BaseType.Union:
+            return 'Option<{0}>'.format(self.ref_name(self.schema.Enums(index)))
+        if base_type == BaseType.Obj and not self.schema.Objects(index).IsStruct():
+            return 'Option<{0}>'.format(self.ref_name(self.schema.Objects(index)))
+        return self.field_type(field)
+
+    def ref_variant_type(self, val):
+        """Type of the variant in the borrowed builder type of a union."""
+        if self.is_string_variant(val):
+            return "&'a str"
+        if self.is_struct_variant(val):
+            return self.variant_type(val)
+        return self.ref_name(self.variant_object(val))
+
+    def builder_fields(self, object):
+        return [f for f in self.table_fields(object) if not self.is_union_type(f)]
+
     def camel_to_snake(_self, name):
         return CAMEL_TO_SNAKE_RE.sub(r'_\g<0>', name).strip('_').lower()
 
@@ -5,14 +5,14 @@ from cfb.context import Context
 
 
 class Generator(object):
-    def __init__(self, bfbs_path):
+    def __init__(self, bfbs_path, proptest=False, borrowed=False):
         self.outdir = path.dirname(bfbs_path)
         self.basename, _ = path.splitext(path.basename(bfbs_path))
 
         with open(bfbs_path, 'rb') as bfbs_file:
             buf = bytearray(bfbs_file.read())
             schema = Schema.GetRootAsSchema(buf, 0)
-            self.context = Context(self.basename, schema)
+            self.context = Context(self.basename, schema, proptest=proptest, borrowed=borrowed)
 
     def generate(self, outdir=None):
         outdir = outdir or self.outdir
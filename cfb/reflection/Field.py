@@ -120,7 +120,14 @@ class Field(object):
             return self._tab.VectorLen(o)
         return 0
 
-def FieldStart(builder): builder.StartObject(11)
+    # Field
+    def Optional(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(26))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def FieldStart(builder): builder.StartObject(12)
 def FieldAddName(builder, name): builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(name), 0)
 def FieldAddType(builder, type): builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(type), 0)
 def FieldAddId(builder, id): builder.PrependUint16Slot(2, id, 0)
@@ -134,4 +141,5 @@ def FieldAddAttributes(builder, attributes): builder.PrependUOffsetTRelativeSlot
 def FieldStartAttributesVector(builder, numElems): return builder.StartVector(4, numElems, 4)
 def FieldAddDocumentation(builder, documentation): builder.PrependUOffsetTRelativeSlot(10, flatbuffers.number_types.UOffsetTFlags.py_type(documentation), 0)
 def FieldStartDocumentationVector(builder, numElems): return builder.StartVector(4, numElems, 4)
+def FieldAddOptional(builder, optional): builder.PrependBoolSlot(11, optional, 0)
 def FieldEnd(builder): return builder.EndObject()
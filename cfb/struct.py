@@ -66,7 +66,7 @@ def struct_padded_fields(ctx, object):
 
     if last_field is not None:
         alignment = ctx.field_alignment(raw_field)
-        padded = align(position, object.Minalign())
+        padded = align(position, ctx.object_alignment(object))
         paddings = generate_paddings(padding_index, padded - position)
         fields.append(StructPaddedField(last_field, paddings))
 
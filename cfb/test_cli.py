@@ -11,7 +11,15 @@ class TestCli(TestCase):
         args = cli.parse_arguments(['test.bfbs'])
         self.assertEqual('test.bfbs', args['<bfbs>'])
         self.assertEqual(None, args['-o'])
+        self.assertFalse(args['--proptest'])
 
         args = cli.parse_arguments(['-o', 'out', 'test.bfbs'])
         self.assertEqual('out', args['-o'])
         self.assertEqual('test.bfbs', args['<bfbs>'])
+
+        args = cli.parse_arguments(['--proptest', 'test.bfbs'])
+        self.assertTrue(args['--proptest'])
+        self.assertFalse(args['--borrowed'])
+
+        args = cli.parse_arguments(['--proptest', '--borrowed', 'test.bfbs'])
+        self.assertTrue(args['--borrowed'])
@@ -24,3 +24,339 @@ class TestContext(TestCase):
         self.assertEqual(
             'out_html_page', self.context.camel_to_snake('OutHTMLPage'))
         self.assertEqual('p2p', self.context.camel_to_snake('P2P'))
+
+    def testFieldStrategy(self):
+        header = next(self.context.schema.Objects(i) for i in range(self.context.schema.ObjectsLength())
+                      if self.context.schema.Objects(i).Name() == b'Ckb.Protocol.Header')
+        fields = dict((f.Name(), f) for f in self.context.fields_sorted_by_offset(header))
+
+        self.assertEqual('any::<u32>()', self.context.field_strategy(fields[b'version']))
+        self.assertEqual('any::<H256>()', self.context.field_strategy(fields[b'parent_hash']))
+        self.assertEqual('strategy::option(&config, any_with::<Bytes>(config.clone()))',
+                         self.context.field_strategy(fields[b'proof']))
+        self.assertTrue(self.context.strategies_use_config(fields.values()))
+
+    def testFieldValueCheck(self):
+        message = next(self.context.schema.Objects(i) for i in range(self.context.schema.ObjectsLength())
+                       if self.context.schema.Objects(i).Name() == b'Ckb.Protocol.SyncMessage')
+        fields = dict((f.Name(), f) for f in self.context.fields_sorted_by_offset(message))
+
+        self.assertEqual(('u8', '0..=8', None), self.context.field_value_check(fields[b'payload_type']))
+        self.assertIsNone(self.context.field_value_check(fields[b'payload']))
+
+    def testReaderType(self):
+        header = next(self.context.schema.Objects(i) for i in range(self.context.schema.ObjectsLength())
+                      if self.context.schema.Objects(i).Name() == b'Ckb.Protocol.Header')
+        fields = dict((f.Name(), f) for f in self.context.fields_sorted_by_offset(header))
+
+        self.assertEqual('u32', self.context.reader_type(fields[b'version']))
+        self.assertEqual("Option<&'a reader::H256>", self.context.reader_type(fields[b'parent_hash']))
+        self.assertEqual("Option<reader::Bytes<'a>>", self.context.reader_type(fields[b'proof']))
+
+
+class TestDefaultValue(TestCase):
+    def setUp(self):
+        dir_path = path.join(path.dirname(path.dirname(path.realpath(
+            __file__))), 'tests', 'common', 'default_value.bfbs')
+        with open(dir_path, 'rb') as bfbs_file:
+            schema = Schema.GetRootAsSchema(bytearray(bfbs_file.read()), 0)
+        self.context = Context('default_value', schema)
+        hero = schema.Objects(0)
+        self.hero = hero
+        self.fields = dict((f.Name(), f) for f in self.context.fields_sorted_by_offset(hero))
+
+    def testFieldDefault(self):
+        self.assertEqual('100u32', self.context.field_default(self.fields[b'hp']))
+        self.assertEqual('-1i16', self.context.field_default(self.fields[b'mp']))
+        self.assertEqual('1.5f32', self.context.field_default(self.fields[b'speed']))
+        self.assertEqual('true', self.context.field_default(self.fields[b'alive']))
+        self.assertEqual('Color::Blue', self.context.field_default(self.fields[b'color']))
+        self.assertEqual('0u64', self.context.field_default(self.fields[b'exp']))
+
+    def testEnumDefault(self):
+        color = self.context.schema.Enums(0)
+        self.assertEqual('Color::Red', self.context.enum_default(color))
+        # Values not declared in the schema are kept as the raw value.
+        self.assertEqual('Color(5)', self.context.enum_default(color, 5))
+
+    def testFieldPresent(self):
+        self.assertEqual('self.hp != 100u32', self.context.field_present(self.fields[b'hp']))
+        self.assertEqual('!self.alive', self.context.field_present(self.fields[b'alive']))
+
+    def testFieldFloatCheck(self):
+        self.assertEqual('verify_float::<f32>(buf, loc)',
+                         self.context.field_float_check(self.fields[b'speed'], 'buf', 'loc'))
+        self.assertIsNone(self.context.field_float_check(self.fields[b'hp'], 'buf', 'loc'))
+
+    def testFieldDefaultCheck(self):
+        self.assertEqual(('u32', 'value == 100u32'), self.context.field_default_check(self.fields[b'hp']))
+        self.assertEqual(('u8', 'value == 1'), self.context.field_default_check(self.fields[b'alive']))
+        self.assertEqual(('i8', 'value == 2i8'), self.context.field_default_check(self.fields[b'color']))
+        self.assertIsNone(self.context.field_default_check(self.fields[b'name']))
+
+    def testHasCustomDefaults(self):
+        self.assertTrue(self.context.has_custom_default(self.fields[b'color']))
+        self.assertFalse(self.context.has_custom_default(self.fields[b'exp']))
+        self.assertFalse(self.context.has_custom_default(self.fields[b'name']))
+        self.assertTrue(self.context.has_custom_defaults(self.hero))
+
+
+class TestOptional(TestCase):
+    def setUp(self):
+        dir_path = path.join(path.dirname(path.dirname(path.realpath(
+            __file__))), 'tests', 'common', 'optional.bfbs')
+        with open(dir_path, 'rb') as bfbs_file:
+            schema = Schema.GetRootAsSchema(bytearray(bfbs_file.read()), 0)
+        self.context = Context('optional', schema)
+        hero = next(schema.Objects(i) for i in range(schema.ObjectsLength())
+                    if schema.Objects(i).Name() == b'example.Hero')
+        self.fields = dict((f.Name(), f) for f in self.context.fields_sorted_by_offset(hero))
+
+    def testIsOptional(self):
+        self.assertTrue(self.context.is_optional(self.fields[b'hp']))
+        self.assertTrue(self.context.is_optional(self.fields[b'color']))
+        self.assertTrue(self.context.is_optional(self.fields[b'hash']))
+        self.assertFalse(self.context.is_optional(self.fields[b'checksum']))
+
+    def testFieldType(self):
+        self.assertEqual('Option<u32>', self.context.field_type(self.fields[b'hp']))
+        self.assertEqual('Option<Color>', self.context.field_type(self.fields[b'color']))
+        self.assertEqual('Option<Hash>', self.context.field_type(self.fields[b'hash']))
+        self.assertEqual('Hash', self.context.field_type(self.fields[b'checksum']))
+
+    def testFieldPresent(self):
+        self.assertEqual('self.alive.is_some()', self.context.field_present(self.fields[b'alive']))
+        self.assertEqual('let Some(f) = self.hash', self.context.field_present(self.fields[b'hash'], 'f'))
+        self.assertEqual('self.checksum.is_present()', self.context.field_present(self.fields[b'checksum']))
+
+    def testReaderType(self):
+        self.assertEqual('Option<u32>', self.context.reader_type(self.fields[b'hp']))
+        self.assertEqual("Option<&'a reader::Hash>", self.context.reader_type(self.fields[b'hash']))
+
+
+class TestDeprecated(TestCase):
+    def setUp(self):
+        dir_path = path.join(path.dirname(path.dirname(path.realpath(
+            __file__))), 'tests', 'common', 'deprecated.bfbs')
+        with open(dir_path, 'rb') as bfbs_file:
+            schema = Schema.GetRootAsSchema(bytearray(bfbs_file.read()), 0)
+        self.context = Context('deprecated', schema)
+        self.hero = schema.Objects(0)
+
+    def testTableFields(self):
+        self.assertEqual([b'hp', b'mp'], [f.Name() for f in self.context.table_fields(self.hero)])
+        self.assertEqual([b'name', b'old_mp'], [f.Name() for f in self.context.deprecated_fields(self.hero)])
+
+    def testVtableNumBytes(self):
+        # The deprecated fields still have vtable entries, so they are not unknown fields.
+        self.assertEqual(12, self.context.vtable_num_bytes(self.hero))
+
+
+class TestSortedVector(TestCase):
+    def setUp(self):
+        dir_path = path.join(path.dirname(path.dirname(path.realpath(
+            __file__))), 'tests', 'common', 'sorted_vector.bfbs')
+        with open(dir_path, 'rb') as bfbs_file:
+            schema = Schema.GetRootAsSchema(bytearray(bfbs_file.read()), 0)
+        self.context = Context('sorted_vector', schema)
+        self.fields = dict((f.Name(), f) for f in self.context.fields_sorted_by_offset(schema.Objects(0)))
+
+    def testElementKeyField(self):
+        self.assertEqual(b'name', self.context.element_key_field(self.fields[b'monsters']).Name())
+        self.assertEqual(b'id', self.context.element_key_field(self.fields[b'stats']).Name())
+
+    def testKeyCmp(self):
+        key = self.context.element_key_field(self.fields[b'stats'])
+        self.assertEqual('u32', self.context.key_type(key))
+        self.assertEqual('self.id.cmp(&other.id)', self.context.key_cmp(key, 'self.id', 'other.id'))
+
+
+class TestUnionVector(TestCase):
+    def setUp(self):
+        dir_path = path.join(path.dirname(path.dirname(path.realpath(
+            __file__))), 'tests', 'common', 'union_vector.bfbs')
+        with open(dir_path, 'rb') as bfbs_file:
+            schema = Schema.GetRootAsSchema(bytearray(bfbs_file.read()), 0)
+        self.context = Context('union_vector', schema)
+        self.fields = dict((f.Name(), f) for f in self.context.fields_sorted_by_offset(schema.Objects(2)))
+
+    def testIsUnion(self):
+        self.assertTrue(self.context.is_union_type(self.fields[b'members_type']))
+        self.assertFalse(self.context.is_element_union(self.fields[b'members_type']))
+        self.assertTrue(self.context.is_element_union(self.fields[b'members']))
+
+    def testFieldPresent(self):
+        self.assertEqual('!self.members.is_empty()', self.context.field_present(self.fields[b'members_type']))
+        self.assertEqual('!self.members.is_empty()', self.context.field_present(self.fields[b'members']))
+
+
+class TestUnionVariants(TestCase):
+    def setUp(self):
+        dir_path = path.join(path.dirname(path.dirname(path.realpath(
+            __file__))), 'tests', 'common', 'union_variants.bfbs')
+        with open(dir_path, 'rb') as bfbs_file:
+            schema = Schema.GetRootAsSchema(bytearray(bfbs_file.read()), 0)
+        self.context = Context('union_variants', schema)
+        self.variants = dict((v.Name(), v) for v in self.context.union_variants(schema.Enums(0)))
+
+    def testVariantType(self):
+        self.assertEqual('Hero', self.context.variant_type(self.variants[b'Hero']))
+        self.assertEqual('Point', self.context.variant_type(self.variants[b'Point']))
+        self.assertEqual('String', self.context.variant_type(self.variants[b'Name']))
+
+    def testVariantComponent(self):
+        self.assertEqual('v', self.context.variant_component(self.variants[b'Hero'], 'v'))
+        self.assertEqual('StructComponent::new(v, 4)', self.context.variant_component(self.variants[b'Point'], 'v'))
+        self.assertEqual('StringComponent::new(v)', self.context.variant_component(self.variants[b'Name'], 'v'))
+
+    def testVariantVerify(self):
+        self.assertEqual('verify_struct(buf, loc, 8, 4).and_then(|_| verification.visit(loc, loc + 8))',
+                         self.context.variant_verify(self.variants[b'Point'], 'loc'))
+        self.assertEqual('StringVerifier::follow(buf, loc).verify_in(verification)',
+                         self.context.variant_verify(self.variants[b'Name'], 'loc'))
+
+
+class TestForceAlign(TestCase):
+    def setUp(self):
+        dir_path = path.join(path.dirname(path.dirname(path.realpath(
+            __file__))), 'tests', 'common', 'force_align.bfbs')
+        with open(dir_path, 'rb') as bfbs_file:
+            schema = Schema.GetRootAsSchema(bytearray(bfbs_file.read()), 0)
+        self.context = Context('force_align', schema)
+        self.vec4 = schema.Objects(2)
+        block = schema.Objects(0)
+        self.fields = dict((f.Name(), f) for f in self.context.table_fields(block))
+
+    def testForceAlign(self):
+        self.assertEqual(16, self.context.force_align(self.vec4))
+        self.assertEqual(32, self.context.force_align(self.fields[b'bytes']))
+        self.assertEqual(0, self.context.force_align(self.fields[b'points']))
+
+    def testElementAlignment(self):
+        self.assertEqual(32, self.context.element_aligment(self.fields[b'bytes']))
+        self.assertEqual(16, self.context.element_aligment(self.fields[b'flags']))
+        self.assertEqual(16, self.context.element_aligment(self.fields[b'points']))
+        self.assertEqual(16, self.context.field_alignment(self.fields[b'position']))
+
+    def testStructFloats(self):
+        self.assertEqual([(0, 'f32'), (4, 'f32'), (8, 'f32'), (12, 'f32')],
+                         self.context.struct_floats(self.vec4))
+        self.assertEqual('reader::Vec4::verify_canonical_floats(buf, loc)',
+                         self.context.element_float_check(self.fields[b'points'], 'buf', 'loc'))
+        self.assertIsNone(self.context.element_float_check(self.fields[b'bytes'], 'buf', 'loc'))
+
+
+class TestOffset64(TestCase):
+    def setUp(self):
+        dir_path = path.join(path.dirname(path.dirname(path.realpath(
+            __file__))), 'tests', 'common', 'offset64.bfbs')
+        with open(dir_path, 'rb') as bfbs_file:
+            schema = Schema.GetRootAsSchema(bytearray(bfbs_file.read()), 0)
+        self.context = Context('offset64', schema)
+        snapshot = schema.Objects(1)
+        self.fields = dict((f.Name(), f) for f in self.context.table_fields(snapshot))
+
+    def testIsOffset64(self):
+        self.assertTrue(self.context.is_offset64(self.fields[b'blocks']))
+        self.assertTrue(self.context.is_offset64(self.fields[b'points']))
+        self.assertFalse(self.context.is_offset64(self.fields[b'ids']))
+        self.assertFalse(self.context.is_offset64(self.fields[b'name']))
+
+    def testOffset64Size(self):
+        self.assertEqual(8, self.context.field_size(self.fields[b'blocks']))
+        self.assertEqual(8, self.context.field_alignment(self.fields[b'points']))
+        self.assertEqual(4, self.context.field_size(self.fields[b'ids']))
+        self.assertEqual(4, self.context.element_aligment(self.fields[b'points']))
+
+
+class TestBitFlags(TestCase):
+    def setUp(self):
+        dir_path = path.join(path.dirname(path.dirname(path.realpath(
+            __file__))), 'tests', 'common', 'bit_flags.bfbs')
+        with open(dir_path, 'rb') as bfbs_file:
+            schema = Schema.GetRootAsSchema(bytearray(bfbs_file.read()), 0)
+        self.context = Context('bit_flags', schema)
+        self.enum = schema.Enums(0)
+        self.fields = dict((f.Name(), f) for f in self.context.table_fields(schema.Objects(0)))
+
+    def testIsBitFlags(self):
+        self.assertTrue(self.context.is_bit_flags(self.enum))
+        self.assertEqual(7, self.context.enum_mask(self.enum))
+
+    def testEnumDefault(self):
+        self.assertEqual('Permission::empty()', self.context.enum_default(self.enum))
+        self.assertEqual('Permission::Read', self.context.field_default(self.fields[b'permission']))
+        self.assertEqual('(Permission::Read | Permission::Execute)', self.context.enum_default(self.enum, 5))
+
+    def testValueCheck(self):
+        check = self.context.field_value_check(self.fields[b'permission'])
+        self.assertEqual(('u8', None, 7), check)
+        self.assertEqual('value & !7 == 0', self.context.value_is_declared(check, 'value'))
+
+
+class TestValidate(TestCase):
+    def setUp(self):
+        dir_path = path.join(path.dirname(path.dirname(path.realpath(
+            __file__))), 'tests', 'common', 'validate.bfbs')
+        with open(dir_path, 'rb') as bfbs_file:
+            schema = Schema.GetRootAsSchema(bytearray(bfbs_file.read()), 0)
+        self.context = Context('validate', schema)
+        self.schema = schema
+
+    def testHasValidate(self):
+        for i in range(self.schema.ObjectsLength()):
+            self.assertTrue(self.context.has_validate(self.schema.Objects(i)))
+
+        dir_path = path.join(path.dirname(path.dirname(path.realpath(
+            __file__))), 'tests', 'common', 'data_order.bfbs')
+        with open(dir_path, 'rb') as bfbs_file:
+            schema = Schema.GetRootAsSchema(bytearray(bfbs_file.read()), 0)
+        self.assertFalse(self.context.has_validate(schema.Objects(0)))
+
+
+class TestLengthBounds(TestCase):
+    def setUp(self):
+        dir_path = path.join(path.dirname(path.dirname(path.realpath(
+            __file__))), 'tests', 'common', 'length_bounds.bfbs')
+        with open(dir_path, 'rb') as bfbs_file:
+            schema = Schema.GetRootAsSchema(bytearray(bfbs_file.read()), 0)
+        self.context = Context('length_bounds', schema)
+        self.fields = dict((f.Name(), f) for f in self.context.table_fields(schema.Objects(0)))
+
+    def testLengthBounds(self):
+        self.assertEqual(('1', '8'), self.context.length_bounds(self.fields[b'code_hash']))
+        self.assertEqual(('0', '3'), self.context.length_bounds(self.fields[b'args']))
+        self.assertIsNone(self.context.length_bounds(self.fields[b'hash_type']))
+
+    def testFieldStrategy(self):
+        self.assertEqual('strategy::bounded_string(&config, 1, 8)',
+                         self.context.field_strategy(self.fields[b'code_hash']))
+        self.assertEqual('strategy::bounded_vec(strategy::string(&config), config.vector_len.clone(), 0, 3)',
+                         self.context.field_strategy(self.fields[b'args']))
+
+
+class TestBorrowed(TestCase):
+    def setUp(self):
+        dir_path = path.join(path.dirname(path.dirname(path.realpath(
+            __file__))), 'tests', 'common', 'union_variants.bfbs')
+        with open(dir_path, 'rb') as bfbs_file:
+            schema = Schema.GetRootAsSchema(bytearray(bfbs_file.read()), 0)
+        self.context = Context('union_variants', schema, borrowed=True)
+        self.schema = schema
+
+    def testRefName(self):
+        self.assertEqual('HeroRef', self.context.ref_name(self.context.object_by_name('Hero')))
+        self.assertEqual("PlayerRef<'c>", self.context.ref_name(self.context.object_by_name('Player'), "'c"))
+        self.assertEqual("RoleRef<'a>", self.context.ref_name(self.schema.Enums(0)))
+
+    def testRefFieldType(self):
+        player = self.context.object_by_name('Player')
+        fields = dict((f.Name(), f) for f in self.context.builder_fields(player))
+        self.assertEqual("Option<RoleRef<'a>>", self.context.ref_field_type(fields[b'role']))
+        self.assertEqual("&'a [RoleRef<'a>]", self.context.ref_field_type(fields[b'roles']))
+
+    def testRefVariantType(self):
+        variants = dict((v.Name(), v) for v in self.context.union_variants(self.schema.Enums(0)))
+        self.assertEqual('HeroRef', self.context.ref_variant_type(variants[b'Hero']))
+        self.assertEqual('Point', self.context.ref_variant_type(variants[b'Point']))
+        self.assertEqual("&'a str", self.context.ref_variant_type(variants[b'Name']))
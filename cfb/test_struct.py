@@ -29,3 +29,29 @@ class TestStruct(TestCase):
         self.assertEqual('u8', fields[1].paddings[0].ty)
         self.assertEqual('u16', fields[1].paddings[1].ty)
         self.assertEqual('u32', fields[1].paddings[2].ty)
+
+    def test_struct_padded_fields_with_arrays(self):
+        dir_path = path.join(path.dirname(path.dirname(path.realpath(
+            __file__))), 'tests', 'common', 'struct_array.bfbs')
+        with open(dir_path, 'rb') as bfbs_file:
+            buf = bytearray(bfbs_file.read())
+            schema = Schema.GetRootAsSchema(buf, 0)
+
+        context = Context("struct_array", schema)
+        fields = struct_padded_fields(context, schema.Objects(0))
+
+        self.assertEqual([b'version', b'numbers', b'hash', b'corners'], [f.field.Name() for f in fields])
+        self.assertEqual(['u16'], [p.ty for p in fields[0].paddings])
+        self.assertEqual([], fields[1].paddings + fields[2].paddings + fields[3].paddings)
+
+        self.assertEqual('[u32; 3]', context.field_type(fields[1].field))
+        self.assertEqual(12, context.field_size(fields[1].field))
+        self.assertEqual(4, context.field_alignment(fields[1].field))
+        self.assertEqual('[Point; 2]', context.field_type(fields[3].field))
+        self.assertEqual(8, context.field_size(fields[3].field))
+        self.assertEqual(2, context.field_alignment(fields[3].field))
+
+        self.assertFalse(context.has_large_arrays(schema.Objects(0)))
+        signature = schema.Objects(3)
+        self.assertTrue(context.has_large_arrays(signature))
+        self.assertEqual('strategy::array::<_, 65>(any::<u8>())', context.field_strategy(signature.Fields(0)))
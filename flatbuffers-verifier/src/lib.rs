@@ -1,13 +1,53 @@
-use flatbuffers::{read_scalar, Follow, UOffsetT, SIZE_SIZEPREFIX, SIZE_UOFFSET};
+use flatbuffers::{
+    EndianScalar, Follow, SOffsetT, Table, UOffsetT, VOffsetT, Vector, SIZE_SIZEPREFIX,
+    SIZE_SOFFSET, SIZE_UOFFSET, SIZE_VOFFSET,
+};
+use std::cell::{Cell, RefCell};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
 use std::error;
 use std::fmt;
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::ops::Deref;
+use std::ptr;
 use std::result;
+use std::str;
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
     OutOfBounds,
     NonNullTerminatedString,
     UnmatchedUnion,
+    Misaligned,
+    NonUtf8String,
+    UnknownEnumValue,
+    InvalidBool,
+    DeprecatedField,
+    UnsortedKeys,
+    DuplicateKey,
+    MissingRequiredField,
+    NonCanonicalFloat,
+    UnknownField,
+    SharedObject,
+    OverlappingObjects,
+    WorkBudgetExceeded,
+    DefaultScalar,
+    /// The object at `path` is rejected by its `Validate` impl with `message`.
+    Invalid {
+        path: FieldPath,
+        message: String,
+    },
+    /// The string or vector `field`, named like `Table.field`, has `len` bytes or elements, out of
+    /// the bounds `min..=max` set by the attributes `cfb_min_len` and `cfb_max_len`.
+    LengthOutOfBounds {
+        field: &'static str,
+        len: usize,
+        min: usize,
+        max: usize,
+    },
 }
 
 pub type Result = result::Result<(), Error>;
@@ -18,20 +58,729 @@ impl fmt::Display for Error {
             Error::OutOfBounds => write!(f, "memory access is out of bounds"),
             Error::NonNullTerminatedString => write!(f, "string is not terminated with null"),
             Error::UnmatchedUnion => write!(f, "union type and value does not match"),
+            Error::Misaligned => write!(f, "memory access is misaligned"),
+            Error::NonUtf8String => write!(f, "string is not valid UTF-8"),
+            Error::UnknownEnumValue => write!(f, "enum value is not declared in the schema"),
+            Error::InvalidBool => write!(f, "bool value is neither 0 nor 1"),
+            Error::DeprecatedField => write!(f, "deprecated field is present"),
+            Error::UnsortedKeys => write!(f, "vector is not sorted by key"),
+            Error::DuplicateKey => write!(f, "vector contains duplicate keys"),
+            Error::MissingRequiredField => write!(f, "required field is absent"),
+            Error::NonCanonicalFloat => write!(f, "float is a NaN with payload or negative zero"),
+            Error::UnknownField => write!(f, "vtable has fields unknown to the schema"),
+            Error::SharedObject => write!(f, "object is referenced more than once"),
+            Error::OverlappingObjects => write!(f, "objects overlap in the buffer"),
+            Error::WorkBudgetExceeded => write!(f, "verification exceeds the work budget"),
+            Error::DefaultScalar => write!(f, "scalar is present with its default value"),
+            Error::Invalid { path, message } => write!(f, "{}: {}", path, message),
+            Error::LengthOutOfBounds {
+                field, len, min, ..
+            } if len < min => write!(f, "{} has length {}, less than {}", field, len, min),
+            Error::LengthOutOfBounds {
+                field, len, max, ..
+            } => {
+                write!(f, "{} has length {}, more than {}", field, len, max)
+            }
         }
     }
 }
 
 impl error::Error for Error {}
 
+/// Configures how buffers are verified.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Options {
+    /// Rejects tables which contain fields deprecated in the schema. They are ignored by default.
+    pub reject_deprecated_fields: bool,
+    /// Rejects tables whose vtable has entries beyond the fields of the schema, such as fields
+    /// added by a newer schema.
+    ///
+    /// By default such fields are accepted for forward compatibility, and only their offsets are
+    /// checked to be within the table, since their types are unknown.
+    pub reject_unknown_fields: bool,
+    /// Rejects buffers in which a table, string, vector or union struct is referenced more than
+    /// once, or overlaps another one.
+    ///
+    /// The builder never shares objects, so every object of a canonical buffer is visited exactly
+    /// once. Vtables are not objects and may still be shared.
+    pub reject_shared_objects: bool,
+    /// Limits the total number of bytes of the objects visited by the verification.
+    ///
+    /// An object referenced several times is charged on every visit, so a small buffer cannot make
+    /// the verification arbitrarily slow, such as with a vector of offsets to one large table.
+    /// A buffer without shared objects never needs more than its length.
+    pub max_work: Option<usize>,
+    /// Rejects scalar fields which are present with the default value declared in the schema.
+    ///
+    /// The builder omits such fields, so they are only found in buffers built by other writers,
+    /// which are accepted by default.
+    pub reject_default_scalars: bool,
+    /// Accepts enum values and union tags which are not declared in the schema, such as the ones
+    /// added by a newer schema.
+    ///
+    /// The flatc readers represent enums as Rust enums, which cannot hold an undeclared value. So
+    /// only `verify_root` accepts such buffers, and the root has to be read with `get_lazy_root`
+    /// and the generated `raw_` or `try_` accessors. The APIs which return a reader still reject
+    /// them, and so do the tables with the attribute `cfb_validate` and the vectors sorted by key
+    /// which contain such values, since `Validate` and the key comparison read them with the
+    /// reader.
+    ///
+    /// Since the type of a union value with an undeclared tag is unknown, only its offset is
+    /// checked to be within the buffer.
+    pub accept_unknown_enum_values: bool,
+}
+
+const DEFAULT_OPTIONS: Options = Options {
+    reject_deprecated_fields: false,
+    reject_unknown_fields: false,
+    reject_shared_objects: false,
+    max_work: None,
+    reject_default_scalars: false,
+    accept_unknown_enum_values: false,
+};
+
+impl Options {
+    /// Verifies the buffer without returning the root, which is the only API that accepts
+    /// undeclared enum values when `accept_unknown_enum_values` is set.
+    ///
+    /// Read the verified buffer with `get_lazy_root` and the generated `raw_` or `try_` accessors,
+    /// since the plain accessors cannot represent undeclared enum values.
+    pub fn verify_root<'a, T>(&self, data: &'a [u8]) -> Result
+    where
+        T: Follow<'a> + 'a,
+        T::Inner: Verify,
+    {
+        if data.len() < SIZE_UOFFSET {
+            return Err(Error::OutOfBounds);
+        }
+
+        follow_root::<T>(data, 0).verify_in(&Verification::new(self))
+    }
+
+    pub fn get_root<'a, T>(&self, data: &'a [u8]) -> result::Result<T::Inner, Error>
+    where
+        T: Follow<'a> + 'a,
+        T::Inner: Verify,
+    {
+        if data.len() < SIZE_UOFFSET {
+            return Err(Error::OutOfBounds);
+        }
+
+        let root = follow_root::<T>(data, 0);
+        root.verify_with(self)?;
+        Ok(root)
+    }
+
+    /// Verifies the buffer like `get_root`, and also returns a report of the objects in it.
+    pub fn get_root_with_report<'a, T>(
+        &self,
+        data: &'a [u8],
+    ) -> result::Result<(T::Inner, VerificationReport), Error>
+    where
+        T: Follow<'a> + 'a,
+        T::Inner: Verify,
+    {
+        if data.len() < SIZE_UOFFSET {
+            return Err(Error::OutOfBounds);
+        }
+
+        let verification = Verification::with_report(self, data.len());
+        verification.cover(0, SIZE_UOFFSET);
+        let root = follow_root::<T>(data, 0);
+        root.verify_in(&verification)?;
+        verification.verify_readable()?;
+        let report = verification.report().expect("verification with report");
+        Ok((root, report))
+    }
+
+    /// Verifies as much of the buffer as possible and collects every violation, instead of
+    /// stopping at the first error.
+    ///
+    /// A table whose layout is invalid is skipped along with its fields, and so is a vector once
+    /// one of its elements is. The verifier never panics, whatever the input.
+    pub fn salvage_root<'a, T>(&self, data: &'a [u8]) -> Salvaged<T::Inner>
+    where
+        T: Follow<'a> + 'a,
+        T::Inner: Verify,
+    {
+        if data.len() < SIZE_UOFFSET {
+            return Salvaged {
+                root: None,
+                violations: vec![Violation {
+                    table_loc: 0,
+                    field: None,
+                    error: Error::OutOfBounds,
+                }],
+            };
+        }
+
+        let verification = Verification::salvaging(self);
+        let root = follow_root::<T>(data, 0);
+        let result = root.verify_in(&verification);
+        let mut violations = verification.violations();
+        // The errors which are never salvaged, an invalid root table or an exceeded work budget,
+        // stop the verification.
+        if let Err(error) = result {
+            violations.push(Violation {
+                table_loc: read_uoffset(data, 0),
+                field: None,
+                error,
+            });
+        }
+        Salvaged {
+            root: Some(root),
+            violations,
+        }
+    }
+
+    pub fn get_size_prefixed_root<'a, T>(&self, data: &'a [u8]) -> result::Result<T::Inner, Error>
+    where
+        T: Follow<'a> + 'a,
+        T::Inner: Verify,
+    {
+        if data.len() < SIZE_SIZEPREFIX + SIZE_UOFFSET {
+            return Err(Error::OutOfBounds);
+        }
+
+        let root = follow_root::<T>(data, SIZE_SIZEPREFIX);
+        root.verify_with(self)?;
+        Ok(root)
+    }
+
+    pub fn get_verified_root<'a, T>(
+        &self,
+        data: &'a [u8],
+    ) -> result::Result<Verified<'a, T::Inner>, Error>
+    where
+        T: Follow<'a> + 'a,
+        T::Inner: Verify,
+    {
+        self.get_root::<T>(data)
+            .map(|root| Verified { buf: data, root })
+    }
+}
+
 pub trait Verify {
-    fn verify(&self) -> Result;
+    fn verify(&self) -> Result {
+        self.verify_with(&Options::default())
+    }
+
+    /// Verifies the object, and rejects it if it contains undeclared enum values, which the flatc
+    /// readers cannot represent, even if `Options::accept_unknown_enum_values` is set.
+    fn verify_with(&self, options: &Options) -> Result {
+        let verification = Verification::new(options);
+        self.verify_in(&verification)?;
+        verification.verify_readable()
+    }
+
+    /// Verifies the object as a part of the buffer whose objects are tracked by `verification`.
+    fn verify_in(&self, verification: &Verification) -> Result;
+}
+
+/// Semantic checks of a table, which the generated `Verify` impl of a table with the attribute
+/// `cfb_validate` calls after its structural checks pass.
+///
+/// The fields can be read with the flatbuffers accessors, since the table and everything it
+/// references have been verified. The error message is reported as `Error::Invalid` along with
+/// `path`.
+pub trait Validate {
+    fn validate(&self, path: &FieldPath) -> result::Result<(), String>;
+}
+
+/// A segment of a `FieldPath`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PathSegment {
+    Field(&'static str),
+    Index(usize),
 }
 
-pub const MAX_OFFSET_LOC: usize = usize::max_value() - SIZE_UOFFSET;
+/// The path from the root table to an object, displayed like `$.loots[1].name`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FieldPath(Vec<PathSegment>);
+
+impl FieldPath {
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "$")?;
+        for segment in &self.0 {
+            match segment {
+                PathSegment::Field(name) => write!(f, ".{}", name)?,
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Statistics of a verified buffer, returned by `get_root_with_report`.
+///
+/// Objects referenced several times are counted on every visit, while vtables are counted once
+/// however many tables share them.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VerificationReport {
+    pub tables: usize,
+    pub vtables: usize,
+    pub strings: usize,
+    pub vectors: usize,
+    /// The total number of elements of all the vectors, including strings in vectors but not the
+    /// bytes of strings.
+    pub elements: usize,
+    /// The number of elements in the longest vector.
+    pub max_vector_len: usize,
+    /// The depth of the most nested table, which is 1 for the root table.
+    pub max_depth: usize,
+    /// The number of bytes occupied by the root uoffset, the vtables and the objects, which
+    /// excludes the padding and the bytes no offset refers to.
+    pub covered_bytes: usize,
+    pub buffer_len: usize,
+}
+
+impl VerificationReport {
+    /// Returns the number of bytes which are neither referenced nor a part of a referenced
+    /// object, such as the padding.
+    pub fn uncovered_bytes(&self) -> usize {
+        self.buffer_len - self.covered_bytes
+    }
+}
+
+#[derive(Debug, Default)]
+struct ReportState {
+    report: VerificationReport,
+    /// The ranges covered by the root uoffset and the vtables, mapping the start of each to its
+    /// end.
+    ranges: BTreeMap<usize, usize>,
+}
+
+/// A violation found by `salvage_root`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Violation {
+    /// The location of the table with the invalid field.
+    pub table_loc: usize,
+    /// The vtable offset of the invalid field, or `None` if the table itself is invalid, such as
+    /// when its vtable is out of bounds or has unknown fields.
+    pub field: Option<VOffsetT>,
+    pub error: Error,
+}
+
+/// The result of `salvage_root`.
+#[derive(Clone, Debug)]
+pub struct Salvaged<T> {
+    /// The root, unless the root offset itself is invalid.
+    ///
+    /// The buffer may still be invalid, so it must be read with the checked `try_` accessors.
+    pub root: Option<T>,
+    /// Every violation found, which is empty if and only if the buffer passes the verification.
+    pub violations: Vec<Violation>,
+}
+
+/// The state of the verification of a buffer.
+///
+/// It records the objects visited so far, to charge them against `Options::max_work`, to detect
+/// shared and overlapping objects when `Options::reject_shared_objects` is set, and to build the
+/// `VerificationReport` if one is requested.
+#[derive(Debug)]
+pub struct Verification<'o> {
+    options: &'o Options,
+    /// The visited objects, mapping the start of each to its end.
+    objects: RefCell<BTreeMap<usize, usize>>,
+    work: Cell<usize>,
+    depth: Cell<usize>,
+    report: Option<RefCell<ReportState>>,
+    /// The violations found so far, if the buffer is being salvaged.
+    violations: Option<RefCell<Vec<Violation>>>,
+    /// The path to the object being verified.
+    path: RefCell<Vec<PathSegment>>,
+    /// The number of undeclared enum values and union tags accepted so far.
+    unknown_enum_values: Cell<usize>,
+}
+
+impl<'o> Verification<'o> {
+    pub fn new(options: &'o Options) -> Self {
+        Verification {
+            options,
+            objects: RefCell::new(BTreeMap::new()),
+            work: Cell::new(0),
+            depth: Cell::new(0),
+            report: None,
+            violations: None,
+            path: RefCell::new(Vec::new()),
+            unknown_enum_values: Cell::new(0),
+        }
+    }
+
+    /// Creates the state which salvages a buffer: the checks record their errors as violations
+    /// and carry on instead of failing.
+    pub fn salvaging(options: &'o Options) -> Self {
+        Verification {
+            violations: Some(RefCell::new(Vec::new())),
+            ..Verification::new(options)
+        }
+    }
+
+    /// Returns the violations found so far, which is empty unless the buffer is being salvaged.
+    pub fn violations(&self) -> Vec<Violation> {
+        self.violations
+            .as_ref()
+            .map_or_else(Vec::new, |violations| violations.borrow().clone())
+    }
+
+    fn num_violations(&self) -> usize {
+        self.violations
+            .as_ref()
+            .map_or(0, |violations| violations.borrow().len())
+    }
+
+    /// Returns whether a violation other than `Error::Invalid` was found after the first `start`
+    /// ones, which means the objects may be unsafe to read with the flatbuffers reader.
+    fn is_malformed_since(&self, start: usize) -> bool {
+        self.violations.as_ref().is_some_and(|violations| {
+            violations.borrow()[start..]
+                .iter()
+                .any(|violation| !matches!(violation.error, Error::Invalid { .. }))
+        })
+    }
+
+    /// Records the error in `result` as a violation of the field of the table at `table_loc`
+    /// if the buffer is being salvaged, and returns `Ok` so the verification carries on.
+    ///
+    /// `WorkBudgetExceeded` is never salvaged, since carrying on would defeat the budget.
+    pub fn salvage(&self, table_loc: usize, field: Option<VOffsetT>, result: Result) -> Result {
+        match (result, &self.violations) {
+            (Err(error), Some(violations)) if error != Error::WorkBudgetExceeded => {
+                violations.borrow_mut().push(Violation {
+                    table_loc,
+                    field,
+                    error,
+                });
+                Ok(())
+            }
+            (result, _) => result,
+        }
+    }
+
+    /// Creates the state which also collects a `VerificationReport` of a buffer of `buffer_len`
+    /// bytes.
+    pub fn with_report(options: &'o Options, buffer_len: usize) -> Self {
+        let report = ReportState {
+            report: VerificationReport {
+                buffer_len,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        Verification {
+            report: Some(RefCell::new(report)),
+            ..Verification::new(options)
+        }
+    }
+
+    /// Returns the report of the objects visited so far, if it is collected.
+    pub fn report(&self) -> Option<VerificationReport> {
+        let state = self.report.as_ref()?.borrow();
+        let objects = self.objects.borrow();
+
+        let mut ranges: Vec<(usize, usize)> = objects
+            .iter()
+            .chain(state.ranges.iter())
+            .map(|(start, end)| (*start, *end))
+            .collect();
+        ranges.sort_unstable();
+        let mut covered_bytes = 0;
+        let mut covered_end = 0;
+        for (start, end) in ranges {
+            let start = start.max(covered_end);
+            if end > start {
+                covered_bytes += end - start;
+                covered_end = end;
+            }
+        }
+
+        Some(VerificationReport {
+            covered_bytes,
+            ..state.report.clone()
+        })
+    }
+
+    fn update_report<F: FnOnce(&mut ReportState)>(&self, f: F) {
+        if let Some(state) = &self.report {
+            f(&mut state.borrow_mut());
+        }
+    }
+
+    /// Marks `buf[start..end]` as covered in the report, for the bytes which are not objects.
+    fn cover(&self, start: usize, end: usize) {
+        self.update_report(|state| {
+            state.ranges.insert(start, end);
+        });
+    }
+
+    fn enter_table(&self, vtab_loc: usize, vtab_num_bytes: usize) {
+        let depth = self.depth.get() + 1;
+        self.depth.set(depth);
+        self.update_report(|state| {
+            state.report.tables += 1;
+            state.report.max_depth = state.report.max_depth.max(depth);
+            if state
+                .ranges
+                .insert(vtab_loc, vtab_loc + vtab_num_bytes)
+                .is_none()
+            {
+                state.report.vtables += 1;
+            }
+        });
+    }
+
+    fn exit_table(&self) {
+        self.depth.set(self.depth.get() - 1);
+    }
+
+    fn count_string(&self) {
+        self.update_report(|state| state.report.strings += 1);
+    }
+
+    fn count_vector(&self, len: usize) {
+        self.update_report(|state| {
+            state.report.vectors += 1;
+            state.report.elements += len;
+            state.report.max_vector_len = state.report.max_vector_len.max(len);
+        });
+    }
+
+    /// Returns the path to the object being verified.
+    pub fn path(&self) -> FieldPath {
+        FieldPath(self.path.borrow().clone())
+    }
+
+    /// Calls `f` to verify the object at `segment` of the object being verified.
+    fn in_path<F: FnOnce() -> Result>(&self, segment: PathSegment, f: F) -> Result {
+        self.path.borrow_mut().push(segment);
+        let result = f();
+        self.path.borrow_mut().pop();
+        result
+    }
+
+    pub fn options(&self) -> &'o Options {
+        self.options
+    }
+
+    /// Rejects an enum value or union tag which is not declared in the schema, unless
+    /// `Options::accept_unknown_enum_values` is set.
+    pub fn unknown_enum_value(&self) -> Result {
+        if self.options.accept_unknown_enum_values {
+            self.unknown_enum_values
+                .set(self.unknown_enum_values.get() + 1);
+            Ok(())
+        } else {
+            Err(Error::UnknownEnumValue)
+        }
+    }
+
+    fn num_unknown_enum_values(&self) -> usize {
+        self.unknown_enum_values.get()
+    }
+
+    /// Rejects the buffer if an undeclared enum value or union tag has been accepted, since the
+    /// flatc readers cannot represent it.
+    pub fn verify_readable(&self) -> Result {
+        if self.num_unknown_enum_values() > 0 {
+            Err(Error::UnknownEnumValue)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the number of bytes of the objects visited so far.
+    pub fn work(&self) -> usize {
+        self.work.get()
+    }
+
+    /// Records a visit to the object occupying `buf[start..end]`.
+    pub fn visit(&self, start: usize, end: usize) -> Result {
+        let work = self.work.get().saturating_add(end - start);
+        self.work.set(work);
+        if self
+            .options
+            .max_work
+            .is_some_and(|max_work| work > max_work)
+        {
+            return Err(Error::WorkBudgetExceeded);
+        }
+
+        if self.options.reject_shared_objects {
+            let mut objects = self.objects.borrow_mut();
+            if let Some((&prev_start, &prev_end)) = objects.range(..end).next_back() {
+                if prev_start == start && prev_end == end {
+                    return Err(Error::SharedObject);
+                }
+                if prev_end > start {
+                    return Err(Error::OverlappingObjects);
+                }
+            }
+            objects.insert(start, end);
+        } else if self.report.is_some() {
+            let mut objects = self.objects.borrow_mut();
+            let prev_end = objects.entry(start).or_insert(end);
+            *prev_end = (*prev_end).max(end);
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Verification<'static> {
+    fn default() -> Self {
+        Verification::new(&DEFAULT_OPTIONS)
+    }
+}
+
+pub const MAX_OFFSET_LOC: usize = usize::MAX - SIZE_UOFFSET;
+
+/// Size of the 64-bit uoffset stored in a field with the attribute `offset64`.
+pub const SIZE_UOFFSET64: usize = 8;
+
+/// The size of the largest scalars, to which every scalar field is aligned.
+const MAX_SCALAR_SIZE: usize = 8;
 
 fn read_uoffset(buf: &[u8], offset_loc: usize) -> usize {
-    read_scalar::<UOffsetT>(&buf[offset_loc..]) as usize
+    let mut bytes = [0u8; SIZE_UOFFSET];
+    bytes.copy_from_slice(&buf[offset_loc..offset_loc + SIZE_UOFFSET]);
+    UOffsetT::from_le_bytes(bytes) as usize
+}
+
+/// Reads the soffset of the table at `loc`, which may be unaligned.
+///
+/// The caller must ensure that `loc + SIZE_SOFFSET` is within the buffer.
+pub fn read_soffset(buf: &[u8], loc: usize) -> SOffsetT {
+    let mut bytes = [0u8; SIZE_SOFFSET];
+    bytes.copy_from_slice(&buf[loc..loc + SIZE_SOFFSET]);
+    SOffsetT::from_le_bytes(bytes)
+}
+
+/// Reads the voffset stored in a vtable at `loc`, which may be unaligned.
+///
+/// The caller must ensure that `loc + SIZE_VOFFSET` is within the buffer.
+pub fn read_voffset(buf: &[u8], loc: usize) -> usize {
+    let mut bytes = [0u8; SIZE_VOFFSET];
+    bytes.copy_from_slice(&buf[loc..loc + SIZE_VOFFSET]);
+    VOffsetT::from_le_bytes(bytes) as usize
+}
+
+/// Reads the scalar at `loc`, which may be misaligned in memory, unlike
+/// `flatbuffers::read_scalar_at`.
+///
+/// `T` must be valid for any bit pattern, so not a reader enum. The caller must ensure that the
+/// scalar is within the buffer.
+pub fn read_scalar_at<T: EndianScalar>(buf: &[u8], loc: usize) -> T {
+    let bytes = &buf[loc..loc + size_of::<T>()];
+    unsafe { ptr::read_unaligned(bytes.as_ptr() as *const T) }.from_little_endian()
+}
+
+/// Follows the root uoffset at `loc` without reading it in place. The buffer must hold it.
+fn follow_root<'a, T: Follow<'a> + 'a>(data: &'a [u8], loc: usize) -> T::Inner {
+    T::follow(data, loc.saturating_add(read_uoffset(data, loc)))
+}
+
+/// Checks that `loc` is aligned to `alignment` relative to the start of the buffer.
+///
+/// The flatbuffers reader dereferences scalars and structs in place, so every location it reads
+/// must be aligned to the size of the scalar or the alignment of the struct. The buffer itself must
+/// also start at an aligned address, which is up to the caller, see [`verify_buffer_alignment`].
+pub fn verify_alignment(loc: usize, alignment: usize) -> Result {
+    if loc % alignment == 0 {
+        Ok(())
+    } else {
+        Err(Error::Misaligned)
+    }
+}
+
+/// Checks that the buffer starts at an address aligned to `alignment` in memory.
+///
+/// The verifier checks the alignments relative to the start of the buffer, so the flatbuffers
+/// reader can only read a verified buffer in place if it starts at an address aligned to the
+/// largest alignment in the schema: 8 for 64-bit scalars, or the largest `force_align`. A `Vec<u8>`
+/// is only guaranteed to be aligned to 1. Copy a misaligned buffer with [`AlignedBuf`].
+pub fn verify_buffer_alignment(buf: &[u8], alignment: usize) -> Result {
+    if buf.as_ptr() as usize % alignment == 0 {
+        Ok(())
+    } else {
+        Err(Error::Misaligned)
+    }
+}
+
+/// A copy of a buffer which starts at an address aligned to `alignment`, see
+/// [`verify_buffer_alignment`].
+pub struct AlignedBuf {
+    storage: Vec<u8>,
+    start: usize,
+    len: usize,
+}
+
+impl AlignedBuf {
+    pub fn new(buf: &[u8], alignment: usize) -> Self {
+        let mut storage = vec![0u8; buf.len() + alignment];
+        let start = storage.as_ptr().align_offset(alignment);
+        storage[start..start + buf.len()].copy_from_slice(buf);
+        AlignedBuf {
+            storage,
+            start,
+            len: buf.len(),
+        }
+    }
+}
+
+impl Deref for AlignedBuf {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.storage[self.start..self.start + self.len]
+    }
+}
+
+/// Floats which have a canonical representation, see [`verify_float`].
+pub trait CanonicalFloat: EndianScalar {
+    fn is_canonical(self) -> bool;
+}
+
+macro_rules! impl_canonical_float {
+    ($ty:ident, $nan_bits:expr) => {
+        impl CanonicalFloat for $ty {
+            fn is_canonical(self) -> bool {
+                if self.is_nan() {
+                    self.to_bits() == $nan_bits
+                } else {
+                    self.to_bits() != (-0.0 as $ty).to_bits()
+                }
+            }
+        }
+    };
+}
+
+impl_canonical_float!(f32, 0x7fc0_0000);
+impl_canonical_float!(f64, 0x7ff8_0000_0000_0000);
+
+/// Checks that the float at `loc` is canonical: the only NaN is the quiet NaN with an empty
+/// payload, and zero is positive. The builder writes every float this way, so each value has
+/// exactly one encoding.
+///
+/// The caller must ensure that the float is within the buffer.
+pub fn verify_float<T: CanonicalFloat>(buf: &[u8], loc: usize) -> Result {
+    if read_scalar_at::<T>(buf, loc).is_canonical() {
+        Ok(())
+    } else {
+        Err(Error::NonCanonicalFloat)
+    }
+}
+
+/// Checks that a struct of `size` bytes stored out of line at `loc`, such as a union value, is
+/// within the buffer and aligned to `alignment`.
+pub fn verify_struct(buf: &[u8], loc: usize, size: usize, alignment: usize) -> Result {
+    try_elements_end(loc, 1, size, buf.len())?;
+    verify_alignment(loc, alignment)
 }
 
 fn try_read_uoffset(buf: &[u8], offset_loc: usize) -> result::Result<usize, Error> {
@@ -47,6 +796,38 @@ pub fn try_follow_uoffset(buf: &[u8], offset_loc: usize) -> result::Result<usize
         .and_then(|offset| offset_loc.checked_add(offset).ok_or(Error::OutOfBounds))
 }
 
+/// Follows the 64-bit uoffset stored at `offset_loc`, which is written for fields with the
+/// attribute `offset64`.
+///
+/// Fails if the offset does not fit in `usize`, so a buffer beyond the address space of the target
+/// is rejected instead of wrapped.
+pub fn try_follow_uoffset64(buf: &[u8], offset_loc: usize) -> result::Result<usize, Error> {
+    let end = offset_loc
+        .checked_add(SIZE_UOFFSET64)
+        .filter(|end| *end <= buf.len())
+        .ok_or(Error::OutOfBounds)?;
+    let mut bytes = [0u8; SIZE_UOFFSET64];
+    bytes.copy_from_slice(&buf[offset_loc..end]);
+    usize::try_from(u64::from_le_bytes(bytes))
+        .ok()
+        .and_then(|offset| offset_loc.checked_add(offset))
+        .ok_or(Error::OutOfBounds)
+}
+
+/// Returns the end of `len` elements of `size` bytes each starting at `loc`, failing if the
+/// computation overflows or the end is beyond `buf_len`.
+fn try_elements_end(
+    loc: usize,
+    len: usize,
+    size: usize,
+    buf_len: usize,
+) -> result::Result<usize, Error> {
+    len.checked_mul(size)
+        .and_then(|num_bytes| loc.checked_add(num_bytes))
+        .filter(|end| *end <= buf_len)
+        .ok_or(Error::OutOfBounds)
+}
+
 #[allow(dead_code)]
 pub struct StringVerifier<'a> {
     buf: &'a [u8],
@@ -61,20 +842,28 @@ impl<'a> Follow<'a> for StringVerifier<'a> {
 }
 
 impl<'a> Verify for StringVerifier<'a> {
-    fn verify(&self) -> Result {
+    fn verify_in(&self, verification: &Verification) -> Result {
         let buf_len = self.buf.len();
 
         let len = try_read_uoffset(self.buf, self.loc)?;
-        let null_loc = (self.loc + SIZE_UOFFSET)
-            .checked_add(len)
+        verify_alignment(self.loc, SIZE_UOFFSET)?;
+        let content_loc = self
+            .loc
+            .checked_add(SIZE_UOFFSET)
             .ok_or(Error::OutOfBounds)?;
+        let null_loc = try_elements_end(content_loc, len, 1, buf_len)?;
 
         if null_loc >= buf_len {
             return Err(Error::OutOfBounds);
         }
+        verification.visit(self.loc, null_loc + 1)?;
+        verification.count_string();
         if self.buf[null_loc] != 0 {
             return Err(Error::NonNullTerminatedString);
         }
+        if str::from_utf8(&self.buf[content_loc..null_loc]).is_err() {
+            return Err(Error::NonUtf8String);
+        }
 
         Ok(())
     }
@@ -94,60 +883,793 @@ impl<'a> Follow<'a> for VectorVerifier<'a> {
 }
 
 impl<'a> VectorVerifier<'a> {
-    pub fn verify_scalar_elements(&self, scalar_size: usize) -> Result {
+    fn try_elements_loc(&self) -> result::Result<usize, Error> {
+        self.loc.checked_add(SIZE_UOFFSET).ok_or(Error::OutOfBounds)
+    }
+
+    pub fn verify_scalar_elements(&self, scalar_size: usize, alignment: usize) -> Result {
         let len = try_read_uoffset(self.buf, self.loc)?;
+        verify_alignment(self.loc, SIZE_UOFFSET)?;
+        let elements_loc = self.try_elements_loc()?;
+        verify_alignment(elements_loc, alignment)?;
+
+        try_elements_end(elements_loc, len, scalar_size, self.buf.len()).map(|_| ())
+    }
+
+    /// Verifies a vector of enums whose underlying type is `T`, rejecting values for which
+    /// `is_declared` returns false, see [`Verification::unknown_enum_value`].
+    pub fn verify_enum_elements<T, F>(&self, verification: &Verification, is_declared: F) -> Result
+    where
+        T: EndianScalar,
+        F: Fn(T) -> bool,
+    {
+        self.verify_scalar_elements(size_of::<T>(), size_of::<T>())?;
 
-        match (self.loc + SIZE_UOFFSET)
-            .checked_add(len * scalar_size)
-            .filter(|loc| *loc <= self.buf.len())
+        let len = read_uoffset(self.buf, self.loc);
+        let elements_loc = self.loc + SIZE_UOFFSET;
+        for i in 0..len {
+            let value = read_scalar_at::<T>(self.buf, elements_loc + i * size_of::<T>());
+            if !is_declared(value) {
+                verification.unknown_enum_value()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verifies a vector of scalars or structs of `size` bytes, calling `verify_element` with the
+    /// location of each element, such as to check that floats are canonical.
+    pub fn verify_scalar_elements_with<F>(
+        &self,
+        size: usize,
+        alignment: usize,
+        verify_element: F,
+    ) -> Result
+    where
+        F: Fn(usize) -> Result,
+    {
+        self.verify_scalar_elements(size, alignment)?;
+
+        let len = read_uoffset(self.buf, self.loc);
+        let elements_loc = self.loc + SIZE_UOFFSET;
+        for i in 0..len {
+            verify_element(elements_loc + i * size)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn verify_bool_elements(&self) -> Result {
+        self.verify_scalar_elements(1, 1)?;
+
+        let len = read_uoffset(self.buf, self.loc);
+        let elements_loc = self.loc + SIZE_UOFFSET;
+        if self.buf[elements_loc..elements_loc + len]
+            .iter()
+            .any(|b| *b > 1)
         {
-            Some(_) => Ok(()),
-            _ => Err(Error::OutOfBounds),
+            return Err(Error::InvalidBool);
         }
+
+        Ok(())
     }
 
-    pub fn verify_reference_elements<E>(&self) -> Result
+    fn for_each_reference<F>(&self, mut f: F) -> Result
+    where
+        F: FnMut(usize) -> Result,
+    {
+        let len = try_read_uoffset(self.buf, self.loc)?;
+        verify_alignment(self.loc, SIZE_UOFFSET)?;
+
+        let mut offset_loc = self.try_elements_loc()?;
+        let end_loc = try_elements_end(offset_loc, len, SIZE_UOFFSET, self.buf.len())?;
+
+        while offset_loc < end_loc {
+            f(offset_loc
+                .checked_add(read_uoffset(self.buf, offset_loc))
+                .ok_or(Error::OutOfBounds)?)?;
+            offset_loc += SIZE_UOFFSET;
+        }
+
+        Ok(())
+    }
+
+    pub fn verify_reference_elements<E>(&self, verification: &Verification) -> Result
     where
         E: Follow<'a>,
         <E as Follow<'a>>::Inner: Verify,
     {
+        let mut index = 0;
+        self.for_each_reference(|loc| {
+            let element = E::follow(self.buf, loc);
+            let result = verification.in_path(PathSegment::Index(index), || {
+                element.verify_in(verification)
+            });
+            index += 1;
+            result
+        })
+    }
+
+    /// Records the visit to the vector, whose elements are `size` bytes each.
+    pub fn visit(&self, size: usize, verification: &Verification) -> Result {
         let len = try_read_uoffset(self.buf, self.loc)?;
+        let end = try_elements_end(self.try_elements_loc()?, len, size, self.buf.len())?;
+        verification.visit(self.loc, end)?;
+        verification.count_vector(len);
+        Ok(())
+    }
 
-        let mut offset_loc = self.loc + SIZE_UOFFSET;
-        let end_loc = offset_loc
-            .checked_add(len * SIZE_UOFFSET)
-            .ok_or(Error::OutOfBounds)?;
-        if end_loc > self.buf.len() {
+    /// Verifies the elements like `verify_reference_elements`, and that they are strictly
+    /// increasing by `compare` like `verify_sorted_elements`.
+    ///
+    /// While salvaging, the order is only checked if the elements have no violations, because
+    /// `compare` reads them with the flatbuffers reader. For the same reason, the vector is
+    /// rejected if the elements contain undeclared enum values, even if they are accepted.
+    pub fn verify_sorted_reference_elements<E, F>(
+        &self,
+        verification: &Verification,
+        compare: F,
+    ) -> Result
+    where
+        E: Follow<'a>,
+        <E as Follow<'a>>::Inner: Verify,
+        F: FnMut(&E::Inner, &E::Inner) -> Ordering,
+    {
+        let num_violations = verification.num_violations();
+        let num_unknown_enum_values = verification.num_unknown_enum_values();
+        self.verify_reference_elements::<E>(verification)?;
+        if verification.num_violations() > num_violations {
+            return Ok(());
+        }
+        if verification.num_unknown_enum_values() > num_unknown_enum_values {
+            return Err(Error::UnknownEnumValue);
+        }
+        self.verify_sorted_elements::<E, F>(compare)
+    }
+
+    /// Verifies the vector and the offsets to its elements, but not the elements themselves.
+    pub fn verify_reference_offsets(&self) -> Result {
+        self.for_each_reference(|_| Ok(()))
+    }
+
+    /// Verifies that the elements are strictly increasing by `compare`, which usually compares
+    /// the `key` fields of tables. The elements must have been verified.
+    ///
+    /// `compare` reads the elements in place with the flatbuffers reader, so the buffer is rejected
+    /// unless it starts at an address aligned to 8, see [`verify_buffer_alignment`].
+    pub fn verify_sorted_elements<E, F>(&self, mut compare: F) -> Result
+    where
+        E: Follow<'a>,
+        F: FnMut(&E::Inner, &E::Inner) -> Ordering,
+    {
+        // The keys are read in place with the flatbuffers reader.
+        verify_buffer_alignment(self.buf, MAX_SCALAR_SIZE)?;
+        let mut prev: Option<E::Inner> = None;
+        self.for_each_reference(|loc| {
+            let element = E::follow(self.buf, loc);
+            if let Some(prev) = &prev {
+                match compare(prev, &element) {
+                    Ordering::Less => {}
+                    Ordering::Equal => return Err(Error::DuplicateKey),
+                    Ordering::Greater => return Err(Error::UnsortedKeys),
+                }
+            }
+            prev = Some(element);
+            Ok(())
+        })
+    }
+}
+
+/// Verifies a vector of unions, which is stored as the vector of type tags at `types_loc` and the
+/// vector of values at `values_loc`. An absent vector is treated as an empty one.
+///
+/// Both vectors must have the same length. `verify` is called with each tag and the location of
+/// the value, and must reject the tags which do not match the union.
+pub fn verify_union_vector<F>(
+    buf: &[u8],
+    types_loc: Option<usize>,
+    values_loc: Option<usize>,
+    verification: &Verification,
+    mut verify: F,
+) -> Result
+where
+    F: FnMut(u8, usize) -> Result,
+{
+    let types = match types_loc {
+        Some(loc) => {
+            let len = try_read_uoffset(buf, loc)?;
+            verify_alignment(loc, SIZE_UOFFSET)?;
+            let start = loc.checked_add(SIZE_UOFFSET).ok_or(Error::OutOfBounds)?;
+            let end = try_elements_end(start, len, 1, buf.len())?;
+            &buf[start..end]
+        }
+        None => &[],
+    };
+
+    let mut types = types.iter().enumerate();
+    if let Some(loc) = values_loc {
+        VectorVerifier::follow(buf, loc).for_each_reference(|value_loc| match types.next() {
+            Some((index, union_type)) => {
+                verification.in_path(PathSegment::Index(index), || verify(*union_type, value_loc))
+            }
+            None => Err(Error::UnmatchedUnion),
+        })?;
+    }
+    if types.next().is_some() {
+        return Err(Error::UnmatchedUnion);
+    }
+
+    // The vector of tags is verified and visited as a field of its own, so only the vector of
+    // values is visited here.
+    if let Some(loc) = values_loc {
+        VectorVerifier::follow(buf, loc).visit(SIZE_UOFFSET, verification)?;
+    }
+    Ok(())
+}
+
+/// Binary searches a vector sorted by key, where `compare` compares the key of an element with
+/// the key to look up.
+///
+/// The vector must have been verified, because the elements are read with the flatbuffers reader.
+pub fn lookup_by_key<'a, T, F>(vector: Vector<'a, T>, mut compare: F) -> Option<T::Inner>
+where
+    T: Follow<'a> + 'a,
+    F: FnMut(&T::Inner) -> Ordering,
+{
+    let (mut low, mut high) = (0, vector.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let element = vector.get(mid);
+        match compare(&element) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Equal => return Some(element),
+            Ordering::Greater => high = mid,
+        }
+    }
+    None
+}
+
+/// Verifies the soffset, vtable and inline object of the table at `loc`.
+///
+/// Returns the vtable location, the vtable size and the inline object size.
+fn try_table_layout(buf: &[u8], loc: usize) -> result::Result<(usize, usize, usize), Error> {
+    let buf_len = buf.len();
+
+    if loc
+        .checked_add(SIZE_SOFFSET)
+        .filter(|end| *end <= buf_len)
+        .is_none()
+    {
+        return Err(Error::OutOfBounds);
+    }
+    verify_alignment(loc, SIZE_SOFFSET)?;
+
+    let soffset = read_soffset(buf, loc);
+    let vtab_loc = if soffset >= 0 {
+        loc.checked_sub(soffset as usize)
+    } else {
+        soffset
+            .checked_neg()
+            .and_then(|foffset| loc.checked_add(foffset as usize))
+    }
+    .ok_or(Error::OutOfBounds)?;
+    if vtab_loc
+        .checked_add(SIZE_VOFFSET + SIZE_VOFFSET)
+        .filter(|end| *end <= buf_len)
+        .is_none()
+    {
+        return Err(Error::OutOfBounds);
+    }
+    verify_alignment(vtab_loc, SIZE_VOFFSET)?;
+
+    let vtab_num_bytes = read_voffset(buf, vtab_loc);
+    let object_inline_num_bytes = read_voffset(buf, vtab_loc + SIZE_VOFFSET);
+    if vtab_num_bytes < SIZE_VOFFSET + SIZE_VOFFSET
+        || vtab_num_bytes % SIZE_VOFFSET != 0
+        || object_inline_num_bytes < SIZE_SOFFSET
+    {
+        return Err(Error::OutOfBounds);
+    }
+    if vtab_loc
+        .checked_add(vtab_num_bytes)
+        .filter(|end| *end <= buf_len)
+        .is_none()
+        || loc
+            .checked_add(object_inline_num_bytes)
+            .filter(|end| *end <= buf_len)
+            .is_none()
+    {
+        return Err(Error::OutOfBounds);
+    }
+
+    Ok((vtab_loc, vtab_num_bytes, object_inline_num_bytes))
+}
+
+/// Locates the field at the vtable offset `field` of a table without verifying the whole table.
+///
+/// Only the table header, the vtable slot and the inline bytes of the field are checked, using
+/// the same rules as the generated `Verify` impls. Returns `None` if the field is absent.
+pub fn try_field_loc(
+    tab: &Table,
+    field: VOffsetT,
+    size: usize,
+    alignment: usize,
+) -> result::Result<Option<usize>, Error> {
+    let (vtab_loc, vtab_num_bytes, object_inline_num_bytes) = try_table_layout(tab.buf, tab.loc)?;
+
+    let field = field as usize;
+    if field + SIZE_VOFFSET > vtab_num_bytes {
+        return Ok(None);
+    }
+    let voffset = read_voffset(tab.buf, vtab_loc + field);
+    if voffset == 0 {
+        return Ok(None);
+    }
+    if voffset < SIZE_SOFFSET
+        || voffset
+            .checked_add(size)
+            .filter(|end| *end <= object_inline_num_bytes)
+            .is_none()
+    {
+        return Err(Error::OutOfBounds);
+    }
+    verify_alignment(tab.loc + voffset, alignment)?;
+
+    Ok(Some(tab.loc + voffset))
+}
+
+/// Verifies a table field by field, which is what the generated `Verify` impls do.
+///
+/// `new` verifies the soffset, the vtable and the inline object once, and the `field_` methods
+/// check a single field given its vtable offset. Absent fields are skipped, unless checked by
+/// `field_required`.
+pub struct TableVerifier<'a, 'v> {
+    buf: &'a [u8],
+    loc: usize,
+    vtab_loc: usize,
+    vtab_num_bytes: usize,
+    object_inline_num_bytes: usize,
+    verification: &'v Verification<'v>,
+    /// The number of violations before the table, to tell whether it has any.
+    num_violations: usize,
+    /// The number of undeclared enum values accepted before the table, to tell whether it has any.
+    num_unknown_enum_values: usize,
+}
+
+impl<'a, 'v> TableVerifier<'a, 'v> {
+    /// Verifies the layout of the table and records the visit to its inline object.
+    ///
+    /// Every voffset in the vtable, including the ones unknown to the schema, must point into the
+    /// inline object.
+    pub fn new(tab: &Table<'a>, verification: &'v Verification<'v>) -> result::Result<Self, Error> {
+        let (vtab_loc, vtab_num_bytes, object_inline_num_bytes) =
+            try_table_layout(tab.buf, tab.loc)?;
+
+        // The vtable and the inline object are within the buffer now, so adding a position inside
+        // them to `vtab_loc` or `tab.loc` cannot overflow.
+        for i in 2..vtab_num_bytes / SIZE_VOFFSET {
+            let voffset = read_voffset(tab.buf, vtab_loc + i * SIZE_VOFFSET);
+            if (voffset > 0 && voffset < SIZE_SOFFSET) || voffset >= object_inline_num_bytes {
+                return Err(Error::OutOfBounds);
+            }
+        }
+        verification.visit(tab.loc, tab.loc + object_inline_num_bytes)?;
+        verification.enter_table(vtab_loc, vtab_num_bytes);
+
+        Ok(TableVerifier {
+            buf: tab.buf,
+            loc: tab.loc,
+            vtab_loc,
+            vtab_num_bytes,
+            object_inline_num_bytes,
+            verification,
+            num_violations: verification.num_violations(),
+            num_unknown_enum_values: verification.num_unknown_enum_values(),
+        })
+    }
+
+    /// Records the error of a check in the violations if the buffer is being salvaged, so the
+    /// verification carries on with the next check.
+    fn salvage(&self, field: Option<VOffsetT>, result: Result) -> Result {
+        self.verification.salvage(self.loc, field, result)
+    }
+
+    /// Rejects a vtable longer than `vtab_num_bytes`, the size of the vtable with all the fields
+    /// of the schema, if `Options::reject_unknown_fields` is set.
+    pub fn verify_known_fields(&self, vtab_num_bytes: usize) -> Result {
+        if self.verification.options().reject_unknown_fields && self.vtab_num_bytes > vtab_num_bytes
+        {
+            return self.salvage(None, Err(Error::UnknownField));
+        }
+        Ok(())
+    }
+
+    fn voffset(&self, field: VOffsetT) -> usize {
+        let field = field as usize;
+        if field + SIZE_VOFFSET > self.vtab_num_bytes {
+            return 0;
+        }
+        read_voffset(self.buf, self.vtab_loc + field)
+    }
+
+    /// Rejects the deprecated field if it is present and `Options::reject_deprecated_fields` is
+    /// set.
+    pub fn field_deprecated(&self, field: VOffsetT) -> Result {
+        if self.verification.options().reject_deprecated_fields && self.voffset(field) > 0 {
+            return self.salvage(Some(field), Err(Error::DeprecatedField));
+        }
+        Ok(())
+    }
+
+    pub fn field_required(&self, field: VOffsetT) -> Result {
+        if self.voffset(field) == 0 {
+            return self.salvage(Some(field), Err(Error::MissingRequiredField));
+        }
+        Ok(())
+    }
+
+    /// Returns the location of the field if it is present, checking that its `size` bytes are
+    /// within the inline object and aligned to `alignment`.
+    fn field_loc(
+        &self,
+        field: VOffsetT,
+        size: usize,
+        alignment: usize,
+    ) -> result::Result<Option<usize>, Error> {
+        let voffset = self.voffset(field);
+        if voffset == 0 {
+            return Ok(None);
+        }
+        if voffset
+            .checked_add(size)
+            .filter(|end| *end <= self.object_inline_num_bytes)
+            .is_none()
+        {
             return Err(Error::OutOfBounds);
         }
+        verify_alignment(self.loc + voffset, alignment)?;
 
-        while offset_loc < end_loc {
-            E::follow(
-                self.buf,
-                offset_loc
-                    .checked_add(read_uoffset(self.buf, offset_loc))
-                    .ok_or(Error::OutOfBounds)?,
-            )
-            .verify()?;
-            offset_loc += SIZE_UOFFSET;
+        Ok(Some(self.loc + voffset))
+    }
+
+    fn read_scalar<T: EndianScalar>(&self, field: VOffsetT) -> result::Result<Option<T>, Error> {
+        Ok(self
+            .field_loc(field, size_of::<T>(), size_of::<T>())?
+            .map(|loc| read_scalar_at::<T>(self.buf, loc)))
+    }
+
+    /// Follows the uoffset stored in the field and returns the location of the referenced
+    /// object, which is not verified.
+    fn follow_offset(&self, field: VOffsetT) -> result::Result<Option<usize>, Error> {
+        self.field_loc(field, SIZE_UOFFSET, SIZE_UOFFSET)?
+            .map(|loc| try_follow_uoffset(self.buf, loc))
+            .transpose()
+    }
+
+    pub fn field_scalar<T: EndianScalar>(&self, field: VOffsetT) -> Result {
+        self.salvage(Some(field), self.read_scalar::<T>(field).map(|_| ()))
+    }
+
+    pub fn field_bool(&self, field: VOffsetT) -> Result {
+        let result = match self.read_scalar::<u8>(field) {
+            Ok(Some(value)) if value > 1 => Err(Error::InvalidBool),
+            result => result.map(|_| ()),
+        };
+        self.salvage(Some(field), result)
+    }
+
+    /// Verifies a field of an enum whose underlying type is `T`, rejecting values for which
+    /// `is_declared` returns false, see [`Verification::unknown_enum_value`].
+    pub fn field_enum<T, F>(&self, field: VOffsetT, is_declared: F) -> Result
+    where
+        T: EndianScalar,
+        F: FnOnce(T) -> bool,
+    {
+        let result = match self.read_scalar::<T>(field) {
+            Ok(Some(value)) if !is_declared(value) => self.verification.unknown_enum_value(),
+            result => result.map(|_| ()),
+        };
+        self.salvage(Some(field), result)
+    }
+
+    /// Rejects the scalar field if it is present, `is_default` returns true for its value, and
+    /// `Options::reject_default_scalars` is set.
+    ///
+    /// The field must have been verified by the check of its type.
+    pub fn field_default<T, F>(&self, field: VOffsetT, is_default: F) -> Result
+    where
+        T: EndianScalar,
+        F: FnOnce(T) -> bool,
+    {
+        if !self.verification.options().reject_default_scalars {
+            return Ok(());
         }
+        match self.read_scalar::<T>(field) {
+            Ok(Some(value)) if is_default(value) => {
+                self.salvage(Some(field), Err(Error::DefaultScalar))
+            }
+            _ => Ok(()),
+        }
+    }
 
+    /// Verifies a float field, which must be canonical, see [`verify_float`].
+    pub fn field_float<T: CanonicalFloat>(&self, field: VOffsetT) -> Result {
+        let result = match self.read_scalar::<T>(field) {
+            Ok(Some(value)) if !value.is_canonical() => Err(Error::NonCanonicalFloat),
+            result => result.map(|_| ()),
+        };
+        self.salvage(Some(field), result)
+    }
+
+    pub fn field_struct(&self, field: VOffsetT, size: usize, alignment: usize) -> Result {
+        self.field_struct_with(field, size, alignment, |_| Ok(()))
+    }
+
+    /// Verifies a struct field, calling `verify` with its location if it is present, such as to
+    /// check that the floats in it are canonical.
+    pub fn field_struct_with<F>(
+        &self,
+        field: VOffsetT,
+        size: usize,
+        alignment: usize,
+        verify: F,
+    ) -> Result
+    where
+        F: FnOnce(usize) -> Result,
+    {
+        let result = match self.field_loc(field, size, alignment) {
+            Ok(Some(loc)) => verify(loc),
+            result => result.map(|_| ()),
+        };
+        self.salvage(Some(field), result)
+    }
+
+    pub fn field_string(&self, field: VOffsetT) -> Result {
+        let result = match self.follow_offset(field) {
+            Ok(Some(loc)) => StringVerifier::follow(self.buf, loc).verify_in(self.verification),
+            result => result.map(|_| ()),
+        };
+        self.salvage(Some(field), result)
+    }
+
+    /// Verifies the table referenced by the field `name` as a `T`.
+    pub fn field_table<T>(&self, field: VOffsetT, name: &'static str) -> Result
+    where
+        T: Follow<'a>,
+        T::Inner: Verify,
+    {
+        let result = match self.follow_offset(field) {
+            Ok(Some(loc)) => self.verification.in_path(PathSegment::Field(name), || {
+                T::follow(self.buf, loc).verify_in(self.verification)
+            }),
+            result => result.map(|_| ()),
+        };
+        self.salvage(Some(field), result)
+    }
+
+    /// Verifies the value of the union field `name`, calling `verify` with the location of the
+    /// value, which must check it according to the type field.
+    pub fn field_union<F>(&self, field: VOffsetT, name: &'static str, verify: F) -> Result
+    where
+        F: FnOnce(usize) -> Result,
+    {
+        let result = match self.follow_offset(field) {
+            Ok(Some(loc)) => self
+                .verification
+                .in_path(PathSegment::Field(name), || verify(loc)),
+            result => result.map(|_| ()),
+        };
+        self.salvage(Some(field), result)
+    }
+
+    /// Verifies the vector of unions `name` whose tags are in the field `types_field`, see
+    /// [`verify_union_vector`].
+    pub fn field_union_vector<F>(
+        &self,
+        types_field: VOffsetT,
+        field: VOffsetT,
+        name: &'static str,
+        verify: F,
+    ) -> Result
+    where
+        F: FnMut(u8, usize) -> Result,
+    {
+        let result = self.follow_offset(types_field).and_then(|types_loc| {
+            let values_loc = self.follow_offset(field)?;
+            self.verification.in_path(PathSegment::Field(name), || {
+                verify_union_vector(self.buf, types_loc, values_loc, self.verification, verify)
+            })
+        });
+        self.salvage(Some(field), result)
+    }
+
+    /// Verifies the vector field `name` whose elements are `size` bytes each, calling `verify` to
+    /// check the elements if it is present.
+    pub fn field_vector<F>(
+        &self,
+        field: VOffsetT,
+        name: &'static str,
+        size: usize,
+        verify: F,
+    ) -> Result
+    where
+        F: FnOnce(&VectorVerifier<'a>) -> Result,
+    {
+        let result = self.follow_offset(field);
+        self.verify_vector(field, name, size, result, verify)
+    }
+
+    /// Verifies a vector field referenced by a 64-bit uoffset, which has the attribute `offset64`.
+    pub fn field_vector64<F>(
+        &self,
+        field: VOffsetT,
+        name: &'static str,
+        size: usize,
+        verify: F,
+    ) -> Result
+    where
+        F: FnOnce(&VectorVerifier<'a>) -> Result,
+    {
+        let result = self.follow_offset64(field);
+        self.verify_vector(field, name, size, result, verify)
+    }
+
+    /// Follows the 64-bit uoffset stored in the field, like `follow_offset`.
+    fn follow_offset64(&self, field: VOffsetT) -> result::Result<Option<usize>, Error> {
+        self.field_loc(field, SIZE_UOFFSET64, SIZE_UOFFSET64)?
+            .map(|loc| try_follow_uoffset64(self.buf, loc))
+            .transpose()
+    }
+
+    /// Checks that the string or vector field `name` has `min..=max` bytes or elements, see the
+    /// attributes `cfb_min_len` and `cfb_max_len`. An absent field has length 0.
+    ///
+    /// The field must be checked by `field_string` or `field_vector` as well, which reports the
+    /// errors of its offset and length.
+    pub fn field_len(&self, field: VOffsetT, name: &'static str, min: usize, max: usize) -> Result {
+        let loc = self.follow_offset(field);
+        self.verify_len(field, name, min, max, loc)
+    }
+
+    /// Checks the length of a vector field referenced by a 64-bit uoffset, like `field_len`.
+    pub fn field_len64(
+        &self,
+        field: VOffsetT,
+        name: &'static str,
+        min: usize,
+        max: usize,
+    ) -> Result {
+        let loc = self.follow_offset64(field);
+        self.verify_len(field, name, min, max, loc)
+    }
+
+    fn verify_len(
+        &self,
+        field: VOffsetT,
+        name: &'static str,
+        min: usize,
+        max: usize,
+        loc: result::Result<Option<usize>, Error>,
+    ) -> Result {
+        let len = match loc.map(|loc| loc.map(|loc| try_read_uoffset(self.buf, loc))) {
+            Ok(None) => 0,
+            Ok(Some(Ok(len))) => len,
+            _ => return Ok(()),
+        };
+        if len < min || len > max {
+            let error = Error::LengthOutOfBounds {
+                field: name,
+                len,
+                min,
+                max,
+            };
+            return self.salvage(Some(field), Err(error));
+        }
         Ok(())
     }
+
+    fn verify_vector<F>(
+        &self,
+        field: VOffsetT,
+        name: &'static str,
+        size: usize,
+        loc: result::Result<Option<usize>, Error>,
+        verify: F,
+    ) -> Result
+    where
+        F: FnOnce(&VectorVerifier<'a>) -> Result,
+    {
+        let result = match loc {
+            Ok(Some(loc)) => {
+                let vector = VectorVerifier::follow(self.buf, loc);
+                self.verification
+                    .in_path(PathSegment::Field(name), || verify(&vector))
+                    .and_then(|_| vector.visit(size, self.verification))
+            }
+            result => result.map(|_| ()),
+        };
+        self.salvage(Some(field), result)
+    }
+
+    /// Calls the `Validate` impl of the table, after all its fields have been verified.
+    ///
+    /// `validate` reads the table in place with the flatbuffers accessors, so the buffer is
+    /// rejected unless it starts at an address aligned to 8, see [`verify_buffer_alignment`].
+    ///
+    /// While salvaging, it is skipped if the table or the objects it references are malformed,
+    /// since `validate` reads them with the flatbuffers accessors. Those which are only invalid
+    /// are safe to read.
+    ///
+    /// The table is rejected if it or the objects it references contain undeclared enum values,
+    /// even if they are accepted, since the accessors cannot represent them.
+    pub fn validate<T: Validate>(&self, table: &T) -> Result {
+        if self.verification.is_malformed_since(self.num_violations) {
+            return Ok(());
+        }
+        if self.verification.num_unknown_enum_values() > self.num_unknown_enum_values {
+            return self.salvage(None, Err(Error::UnknownEnumValue));
+        }
+        let path = self.verification.path();
+        let result = verify_buffer_alignment(self.buf, MAX_SCALAR_SIZE).and_then(|_| {
+            table
+                .validate(&path)
+                .map_err(|message| Error::Invalid { path, message })
+        });
+        self.salvage(None, result)
+    }
 }
 
+impl<'a, 'v> Drop for TableVerifier<'a, 'v> {
+    fn drop(&mut self) {
+        self.verification.exit_table();
+    }
+}
+
+/// Verifies the buffer with the default `Options` and returns the root.
 pub fn get_root<'a, T>(data: &'a [u8]) -> result::Result<T::Inner, Error>
 where
     T: Follow<'a> + 'a,
     T::Inner: Verify,
+{
+    Options::default().get_root::<T>(data)
+}
+
+/// Returns the root without verifying the tree, to read it with the checked `try_` accessors
+/// generated along with the `Verify` impls.
+///
+/// Only the root offset is checked, so the unchecked flatbuffers accessors of the returned reader
+/// and its descendants may still panic. Read buffers verified by `Options::verify_root` with it
+/// too, since they may contain undeclared enum values.
+pub fn get_lazy_root<'a, T>(data: &'a [u8]) -> result::Result<T::Inner, Error>
+where
+    T: Follow<'a> + 'a,
 {
     if data.len() < SIZE_UOFFSET {
         return Err(Error::OutOfBounds);
     }
 
-    let root = flatbuffers::get_root::<T>(data);
-    root.verify()?;
-    Ok(root)
+    Ok(follow_root::<T>(data, 0))
+}
+
+/// Salvages the buffer with the default `Options`, see [`Options::salvage_root`].
+pub fn salvage_root<'a, T>(data: &'a [u8]) -> Salvaged<T::Inner>
+where
+    T: Follow<'a> + 'a,
+    T::Inner: Verify,
+{
+    Options::default().salvage_root::<T>(data)
+}
+
+/// Verifies the buffer with the default `Options`, and returns the root and a report of the
+/// objects in it.
+pub fn get_root_with_report<'a, T>(
+    data: &'a [u8],
+) -> result::Result<(T::Inner, VerificationReport), Error>
+where
+    T: Follow<'a> + 'a,
+    T::Inner: Verify,
+{
+    Options::default().get_root_with_report::<T>(data)
 }
 
 pub fn get_size_prefixed_root<'a, T>(data: &'a [u8]) -> result::Result<T::Inner, Error>
@@ -155,11 +1677,239 @@ where
     T: Follow<'a> + 'a,
     T::Inner: Verify,
 {
-    if data.len() < SIZE_SIZEPREFIX + SIZE_UOFFSET {
-        return Err(Error::OutOfBounds);
+    Options::default().get_size_prefixed_root::<T>(data)
+}
+
+/// A reader which has passed the verification.
+///
+/// It can only be obtained from `get_verified_root`, `get_verified_size_prefixed_root` or
+/// `VerifiedBuf::root`, so APIs can demand verified input in their signatures. It dereferences to
+/// the reader.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Verified<'a, T> {
+    buf: &'a [u8],
+    root: T,
+}
+
+impl<'a, T> Verified<'a, T> {
+    /// Returns the verified buffer.
+    pub fn buf(&self) -> &'a [u8] {
+        self.buf
+    }
+
+    pub fn into_inner(self) -> T {
+        self.root
+    }
+}
+
+impl<'a, T> Deref for Verified<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.root
+    }
+}
+
+pub fn get_verified_root<'a, T>(data: &'a [u8]) -> result::Result<Verified<'a, T::Inner>, Error>
+where
+    T: Follow<'a> + 'a,
+    T::Inner: Verify,
+{
+    get_root::<T>(data).map(|root| Verified { buf: data, root })
+}
+
+pub fn get_verified_size_prefixed_root<'a, T>(
+    data: &'a [u8],
+) -> result::Result<Verified<'a, T::Inner>, Error>
+where
+    T: Follow<'a> + 'a,
+    T::Inner: Verify,
+{
+    get_size_prefixed_root::<T>(data).map(|root| Verified { buf: data, root })
+}
+
+/// Maps a table reader to the same reader borrowing a buffer for any lifetime.
+///
+/// The generated verifier implements it for every table, so `VerifiedBuf<Monster<'static>>` holds a
+/// buffer with a `Monster` root.
+pub trait Root {
+    type Reader<'a>: Follow<'a, Inner = Self::Reader<'a>> + Verify + 'a;
+}
+
+/// An owned buffer which has passed the verification.
+///
+/// The root can be read again and again without verifying the buffer, and the buffer can be sent to
+/// other threads and stored in caches.
+///
+/// Reading the root still needs the buffer to start at an aligned address, see
+/// [`verify_buffer_alignment`].
+pub struct VerifiedBuf<T> {
+    buf: Vec<u8>,
+    root: PhantomData<fn() -> T>,
+}
+
+impl<T: Root> VerifiedBuf<T> {
+    /// Verifies the buffer and takes the ownership.
+    ///
+    /// Returns the buffer back along with the error if the verification fails.
+    pub fn new(buf: Vec<u8>) -> result::Result<Self, (Vec<u8>, Error)> {
+        Self::with_options(buf, &DEFAULT_OPTIONS)
     }
 
-    let root = flatbuffers::get_size_prefixed_root::<T>(data);
-    root.verify()?;
-    Ok(root)
+    /// Verifies the buffer with the options and takes the ownership.
+    pub fn with_options(buf: Vec<u8>, options: &Options) -> result::Result<Self, (Vec<u8>, Error)> {
+        let verified = options.get_root::<T::Reader<'_>>(&buf).map(|_| ());
+        match verified {
+            Ok(()) => Ok(VerifiedBuf {
+                buf,
+                root: PhantomData,
+            }),
+            Err(err) => Err((buf, err)),
+        }
+    }
+
+    pub fn root(&self) -> Verified<'_, T::Reader<'_>> {
+        Verified {
+            buf: &self.buf,
+            root: follow_root::<T::Reader<'_>>(&self.buf, 0),
+        }
+    }
+}
+
+impl<T> VerifiedBuf<T> {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+impl<T> fmt::Debug for VerifiedBuf<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("VerifiedBuf").field(&self.buf).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_elements_end() {
+        assert_eq!(try_elements_end(4, 2, 8, 20), Ok(20));
+        assert_eq!(try_elements_end(4, 2, 8, 19), Err(Error::OutOfBounds));
+        assert_eq!(try_elements_end(4, 0, 8, 4), Ok(4));
+    }
+
+    // The length of a vector is a u32, so on 32-bit targets `len * size` can overflow usize. Use
+    // usize::MAX to exercise the same paths on 64-bit targets.
+    #[test]
+    fn test_try_elements_end_overflow() {
+        assert_eq!(
+            try_elements_end(4, usize::MAX / 4 + 1, 4, usize::MAX),
+            Err(Error::OutOfBounds)
+        );
+        assert_eq!(
+            try_elements_end(usize::MAX - 3, 1, 4, usize::MAX),
+            Err(Error::OutOfBounds)
+        );
+    }
+
+    #[test]
+    fn test_try_follow_uoffset64() {
+        let mut buf = [0u8; 16];
+        buf[4..12].copy_from_slice(&8u64.to_le_bytes());
+        assert_eq!(try_follow_uoffset64(&buf, 4), Ok(12));
+        assert_eq!(try_follow_uoffset64(&buf, 9), Err(Error::OutOfBounds));
+        assert_eq!(
+            try_follow_uoffset64(&buf, usize::MAX - 3),
+            Err(Error::OutOfBounds)
+        );
+
+        buf[4..12].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(try_follow_uoffset64(&buf, 4), Err(Error::OutOfBounds));
+    }
+
+    #[test]
+    fn test_verify_float() {
+        let buf: Vec<u8> = [
+            0.5f32.to_le_bytes(),
+            f32::from_bits(0x7fc0_0000).to_le_bytes(),
+            (-0.0f32).to_le_bytes(),
+            f32::from_bits(0x7fc0_0001).to_le_bytes(),
+            f32::from_bits(0xffc0_0000).to_le_bytes(),
+        ]
+        .concat();
+        assert_eq!(verify_float::<f32>(&buf, 0), Ok(()));
+        assert_eq!(verify_float::<f32>(&buf, 4), Ok(()));
+        for loc in [8, 12, 16] {
+            assert_eq!(
+                verify_float::<f32>(&buf, loc),
+                Err(Error::NonCanonicalFloat)
+            );
+        }
+
+        let buf = (-0.0f64).to_le_bytes();
+        assert_eq!(verify_float::<f64>(&buf, 0), Err(Error::NonCanonicalFloat));
+    }
+
+    #[test]
+    fn test_verify_struct_bounds() {
+        let buf = [0u8; 8];
+        assert_eq!(verify_struct(&buf, 4, 4, 1), Ok(()));
+        assert_eq!(verify_struct(&buf, 5, 4, 1), Err(Error::OutOfBounds));
+        assert_eq!(
+            verify_struct(&buf, usize::MAX, 4, 1),
+            Err(Error::OutOfBounds)
+        );
+    }
+
+    #[test]
+    fn test_verification_visit() {
+        let options = Options {
+            reject_shared_objects: true,
+            ..Default::default()
+        };
+        let verification = Verification::new(&options);
+        assert_eq!(verification.visit(8, 16), Ok(()));
+        assert_eq!(verification.visit(16, 20), Ok(()));
+        assert_eq!(verification.visit(0, 8), Ok(()));
+        assert_eq!(verification.visit(8, 16), Err(Error::SharedObject));
+        assert_eq!(verification.visit(8, 12), Err(Error::OverlappingObjects));
+        assert_eq!(verification.visit(4, 12), Err(Error::OverlappingObjects));
+        assert_eq!(verification.visit(18, 24), Err(Error::OverlappingObjects));
+        assert_eq!(verification.visit(20, 24), Ok(()));
+
+        // Without the option, shared objects are only charged again.
+        let options = Options {
+            max_work: Some(24),
+            ..Default::default()
+        };
+        let verification = Verification::new(&options);
+        for _ in 0..3 {
+            assert_eq!(verification.visit(8, 16), Ok(()));
+        }
+        assert_eq!(verification.work(), 24);
+        assert_eq!(verification.visit(8, 9), Err(Error::WorkBudgetExceeded));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_try_elements_end_u32_len() {
+        assert_eq!(
+            try_elements_end(8, u32::MAX as usize, 8, usize::MAX),
+            Ok(8 + u32::MAX as usize * 8)
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "32")]
+    fn test_try_elements_end_u32_len() {
+        assert_eq!(
+            try_elements_end(8, u32::MAX as usize, 8, usize::MAX),
+            Err(Error::OutOfBounds)
+        );
+    }
 }
@@ -1,9 +1,14 @@
 use crate::alignment::{align, align_after};
 use crate::scalar::Scalar;
 use crate::types::{
-    Len, UOffset, VOffset, SIZE_OF_LEN, SIZE_OF_SOFFSET, SIZE_OF_UOFFSET, SIZE_OF_VOFFSET,
+    Len, UOffset, UOffset64, VOffset, SIZE_OF_LEN, SIZE_OF_SOFFSET, SIZE_OF_UOFFSET,
+    SIZE_OF_UOFFSET64, SIZE_OF_VOFFSET,
 };
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error;
+use std::fmt;
 use std::mem;
 
 pub trait Component<'c> {
@@ -23,6 +28,8 @@ where
 pub struct DesignatedComponent<'c> {
     /// Where to store the UOffset in the buffer.
     offset_position: usize,
+    /// Whether the offset is a `UOffset64`.
+    offset64: bool,
     component: Box<dyn Component<'c> + 'c>,
 }
 
@@ -33,15 +40,119 @@ impl<'c> DesignatedComponent<'c> {
     ) -> DesignatedComponent<'c> {
         DesignatedComponent {
             offset_position,
+            offset64: false,
+            component,
+        }
+    }
+
+    /// Stores a `UOffset64` at `offset_position`, for fields with the attribute `offset64`.
+    ///
+    /// The component is built after all the components referenced by 32-bit offsets, so they stay
+    /// within the range of `UOffset`.
+    pub fn with_offset64(
+        offset_position: usize,
+        component: Box<dyn Component<'c> + 'c>,
+    ) -> DesignatedComponent<'c> {
+        DesignatedComponent {
+            offset_position,
+            offset64: true,
             component,
         }
     }
 
     fn build(self, builder: &mut Builder<'c>) {
         let position = self.component.build(builder);
-        let uoffset = (position - self.offset_position) as UOffset;
+        let offset = position - self.offset_position;
+
+        if self.offset64 {
+            builder.set_scalar(self.offset_position, offset as UOffset64);
+        } else {
+            match UOffset::try_from(offset) {
+                Ok(uoffset) => builder.set_scalar(self.offset_position, uoffset),
+                Err(_) => builder.fail(BuildError::OffsetOverflow),
+            }
+        }
+    }
+}
+
+/// A string or vector field whose length is out of the bounds set by the attributes `cfb_min_len`
+/// and `cfb_max_len`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LengthError {
+    /// The field, named like `Table.field`.
+    pub field: &'static str,
+    /// The number of bytes of the string or elements of the vector.
+    pub len: usize,
+    pub min: usize,
+    pub max: usize,
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.len < self.min {
+            write!(
+                f,
+                "{} has length {}, less than {}",
+                self.field, self.len, self.min
+            )
+        } else {
+            write!(
+                f,
+                "{} has length {}, more than {}",
+                self.field, self.len, self.max
+            )
+        }
+    }
+}
+
+impl error::Error for LengthError {}
+
+/// An error which stops the build, see `Builder::try_build`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum BuildError {
+    Length(LengthError),
+    /// The vector `field`, named like `Table.field`, is sorted by key and has two elements with
+    /// the same key, which the verifier rejects.
+    DuplicateKey {
+        field: &'static str,
+    },
+    /// An object is 4 GiB or more after the 32-bit offset referencing it.
+    OffsetOverflow,
+    /// A string, vector or nested buffer has 2^32 bytes or elements or more, which its 32-bit
+    /// length cannot hold.
+    LengthOverflow,
+    /// A union has the variant `Unknown` with `union_type`, whose value cannot be written without
+    /// the schema declaring it.
+    UnknownUnionVariant {
+        union_type: u8,
+    },
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::Length(error) => error.fmt(f),
+            BuildError::DuplicateKey { field } => write!(f, "{} has duplicate keys", field),
+            BuildError::OffsetOverflow => write!(
+                f,
+                "uoffset exceeds 4 GiB, use the attribute offset64 for large vectors"
+            ),
+            BuildError::LengthOverflow => write!(f, "length exceeds the 32-bit limit"),
+            BuildError::UnknownUnionVariant { union_type } => write!(
+                f,
+                "union type {} is not declared in the schema, so its value cannot be written",
+                union_type
+            ),
+        }
+    }
+}
+
+impl error::Error for BuildError {}
 
-        builder.set_scalar(self.offset_position, uoffset);
+impl From<LengthError> for BuildError {
+    fn from(error: LengthError) -> Self {
+        BuildError::Length(error)
     }
 }
 
@@ -49,7 +160,11 @@ pub struct Builder<'c> {
     buffer: Vec<u8>,
     components: Vec<DesignatedComponent<'c>>,
     new_components: Vec<DesignatedComponent<'c>>,
+    /// Components referenced by 64-bit offsets, built in order after all the other components.
+    offset64_components: Vec<DesignatedComponent<'c>>,
     vtables: HashMap<Vec<u8>, usize>,
+    /// The first error, which stops the build.
+    error: Option<BuildError>,
 }
 
 impl<'c> Builder<'c> {
@@ -58,7 +173,9 @@ impl<'c> Builder<'c> {
             buffer: vec![0u8; SIZE_OF_UOFFSET],
             components: vec![DesignatedComponent::new(0, Box::new(root))],
             new_components: Default::default(),
+            offset64_components: Default::default(),
             vtables: Default::default(),
+            error: None,
         }
     }
 
@@ -70,7 +187,9 @@ impl<'c> Builder<'c> {
             buffer,
             components: vec![DesignatedComponent::new(offset_position, Box::new(root))],
             new_components: Default::default(),
+            offset64_components: Default::default(),
             vtables: Default::default(),
+            error: None,
         }
     }
 
@@ -82,19 +201,69 @@ impl<'c> Builder<'c> {
             buffer,
             components: vec![DesignatedComponent::new(0, Box::new(root))],
             new_components: Default::default(),
+            offset64_components: Default::default(),
             vtables: Default::default(),
+            error: None,
         }
     }
 
-    pub fn build(mut self) -> Vec<u8> {
-        while let Some(component) = self.components.pop() {
-            component.build(&mut self);
-            if !self.new_components.is_empty() {
-                self.components.extend(self.new_components.drain(..).rev());
+    /// Builds the buffer.
+    ///
+    /// Panics if the build fails, see `try_build`.
+    pub fn build(self) -> Vec<u8> {
+        self.try_build().unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Builds the buffer, or fails if a string or vector field has a length out of the bounds set
+    /// by the attributes `cfb_min_len` and `cfb_max_len`, a vector sorted by key has duplicate
+    /// keys, or a 32-bit offset overflows.
+    pub fn try_build(self) -> Result<Vec<u8>, BuildError> {
+        match self.finish() {
+            (buffer, None) => Ok(buffer),
+            (_, Some(error)) => Err(error),
+        }
+    }
+
+    /// Builds the components until they are all built or an error stops the build.
+    fn finish(mut self) -> (Vec<u8>, Option<BuildError>) {
+        loop {
+            while let Some(component) = self.components.pop() {
+                component.build(&mut self);
+                if self.error.is_some() {
+                    return (self.buffer, self.error);
+                }
+                if !self.new_components.is_empty() {
+                    self.components.extend(self.new_components.drain(..).rev());
+                }
             }
+            if self.offset64_components.is_empty() {
+                break;
+            }
+            self.components
+                .extend(self.offset64_components.drain(..).rev());
         }
 
-        self.buffer
+        (self.buffer, self.error)
+    }
+
+    /// Checks that the string or vector `field`, named like `Table.field`, has `min..=max` bytes or
+    /// elements. Otherwise the build fails.
+    pub fn check_len(&mut self, field: &'static str, len: usize, min: usize, max: usize) {
+        if len < min || len > max {
+            self.fail(BuildError::Length(LengthError {
+                field,
+                len,
+                min,
+                max,
+            }));
+        }
+    }
+
+    /// Records the error, which stops the build unless an earlier error already has.
+    fn fail(&mut self, error: BuildError) {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
     }
 
     pub fn as_bytes(&self) -> &[u8] {
@@ -106,8 +275,13 @@ impl<'c> Builder<'c> {
     }
 
     pub fn push_component(&mut self, component: DesignatedComponent<'c>) {
-        assert!(component.offset_position + 4 <= self.tell());
-        self.new_components.push(component);
+        if component.offset64 {
+            assert!(component.offset_position + SIZE_OF_UOFFSET64 <= self.tell());
+            self.offset64_components.push(component);
+        } else {
+            assert!(component.offset_position + SIZE_OF_UOFFSET <= self.tell());
+            self.new_components.push(component);
+        }
     }
 
     pub fn extend_from_slice(&mut self, bytes: &[u8]) -> &mut Self {
@@ -115,13 +289,14 @@ impl<'c> Builder<'c> {
         self
     }
 
+    /// Pushes the canonical representation of the scalar, see `Scalar::canonicalize`.
     pub fn push_scalar<T: Scalar>(&mut self, mut s: T) {
-        s = s.to_le();
-        self.buffer.extend_from_slice(s.to_le().as_bytes());
+        s = s.canonicalize().to_le();
+        self.buffer.extend_from_slice(s.as_bytes());
     }
 
     pub fn set_scalar<T: Scalar>(&mut self, position: usize, mut s: T) {
-        s = s.to_le();
+        s = s.canonicalize().to_le();
         let src = s.as_bytes();
         assert!(position + src.len() <= self.buffer.len());
         let target = &mut self.buffer[position..position + src.len()];
@@ -232,7 +407,13 @@ impl<'c, T: AsRef<str>> Component<'c> for StringComponent<T> {
         builder.align(SIZE_OF_LEN);
         let position = builder.tell();
 
-        builder.push_scalar(s.len() as Len);
+        match Len::try_from(s.len()) {
+            Ok(len) => builder.push_scalar(len),
+            Err(_) => {
+                builder.fail(BuildError::LengthOverflow);
+                return position;
+            }
+        }
         builder.extend_from_slice(s.as_bytes());
         builder.push_scalar(0u8);
 
@@ -240,30 +421,94 @@ impl<'c, T: AsRef<str>> Component<'c> for StringComponent<T> {
     }
 }
 
+/// A struct stored out of line, such as the value of a union.
+#[derive(Debug)]
+pub struct StructComponent<T> {
+    value: T,
+    alignment: usize,
+}
+
+impl<T> StructComponent<T> {
+    pub fn new(value: T, alignment: usize) -> Self {
+        StructComponent { value, alignment }
+    }
+}
+
+impl<'c, T> Component<'c> for StructComponent<T>
+where
+    T: Scalar,
+{
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        builder.align(self.alignment);
+        let position = builder.tell();
+        builder.push_scalar(self.value);
+
+        position
+    }
+}
+
+/// The value of a union variant undeclared in the schema, which fails the build with
+/// `BuildError::UnknownUnionVariant`.
+///
+/// The layout of such a value is unknown, so it cannot be written back without losing its fields.
+#[derive(Debug)]
+pub struct UnknownUnionComponent {
+    union_type: u8,
+}
+
+impl UnknownUnionComponent {
+    pub fn new(union_type: u8) -> Self {
+        UnknownUnionComponent { union_type }
+    }
+}
+
+impl<'c> Component<'c> for UnknownUnionComponent {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        builder.fail(BuildError::UnknownUnionVariant {
+            union_type: self.union_type,
+        });
+        builder.tell()
+    }
+}
+
+/// A vector of scalars or structs, which are taken from any iterable of exact size, such as a `Vec`
+/// or the iterator `slice.iter().copied()`.
 #[derive(Debug)]
 pub struct ScalarVectorComponent<T> {
-    scalars: Vec<T>,
+    scalars: T,
     alignment: usize,
 }
 
 impl<T> ScalarVectorComponent<T> {
-    pub fn new(scalars: Vec<T>, alignment: usize) -> Self {
+    pub fn new(scalars: T, alignment: usize) -> Self {
         ScalarVectorComponent { scalars, alignment }
     }
 }
 
-impl<'c, T> Component<'c> for ScalarVectorComponent<T>
+impl<'c, T, I, S> Component<'c> for ScalarVectorComponent<T>
 where
-    T: Scalar,
+    T: IntoIterator<Item = S, IntoIter = I>,
+    I: ExactSizeIterator<Item = S>,
+    S: Scalar,
 {
     fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
         builder.align_after(SIZE_OF_LEN, self.alignment);
         let position = builder.tell();
 
-        builder.push_scalar(self.scalars.len() as Len);
-        for s in self.scalars {
+        let scalars = self.scalars.into_iter();
+        match Len::try_from(scalars.len()) {
+            Ok(len) => builder.push_scalar(len),
+            Err(_) => {
+                builder.fail(BuildError::LengthOverflow);
+                return position;
+            }
+        }
+        // The alignment may be forced above the scalar alignment, which only applies to the first
+        // element.
+        debug_assert_eq!(builder.tell(), align(builder.tell(), self.alignment));
+        for s in scalars {
             // Scalar MUST already aligned
-            debug_assert_eq!(builder.tell(), align(builder.tell(), self.alignment));
+            debug_assert_eq!(builder.tell(), align(builder.tell(), mem::align_of::<S>()));
             builder.push_scalar(s);
         }
 
@@ -272,11 +517,39 @@ where
 }
 
 #[derive(Debug)]
-pub struct ReferenceVectorComponent<T>(T);
+pub struct ReferenceVectorComponent<T> {
+    references: T,
+    /// The field of a vector sorted by key which has duplicate keys, which fails the build.
+    duplicate_key: Option<&'static str>,
+}
 
 impl<T> ReferenceVectorComponent<T> {
     pub fn new(references: T) -> Self {
-        ReferenceVectorComponent(references)
+        ReferenceVectorComponent {
+            references,
+            duplicate_key: None,
+        }
+    }
+}
+
+impl<C> ReferenceVectorComponent<Vec<C>> {
+    /// Sorts the references of the vector `field`, named like `Table.field`, with `compare`, such
+    /// as by the `key` field of tables, so the encoding does not depend on the insertion order and
+    /// readers can binary search the vector.
+    ///
+    /// The build fails if two references compare equal.
+    pub fn sorted_by<F>(field: &'static str, mut references: Vec<C>, mut compare: F) -> Self
+    where
+        F: FnMut(&C, &C) -> Ordering,
+    {
+        references.sort_by(&mut compare);
+        let duplicate = references
+            .windows(2)
+            .any(|pair| compare(&pair[0], &pair[1]) == Ordering::Equal);
+        ReferenceVectorComponent {
+            references,
+            duplicate_key: if duplicate { Some(field) } else { None },
+        }
     }
 }
 
@@ -287,13 +560,22 @@ where
     C: Component<'c> + 'c,
 {
     fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        if let Some(field) = self.duplicate_key {
+            builder.fail(BuildError::DuplicateKey { field });
+        }
         builder.align_after(SIZE_OF_LEN, SIZE_OF_UOFFSET);
         let position = builder.tell();
 
-        let iter = self.0.into_iter();
+        let iter = self.references.into_iter();
         let len = iter.len();
 
-        builder.push_scalar(len as Len);
+        match Len::try_from(len) {
+            Ok(len) => builder.push_scalar(len),
+            Err(_) => {
+                builder.fail(BuildError::LengthOverflow);
+                return position;
+            }
+        }
         builder.pad(len * SIZE_OF_UOFFSET);
         let mut current_offset_position = position + SIZE_OF_LEN;
         for c in iter {
@@ -309,13 +591,21 @@ where
 }
 
 #[derive(Debug)]
-pub struct NestedBufferComponent<T>(T);
+pub struct NestedBufferComponent<T> {
+    root: T,
+    alignment: usize,
+}
 
 impl<T> NestedBufferComponent<T> {
     const NESTED_BUFFER_ALIGNMENT: usize = 8;
 
     pub fn new(root: T) -> Self {
-        NestedBufferComponent(root)
+        Self::with_alignment(root, Self::NESTED_BUFFER_ALIGNMENT)
+    }
+
+    /// Aligns the nested buffer to `alignment`, which is set by `force_align` on the field.
+    pub fn with_alignment(root: T, alignment: usize) -> Self {
+        NestedBufferComponent { root, alignment }
     }
 }
 
@@ -324,16 +614,22 @@ where
     T: Component<'c> + 'c,
 {
     fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
-        builder.align_after(SIZE_OF_LEN, Self::NESTED_BUFFER_ALIGNMENT);
+        builder.align_after(SIZE_OF_LEN, self.alignment);
         let len_position = builder.tell();
         builder.pad(SIZE_OF_LEN);
 
-        let buffer = mem::replace(&mut builder.buffer, Vec::new());
-        let nested_builder = Builder::with_buffer(buffer, self.0);
-        let nested_buffer = nested_builder.build();
+        let buffer = mem::take(&mut builder.buffer);
+        let nested_builder = Builder::with_buffer(buffer, self.root);
+        let (nested_buffer, error) = nested_builder.finish();
+        if let Some(error) = error {
+            builder.fail(error);
+        }
         let len = nested_buffer.len() - len_position - SIZE_OF_LEN;
-        mem::replace(&mut builder.buffer, nested_buffer);
-        builder.set_scalar(len_position, len as Len);
+        builder.buffer = nested_buffer;
+        match Len::try_from(len) {
+            Ok(len) => builder.set_scalar(len_position, len),
+            Err(_) => builder.fail(BuildError::LengthOverflow),
+        }
 
         len_position
     }
@@ -356,6 +652,27 @@ mod tests {
         assert_eq!(expect, buf);
     }
 
+    #[test]
+    fn test_check_len() {
+        let component = |builder: &mut Builder| {
+            builder.check_len("Table.field", 2, 0, 1);
+            builder.check_len("Table.other", 0, 1, 1);
+            builder.tell()
+        };
+        let error = LengthError {
+            field: "Table.field",
+            len: 2,
+            min: 0,
+            max: 1,
+        };
+        let error = BuildError::Length(error);
+        assert_eq!(Err(error.clone()), Builder::new(component).try_build());
+        assert_eq!(
+            Err(error),
+            Builder::new(NestedBufferComponent::new(component)).try_build()
+        );
+    }
+
     #[test]
     fn test_owned_string_component() {
         let s = String::from("String");
@@ -414,6 +731,20 @@ mod tests {
         assert_eq!(expect, buf);
     }
 
+    #[test]
+    fn test_struct_component_alignment() {
+        let builder = Builder::new(StructComponent::new(7u64, 8));
+        let buf = builder.build();
+
+        let expect = [
+            &8u32.to_le_bytes()[..],
+            &[0u8, 0, 0, 0], // padding
+            &7u64.to_le_bytes(),
+        ]
+        .concat();
+        assert_eq!(expect, buf);
+    }
+
     #[test]
     fn test_scalar_vector_component() {
         let scalars = vec![1u32, 9];
@@ -433,6 +764,14 @@ mod tests {
         assert_eq!(expect, buf);
     }
 
+    #[test]
+    fn test_borrowed_scalar_vector_component() {
+        let scalars = [1u32, 9];
+        let borrowed = Builder::new(ScalarVectorComponent::new(scalars.iter().copied(), 4));
+        let owned = Builder::new(ScalarVectorComponent::new(scalars.to_vec(), 4));
+        assert_eq!(owned.build(), borrowed.build());
+    }
+
     #[test]
     fn test_reference_vector_component() {
         let builder = Builder::new(ReferenceVectorComponent::new(vec![
@@ -465,6 +804,125 @@ mod tests {
         assert_eq!(expect, buf);
     }
 
+    #[test]
+    fn test_reference_vector_component_sorted_by() {
+        let sorted = Builder::new(ReferenceVectorComponent::sorted_by(
+            "Table.field",
+            vec![
+                StringComponent::new(String::from("s2")),
+                StringComponent::new(String::from("s1")),
+            ],
+            |a, b| a.0.cmp(&b.0),
+        ))
+        .build();
+        let expect = Builder::new(ReferenceVectorComponent::new(vec![
+            StringComponent::new(String::from("s1")),
+            StringComponent::new(String::from("s2")),
+        ]))
+        .build();
+        assert_eq!(expect, sorted);
+
+        let duplicate = Builder::new(ReferenceVectorComponent::sorted_by(
+            "Table.field",
+            vec![StringComponent::new("s"), StringComponent::new("s")],
+            |a, b| a.0.cmp(b.0),
+        ))
+        .try_build();
+        assert_eq!(
+            Err(BuildError::DuplicateKey {
+                field: "Table.field"
+            }),
+            duplicate
+        );
+    }
+
+    #[test]
+    fn test_offset64_component() {
+        let builder = Builder::new(|builder: &mut Builder| {
+            let position = builder.tell();
+            // The offset64 field is pushed first, but its component is built last.
+            builder.pad(SIZE_OF_UOFFSET64 + SIZE_OF_UOFFSET);
+            builder.push_component(DesignatedComponent::with_offset64(
+                position,
+                Box::new(ScalarVectorComponent::new(vec![1u64], 8)),
+            ));
+            builder.push_component(DesignatedComponent::new(
+                position + SIZE_OF_UOFFSET64,
+                Box::new(StringComponent::new("s")),
+            ));
+            position
+        });
+        let buf = builder.build();
+
+        let expect = [
+            // root uoffset
+            &4u32.to_le_bytes()[..],
+            // uoffset64 of the vector
+            &24u64.to_le_bytes(),
+            // uoffset of the string
+            &4u32.to_le_bytes(),
+            // string
+            &1u32.to_le_bytes(),
+            b"s\0",
+            // padding so the elements are aligned to 8
+            &[0u8; 6],
+            // vector
+            &1u32.to_le_bytes(),
+            &1u64.to_le_bytes(),
+        ]
+        .concat();
+        assert_eq!(expect, buf);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_uoffset_overflow() {
+        // The root is reported 4 GiB after its offset, without allocating the buffer.
+        let builder = Builder::new(|_builder: &mut Builder| 1usize << 32);
+        assert_eq!(Err(BuildError::OffsetOverflow), builder.try_build());
+    }
+
+    /// An iterator which claims `len` items without yielding any, to exercise lengths beyond
+    /// `Len` without allocating them.
+    struct ClaimedLen<T>(usize, std::marker::PhantomData<T>);
+
+    impl<T> ClaimedLen<T> {
+        fn new(len: usize) -> Self {
+            ClaimedLen(len, std::marker::PhantomData)
+        }
+    }
+
+    impl<T> Iterator for ClaimedLen<T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            None
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.0, Some(self.0))
+        }
+    }
+
+    impl<T> ExactSizeIterator for ClaimedLen<T> {}
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_length_overflow() {
+        let scalars = ClaimedLen::<u8>::new(1 << 32);
+        let builder = Builder::new(ScalarVectorComponent::new(scalars, 1));
+        assert_eq!(Err(BuildError::LengthOverflow), builder.try_build());
+
+        let references = ClaimedLen::<StringComponent<&str>>::new(1 << 32);
+        let builder = Builder::new(ReferenceVectorComponent::new(references));
+        assert_eq!(Err(BuildError::LengthOverflow), builder.try_build());
+
+        // The largest length still fits.
+        let scalars = ClaimedLen::<u8>::new(Len::MAX as usize);
+        let builder = Builder::new(ScalarVectorComponent::new(scalars, 1));
+        assert!(builder.try_build().is_ok());
+    }
+
     #[test]
     fn test_deduplicate_vtable() {
         let mut builder = Builder::new(|builder: &mut Builder| builder.tell());
@@ -572,4 +1030,18 @@ mod tests {
         .concat();
         assert_eq!(expect, buf);
     }
+
+    #[test]
+    fn test_nested_buffer_with_alignment() {
+        let builder = Builder::new(NestedBufferComponent::with_alignment(
+            StringComponent::new("String"),
+            32,
+        ));
+        let buf = builder.build();
+
+        // The root uoffset is followed by padding so the nested buffer starts at 32.
+        assert_eq!(&buf[..4], &28u32.to_le_bytes());
+        assert_eq!(&buf[28..32], &15u32.to_le_bytes());
+        assert_eq!(&buf[32..36], &4u32.to_le_bytes());
+    }
 }
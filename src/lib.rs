@@ -1,4 +1,6 @@
 pub mod alignment;
 pub mod builder;
 pub mod scalar;
+#[cfg(feature = "proptest")]
+pub mod strategy;
 pub mod types;
@@ -1,11 +1,19 @@
-use std::intrinsics::copy_nonoverlapping;
-use std::mem::{size_of, uninitialized};
+use std::mem::size_of;
+use std::ptr;
 use std::slice;
 
 pub trait Scalar: Sized {
     fn to_le(self) -> Self;
     fn from_le(x: Self) -> Self;
 
+    /// Returns the canonical representation of the value, which is the one written by the builder.
+    ///
+    /// Only floats have several representations of the same value: every NaN becomes the quiet NaN
+    /// with an empty payload, and `-0.0` becomes `0.0`.
+    fn canonicalize(self) -> Self {
+        self
+    }
+
     /// Gets bytes representing the scalar in native endian.
     fn as_bytes(&self) -> &[u8] {
         unsafe { slice::from_raw_parts(self as *const Self as *const u8, size_of::<Self>()) }
@@ -14,12 +22,15 @@ pub trait Scalar: Sized {
     /// Read scalar from bytes in native endian.
     fn from_bytes(bytes: &[u8]) -> Self {
         assert!(bytes.len() >= size_of::<Self>());
-        let mut x: Self = unsafe { uninitialized() };
-        unsafe { copy_nonoverlapping(bytes.as_ptr() as *const Self, &mut x as *mut Self, 1) };
-        x
+        unsafe { ptr::read_unaligned(bytes.as_ptr() as *const Self) }
     }
 }
 
+/// Bits of the only NaN written by the builder, the quiet NaN with an empty payload.
+pub const CANONICAL_NAN_F32_BITS: u32 = 0x7fc0_0000;
+/// Bits of the only NaN written by the builder, the quiet NaN with an empty payload.
+pub const CANONICAL_NAN_F64_BITS: u64 = 0x7ff8_0000_0000_0000;
+
 const FALSE_BYTES: &[u8] = &[0];
 const TRUE_BYTES: &[u8] = &[1];
 
@@ -82,7 +93,7 @@ impl_scalar_for_int!(i64);
 impl_scalar_for_int!(u64);
 
 macro_rules! impl_scalar_for_float {
-    ($ty:ident) => {
+    ($ty:ident, $nan_bits:expr) => {
         impl Scalar for $ty {
             fn to_le(self) -> Self {
                 #[cfg(target_endian = "little")]
@@ -104,21 +115,55 @@ macro_rules! impl_scalar_for_float {
                     Self::from_bits(x.to_bits().swap_bytes())
                 }
             }
+            fn canonicalize(self) -> Self {
+                if self.is_nan() {
+                    Self::from_bits($nan_bits)
+                } else if self == 0.0 {
+                    0.0
+                } else {
+                    self
+                }
+            }
         }
     };
 }
 
-impl_scalar_for_float!(f32);
-impl_scalar_for_float!(f64);
+impl_scalar_for_float!(f32, CANONICAL_NAN_F32_BITS);
+impl_scalar_for_float!(f64, CANONICAL_NAN_F64_BITS);
+
+/// Fixed-length arrays in structs, which convert every element.
+impl<T: Scalar, const N: usize> Scalar for [T; N] {
+    fn to_le(self) -> Self {
+        self.map(T::to_le)
+    }
+    fn from_le(x: Self) -> Self {
+        x.map(T::from_le)
+    }
+    fn canonicalize(self) -> Self {
+        self.map(T::canonicalize)
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_unaligned_bytes() {
+        let bytes = [0u8, 1, 2, 3, 4, 5, 6, 7, 8];
+        // One of the two slices starts at an odd address.
+        for start in 0..2 {
+            let mut expected = [0u8; 8];
+            expected.copy_from_slice(&bytes[start..start + 8]);
+            let expected = u64::from_ne_bytes(expected);
+            assert_eq!(expected, u64::from_bytes(&bytes[start..]));
+        }
+    }
+
     #[test]
     fn test_scalar() {
-        assert_eq!(true, bool::from_le(true.to_le()));
-        assert_eq!(false, bool::from_le(false.to_le()));
+        assert!(bool::from_le(true.to_le()));
+        assert!(!bool::from_le(false.to_le()));
         assert_eq!(1u8, u8::from_le(1u8.to_le()));
         assert_eq!(1u16, u16::from_le(1u16.to_le()));
         #[allow(clippy::float_cmp)]
@@ -127,16 +172,40 @@ mod tests {
         }
 
         assert_eq!(1u8, 1u8.to_le());
+        assert_eq!([1u16, 2], <[u16; 2]>::from_le([1u16, 2].to_le()));
 
         #[cfg(target_endian = "little")]
         {
             assert_eq!(1u16, 1u16.to_le());
             assert_eq!(1u32, 1u32.to_le());
+            assert_eq!([1u16, 2], [1u16, 2].to_le());
         }
         #[cfg(not(target_endian = "little"))]
         {
             assert_eq!(1u16.swap_bytes(), 1u16.to_le());
             assert_eq!(1u32.swap_bytes(), 1u32.to_le());
+            assert_eq!([1u16.swap_bytes(), 2u16.swap_bytes()], [1u16, 2].to_le());
         }
     }
+
+    #[test]
+    fn test_canonicalize_float() {
+        let payload_nan = f32::from_bits(0x7fc0_0001);
+        assert_eq!(CANONICAL_NAN_F32_BITS, payload_nan.canonicalize().to_bits());
+        assert_eq!(CANONICAL_NAN_F32_BITS, (-f32::NAN).canonicalize().to_bits());
+        assert_eq!(
+            CANONICAL_NAN_F64_BITS,
+            f64::from_bits(0xfff0_0000_0000_0001)
+                .canonicalize()
+                .to_bits()
+        );
+        assert_eq!(0, (-0.0f32).canonicalize().to_bits());
+        assert_eq!(0, (-0.0f64).canonicalize().to_bits());
+        assert_eq!((-1.5f64).to_bits(), (-1.5f64).canonicalize().to_bits());
+        assert_eq!(
+            [0, CANONICAL_NAN_F32_BITS],
+            [-0.0f32, payload_nan].canonicalize().map(f32::to_bits)
+        );
+        assert_eq!(7u32, 7u32.canonicalize());
+    }
 }
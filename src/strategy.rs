@@ -0,0 +1,183 @@
+//! Support for the proptest strategies generated by `cfbc --proptest`.
+//!
+//! Every generated table and union implements `proptest::arbitrary::Arbitrary` with `Config` as
+//! the parameters, so the size of generated values can be tuned with `any_with`.
+use proptest::collection::{size_range, vec, SizeRange};
+use proptest::option::{self, OptionStrategy};
+use proptest::prelude::*;
+use std::cmp::Ordering;
+use std::convert::TryInto;
+
+/// Size knobs shared by all generated strategies.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Length of vectors of tables and strings.
+    pub vector_len: SizeRange,
+    /// Length of vectors of scalars and structs.
+    pub scalar_vector_len: SizeRange,
+    /// Number of chars in strings.
+    pub string_len: SizeRange,
+    /// Probability that an optional table, union or nested buffer is present.
+    pub some_probability: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            vector_len: size_range(0..4),
+            scalar_vector_len: size_range(0..32),
+            string_len: size_range(0..32),
+            some_probability: 0.9,
+        }
+    }
+}
+
+/// Generates strings with `config.string_len` chars.
+pub fn string(config: &Config) -> BoxedStrategy<String> {
+    vec(any::<char>(), config.string_len.clone())
+        .prop_map(|chars| chars.into_iter().collect())
+        .boxed()
+}
+
+/// Generates strings like `string`, truncated to at most `max` bytes and padded to at least `min`
+/// bytes, for fields with the attributes `cfb_min_len` and `cfb_max_len`.
+pub fn bounded_string(config: &Config, min: usize, max: usize) -> BoxedStrategy<String> {
+    string(config)
+        .prop_map(move |mut s| {
+            while s.len() > max {
+                s.pop();
+            }
+            while s.len() < min {
+                s.push('a');
+            }
+            s
+        })
+        .boxed()
+}
+
+/// Generates optional values which are present with probability `config.some_probability`.
+pub fn option<S: Strategy>(config: &Config, strategy: S) -> OptionStrategy<S> {
+    option::weighted(config.some_probability, strategy)
+}
+
+/// Generates vectors whose elements are distinct by `compare`, such as vectors of tables sorted by
+/// the `key` field, which must not contain duplicate keys.
+pub fn unique_vec<S, F>(strategy: S, len: SizeRange, compare: F) -> BoxedStrategy<Vec<S::Value>>
+where
+    S: Strategy + 'static,
+    F: Fn(&S::Value, &S::Value) -> Ordering + 'static,
+{
+    vec(strategy, len)
+        .prop_map(move |mut values| {
+            values.sort_by(&compare);
+            values.dedup_by(|a, b| compare(a, b) == Ordering::Equal);
+            values
+        })
+        .boxed()
+}
+
+/// Generates vectors like `vec`, truncated to at most `max` elements and padded to at least `min`
+/// elements, for fields with the attributes `cfb_min_len` and `cfb_max_len`.
+pub fn bounded_vec<S>(
+    strategy: S,
+    len: SizeRange,
+    min: usize,
+    max: usize,
+) -> BoxedStrategy<Vec<S::Value>>
+where
+    S: Strategy + Clone + 'static,
+{
+    (vec(strategy.clone(), len), vec(strategy, min))
+        .prop_map(move |(mut values, padding)| {
+            let missing = min.saturating_sub(values.len());
+            values.extend(padding.into_iter().take(missing));
+            values.truncate(max);
+            values
+        })
+        .boxed()
+}
+
+/// Generates vectors like `unique_vec` with `min..=max` elements, for sorted vectors with the
+/// attributes `cfb_min_len` and `cfb_max_len`. Values with fewer than `min` distinct elements are
+/// rejected.
+pub fn bounded_unique_vec<S, F>(
+    strategy: S,
+    len: SizeRange,
+    min: usize,
+    max: usize,
+    compare: F,
+) -> BoxedStrategy<Vec<S::Value>>
+where
+    S: Strategy + Clone + 'static,
+    F: Fn(&S::Value, &S::Value) -> Ordering + 'static,
+{
+    bounded_vec(strategy, len, min, max)
+        .prop_map(move |mut values| {
+            values.sort_by(&compare);
+            values.dedup_by(|a, b| compare(a, b) == Ordering::Equal);
+            values
+        })
+        .prop_filter("fewer distinct elements than cfb_min_len", move |values| {
+            values.len() >= min
+        })
+        .boxed()
+}
+
+/// Generates fixed-length arrays of any length, while `any` only supports arrays of up to 32
+/// elements.
+pub fn array<S, const N: usize>(strategy: S) -> BoxedStrategy<[S::Value; N]>
+where
+    S: Strategy + 'static,
+{
+    vec(strategy, N)
+        .prop_map(|values| values.try_into().expect("vector of N elements"))
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn proptest_string_len(
+            s in string(&Config {
+                string_len: size_range(2..4),
+                ..Default::default()
+            })
+        ) {
+            let len = s.chars().count();
+            assert!((2..4).contains(&len));
+        }
+
+        #[test]
+        fn proptest_unique_vec(
+            values in unique_vec(0u8..4, size_range(0..8), |a: &u8, b: &u8| a.cmp(b))
+        ) {
+            assert!(values.windows(2).all(|w| w[0] < w[1]));
+        }
+
+        #[test]
+        fn proptest_array(values in array::<_, 40>(1u8..)) {
+            assert!(values.iter().all(|v| *v > 0));
+        }
+
+        #[test]
+        fn proptest_bounded_string(s in bounded_string(&Config::default(), 3, 8)) {
+            assert!((3..=8).contains(&s.len()));
+        }
+
+        #[test]
+        fn proptest_bounded_vec(values in bounded_vec(any::<u8>(), size_range(0..8), 2, 5)) {
+            assert!((2..=5).contains(&values.len()));
+        }
+
+        #[test]
+        fn proptest_bounded_unique_vec(
+            values in bounded_unique_vec(0u8..16, size_range(0..8), 2, 5, |a: &u8, b: &u8| a.cmp(b))
+        ) {
+            assert!((2..=5).contains(&values.len()));
+            assert!(values.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+}
@@ -8,11 +8,15 @@ pub type SOffset = i32;
 pub type VOffset = u16;
 /// Length of vector and string.
 pub type Len = u32;
+/// Unsigned offset used by the fields with the attribute `offset64`, so the referenced vectors can
+/// be beyond 4 GiB.
+pub type UOffset64 = u64;
 
 pub const SIZE_OF_UOFFSET: usize = size_of::<UOffset>();
 pub const SIZE_OF_SOFFSET: usize = size_of::<SOffset>();
 pub const SIZE_OF_VOFFSET: usize = size_of::<VOffset>();
 pub const SIZE_OF_LEN: usize = size_of::<Len>();
+pub const SIZE_OF_UOFFSET64: usize = size_of::<UOffset64>();
 
 #[cfg(test)]
 mod tests {
@@ -24,5 +28,6 @@ mod tests {
         assert_eq!(4, SIZE_OF_SOFFSET);
         assert_eq!(2, SIZE_OF_VOFFSET);
         assert_eq!(4, SIZE_OF_LEN);
+        assert_eq!(8, SIZE_OF_UOFFSET64);
     }
 }
@@ -0,0 +1,126 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use cfb::strategy::Config;
+use common::bit_flags_builder::example as cfbe;
+use common::bit_flags_generated::example as fbe;
+use flatbuffers::FlatBufferBuilder;
+use flatbuffers_verifier::{get_root, Error};
+use proptest::prelude::*;
+
+/// Builds a `File` with flatc, so the flags can contain undeclared bits.
+fn file(permission: u8, permissions: Option<&[u8]>) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let permissions = permissions.map(|bits| {
+        let flags: Vec<_> = bits
+            .iter()
+            .map(|bits| fbe::Permission::from_bits_retain(*bits))
+            .collect();
+        fbb.create_vector(&flags)
+    });
+    let root = fbe::File::create(
+        &mut fbb,
+        &fbe::FileArgs {
+            permission: fbe::Permission::from_bits_retain(permission),
+            permissions,
+        },
+    );
+    fbb.finish_minimal(root);
+    fbb.finished_data().to_vec()
+}
+
+fn _test_bit_flags_builder(file: cfbe::File) {
+    let buf = Builder::new(file.clone()).build();
+    let root = get_root::<fbe::File>(&buf).expect("verified");
+
+    assert_eq!(file.permission.bits(), root.permission().bits());
+    let permissions = root.permissions().map_or(vec![], |permissions| {
+        common::collect_flatbuffers_vector(&permissions)
+    });
+    assert_eq!(file.permissions.len(), permissions.len());
+    for (expected, permission) in file.permissions.iter().zip(permissions) {
+        assert_eq!(expected.bits(), permission.bits());
+    }
+}
+
+#[test]
+fn test_bit_flags_operations() {
+    let read_write = cfbe::Permission::Read | cfbe::Permission::Write;
+    assert_eq!(read_write.bits(), 3);
+    assert!(read_write.contains(cfbe::Permission::Write));
+    assert!(!read_write.contains(cfbe::Permission::Execute));
+    assert!(read_write.intersects(cfbe::Permission::Read | cfbe::Permission::Execute));
+    assert_eq!(read_write - cfbe::Permission::Read, cfbe::Permission::Write);
+    assert_eq!(read_write & cfbe::Permission::Read, cfbe::Permission::Read);
+    assert_eq!(!read_write, cfbe::Permission::Execute);
+    assert_eq!(
+        read_write ^ cfbe::Permission::all(),
+        cfbe::Permission::Execute
+    );
+
+    let mut flags = cfbe::Permission::empty();
+    assert!(flags.is_empty());
+    flags.insert(cfbe::Permission::Execute);
+    flags |= cfbe::Permission::Read;
+    flags.remove(cfbe::Permission::Read);
+    assert_eq!(flags, cfbe::Permission::Execute);
+
+    assert_eq!(
+        cfbe::Permission::from_bits(5),
+        Some(cfbe::Permission::Read | flags)
+    );
+    assert_eq!(cfbe::Permission::from_bits(8), None);
+    assert_eq!(
+        cfbe::Permission::from_bits_truncate(0xff),
+        cfbe::Permission::all()
+    );
+}
+
+#[test]
+fn test_bit_flags_builder() {
+    _test_bit_flags_builder(cfbe::File {
+        permission: cfbe::Permission::Read | cfbe::Permission::Execute,
+        permissions: vec![
+            cfbe::Permission::empty(),
+            cfbe::Permission::all(),
+            cfbe::Permission::Write,
+        ],
+    });
+    _test_bit_flags_builder(cfbe::File {
+        permission: cfbe::Permission::empty(),
+        permissions: vec![],
+    });
+    _test_bit_flags_builder(cfbe::File::default());
+}
+
+#[test]
+fn test_bit_flags_default() {
+    assert_eq!(cfbe::File::default().permission, cfbe::Permission::Read);
+
+    // The default is omitted, so the table is the same as the one without any field.
+    let buf = Builder::new(cfbe::File::default()).build();
+    assert_eq!(buf, file(1, None));
+}
+
+#[test]
+fn test_bit_flags_verifier() {
+    assert!(get_root::<fbe::File>(&file(7, Some(&[0, 7]))).is_ok());
+
+    for buf in [file(8, None), file(0x81, None), file(0, Some(&[1, 16]))] {
+        assert_eq!(
+            get_root::<fbe::File>(&buf).map(|_| ()),
+            Err(Error::UnknownEnumValue)
+        );
+    }
+
+    let buf = file(9, None);
+    let root = flatbuffers::get_root::<fbe::File>(&buf);
+    assert_eq!(root.try_permission(), Err(Error::UnknownEnumValue));
+}
+
+proptest! {
+    #[test]
+    fn proptest_bit_flags_builder(file in any_with::<cfbe::File>(Config::default())) {
+        _test_bit_flags_builder(file);
+    }
+}
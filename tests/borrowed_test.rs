@@ -0,0 +1,122 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use cfb::strategy::Config;
+use common::data_order_builder as do_cfbe;
+use common::sorted_vector_builder::example as sv_cfbe;
+use common::sorted_vector_generated::example as sv;
+use common::union_variants_builder::example as uv_cfbe;
+use flatbuffers_verifier::get_root;
+use proptest::prelude::*;
+
+fn monster() -> do_cfbe::Monster {
+    do_cfbe::Monster {
+        name: "monster".to_string(),
+        stat: Some(do_cfbe::Stat { hp: 10, mp: 5 }),
+        loots: vec![
+            do_cfbe::Item {
+                name: "sword".to_string(),
+            },
+            do_cfbe::Item {
+                name: "shield".to_string(),
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_build_by_reference() {
+    let monster = monster();
+    let first = Builder::new(&monster).build();
+    let second = Builder::new(&monster).build();
+    assert_eq!(first, second);
+    assert_eq!(Builder::new(monster).build(), first);
+}
+
+#[test]
+fn test_build_borrowed_table() {
+    let names = ["sword", "shield"];
+    let loots: Vec<_> = names.iter().map(|name| do_cfbe::ItemRef { name }).collect();
+    let monster_ref = do_cfbe::MonsterRef {
+        name: "monster",
+        stat: Some(do_cfbe::StatRef { hp: 10, mp: 5 }),
+        loots: &loots,
+    };
+    assert_eq!(
+        Builder::new(monster()).build(),
+        Builder::new(monster_ref).build()
+    );
+}
+
+#[test]
+fn test_build_borrowed_sorted_vector() {
+    let monsters = [
+        sv_cfbe::MonsterRef { name: "b", hp: 2 },
+        sv_cfbe::MonsterRef { name: "a", hp: 1 },
+    ];
+    let stats = [
+        sv_cfbe::StatRef { id: 2, value: 20 },
+        sv_cfbe::StatRef { id: 1, value: 10 },
+    ];
+    let buf = Builder::new(sv_cfbe::IndexRef {
+        monsters: &monsters,
+        stats: &stats,
+    })
+    .build();
+
+    let root = get_root::<sv::Index>(&buf).expect("verified");
+    let names: Vec<_> = root
+        .monsters()
+        .expect("monsters")
+        .iter()
+        .map(|monster| monster.name())
+        .collect();
+    assert_eq!(vec!["a", "b"], names);
+    // The borrowed slices are sorted in the buffer, not in place.
+    assert_eq!("b", monsters[0].name);
+}
+
+#[test]
+fn test_build_borrowed_union() {
+    let roles = [
+        uv_cfbe::RoleRef::Name("hero"),
+        uv_cfbe::RoleRef::Hero(uv_cfbe::HeroRef { hp: 10 }),
+        uv_cfbe::RoleRef::Point(uv_cfbe::Point {
+            x: 1,
+            y: 2,
+            ..Default::default()
+        }),
+    ];
+    let player_ref = uv_cfbe::PlayerRef {
+        role: Some(uv_cfbe::RoleRef::Name("player")),
+        roles: &roles,
+    };
+    let player = uv_cfbe::Player {
+        role: Some(uv_cfbe::Role::Name("player".to_string())),
+        roles: vec![
+            uv_cfbe::Role::Name("hero".to_string()),
+            uv_cfbe::Role::Hero(uv_cfbe::Hero { hp: 10 }),
+            uv_cfbe::Role::Point(uv_cfbe::Point {
+                x: 1,
+                y: 2,
+                ..Default::default()
+            }),
+        ],
+    };
+    assert_eq!(
+        Builder::new(&player).build(),
+        Builder::new(player_ref).build()
+    );
+}
+
+proptest! {
+    #[test]
+    fn proptest_build_by_reference(player in any_with::<uv_cfbe::Player>(Config::default())) {
+        prop_assert_eq!(Builder::new(&player).build(), Builder::new(player.clone()).build());
+    }
+
+    #[test]
+    fn proptest_build_sorted_by_reference(index in any_with::<sv_cfbe::Index>(Config::default())) {
+        prop_assert_eq!(Builder::new(&index).build(), Builder::new(index.clone()).build());
+    }
+}
@@ -0,0 +1,94 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use flatbuffers_verifier::{get_lazy_root, get_root, Error};
+
+fn monster() -> Vec<u8> {
+    use common::data_order_builder as cfbe;
+
+    Builder::new(cfbe::Monster {
+        name: "orc".to_owned(),
+        stat: Some(cfbe::Stat { hp: 10, mp: 20 }),
+        loots: vec![cfbe::Item {
+            name: "sword".to_owned(),
+        }],
+    })
+    .build()
+}
+
+fn find(buf: &[u8], needle: &[u8]) -> usize {
+    buf.windows(needle.len())
+        .position(|window| window == needle)
+        .expect("needle in buffer")
+}
+
+#[test]
+fn test_checked_accessors_on_valid_buffer() {
+    use common::data_order_generated::Monster;
+
+    let buf = monster();
+    let root = get_lazy_root::<Monster>(&buf).expect("lazy root");
+
+    assert_eq!(root.try_name(), Ok(Some("orc")));
+    let stat = root.try_stat().unwrap().unwrap();
+    assert_eq!(stat.try_hp(), Ok(10));
+    assert_eq!(stat.try_mp(), Ok(20));
+    let loots = root.try_loots().unwrap().unwrap();
+    assert_eq!(loots.len(), 1);
+    assert_eq!(loots.get(0).try_name(), Ok(Some("sword")));
+}
+
+#[test]
+fn test_checked_accessors_only_check_touched_bytes() {
+    use common::data_order_generated::Monster;
+
+    let mut buf = monster();
+    let pos = find(&buf, b"sword");
+    buf[pos] = 0xff;
+    assert_eq!(get_root::<Monster>(&buf), Err(Error::NonUtf8String));
+
+    let root = get_lazy_root::<Monster>(&buf).expect("lazy root");
+    assert_eq!(root.try_name(), Ok(Some("orc")));
+    assert_eq!(root.try_stat().unwrap().unwrap().try_hp(), Ok(10));
+    let loots = root.try_loots().unwrap().unwrap();
+    assert_eq!(loots.get(0).try_name(), Err(Error::NonUtf8String));
+}
+
+#[test]
+fn test_checked_accessors_on_truncated_buffer() {
+    use common::data_order_generated::Monster;
+
+    let buf = monster();
+    let pos = find(&buf, b"sword");
+    // Cut the buffer inside the string length.
+    let buf = buf[..pos - 2].to_vec();
+
+    let root = get_lazy_root::<Monster>(&buf).expect("lazy root");
+    let loots = root.try_loots().unwrap().unwrap();
+    assert_eq!(loots.get(0).try_name(), Err(Error::OutOfBounds));
+}
+
+#[test]
+fn test_checked_union_accessors() {
+    use common::union_builder::example as cfbe;
+    use common::union_generated::example::Player;
+
+    let buf = Builder::new(cfbe::Player {
+        role: Some(cfbe::Role::Monster(cfbe::Monster { hp: 7 })),
+    })
+    .build();
+    let root = get_lazy_root::<Player>(&buf).expect("lazy root");
+
+    assert!(root.try_role_as_hero().unwrap().is_none());
+    assert_eq!(root.try_role_as_monster().unwrap().unwrap().try_hp(), Ok(7));
+}
+
+#[test]
+fn test_lazy_root_out_of_bounds() {
+    use common::union_generated::example::Player;
+
+    let buf = [le!(8u32)].concat();
+    let root = get_lazy_root::<Player>(&buf).expect("lazy root");
+    assert_eq!(root.try_role_type(), Err(Error::OutOfBounds));
+    assert!(get_lazy_root::<Player>(&buf[..2]).is_err());
+}
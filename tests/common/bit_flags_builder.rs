@@ -0,0 +1,314 @@
+//! This file is auto-generated by cfbc.
+pub mod example {
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
+
+    use cfb::builder::{
+        Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
+    };
+    use cfb::scalar::Scalar;
+    use cfb::types::{SOffset, SIZE_OF_SOFFSET};
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
+
+    /// Flags of the `bit_flags` enum `Permission`, which are combined with the set operators.
+    #[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    pub struct Permission(u8);
+
+    #[allow(non_upper_case_globals)]
+    impl Permission {
+        pub const Read: Permission = Permission(1);
+        pub const Write: Permission = Permission(2);
+        pub const Execute: Permission = Permission(4);
+
+        pub const fn empty() -> Self {
+            Permission(0)
+        }
+
+        pub const fn all() -> Self {
+            Permission(7)
+        }
+
+        pub const fn bits(self) -> u8 {
+            self.0
+        }
+
+        /// Returns `None` if `bits` contains flags which are not declared in the schema.
+        pub const fn from_bits(bits: u8) -> Option<Self> {
+            if bits & !Self::all().0 == 0 {
+                Some(Permission(bits))
+            } else {
+                None
+            }
+        }
+
+        /// Drops the flags which are not declared in the schema.
+        pub const fn from_bits_truncate(bits: u8) -> Self {
+            Permission(bits & Self::all().0)
+        }
+
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+
+        pub const fn intersects(self, other: Self) -> bool {
+            self.0 & other.0 != 0
+        }
+
+        pub fn insert(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+
+        pub fn remove(&mut self, other: Self) {
+            self.0 &= !other.0;
+        }
+    }
+
+    impl std::ops::BitOr for Permission {
+        type Output = Self;
+
+        fn bitor(self, other: Self) -> Self {
+            Permission(self.0 | other.0)
+        }
+    }
+
+    impl std::ops::BitOrAssign for Permission {
+        fn bitor_assign(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+    }
+
+    impl std::ops::BitAnd for Permission {
+        type Output = Self;
+
+        fn bitand(self, other: Self) -> Self {
+            Permission(self.0 & other.0)
+        }
+    }
+
+    impl std::ops::BitAndAssign for Permission {
+        fn bitand_assign(&mut self, other: Self) {
+            self.0 &= other.0;
+        }
+    }
+
+    impl std::ops::BitXor for Permission {
+        type Output = Self;
+
+        fn bitxor(self, other: Self) -> Self {
+            Permission(self.0 ^ other.0)
+        }
+    }
+
+    impl std::ops::Sub for Permission {
+        type Output = Self;
+
+        fn sub(self, other: Self) -> Self {
+            Permission(self.0 & !other.0)
+        }
+    }
+
+    impl std::ops::Not for Permission {
+        type Output = Self;
+
+        fn not(self) -> Self {
+            Self::from_bits_truncate(!self.0)
+        }
+    }
+
+    impl Scalar for Permission {
+        fn to_le(self) -> Self {
+            Permission(self.0.to_le())
+        }
+
+        fn from_le(x: Self) -> Self {
+            Permission(u8::from_le(x.0))
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Permission {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            any::<u8>().prop_map(Permission::from_bits_truncate).boxed()
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct File {
+        pub permission: Permission,
+        pub permissions: Vec<Permission>,
+    }
+
+    impl Default for File {
+        fn default() -> Self {
+            File {
+                permission: Permission::Read,
+                permissions: Default::default(),
+            }
+        }
+    }
+
+    impl File {
+        const VT_PERMISSION: usize = 4;
+        const SIZE_PERMISSION: usize = 1;
+        const ALIGNMENT_PERMISSION: usize = 1;
+        const VT_PERMISSIONS: usize = 6;
+        const SIZE_PERMISSIONS: usize = 4;
+        const ALIGNMENT_PERMISSIONS: usize = 4;
+        const ALIGNMENT: usize = 4;
+    }
+
+    impl<'c> Component<'c> for File {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.permissions.is_empty() {
+                    vtable.add_field(Self::VT_PERMISSIONS, Self::SIZE_PERMISSIONS, Self::ALIGNMENT_PERMISSIONS);
+                }
+                if self.permission != Permission::Read {
+                    vtable.add_field(Self::VT_PERMISSION, Self::SIZE_PERMISSION, Self::ALIGNMENT_PERMISSION);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.permissions.is_empty() {
+                builder.align(Self::ALIGNMENT_PERMISSIONS);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_PERMISSIONS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.permissions, 1)),
+                ));
+            }
+            if self.permission != Permission::Read {
+                builder.align(Self::ALIGNMENT_PERMISSION);
+                builder.push_scalar(self.permission);
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c File {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.permissions.is_empty() {
+                    vtable.add_field(File::VT_PERMISSIONS, File::SIZE_PERMISSIONS, File::ALIGNMENT_PERMISSIONS);
+                }
+                if self.permission != Permission::Read {
+                    vtable.add_field(File::VT_PERMISSION, File::SIZE_PERMISSION, File::ALIGNMENT_PERMISSION);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, File::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.permissions.is_empty() {
+                builder.align(File::ALIGNMENT_PERMISSIONS);
+                let offset_position = builder.tell();
+                builder.pad(File::SIZE_PERMISSIONS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.permissions.iter().copied(), 1)),
+                ));
+            }
+            if self.permission != Permission::Read {
+                builder.align(File::ALIGNMENT_PERMISSION);
+                builder.push_scalar(self.permission);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `File` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct FileRef<'a> {
+        pub permission: Permission,
+        pub permissions: &'a [Permission],
+    }
+
+    impl<'a> Default for FileRef<'a> {
+        fn default() -> Self {
+            FileRef {
+                permission: Permission::Read,
+                permissions: Default::default(),
+            }
+        }
+    }
+
+    impl<'c> Component<'c> for FileRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.permissions.is_empty() {
+                    vtable.add_field(File::VT_PERMISSIONS, File::SIZE_PERMISSIONS, File::ALIGNMENT_PERMISSIONS);
+                }
+                if self.permission != Permission::Read {
+                    vtable.add_field(File::VT_PERMISSION, File::SIZE_PERMISSION, File::ALIGNMENT_PERMISSION);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, File::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.permissions.is_empty() {
+                builder.align(File::ALIGNMENT_PERMISSIONS);
+                let offset_position = builder.tell();
+                builder.pad(File::SIZE_PERMISSIONS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.permissions.iter().copied(), 1)),
+                ));
+            }
+            if self.permission != Permission::Read {
+                builder.align(File::ALIGNMENT_PERMISSION);
+                builder.push_scalar(self.permission);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for File {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                any::<Permission>(),
+                vec(any::<Permission>(), config.scalar_vector_len.clone()),
+            )
+                .prop_map(|(permission, permissions)| File {
+                    permission,
+                    permissions,
+                })
+                .boxed()
+        }
+    }
+}
@@ -0,0 +1,187 @@
+// Written by hand in the style of the flatc output, since flatc 0.6 generates Rust enums for
+// `bit_flags` enums, which cannot hold combined flags. `make gen` does not regenerate it, so keep it
+// in sync with the schema.
+
+
+pub mod example {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+
+#[allow(non_camel_case_types)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Permission(u8);
+
+#[allow(non_upper_case_globals)]
+impl Permission {
+  pub const Read: Permission = Permission(1);
+  pub const Write: Permission = Permission(2);
+  pub const Execute: Permission = Permission(4);
+
+  pub const fn empty() -> Self {
+    Permission(0)
+  }
+  pub const fn all() -> Self {
+    Permission(7)
+  }
+  pub const fn bits(self) -> u8 {
+    self.0
+  }
+  pub const fn from_bits_retain(bits: u8) -> Self {
+    Permission(bits)
+  }
+  pub const fn contains(self, other: Self) -> bool {
+    self.0 & other.0 == other.0
+  }
+}
+
+impl ::std::ops::BitOr for Permission {
+  type Output = Self;
+  #[inline]
+  fn bitor(self, other: Self) -> Self {
+    Permission(self.0 | other.0)
+  }
+}
+
+impl<'a> flatbuffers::Follow<'a> for Permission {
+  type Inner = Self;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::read_scalar_at::<Self>(buf, loc)
+  }
+}
+
+impl flatbuffers::EndianScalar for Permission {
+  #[inline]
+  fn to_little_endian(self) -> Self {
+    Permission(u8::to_le(self.0))
+  }
+  #[inline]
+  fn from_little_endian(self) -> Self {
+    Permission(u8::from_le(self.0))
+  }
+}
+
+impl flatbuffers::Push for Permission {
+    type Output = Permission;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        flatbuffers::emplace_scalar::<Permission>(dst, *self);
+    }
+}
+
+pub enum FileOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct File<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for File<'a> {
+    type Inner = File<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> File<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        File {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args FileArgs<'args>) -> flatbuffers::WIPOffset<File<'bldr>> {
+      let mut builder = FileBuilder::new(_fbb);
+      if let Some(x) = args.permissions { builder.add_permissions(x); }
+      builder.add_permission(args.permission);
+      builder.finish()
+    }
+
+    pub const VT_PERMISSION: flatbuffers::VOffsetT = 4;
+    pub const VT_PERMISSIONS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn permission(&self) -> Permission {
+    self._tab.get::<Permission>(File::VT_PERMISSION, Some(Permission::Read)).unwrap()
+  }
+  #[inline]
+  pub fn permissions(&self) -> Option<flatbuffers::Vector<'a, Permission>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Permission>>>(File::VT_PERMISSIONS, None)
+  }
+}
+
+pub struct FileArgs<'a> {
+    pub permission: Permission,
+    pub permissions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , Permission>>>,
+}
+impl<'a> Default for FileArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        FileArgs {
+            permission: Permission::Read,
+            permissions: None,
+        }
+    }
+}
+pub struct FileBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FileBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_permission(&mut self, permission: Permission) {
+    self.fbb_.push_slot::<Permission>(File::VT_PERMISSION, permission, Permission::Read);
+  }
+  #[inline]
+  pub fn add_permissions(&mut self, permissions: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Permission>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(File::VT_PERMISSIONS, permissions);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FileBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FileBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<File<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+#[inline]
+pub fn get_root_as_file<'a>(buf: &'a [u8]) -> File<'a> {
+  flatbuffers::get_root::<File<'a>>(buf)
+}
+
+#[inline]
+pub fn get_size_prefixed_root_as_file<'a>(buf: &'a [u8]) -> File<'a> {
+  flatbuffers::get_size_prefixed_root::<File<'a>>(buf)
+}
+
+#[inline]
+pub fn finish_file_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<File<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_file_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<File<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
+}  // pub mod example
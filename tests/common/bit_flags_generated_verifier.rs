@@ -0,0 +1,79 @@
+//! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
+use super::bit_flags_generated as reader;
+
+pub mod example {
+    #![allow(unused_imports)]
+
+    use super::reader::example as reader;
+    use flatbuffers::{self, Follow};
+    use flatbuffers_verifier::{
+        lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+        verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+        TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+    };
+    use std::cmp::Ordering;
+    use std::result;
+
+    impl<'a> Verify for reader::File<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(8)?;
+            table.field_enum(Self::VT_PERMISSION, |value: u8| value & !7 == 0)?;
+            table.field_default(Self::VT_PERMISSION, |value: u8| value == 1u8)?;
+            table.field_vector(Self::VT_PERMISSIONS, "permissions", 1, |vector| {
+                vector.verify_enum_elements(verification, |value: u8| value & !7 == 0)
+            })?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::File<'_> {
+        type Reader<'a> = reader::File<'a>;
+    }
+
+    impl<'a> reader::File<'a> {
+        pub fn try_permission(&self) -> result::Result<reader::Permission, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_PERMISSION,
+                1,
+                1,
+            )? {
+                if read_scalar_at::<u8>(self._tab.buf, loc) & !7 != 0 {
+                    return Err(Error::UnknownEnumValue);
+                }
+            }
+            Ok(self.permission())
+        }
+
+        /// Reads `permission` as the underlying value of the enum, which may be undeclared in
+        /// the schema, see `Options::accept_unknown_enum_values`.
+        pub fn raw_permission(&self) -> u8 {
+            self._tab
+                .get::<u8>(Self::VT_PERMISSION, Some(1))
+                .unwrap()
+        }
+
+        pub fn try_permissions(&self) -> result::Result<Option<flatbuffers::Vector<'a, reader::Permission>>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_PERMISSIONS,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                verifier.verify_enum_elements(&Verification::default(), |value: u8| value & !7 == 0)?;
+            }
+            Ok(self.permissions())
+        }
+
+        /// Reads `permissions` as the underlying values of the enum, which may be undeclared in
+        /// the schema, see `Options::accept_unknown_enum_values`.
+        pub fn raw_permissions(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Self::VT_PERMISSIONS, None)
+        }
+    }
+}
@@ -1,16 +1,23 @@
 //! This file is auto-generated by cfbc.
 pub mod ckb {
     pub mod protocol {
-        #![allow(unused_imports, clippy::large_enum_variant)]
+        #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
 
         use cfb::builder::{
             Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
-            ScalarVectorComponent, StringComponent,
+            ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
         };
         use cfb::scalar::Scalar;
         use cfb::types::{SOffset, SIZE_OF_SOFFSET};
-        #[cfg(not(target_endian = "little"))]
-        use std::mem::transmute;
+        use std::cmp::Ordering;
+        #[cfg(feature = "proptest")]
+        use cfb::strategy;
+        #[cfg(feature = "proptest")]
+        use proptest::{
+            collection::vec,
+            prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+            sample::select,
+        };
 
         #[derive(Clone, PartialEq, Debug)]
         pub enum RelayPayload {
@@ -20,6 +27,10 @@ pub mod ckb {
             BlockTransactions(BlockTransactions),
             GetBlockProposal(GetBlockProposal),
             BlockProposal(BlockProposal),
+            /// A variant undeclared in the schema, such as one added by a newer schema, with its union
+            /// type. Its value cannot be written, so building it fails with
+            /// `BuildError::UnknownUnionVariant`.
+            Unknown(u8),
         }
 
         impl RelayPayload {
@@ -31,10 +42,110 @@ pub mod ckb {
                     RelayPayload::BlockTransactions(_) => 4,
                     RelayPayload::GetBlockProposal(_) => 5,
                     RelayPayload::BlockProposal(_) => 6,
+                    RelayPayload::Unknown(union_type) => *union_type,
+                }
+            }
+        }
+
+        impl<'c> Component<'c> for RelayPayload {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                match *self {
+                    RelayPayload::CompactBlock(v) => Box::new(v).build(builder),
+                    RelayPayload::ValidTransaction(v) => Box::new(v).build(builder),
+                    RelayPayload::GetBlockTransactions(v) => Box::new(v).build(builder),
+                    RelayPayload::BlockTransactions(v) => Box::new(v).build(builder),
+                    RelayPayload::GetBlockProposal(v) => Box::new(v).build(builder),
+                    RelayPayload::BlockProposal(v) => Box::new(v).build(builder),
+                    RelayPayload::Unknown(union_type) => Box::new(UnknownUnionComponent::new(union_type)).build(builder),
+                }
+            }
+        }
+
+        /// Builds the union without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c RelayPayload {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                match *self {
+                    RelayPayload::CompactBlock(v) => Box::new(v).build(builder),
+                    RelayPayload::ValidTransaction(v) => Box::new(v).build(builder),
+                    RelayPayload::GetBlockTransactions(v) => Box::new(v).build(builder),
+                    RelayPayload::BlockTransactions(v) => Box::new(v).build(builder),
+                    RelayPayload::GetBlockProposal(v) => Box::new(v).build(builder),
+                    RelayPayload::BlockProposal(v) => Box::new(v).build(builder),
+                    RelayPayload::Unknown(union_type) => Box::new(UnknownUnionComponent::new(*union_type)).build(builder),
+                }
+            }
+        }
+
+        /// The variant of `RelayPayload` which borrows its strings and tables, see `--borrowed`.
+        #[derive(Clone, Copy, PartialEq, Debug)]
+        pub enum RelayPayloadRef<'a> {
+            CompactBlock(CompactBlockRef<'a>),
+            ValidTransaction(ValidTransactionRef<'a>),
+            GetBlockTransactions(GetBlockTransactionsRef<'a>),
+            BlockTransactions(BlockTransactionsRef<'a>),
+            GetBlockProposal(GetBlockProposalRef<'a>),
+            BlockProposal(BlockProposalRef<'a>),
+            Unknown(u8),
+        }
+
+        impl<'a> RelayPayloadRef<'a> {
+            pub fn union_type(&self) -> u8 {
+                match self {
+                    RelayPayloadRef::CompactBlock(_) => 1,
+                    RelayPayloadRef::ValidTransaction(_) => 2,
+                    RelayPayloadRef::GetBlockTransactions(_) => 3,
+                    RelayPayloadRef::BlockTransactions(_) => 4,
+                    RelayPayloadRef::GetBlockProposal(_) => 5,
+                    RelayPayloadRef::BlockProposal(_) => 6,
+                    RelayPayloadRef::Unknown(union_type) => *union_type,
+                }
+            }
+        }
+
+        impl<'c> Component<'c> for RelayPayloadRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                match *self {
+                    RelayPayloadRef::CompactBlock(v) => Box::new(v).build(builder),
+                    RelayPayloadRef::ValidTransaction(v) => Box::new(v).build(builder),
+                    RelayPayloadRef::GetBlockTransactions(v) => Box::new(v).build(builder),
+                    RelayPayloadRef::BlockTransactions(v) => Box::new(v).build(builder),
+                    RelayPayloadRef::GetBlockProposal(v) => Box::new(v).build(builder),
+                    RelayPayloadRef::BlockProposal(v) => Box::new(v).build(builder),
+                    RelayPayloadRef::Unknown(union_type) => Box::new(UnknownUnionComponent::new(union_type)).build(builder),
                 }
             }
         }
 
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for RelayPayload {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                proptest::strategy::Union::new(vec![
+                    any_with::<CompactBlock>(config.clone())
+                        .prop_map(RelayPayload::CompactBlock)
+                        .boxed(),
+                    any_with::<ValidTransaction>(config.clone())
+                        .prop_map(RelayPayload::ValidTransaction)
+                        .boxed(),
+                    any_with::<GetBlockTransactions>(config.clone())
+                        .prop_map(RelayPayload::GetBlockTransactions)
+                        .boxed(),
+                    any_with::<BlockTransactions>(config.clone())
+                        .prop_map(RelayPayload::BlockTransactions)
+                        .boxed(),
+                    any_with::<GetBlockProposal>(config.clone())
+                        .prop_map(RelayPayload::GetBlockProposal)
+                        .boxed(),
+                    any_with::<BlockProposal>(config.clone())
+                        .prop_map(RelayPayload::BlockProposal)
+                        .boxed(),
+                ])
+                .boxed()
+            }
+        }
+
         #[derive(Clone, PartialEq, Debug)]
         pub enum SyncPayload {
             GetHeaders(GetHeaders),
@@ -45,6 +156,10 @@ pub mod ckb {
             AddFilter(AddFilter),
             ClearFilter(ClearFilter),
             FilteredBlock(FilteredBlock),
+            /// A variant undeclared in the schema, such as one added by a newer schema, with its union
+            /// type. Its value cannot be written, so building it fails with
+            /// `BuildError::UnknownUnionVariant`.
+            Unknown(u8),
         }
 
         impl SyncPayload {
@@ -58,10 +173,126 @@ pub mod ckb {
                     SyncPayload::AddFilter(_) => 6,
                     SyncPayload::ClearFilter(_) => 7,
                     SyncPayload::FilteredBlock(_) => 8,
+                    SyncPayload::Unknown(union_type) => *union_type,
+                }
+            }
+        }
+
+        impl<'c> Component<'c> for SyncPayload {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                match *self {
+                    SyncPayload::GetHeaders(v) => Box::new(v).build(builder),
+                    SyncPayload::Headers(v) => Box::new(v).build(builder),
+                    SyncPayload::GetBlocks(v) => Box::new(v).build(builder),
+                    SyncPayload::Block(v) => Box::new(v).build(builder),
+                    SyncPayload::SetFilter(v) => Box::new(v).build(builder),
+                    SyncPayload::AddFilter(v) => Box::new(v).build(builder),
+                    SyncPayload::ClearFilter(v) => Box::new(v).build(builder),
+                    SyncPayload::FilteredBlock(v) => Box::new(v).build(builder),
+                    SyncPayload::Unknown(union_type) => Box::new(UnknownUnionComponent::new(union_type)).build(builder),
                 }
             }
         }
 
+        /// Builds the union without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c SyncPayload {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                match *self {
+                    SyncPayload::GetHeaders(v) => Box::new(v).build(builder),
+                    SyncPayload::Headers(v) => Box::new(v).build(builder),
+                    SyncPayload::GetBlocks(v) => Box::new(v).build(builder),
+                    SyncPayload::Block(v) => Box::new(v).build(builder),
+                    SyncPayload::SetFilter(v) => Box::new(v).build(builder),
+                    SyncPayload::AddFilter(v) => Box::new(v).build(builder),
+                    SyncPayload::ClearFilter(v) => Box::new(v).build(builder),
+                    SyncPayload::FilteredBlock(v) => Box::new(v).build(builder),
+                    SyncPayload::Unknown(union_type) => Box::new(UnknownUnionComponent::new(*union_type)).build(builder),
+                }
+            }
+        }
+
+        /// The variant of `SyncPayload` which borrows its strings and tables, see `--borrowed`.
+        #[derive(Clone, Copy, PartialEq, Debug)]
+        pub enum SyncPayloadRef<'a> {
+            GetHeaders(GetHeadersRef<'a>),
+            Headers(HeadersRef<'a>),
+            GetBlocks(GetBlocksRef<'a>),
+            Block(BlockRef<'a>),
+            SetFilter(SetFilterRef<'a>),
+            AddFilter(AddFilterRef<'a>),
+            ClearFilter(ClearFilterRef),
+            FilteredBlock(FilteredBlockRef<'a>),
+            Unknown(u8),
+        }
+
+        impl<'a> SyncPayloadRef<'a> {
+            pub fn union_type(&self) -> u8 {
+                match self {
+                    SyncPayloadRef::GetHeaders(_) => 1,
+                    SyncPayloadRef::Headers(_) => 2,
+                    SyncPayloadRef::GetBlocks(_) => 3,
+                    SyncPayloadRef::Block(_) => 4,
+                    SyncPayloadRef::SetFilter(_) => 5,
+                    SyncPayloadRef::AddFilter(_) => 6,
+                    SyncPayloadRef::ClearFilter(_) => 7,
+                    SyncPayloadRef::FilteredBlock(_) => 8,
+                    SyncPayloadRef::Unknown(union_type) => *union_type,
+                }
+            }
+        }
+
+        impl<'c> Component<'c> for SyncPayloadRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                match *self {
+                    SyncPayloadRef::GetHeaders(v) => Box::new(v).build(builder),
+                    SyncPayloadRef::Headers(v) => Box::new(v).build(builder),
+                    SyncPayloadRef::GetBlocks(v) => Box::new(v).build(builder),
+                    SyncPayloadRef::Block(v) => Box::new(v).build(builder),
+                    SyncPayloadRef::SetFilter(v) => Box::new(v).build(builder),
+                    SyncPayloadRef::AddFilter(v) => Box::new(v).build(builder),
+                    SyncPayloadRef::ClearFilter(v) => Box::new(v).build(builder),
+                    SyncPayloadRef::FilteredBlock(v) => Box::new(v).build(builder),
+                    SyncPayloadRef::Unknown(union_type) => Box::new(UnknownUnionComponent::new(union_type)).build(builder),
+                }
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for SyncPayload {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                proptest::strategy::Union::new(vec![
+                    any_with::<GetHeaders>(config.clone())
+                        .prop_map(SyncPayload::GetHeaders)
+                        .boxed(),
+                    any_with::<Headers>(config.clone())
+                        .prop_map(SyncPayload::Headers)
+                        .boxed(),
+                    any_with::<GetBlocks>(config.clone())
+                        .prop_map(SyncPayload::GetBlocks)
+                        .boxed(),
+                    any_with::<Block>(config.clone())
+                        .prop_map(SyncPayload::Block)
+                        .boxed(),
+                    any_with::<SetFilter>(config.clone())
+                        .prop_map(SyncPayload::SetFilter)
+                        .boxed(),
+                    any_with::<AddFilter>(config.clone())
+                        .prop_map(SyncPayload::AddFilter)
+                        .boxed(),
+                    any_with::<ClearFilter>(config.clone())
+                        .prop_map(SyncPayload::ClearFilter)
+                        .boxed(),
+                    any_with::<FilteredBlock>(config.clone())
+                        .prop_map(SyncPayload::FilteredBlock)
+                        .boxed(),
+                ])
+                .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct AddFilter {
             pub filter: Vec<u8>,
@@ -102,6 +333,85 @@ pub mod ckb {
             }
         }
 
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c AddFilter {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.filter.is_empty() {
+                        vtable.add_field(AddFilter::VT_FILTER, AddFilter::SIZE_FILTER, AddFilter::ALIGNMENT_FILTER);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, AddFilter::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.filter.is_empty() {
+                    builder.align(AddFilter::ALIGNMENT_FILTER);
+                    let offset_position = builder.tell();
+                    builder.pad(AddFilter::SIZE_FILTER);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.filter.iter().copied(), 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `AddFilter` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct AddFilterRef<'a> {
+            pub filter: &'a [u8],
+        }
+
+        impl<'c> Component<'c> for AddFilterRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.filter.is_empty() {
+                        vtable.add_field(AddFilter::VT_FILTER, AddFilter::SIZE_FILTER, AddFilter::ALIGNMENT_FILTER);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, AddFilter::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.filter.is_empty() {
+                    builder.align(AddFilter::ALIGNMENT_FILTER);
+                    let offset_position = builder.tell();
+                    builder.pad(AddFilter::SIZE_FILTER);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.filter.iter().copied(), 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for AddFilter {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    vec(any::<u8>(), config.scalar_vector_len.clone()),
+                )
+                    .prop_map(|(filter,)| AddFilter {
+                        filter,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct Block {
             pub header: Option<Header>,
@@ -190,6 +500,166 @@ pub mod ckb {
             }
         }
 
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c Block {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.header.is_some() {
+                        vtable.add_field(Block::VT_HEADER, Block::SIZE_HEADER, Block::ALIGNMENT_HEADER);
+                    }
+                    if !self.uncles.is_empty() {
+                        vtable.add_field(Block::VT_UNCLES, Block::SIZE_UNCLES, Block::ALIGNMENT_UNCLES);
+                    }
+                    if !self.commit_transactions.is_empty() {
+                        vtable.add_field(Block::VT_COMMIT_TRANSACTIONS, Block::SIZE_COMMIT_TRANSACTIONS, Block::ALIGNMENT_COMMIT_TRANSACTIONS);
+                    }
+                    if !self.proposal_transactions.is_empty() {
+                        vtable.add_field(Block::VT_PROPOSAL_TRANSACTIONS, Block::SIZE_PROPOSAL_TRANSACTIONS, Block::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Block::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if let Some(f) = &self.header {
+                    builder.align(Block::ALIGNMENT_HEADER);
+                    let offset_position = builder.tell();
+                    builder.pad(Block::SIZE_HEADER);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if !self.uncles.is_empty() {
+                    builder.align(Block::ALIGNMENT_UNCLES);
+                    let offset_position = builder.tell();
+                    builder.pad(Block::SIZE_UNCLES);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.uncles.iter())),
+                    ));
+                }
+                if !self.commit_transactions.is_empty() {
+                    builder.align(Block::ALIGNMENT_COMMIT_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(Block::SIZE_COMMIT_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.commit_transactions.iter())),
+                    ));
+                }
+                if !self.proposal_transactions.is_empty() {
+                    builder.align(Block::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(Block::SIZE_PROPOSAL_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.proposal_transactions.iter().copied(), 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `Block` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct BlockRef<'a> {
+            pub header: Option<HeaderRef<'a>>,
+            pub uncles: &'a [UncleBlockRef<'a>],
+            pub commit_transactions: &'a [TransactionRef<'a>],
+            pub proposal_transactions: &'a [ProposalShortId],
+        }
+
+        impl<'c> Component<'c> for BlockRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.header.is_some() {
+                        vtable.add_field(Block::VT_HEADER, Block::SIZE_HEADER, Block::ALIGNMENT_HEADER);
+                    }
+                    if !self.uncles.is_empty() {
+                        vtable.add_field(Block::VT_UNCLES, Block::SIZE_UNCLES, Block::ALIGNMENT_UNCLES);
+                    }
+                    if !self.commit_transactions.is_empty() {
+                        vtable.add_field(Block::VT_COMMIT_TRANSACTIONS, Block::SIZE_COMMIT_TRANSACTIONS, Block::ALIGNMENT_COMMIT_TRANSACTIONS);
+                    }
+                    if !self.proposal_transactions.is_empty() {
+                        vtable.add_field(Block::VT_PROPOSAL_TRANSACTIONS, Block::SIZE_PROPOSAL_TRANSACTIONS, Block::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Block::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if let Some(f) = self.header {
+                    builder.align(Block::ALIGNMENT_HEADER);
+                    let offset_position = builder.tell();
+                    builder.pad(Block::SIZE_HEADER);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if !self.uncles.is_empty() {
+                    builder.align(Block::ALIGNMENT_UNCLES);
+                    let offset_position = builder.tell();
+                    builder.pad(Block::SIZE_UNCLES);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.uncles.iter().copied())),
+                    ));
+                }
+                if !self.commit_transactions.is_empty() {
+                    builder.align(Block::ALIGNMENT_COMMIT_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(Block::SIZE_COMMIT_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.commit_transactions.iter().copied())),
+                    ));
+                }
+                if !self.proposal_transactions.is_empty() {
+                    builder.align(Block::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(Block::SIZE_PROPOSAL_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.proposal_transactions.iter().copied(), 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for Block {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    strategy::option(&config, any_with::<Header>(config.clone())),
+                    vec(any_with::<UncleBlock>(config.clone()), config.vector_len.clone()),
+                    vec(any_with::<Transaction>(config.clone()), config.vector_len.clone()),
+                    vec(any::<ProposalShortId>(), config.scalar_vector_len.clone()),
+                )
+                    .prop_map(|(header, uncles, commit_transactions, proposal_transactions)| Block {
+                        header,
+                        uncles,
+                        commit_transactions,
+                        proposal_transactions,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct BlockProposal {
             pub transactions: Vec<Transaction>,
@@ -230,6 +700,85 @@ pub mod ckb {
             }
         }
 
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c BlockProposal {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.transactions.is_empty() {
+                        vtable.add_field(BlockProposal::VT_TRANSACTIONS, BlockProposal::SIZE_TRANSACTIONS, BlockProposal::ALIGNMENT_TRANSACTIONS);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, BlockProposal::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.transactions.is_empty() {
+                    builder.align(BlockProposal::ALIGNMENT_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(BlockProposal::SIZE_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.transactions.iter())),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `BlockProposal` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct BlockProposalRef<'a> {
+            pub transactions: &'a [TransactionRef<'a>],
+        }
+
+        impl<'c> Component<'c> for BlockProposalRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.transactions.is_empty() {
+                        vtable.add_field(BlockProposal::VT_TRANSACTIONS, BlockProposal::SIZE_TRANSACTIONS, BlockProposal::ALIGNMENT_TRANSACTIONS);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, BlockProposal::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.transactions.is_empty() {
+                    builder.align(BlockProposal::ALIGNMENT_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(BlockProposal::SIZE_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.transactions.iter().copied())),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for BlockProposal {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    vec(any_with::<Transaction>(config.clone()), config.vector_len.clone()),
+                )
+                    .prop_map(|(transactions,)| BlockProposal {
+                        transactions,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct BlockTransactions {
             pub hash: H256,
@@ -281,13 +830,109 @@ pub mod ckb {
             }
         }
 
-        #[derive(Default, Clone, Debug, PartialEq)]
-        pub struct Bytes {
-            pub seq: Vec<u8>,
-        }
-
-        impl Bytes {
-            const VT_SEQ: usize = 4;
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c BlockTransactions {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.transactions.is_empty() {
+                        vtable.add_field(BlockTransactions::VT_TRANSACTIONS, BlockTransactions::SIZE_TRANSACTIONS, BlockTransactions::ALIGNMENT_TRANSACTIONS);
+                    }
+                    if self.hash.is_present() {
+                        vtable.add_field(BlockTransactions::VT_HASH, BlockTransactions::SIZE_HASH, BlockTransactions::ALIGNMENT_HASH);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, BlockTransactions::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.transactions.is_empty() {
+                    builder.align(BlockTransactions::ALIGNMENT_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(BlockTransactions::SIZE_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.transactions.iter())),
+                    ));
+                }
+                if self.hash.is_present() {
+                    builder.align(BlockTransactions::ALIGNMENT_HASH);
+                    builder.push_scalar(self.hash);
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `BlockTransactions` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct BlockTransactionsRef<'a> {
+            pub hash: H256,
+            pub transactions: &'a [TransactionRef<'a>],
+        }
+
+        impl<'c> Component<'c> for BlockTransactionsRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.transactions.is_empty() {
+                        vtable.add_field(BlockTransactions::VT_TRANSACTIONS, BlockTransactions::SIZE_TRANSACTIONS, BlockTransactions::ALIGNMENT_TRANSACTIONS);
+                    }
+                    if self.hash.is_present() {
+                        vtable.add_field(BlockTransactions::VT_HASH, BlockTransactions::SIZE_HASH, BlockTransactions::ALIGNMENT_HASH);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, BlockTransactions::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.transactions.is_empty() {
+                    builder.align(BlockTransactions::ALIGNMENT_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(BlockTransactions::SIZE_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.transactions.iter().copied())),
+                    ));
+                }
+                if self.hash.is_present() {
+                    builder.align(BlockTransactions::ALIGNMENT_HASH);
+                    builder.push_scalar(self.hash);
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for BlockTransactions {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    any::<H256>(),
+                    vec(any_with::<Transaction>(config.clone()), config.vector_len.clone()),
+                )
+                    .prop_map(|(hash, transactions)| BlockTransactions {
+                        hash,
+                        transactions,
+                    })
+                    .boxed()
+            }
+        }
+
+        #[derive(Default, Clone, Debug, PartialEq)]
+        pub struct Bytes {
+            pub seq: Vec<u8>,
+        }
+
+        impl Bytes {
+            const VT_SEQ: usize = 4;
             const SIZE_SEQ: usize = 4;
             const ALIGNMENT_SEQ: usize = 4;
             const ALIGNMENT: usize = 4;
@@ -321,6 +966,85 @@ pub mod ckb {
             }
         }
 
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c Bytes {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.seq.is_empty() {
+                        vtable.add_field(Bytes::VT_SEQ, Bytes::SIZE_SEQ, Bytes::ALIGNMENT_SEQ);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Bytes::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.seq.is_empty() {
+                    builder.align(Bytes::ALIGNMENT_SEQ);
+                    let offset_position = builder.tell();
+                    builder.pad(Bytes::SIZE_SEQ);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.seq.iter().copied(), 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `Bytes` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct BytesRef<'a> {
+            pub seq: &'a [u8],
+        }
+
+        impl<'c> Component<'c> for BytesRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.seq.is_empty() {
+                        vtable.add_field(Bytes::VT_SEQ, Bytes::SIZE_SEQ, Bytes::ALIGNMENT_SEQ);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Bytes::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.seq.is_empty() {
+                    builder.align(Bytes::ALIGNMENT_SEQ);
+                    let offset_position = builder.tell();
+                    builder.pad(Bytes::SIZE_SEQ);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.seq.iter().copied(), 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for Bytes {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    vec(any::<u8>(), config.scalar_vector_len.clone()),
+                )
+                    .prop_map(|(seq,)| Bytes {
+                        seq,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct CellInput {
             pub hash: H256,
@@ -383,6 +1107,119 @@ pub mod ckb {
             }
         }
 
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c CellInput {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.index != 0u32 {
+                        vtable.add_field(CellInput::VT_INDEX, CellInput::SIZE_INDEX, CellInput::ALIGNMENT_INDEX);
+                    }
+                    if !self.args.is_empty() {
+                        vtable.add_field(CellInput::VT_ARGS, CellInput::SIZE_ARGS, CellInput::ALIGNMENT_ARGS);
+                    }
+                    if self.hash.is_present() {
+                        vtable.add_field(CellInput::VT_HASH, CellInput::SIZE_HASH, CellInput::ALIGNMENT_HASH);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, CellInput::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.index != 0u32 {
+                    builder.align(CellInput::ALIGNMENT_INDEX);
+                    builder.push_scalar(self.index);
+                }
+                if !self.args.is_empty() {
+                    builder.align(CellInput::ALIGNMENT_ARGS);
+                    let offset_position = builder.tell();
+                    builder.pad(CellInput::SIZE_ARGS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.args.iter())),
+                    ));
+                }
+                if self.hash.is_present() {
+                    builder.align(CellInput::ALIGNMENT_HASH);
+                    builder.push_scalar(self.hash);
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `CellInput` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct CellInputRef<'a> {
+            pub hash: H256,
+            pub index: u32,
+            pub args: &'a [BytesRef<'a>],
+        }
+
+        impl<'c> Component<'c> for CellInputRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.index != 0u32 {
+                        vtable.add_field(CellInput::VT_INDEX, CellInput::SIZE_INDEX, CellInput::ALIGNMENT_INDEX);
+                    }
+                    if !self.args.is_empty() {
+                        vtable.add_field(CellInput::VT_ARGS, CellInput::SIZE_ARGS, CellInput::ALIGNMENT_ARGS);
+                    }
+                    if self.hash.is_present() {
+                        vtable.add_field(CellInput::VT_HASH, CellInput::SIZE_HASH, CellInput::ALIGNMENT_HASH);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, CellInput::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.index != 0u32 {
+                    builder.align(CellInput::ALIGNMENT_INDEX);
+                    builder.push_scalar(self.index);
+                }
+                if !self.args.is_empty() {
+                    builder.align(CellInput::ALIGNMENT_ARGS);
+                    let offset_position = builder.tell();
+                    builder.pad(CellInput::SIZE_ARGS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.args.iter().copied())),
+                    ));
+                }
+                if self.hash.is_present() {
+                    builder.align(CellInput::ALIGNMENT_HASH);
+                    builder.push_scalar(self.hash);
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for CellInput {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    any::<H256>(),
+                    any::<u32>(),
+                    vec(any_with::<Bytes>(config.clone()), config.vector_len.clone()),
+                )
+                    .prop_map(|(hash, index, args)| CellInput {
+                        hash,
+                        index,
+                        args,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct CellOutput {
             pub capacity: u64,
@@ -466,59 +1303,257 @@ pub mod ckb {
             }
         }
 
-        #[derive(Default, Clone, Debug, PartialEq)]
-        pub struct ClearFilter {
-        }
-
-        impl ClearFilter {
-            const ALIGNMENT: usize = 4;
-        }
-
-        impl<'c> Component<'c> for ClearFilter {
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c CellOutput {
             fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
                 let vtable_start = {
-                    let vtable = builder.start_vtable();
+                    let mut vtable = builder.start_vtable();
+                    if self.capacity != 0u64 {
+                        vtable.add_field(CellOutput::VT_CAPACITY, CellOutput::SIZE_CAPACITY, CellOutput::ALIGNMENT_CAPACITY);
+                    }
+                    if self.data.is_some() {
+                        vtable.add_field(CellOutput::VT_DATA, CellOutput::SIZE_DATA, CellOutput::ALIGNMENT_DATA);
+                    }
+                    if self.lock.is_some() {
+                        vtable.add_field(CellOutput::VT_LOCK, CellOutput::SIZE_LOCK, CellOutput::ALIGNMENT_LOCK);
+                    }
+                    if self.type_.is_some() {
+                        vtable.add_field(CellOutput::VT_TYPE_, CellOutput::SIZE_TYPE_, CellOutput::ALIGNMENT_TYPE_);
+                    }
                     vtable.finish()
                 };
 
-                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+                builder.align_after(SIZE_OF_SOFFSET, CellOutput::ALIGNMENT);
 
                 let table_start = builder.tell();
                 builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.capacity != 0u64 {
+                    builder.align(CellOutput::ALIGNMENT_CAPACITY);
+                    builder.push_scalar(self.capacity);
+                }
+                if let Some(f) = &self.data {
+                    builder.align(CellOutput::ALIGNMENT_DATA);
+                    let offset_position = builder.tell();
+                    builder.pad(CellOutput::SIZE_DATA);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if let Some(f) = &self.lock {
+                    builder.align(CellOutput::ALIGNMENT_LOCK);
+                    let offset_position = builder.tell();
+                    builder.pad(CellOutput::SIZE_LOCK);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if let Some(f) = &self.type_ {
+                    builder.align(CellOutput::ALIGNMENT_TYPE_);
+                    let offset_position = builder.tell();
+                    builder.pad(CellOutput::SIZE_TYPE_);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
 
                 table_start
             }
         }
 
-        #[derive(Default, Clone, Debug, PartialEq)]
-        pub struct CompactBlock {
-            pub header: Option<Header>,
-            pub nonce: u64,
-            pub short_ids: Vec<Bytes>,
-            pub prefilled_transactions: Vec<IndexTransaction>,
-            pub uncles: Vec<UncleBlock>,
-            pub proposal_transactions: Vec<ProposalShortId>,
+        /// The variant of `CellOutput` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct CellOutputRef<'a> {
+            pub capacity: u64,
+            pub data: Option<BytesRef<'a>>,
+            pub lock: Option<ScriptRef<'a>>,
+            pub type_: Option<ScriptRef<'a>>,
         }
 
-        impl CompactBlock {
-            const VT_HEADER: usize = 4;
-            const SIZE_HEADER: usize = 4;
-            const ALIGNMENT_HEADER: usize = 4;
-            const VT_NONCE: usize = 6;
-            const SIZE_NONCE: usize = 8;
-            const ALIGNMENT_NONCE: usize = 8;
-            const VT_SHORT_IDS: usize = 8;
-            const SIZE_SHORT_IDS: usize = 4;
-            const ALIGNMENT_SHORT_IDS: usize = 4;
-            const VT_PREFILLED_TRANSACTIONS: usize = 10;
-            const SIZE_PREFILLED_TRANSACTIONS: usize = 4;
-            const ALIGNMENT_PREFILLED_TRANSACTIONS: usize = 4;
-            const VT_UNCLES: usize = 12;
-            const SIZE_UNCLES: usize = 4;
-            const ALIGNMENT_UNCLES: usize = 4;
-            const VT_PROPOSAL_TRANSACTIONS: usize = 14;
-            const SIZE_PROPOSAL_TRANSACTIONS: usize = 4;
-            const ALIGNMENT_PROPOSAL_TRANSACTIONS: usize = 4;
+        impl<'c> Component<'c> for CellOutputRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.capacity != 0u64 {
+                        vtable.add_field(CellOutput::VT_CAPACITY, CellOutput::SIZE_CAPACITY, CellOutput::ALIGNMENT_CAPACITY);
+                    }
+                    if self.data.is_some() {
+                        vtable.add_field(CellOutput::VT_DATA, CellOutput::SIZE_DATA, CellOutput::ALIGNMENT_DATA);
+                    }
+                    if self.lock.is_some() {
+                        vtable.add_field(CellOutput::VT_LOCK, CellOutput::SIZE_LOCK, CellOutput::ALIGNMENT_LOCK);
+                    }
+                    if self.type_.is_some() {
+                        vtable.add_field(CellOutput::VT_TYPE_, CellOutput::SIZE_TYPE_, CellOutput::ALIGNMENT_TYPE_);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, CellOutput::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.capacity != 0u64 {
+                    builder.align(CellOutput::ALIGNMENT_CAPACITY);
+                    builder.push_scalar(self.capacity);
+                }
+                if let Some(f) = self.data {
+                    builder.align(CellOutput::ALIGNMENT_DATA);
+                    let offset_position = builder.tell();
+                    builder.pad(CellOutput::SIZE_DATA);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if let Some(f) = self.lock {
+                    builder.align(CellOutput::ALIGNMENT_LOCK);
+                    let offset_position = builder.tell();
+                    builder.pad(CellOutput::SIZE_LOCK);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if let Some(f) = self.type_ {
+                    builder.align(CellOutput::ALIGNMENT_TYPE_);
+                    let offset_position = builder.tell();
+                    builder.pad(CellOutput::SIZE_TYPE_);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for CellOutput {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    any::<u64>(),
+                    strategy::option(&config, any_with::<Bytes>(config.clone())),
+                    strategy::option(&config, any_with::<Script>(config.clone())),
+                    strategy::option(&config, any_with::<Script>(config.clone())),
+                )
+                    .prop_map(|(capacity, data, lock, type_)| CellOutput {
+                        capacity,
+                        data,
+                        lock,
+                        type_,
+                    })
+                    .boxed()
+            }
+        }
+
+        #[derive(Default, Clone, Debug, PartialEq)]
+        pub struct ClearFilter {
+        }
+
+        impl ClearFilter {
+            const ALIGNMENT: usize = 4;
+        }
+
+        impl<'c> Component<'c> for ClearFilter {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let vtable = builder.start_vtable();
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+
+                table_start
+            }
+        }
+
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c ClearFilter {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let vtable = builder.start_vtable();
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, ClearFilter::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+
+                table_start
+            }
+        }
+
+        /// The variant of `ClearFilter` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct ClearFilterRef {
+        }
+
+        impl<'c> Component<'c> for ClearFilterRef {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let vtable = builder.start_vtable();
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, ClearFilter::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for ClearFilter {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                Just(ClearFilter {}).boxed()
+            }
+        }
+
+        #[derive(Default, Clone, Debug, PartialEq)]
+        pub struct CompactBlock {
+            pub header: Option<Header>,
+            pub nonce: u64,
+            pub short_ids: Vec<Bytes>,
+            pub prefilled_transactions: Vec<IndexTransaction>,
+            pub uncles: Vec<UncleBlock>,
+            pub proposal_transactions: Vec<ProposalShortId>,
+        }
+
+        impl CompactBlock {
+            const VT_HEADER: usize = 4;
+            const SIZE_HEADER: usize = 4;
+            const ALIGNMENT_HEADER: usize = 4;
+            const VT_NONCE: usize = 6;
+            const SIZE_NONCE: usize = 8;
+            const ALIGNMENT_NONCE: usize = 8;
+            const VT_SHORT_IDS: usize = 8;
+            const SIZE_SHORT_IDS: usize = 4;
+            const ALIGNMENT_SHORT_IDS: usize = 4;
+            const VT_PREFILLED_TRANSACTIONS: usize = 10;
+            const SIZE_PREFILLED_TRANSACTIONS: usize = 4;
+            const ALIGNMENT_PREFILLED_TRANSACTIONS: usize = 4;
+            const VT_UNCLES: usize = 12;
+            const SIZE_UNCLES: usize = 4;
+            const ALIGNMENT_UNCLES: usize = 4;
+            const VT_PROPOSAL_TRANSACTIONS: usize = 14;
+            const SIZE_PROPOSAL_TRANSACTIONS: usize = 4;
+            const ALIGNMENT_PROPOSAL_TRANSACTIONS: usize = 4;
             const ALIGNMENT: usize = 8;
         }
 
@@ -605,71 +1640,83 @@ pub mod ckb {
             }
         }
 
-        #[derive(Default, Clone, Debug, PartialEq)]
-        pub struct FilteredBlock {
-            pub header: Option<Header>,
-            pub transactions: Vec<Transaction>,
-            pub proof: Option<MerkleProof>,
-        }
-
-        impl FilteredBlock {
-            const VT_HEADER: usize = 4;
-            const SIZE_HEADER: usize = 4;
-            const ALIGNMENT_HEADER: usize = 4;
-            const VT_TRANSACTIONS: usize = 6;
-            const SIZE_TRANSACTIONS: usize = 4;
-            const ALIGNMENT_TRANSACTIONS: usize = 4;
-            const VT_PROOF: usize = 8;
-            const SIZE_PROOF: usize = 4;
-            const ALIGNMENT_PROOF: usize = 4;
-            const ALIGNMENT: usize = 4;
-        }
-
-        impl<'c> Component<'c> for FilteredBlock {
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c CompactBlock {
             fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
                 let vtable_start = {
                     let mut vtable = builder.start_vtable();
+                    if self.nonce != 0u64 {
+                        vtable.add_field(CompactBlock::VT_NONCE, CompactBlock::SIZE_NONCE, CompactBlock::ALIGNMENT_NONCE);
+                    }
                     if self.header.is_some() {
-                        vtable.add_field(Self::VT_HEADER, Self::SIZE_HEADER, Self::ALIGNMENT_HEADER);
+                        vtable.add_field(CompactBlock::VT_HEADER, CompactBlock::SIZE_HEADER, CompactBlock::ALIGNMENT_HEADER);
                     }
-                    if !self.transactions.is_empty() {
-                        vtable.add_field(Self::VT_TRANSACTIONS, Self::SIZE_TRANSACTIONS, Self::ALIGNMENT_TRANSACTIONS);
+                    if !self.short_ids.is_empty() {
+                        vtable.add_field(CompactBlock::VT_SHORT_IDS, CompactBlock::SIZE_SHORT_IDS, CompactBlock::ALIGNMENT_SHORT_IDS);
                     }
-                    if self.proof.is_some() {
-                        vtable.add_field(Self::VT_PROOF, Self::SIZE_PROOF, Self::ALIGNMENT_PROOF);
+                    if !self.prefilled_transactions.is_empty() {
+                        vtable.add_field(CompactBlock::VT_PREFILLED_TRANSACTIONS, CompactBlock::SIZE_PREFILLED_TRANSACTIONS, CompactBlock::ALIGNMENT_PREFILLED_TRANSACTIONS);
+                    }
+                    if !self.uncles.is_empty() {
+                        vtable.add_field(CompactBlock::VT_UNCLES, CompactBlock::SIZE_UNCLES, CompactBlock::ALIGNMENT_UNCLES);
+                    }
+                    if !self.proposal_transactions.is_empty() {
+                        vtable.add_field(CompactBlock::VT_PROPOSAL_TRANSACTIONS, CompactBlock::SIZE_PROPOSAL_TRANSACTIONS, CompactBlock::ALIGNMENT_PROPOSAL_TRANSACTIONS);
                     }
                     vtable.finish()
                 };
 
-                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+                builder.align_after(SIZE_OF_SOFFSET, CompactBlock::ALIGNMENT);
 
                 let table_start = builder.tell();
                 builder.push_scalar((table_start - vtable_start) as SOffset);
-                if let Some(f) = self.header {
-                    builder.align(Self::ALIGNMENT_HEADER);
+                if self.nonce != 0u64 {
+                    builder.align(CompactBlock::ALIGNMENT_NONCE);
+                    builder.push_scalar(self.nonce);
+                }
+                if let Some(f) = &self.header {
+                    builder.align(CompactBlock::ALIGNMENT_HEADER);
                     let offset_position = builder.tell();
-                    builder.pad(Self::SIZE_HEADER);
+                    builder.pad(CompactBlock::SIZE_HEADER);
                     builder.push_component(DesignatedComponent::new(
                         offset_position,
                         Box::new(f),
                     ));
                 }
-                if !self.transactions.is_empty() {
-                    builder.align(Self::ALIGNMENT_TRANSACTIONS);
+                if !self.short_ids.is_empty() {
+                    builder.align(CompactBlock::ALIGNMENT_SHORT_IDS);
                     let offset_position = builder.tell();
-                    builder.pad(Self::SIZE_TRANSACTIONS);
+                    builder.pad(CompactBlock::SIZE_SHORT_IDS);
                     builder.push_component(DesignatedComponent::new(
                         offset_position,
-                        Box::new(ReferenceVectorComponent::new(self.transactions)),
+                        Box::new(ReferenceVectorComponent::new(self.short_ids.iter())),
                     ));
                 }
-                if let Some(f) = self.proof {
-                    builder.align(Self::ALIGNMENT_PROOF);
+                if !self.prefilled_transactions.is_empty() {
+                    builder.align(CompactBlock::ALIGNMENT_PREFILLED_TRANSACTIONS);
                     let offset_position = builder.tell();
-                    builder.pad(Self::SIZE_PROOF);
+                    builder.pad(CompactBlock::SIZE_PREFILLED_TRANSACTIONS);
                     builder.push_component(DesignatedComponent::new(
                         offset_position,
-                        Box::new(f),
+                        Box::new(ReferenceVectorComponent::new(self.prefilled_transactions.iter())),
+                    ));
+                }
+                if !self.uncles.is_empty() {
+                    builder.align(CompactBlock::ALIGNMENT_UNCLES);
+                    let offset_position = builder.tell();
+                    builder.pad(CompactBlock::SIZE_UNCLES);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.uncles.iter())),
+                    ));
+                }
+                if !self.proposal_transactions.is_empty() {
+                    builder.align(CompactBlock::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(CompactBlock::SIZE_PROPOSAL_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.proposal_transactions.iter().copied(), 1)),
                     ));
                 }
 
@@ -677,69 +1724,490 @@ pub mod ckb {
             }
         }
 
-        #[derive(Default, Clone, Debug, PartialEq)]
-        pub struct GetBlockProposal {
-            pub block_number: u64,
-            pub proposal_transactions: Vec<ProposalShortId>,
-        }
-
-        impl GetBlockProposal {
-            const VT_BLOCK_NUMBER: usize = 4;
-            const SIZE_BLOCK_NUMBER: usize = 8;
-            const ALIGNMENT_BLOCK_NUMBER: usize = 8;
-            const VT_PROPOSAL_TRANSACTIONS: usize = 6;
-            const SIZE_PROPOSAL_TRANSACTIONS: usize = 4;
-            const ALIGNMENT_PROPOSAL_TRANSACTIONS: usize = 4;
-            const ALIGNMENT: usize = 8;
+        /// The variant of `CompactBlock` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct CompactBlockRef<'a> {
+            pub header: Option<HeaderRef<'a>>,
+            pub nonce: u64,
+            pub short_ids: &'a [BytesRef<'a>],
+            pub prefilled_transactions: &'a [IndexTransactionRef<'a>],
+            pub uncles: &'a [UncleBlockRef<'a>],
+            pub proposal_transactions: &'a [ProposalShortId],
         }
 
-        impl<'c> Component<'c> for GetBlockProposal {
+        impl<'c> Component<'c> for CompactBlockRef<'c> {
             fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
                 let vtable_start = {
                     let mut vtable = builder.start_vtable();
-                    if self.block_number != 0u64 {
-                        vtable.add_field(Self::VT_BLOCK_NUMBER, Self::SIZE_BLOCK_NUMBER, Self::ALIGNMENT_BLOCK_NUMBER);
+                    if self.nonce != 0u64 {
+                        vtable.add_field(CompactBlock::VT_NONCE, CompactBlock::SIZE_NONCE, CompactBlock::ALIGNMENT_NONCE);
+                    }
+                    if self.header.is_some() {
+                        vtable.add_field(CompactBlock::VT_HEADER, CompactBlock::SIZE_HEADER, CompactBlock::ALIGNMENT_HEADER);
+                    }
+                    if !self.short_ids.is_empty() {
+                        vtable.add_field(CompactBlock::VT_SHORT_IDS, CompactBlock::SIZE_SHORT_IDS, CompactBlock::ALIGNMENT_SHORT_IDS);
+                    }
+                    if !self.prefilled_transactions.is_empty() {
+                        vtable.add_field(CompactBlock::VT_PREFILLED_TRANSACTIONS, CompactBlock::SIZE_PREFILLED_TRANSACTIONS, CompactBlock::ALIGNMENT_PREFILLED_TRANSACTIONS);
+                    }
+                    if !self.uncles.is_empty() {
+                        vtable.add_field(CompactBlock::VT_UNCLES, CompactBlock::SIZE_UNCLES, CompactBlock::ALIGNMENT_UNCLES);
                     }
                     if !self.proposal_transactions.is_empty() {
-                        vtable.add_field(Self::VT_PROPOSAL_TRANSACTIONS, Self::SIZE_PROPOSAL_TRANSACTIONS, Self::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                        vtable.add_field(CompactBlock::VT_PROPOSAL_TRANSACTIONS, CompactBlock::SIZE_PROPOSAL_TRANSACTIONS, CompactBlock::ALIGNMENT_PROPOSAL_TRANSACTIONS);
                     }
                     vtable.finish()
                 };
 
-                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+                builder.align_after(SIZE_OF_SOFFSET, CompactBlock::ALIGNMENT);
 
                 let table_start = builder.tell();
                 builder.push_scalar((table_start - vtable_start) as SOffset);
-                if self.block_number != 0u64 {
-                    builder.align(Self::ALIGNMENT_BLOCK_NUMBER);
-                    builder.push_scalar(self.block_number);
+                if self.nonce != 0u64 {
+                    builder.align(CompactBlock::ALIGNMENT_NONCE);
+                    builder.push_scalar(self.nonce);
                 }
-                if !self.proposal_transactions.is_empty() {
-                    builder.align(Self::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                if let Some(f) = self.header {
+                    builder.align(CompactBlock::ALIGNMENT_HEADER);
                     let offset_position = builder.tell();
-                    builder.pad(Self::SIZE_PROPOSAL_TRANSACTIONS);
+                    builder.pad(CompactBlock::SIZE_HEADER);
                     builder.push_component(DesignatedComponent::new(
                         offset_position,
-                        Box::new(ScalarVectorComponent::new(self.proposal_transactions, 1)),
+                        Box::new(f),
                     ));
                 }
-
-                table_start
-            }
-        }
-
-        #[derive(Default, Clone, Debug, PartialEq)]
-        pub struct GetBlockTransactions {
-            pub hash: H256,
-            pub indexes: Vec<u32>,
-        }
-
-        impl GetBlockTransactions {
-            const VT_HASH: usize = 4;
-            const SIZE_HASH: usize = 32;
-            const ALIGNMENT_HASH: usize = 1;
-            const VT_INDEXES: usize = 6;
-            const SIZE_INDEXES: usize = 4;
+                if !self.short_ids.is_empty() {
+                    builder.align(CompactBlock::ALIGNMENT_SHORT_IDS);
+                    let offset_position = builder.tell();
+                    builder.pad(CompactBlock::SIZE_SHORT_IDS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.short_ids.iter().copied())),
+                    ));
+                }
+                if !self.prefilled_transactions.is_empty() {
+                    builder.align(CompactBlock::ALIGNMENT_PREFILLED_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(CompactBlock::SIZE_PREFILLED_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.prefilled_transactions.iter().copied())),
+                    ));
+                }
+                if !self.uncles.is_empty() {
+                    builder.align(CompactBlock::ALIGNMENT_UNCLES);
+                    let offset_position = builder.tell();
+                    builder.pad(CompactBlock::SIZE_UNCLES);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.uncles.iter().copied())),
+                    ));
+                }
+                if !self.proposal_transactions.is_empty() {
+                    builder.align(CompactBlock::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(CompactBlock::SIZE_PROPOSAL_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.proposal_transactions.iter().copied(), 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for CompactBlock {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    strategy::option(&config, any_with::<Header>(config.clone())),
+                    any::<u64>(),
+                    vec(any_with::<Bytes>(config.clone()), config.vector_len.clone()),
+                    vec(any_with::<IndexTransaction>(config.clone()), config.vector_len.clone()),
+                    vec(any_with::<UncleBlock>(config.clone()), config.vector_len.clone()),
+                    vec(any::<ProposalShortId>(), config.scalar_vector_len.clone()),
+                )
+                    .prop_map(|(header, nonce, short_ids, prefilled_transactions, uncles, proposal_transactions)| CompactBlock {
+                        header,
+                        nonce,
+                        short_ids,
+                        prefilled_transactions,
+                        uncles,
+                        proposal_transactions,
+                    })
+                    .boxed()
+            }
+        }
+
+        #[derive(Default, Clone, Debug, PartialEq)]
+        pub struct FilteredBlock {
+            pub header: Option<Header>,
+            pub transactions: Vec<Transaction>,
+            pub proof: Option<MerkleProof>,
+        }
+
+        impl FilteredBlock {
+            const VT_HEADER: usize = 4;
+            const SIZE_HEADER: usize = 4;
+            const ALIGNMENT_HEADER: usize = 4;
+            const VT_TRANSACTIONS: usize = 6;
+            const SIZE_TRANSACTIONS: usize = 4;
+            const ALIGNMENT_TRANSACTIONS: usize = 4;
+            const VT_PROOF: usize = 8;
+            const SIZE_PROOF: usize = 4;
+            const ALIGNMENT_PROOF: usize = 4;
+            const ALIGNMENT: usize = 4;
+        }
+
+        impl<'c> Component<'c> for FilteredBlock {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.header.is_some() {
+                        vtable.add_field(Self::VT_HEADER, Self::SIZE_HEADER, Self::ALIGNMENT_HEADER);
+                    }
+                    if !self.transactions.is_empty() {
+                        vtable.add_field(Self::VT_TRANSACTIONS, Self::SIZE_TRANSACTIONS, Self::ALIGNMENT_TRANSACTIONS);
+                    }
+                    if self.proof.is_some() {
+                        vtable.add_field(Self::VT_PROOF, Self::SIZE_PROOF, Self::ALIGNMENT_PROOF);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if let Some(f) = self.header {
+                    builder.align(Self::ALIGNMENT_HEADER);
+                    let offset_position = builder.tell();
+                    builder.pad(Self::SIZE_HEADER);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if !self.transactions.is_empty() {
+                    builder.align(Self::ALIGNMENT_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(Self::SIZE_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.transactions)),
+                    ));
+                }
+                if let Some(f) = self.proof {
+                    builder.align(Self::ALIGNMENT_PROOF);
+                    let offset_position = builder.tell();
+                    builder.pad(Self::SIZE_PROOF);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c FilteredBlock {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.header.is_some() {
+                        vtable.add_field(FilteredBlock::VT_HEADER, FilteredBlock::SIZE_HEADER, FilteredBlock::ALIGNMENT_HEADER);
+                    }
+                    if !self.transactions.is_empty() {
+                        vtable.add_field(FilteredBlock::VT_TRANSACTIONS, FilteredBlock::SIZE_TRANSACTIONS, FilteredBlock::ALIGNMENT_TRANSACTIONS);
+                    }
+                    if self.proof.is_some() {
+                        vtable.add_field(FilteredBlock::VT_PROOF, FilteredBlock::SIZE_PROOF, FilteredBlock::ALIGNMENT_PROOF);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, FilteredBlock::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if let Some(f) = &self.header {
+                    builder.align(FilteredBlock::ALIGNMENT_HEADER);
+                    let offset_position = builder.tell();
+                    builder.pad(FilteredBlock::SIZE_HEADER);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if !self.transactions.is_empty() {
+                    builder.align(FilteredBlock::ALIGNMENT_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(FilteredBlock::SIZE_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.transactions.iter())),
+                    ));
+                }
+                if let Some(f) = &self.proof {
+                    builder.align(FilteredBlock::ALIGNMENT_PROOF);
+                    let offset_position = builder.tell();
+                    builder.pad(FilteredBlock::SIZE_PROOF);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `FilteredBlock` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct FilteredBlockRef<'a> {
+            pub header: Option<HeaderRef<'a>>,
+            pub transactions: &'a [TransactionRef<'a>],
+            pub proof: Option<MerkleProofRef<'a>>,
+        }
+
+        impl<'c> Component<'c> for FilteredBlockRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.header.is_some() {
+                        vtable.add_field(FilteredBlock::VT_HEADER, FilteredBlock::SIZE_HEADER, FilteredBlock::ALIGNMENT_HEADER);
+                    }
+                    if !self.transactions.is_empty() {
+                        vtable.add_field(FilteredBlock::VT_TRANSACTIONS, FilteredBlock::SIZE_TRANSACTIONS, FilteredBlock::ALIGNMENT_TRANSACTIONS);
+                    }
+                    if self.proof.is_some() {
+                        vtable.add_field(FilteredBlock::VT_PROOF, FilteredBlock::SIZE_PROOF, FilteredBlock::ALIGNMENT_PROOF);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, FilteredBlock::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if let Some(f) = self.header {
+                    builder.align(FilteredBlock::ALIGNMENT_HEADER);
+                    let offset_position = builder.tell();
+                    builder.pad(FilteredBlock::SIZE_HEADER);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if !self.transactions.is_empty() {
+                    builder.align(FilteredBlock::ALIGNMENT_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(FilteredBlock::SIZE_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.transactions.iter().copied())),
+                    ));
+                }
+                if let Some(f) = self.proof {
+                    builder.align(FilteredBlock::ALIGNMENT_PROOF);
+                    let offset_position = builder.tell();
+                    builder.pad(FilteredBlock::SIZE_PROOF);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for FilteredBlock {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    strategy::option(&config, any_with::<Header>(config.clone())),
+                    vec(any_with::<Transaction>(config.clone()), config.vector_len.clone()),
+                    strategy::option(&config, any_with::<MerkleProof>(config.clone())),
+                )
+                    .prop_map(|(header, transactions, proof)| FilteredBlock {
+                        header,
+                        transactions,
+                        proof,
+                    })
+                    .boxed()
+            }
+        }
+
+        #[derive(Default, Clone, Debug, PartialEq)]
+        pub struct GetBlockProposal {
+            pub block_number: u64,
+            pub proposal_transactions: Vec<ProposalShortId>,
+        }
+
+        impl GetBlockProposal {
+            const VT_BLOCK_NUMBER: usize = 4;
+            const SIZE_BLOCK_NUMBER: usize = 8;
+            const ALIGNMENT_BLOCK_NUMBER: usize = 8;
+            const VT_PROPOSAL_TRANSACTIONS: usize = 6;
+            const SIZE_PROPOSAL_TRANSACTIONS: usize = 4;
+            const ALIGNMENT_PROPOSAL_TRANSACTIONS: usize = 4;
+            const ALIGNMENT: usize = 8;
+        }
+
+        impl<'c> Component<'c> for GetBlockProposal {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.block_number != 0u64 {
+                        vtable.add_field(Self::VT_BLOCK_NUMBER, Self::SIZE_BLOCK_NUMBER, Self::ALIGNMENT_BLOCK_NUMBER);
+                    }
+                    if !self.proposal_transactions.is_empty() {
+                        vtable.add_field(Self::VT_PROPOSAL_TRANSACTIONS, Self::SIZE_PROPOSAL_TRANSACTIONS, Self::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.block_number != 0u64 {
+                    builder.align(Self::ALIGNMENT_BLOCK_NUMBER);
+                    builder.push_scalar(self.block_number);
+                }
+                if !self.proposal_transactions.is_empty() {
+                    builder.align(Self::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(Self::SIZE_PROPOSAL_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.proposal_transactions, 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c GetBlockProposal {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.block_number != 0u64 {
+                        vtable.add_field(GetBlockProposal::VT_BLOCK_NUMBER, GetBlockProposal::SIZE_BLOCK_NUMBER, GetBlockProposal::ALIGNMENT_BLOCK_NUMBER);
+                    }
+                    if !self.proposal_transactions.is_empty() {
+                        vtable.add_field(GetBlockProposal::VT_PROPOSAL_TRANSACTIONS, GetBlockProposal::SIZE_PROPOSAL_TRANSACTIONS, GetBlockProposal::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, GetBlockProposal::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.block_number != 0u64 {
+                    builder.align(GetBlockProposal::ALIGNMENT_BLOCK_NUMBER);
+                    builder.push_scalar(self.block_number);
+                }
+                if !self.proposal_transactions.is_empty() {
+                    builder.align(GetBlockProposal::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(GetBlockProposal::SIZE_PROPOSAL_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.proposal_transactions.iter().copied(), 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `GetBlockProposal` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct GetBlockProposalRef<'a> {
+            pub block_number: u64,
+            pub proposal_transactions: &'a [ProposalShortId],
+        }
+
+        impl<'c> Component<'c> for GetBlockProposalRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.block_number != 0u64 {
+                        vtable.add_field(GetBlockProposal::VT_BLOCK_NUMBER, GetBlockProposal::SIZE_BLOCK_NUMBER, GetBlockProposal::ALIGNMENT_BLOCK_NUMBER);
+                    }
+                    if !self.proposal_transactions.is_empty() {
+                        vtable.add_field(GetBlockProposal::VT_PROPOSAL_TRANSACTIONS, GetBlockProposal::SIZE_PROPOSAL_TRANSACTIONS, GetBlockProposal::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, GetBlockProposal::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.block_number != 0u64 {
+                    builder.align(GetBlockProposal::ALIGNMENT_BLOCK_NUMBER);
+                    builder.push_scalar(self.block_number);
+                }
+                if !self.proposal_transactions.is_empty() {
+                    builder.align(GetBlockProposal::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(GetBlockProposal::SIZE_PROPOSAL_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.proposal_transactions.iter().copied(), 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for GetBlockProposal {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    any::<u64>(),
+                    vec(any::<ProposalShortId>(), config.scalar_vector_len.clone()),
+                )
+                    .prop_map(|(block_number, proposal_transactions)| GetBlockProposal {
+                        block_number,
+                        proposal_transactions,
+                    })
+                    .boxed()
+            }
+        }
+
+        #[derive(Default, Clone, Debug, PartialEq)]
+        pub struct GetBlockTransactions {
+            pub hash: H256,
+            pub indexes: Vec<u32>,
+        }
+
+        impl GetBlockTransactions {
+            const VT_HASH: usize = 4;
+            const SIZE_HASH: usize = 32;
+            const ALIGNMENT_HASH: usize = 1;
+            const VT_INDEXES: usize = 6;
+            const SIZE_INDEXES: usize = 4;
             const ALIGNMENT_INDEXES: usize = 4;
             const ALIGNMENT: usize = 4;
         }
@@ -779,6 +2247,102 @@ pub mod ckb {
             }
         }
 
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c GetBlockTransactions {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.indexes.is_empty() {
+                        vtable.add_field(GetBlockTransactions::VT_INDEXES, GetBlockTransactions::SIZE_INDEXES, GetBlockTransactions::ALIGNMENT_INDEXES);
+                    }
+                    if self.hash.is_present() {
+                        vtable.add_field(GetBlockTransactions::VT_HASH, GetBlockTransactions::SIZE_HASH, GetBlockTransactions::ALIGNMENT_HASH);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, GetBlockTransactions::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.indexes.is_empty() {
+                    builder.align(GetBlockTransactions::ALIGNMENT_INDEXES);
+                    let offset_position = builder.tell();
+                    builder.pad(GetBlockTransactions::SIZE_INDEXES);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.indexes.iter().copied(), 4)),
+                    ));
+                }
+                if self.hash.is_present() {
+                    builder.align(GetBlockTransactions::ALIGNMENT_HASH);
+                    builder.push_scalar(self.hash);
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `GetBlockTransactions` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct GetBlockTransactionsRef<'a> {
+            pub hash: H256,
+            pub indexes: &'a [u32],
+        }
+
+        impl<'c> Component<'c> for GetBlockTransactionsRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.indexes.is_empty() {
+                        vtable.add_field(GetBlockTransactions::VT_INDEXES, GetBlockTransactions::SIZE_INDEXES, GetBlockTransactions::ALIGNMENT_INDEXES);
+                    }
+                    if self.hash.is_present() {
+                        vtable.add_field(GetBlockTransactions::VT_HASH, GetBlockTransactions::SIZE_HASH, GetBlockTransactions::ALIGNMENT_HASH);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, GetBlockTransactions::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.indexes.is_empty() {
+                    builder.align(GetBlockTransactions::ALIGNMENT_INDEXES);
+                    let offset_position = builder.tell();
+                    builder.pad(GetBlockTransactions::SIZE_INDEXES);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.indexes.iter().copied(), 4)),
+                    ));
+                }
+                if self.hash.is_present() {
+                    builder.align(GetBlockTransactions::ALIGNMENT_HASH);
+                    builder.push_scalar(self.hash);
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for GetBlockTransactions {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    any::<H256>(),
+                    vec(any::<u32>(), config.scalar_vector_len.clone()),
+                )
+                    .prop_map(|(hash, indexes)| GetBlockTransactions {
+                        hash,
+                        indexes,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct GetBlocks {
             pub block_hashes: Vec<H256>,
@@ -796,22 +2360,85 @@ pub mod ckb {
                 let vtable_start = {
                     let mut vtable = builder.start_vtable();
                     if !self.block_hashes.is_empty() {
-                        vtable.add_field(Self::VT_BLOCK_HASHES, Self::SIZE_BLOCK_HASHES, Self::ALIGNMENT_BLOCK_HASHES);
+                        vtable.add_field(Self::VT_BLOCK_HASHES, Self::SIZE_BLOCK_HASHES, Self::ALIGNMENT_BLOCK_HASHES);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.block_hashes.is_empty() {
+                    builder.align(Self::ALIGNMENT_BLOCK_HASHES);
+                    let offset_position = builder.tell();
+                    builder.pad(Self::SIZE_BLOCK_HASHES);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.block_hashes, 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c GetBlocks {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.block_hashes.is_empty() {
+                        vtable.add_field(GetBlocks::VT_BLOCK_HASHES, GetBlocks::SIZE_BLOCK_HASHES, GetBlocks::ALIGNMENT_BLOCK_HASHES);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, GetBlocks::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.block_hashes.is_empty() {
+                    builder.align(GetBlocks::ALIGNMENT_BLOCK_HASHES);
+                    let offset_position = builder.tell();
+                    builder.pad(GetBlocks::SIZE_BLOCK_HASHES);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.block_hashes.iter().copied(), 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `GetBlocks` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct GetBlocksRef<'a> {
+            pub block_hashes: &'a [H256],
+        }
+
+        impl<'c> Component<'c> for GetBlocksRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.block_hashes.is_empty() {
+                        vtable.add_field(GetBlocks::VT_BLOCK_HASHES, GetBlocks::SIZE_BLOCK_HASHES, GetBlocks::ALIGNMENT_BLOCK_HASHES);
                     }
                     vtable.finish()
                 };
 
-                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+                builder.align_after(SIZE_OF_SOFFSET, GetBlocks::ALIGNMENT);
 
                 let table_start = builder.tell();
                 builder.push_scalar((table_start - vtable_start) as SOffset);
                 if !self.block_hashes.is_empty() {
-                    builder.align(Self::ALIGNMENT_BLOCK_HASHES);
+                    builder.align(GetBlocks::ALIGNMENT_BLOCK_HASHES);
                     let offset_position = builder.tell();
-                    builder.pad(Self::SIZE_BLOCK_HASHES);
+                    builder.pad(GetBlocks::SIZE_BLOCK_HASHES);
                     builder.push_component(DesignatedComponent::new(
                         offset_position,
-                        Box::new(ScalarVectorComponent::new(self.block_hashes, 1)),
+                        Box::new(ScalarVectorComponent::new(self.block_hashes.iter().copied(), 1)),
                     ));
                 }
 
@@ -819,6 +2446,22 @@ pub mod ckb {
             }
         }
 
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for GetBlocks {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    vec(any::<H256>(), config.scalar_vector_len.clone()),
+                )
+                    .prop_map(|(block_hashes,)| GetBlocks {
+                        block_hashes,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct GetHeaders {
             pub version: u32,
@@ -881,8 +2524,121 @@ pub mod ckb {
             }
         }
 
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c GetHeaders {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.version != 0u32 {
+                        vtable.add_field(GetHeaders::VT_VERSION, GetHeaders::SIZE_VERSION, GetHeaders::ALIGNMENT_VERSION);
+                    }
+                    if !self.block_locator_hashes.is_empty() {
+                        vtable.add_field(GetHeaders::VT_BLOCK_LOCATOR_HASHES, GetHeaders::SIZE_BLOCK_LOCATOR_HASHES, GetHeaders::ALIGNMENT_BLOCK_LOCATOR_HASHES);
+                    }
+                    if self.hash_stop.is_present() {
+                        vtable.add_field(GetHeaders::VT_HASH_STOP, GetHeaders::SIZE_HASH_STOP, GetHeaders::ALIGNMENT_HASH_STOP);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, GetHeaders::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.version != 0u32 {
+                    builder.align(GetHeaders::ALIGNMENT_VERSION);
+                    builder.push_scalar(self.version);
+                }
+                if !self.block_locator_hashes.is_empty() {
+                    builder.align(GetHeaders::ALIGNMENT_BLOCK_LOCATOR_HASHES);
+                    let offset_position = builder.tell();
+                    builder.pad(GetHeaders::SIZE_BLOCK_LOCATOR_HASHES);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.block_locator_hashes.iter().copied(), 1)),
+                    ));
+                }
+                if self.hash_stop.is_present() {
+                    builder.align(GetHeaders::ALIGNMENT_HASH_STOP);
+                    builder.push_scalar(self.hash_stop);
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `GetHeaders` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct GetHeadersRef<'a> {
+            pub version: u32,
+            pub block_locator_hashes: &'a [H256],
+            pub hash_stop: H256,
+        }
+
+        impl<'c> Component<'c> for GetHeadersRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.version != 0u32 {
+                        vtable.add_field(GetHeaders::VT_VERSION, GetHeaders::SIZE_VERSION, GetHeaders::ALIGNMENT_VERSION);
+                    }
+                    if !self.block_locator_hashes.is_empty() {
+                        vtable.add_field(GetHeaders::VT_BLOCK_LOCATOR_HASHES, GetHeaders::SIZE_BLOCK_LOCATOR_HASHES, GetHeaders::ALIGNMENT_BLOCK_LOCATOR_HASHES);
+                    }
+                    if self.hash_stop.is_present() {
+                        vtable.add_field(GetHeaders::VT_HASH_STOP, GetHeaders::SIZE_HASH_STOP, GetHeaders::ALIGNMENT_HASH_STOP);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, GetHeaders::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.version != 0u32 {
+                    builder.align(GetHeaders::ALIGNMENT_VERSION);
+                    builder.push_scalar(self.version);
+                }
+                if !self.block_locator_hashes.is_empty() {
+                    builder.align(GetHeaders::ALIGNMENT_BLOCK_LOCATOR_HASHES);
+                    let offset_position = builder.tell();
+                    builder.pad(GetHeaders::SIZE_BLOCK_LOCATOR_HASHES);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.block_locator_hashes.iter().copied(), 1)),
+                    ));
+                }
+                if self.hash_stop.is_present() {
+                    builder.align(GetHeaders::ALIGNMENT_HASH_STOP);
+                    builder.push_scalar(self.hash_stop);
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for GetHeaders {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    any::<u32>(),
+                    vec(any::<H256>(), config.scalar_vector_len.clone()),
+                    any::<H256>(),
+                )
+                    .prop_map(|(version, block_locator_hashes, hash_stop)| GetHeaders {
+                        version,
+                        block_locator_hashes,
+                        hash_stop,
+                    })
+                    .boxed()
+            }
+        }
+
         #[repr(C, align(1))]
-        #[derive(Default, Clone, Debug, PartialEq)]
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
         pub struct H256 {
             pub u0: u8,
             pub u1: u8,
@@ -1010,6 +2766,92 @@ pub mod ckb {
             }
         }
 
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for H256 {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                (
+                    (
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                    ),
+                    (
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                    ),
+                    (
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                    ),
+                    (
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                    ),
+                )
+                    .prop_map(|((u0, u1, u2, u3, u4, u5, u6, u7), (u8_, u9, u10, u11, u12, u13, u14, u15), (u16_, u17, u18, u19, u20, u21, u22, u23), (u24, u25, u26, u27, u28, u29, u30, u31))| H256 {
+                        u0,
+                        u1,
+                        u2,
+                        u3,
+                        u4,
+                        u5,
+                        u6,
+                        u7,
+                        u8_,
+                        u9,
+                        u10,
+                        u11,
+                        u12,
+                        u13,
+                        u14,
+                        u15,
+                        u16_,
+                        u17,
+                        u18,
+                        u19,
+                        u20,
+                        u21,
+                        u22,
+                        u23,
+                        u24,
+                        u25,
+                        u26,
+                        u27,
+                        u28,
+                        u29,
+                        u30,
+                        u31,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct Header {
             pub version: u32,
@@ -1066,109 +2908,347 @@ pub mod ckb {
             const ALIGNMENT: usize = 8;
         }
 
-        impl<'c> Component<'c> for Header {
+        impl<'c> Component<'c> for Header {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.timestamp != 0u64 {
+                        vtable.add_field(Self::VT_TIMESTAMP, Self::SIZE_TIMESTAMP, Self::ALIGNMENT_TIMESTAMP);
+                    }
+                    if self.number != 0u64 {
+                        vtable.add_field(Self::VT_NUMBER, Self::SIZE_NUMBER, Self::ALIGNMENT_NUMBER);
+                    }
+                    if self.nonce != 0u64 {
+                        vtable.add_field(Self::VT_NONCE, Self::SIZE_NONCE, Self::ALIGNMENT_NONCE);
+                    }
+                    if self.version != 0u32 {
+                        vtable.add_field(Self::VT_VERSION, Self::SIZE_VERSION, Self::ALIGNMENT_VERSION);
+                    }
+                    if self.difficulty.is_some() {
+                        vtable.add_field(Self::VT_DIFFICULTY, Self::SIZE_DIFFICULTY, Self::ALIGNMENT_DIFFICULTY);
+                    }
+                    if self.proof.is_some() {
+                        vtable.add_field(Self::VT_PROOF, Self::SIZE_PROOF, Self::ALIGNMENT_PROOF);
+                    }
+                    if self.uncles_count != 0u32 {
+                        vtable.add_field(Self::VT_UNCLES_COUNT, Self::SIZE_UNCLES_COUNT, Self::ALIGNMENT_UNCLES_COUNT);
+                    }
+                    if self.parent_hash.is_present() {
+                        vtable.add_field(Self::VT_PARENT_HASH, Self::SIZE_PARENT_HASH, Self::ALIGNMENT_PARENT_HASH);
+                    }
+                    if self.txs_commit.is_present() {
+                        vtable.add_field(Self::VT_TXS_COMMIT, Self::SIZE_TXS_COMMIT, Self::ALIGNMENT_TXS_COMMIT);
+                    }
+                    if self.txs_proposal.is_present() {
+                        vtable.add_field(Self::VT_TXS_PROPOSAL, Self::SIZE_TXS_PROPOSAL, Self::ALIGNMENT_TXS_PROPOSAL);
+                    }
+                    if self.cellbase_id.is_present() {
+                        vtable.add_field(Self::VT_CELLBASE_ID, Self::SIZE_CELLBASE_ID, Self::ALIGNMENT_CELLBASE_ID);
+                    }
+                    if self.uncles_hash.is_present() {
+                        vtable.add_field(Self::VT_UNCLES_HASH, Self::SIZE_UNCLES_HASH, Self::ALIGNMENT_UNCLES_HASH);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.timestamp != 0u64 {
+                    builder.align(Self::ALIGNMENT_TIMESTAMP);
+                    builder.push_scalar(self.timestamp);
+                }
+                if self.number != 0u64 {
+                    builder.align(Self::ALIGNMENT_NUMBER);
+                    builder.push_scalar(self.number);
+                }
+                if self.nonce != 0u64 {
+                    builder.align(Self::ALIGNMENT_NONCE);
+                    builder.push_scalar(self.nonce);
+                }
+                if self.version != 0u32 {
+                    builder.align(Self::ALIGNMENT_VERSION);
+                    builder.push_scalar(self.version);
+                }
+                if let Some(f) = self.difficulty {
+                    builder.align(Self::ALIGNMENT_DIFFICULTY);
+                    let offset_position = builder.tell();
+                    builder.pad(Self::SIZE_DIFFICULTY);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if let Some(f) = self.proof {
+                    builder.align(Self::ALIGNMENT_PROOF);
+                    let offset_position = builder.tell();
+                    builder.pad(Self::SIZE_PROOF);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if self.uncles_count != 0u32 {
+                    builder.align(Self::ALIGNMENT_UNCLES_COUNT);
+                    builder.push_scalar(self.uncles_count);
+                }
+                if self.parent_hash.is_present() {
+                    builder.align(Self::ALIGNMENT_PARENT_HASH);
+                    builder.push_scalar(self.parent_hash);
+                }
+                if self.txs_commit.is_present() {
+                    builder.align(Self::ALIGNMENT_TXS_COMMIT);
+                    builder.push_scalar(self.txs_commit);
+                }
+                if self.txs_proposal.is_present() {
+                    builder.align(Self::ALIGNMENT_TXS_PROPOSAL);
+                    builder.push_scalar(self.txs_proposal);
+                }
+                if self.cellbase_id.is_present() {
+                    builder.align(Self::ALIGNMENT_CELLBASE_ID);
+                    builder.push_scalar(self.cellbase_id);
+                }
+                if self.uncles_hash.is_present() {
+                    builder.align(Self::ALIGNMENT_UNCLES_HASH);
+                    builder.push_scalar(self.uncles_hash);
+                }
+
+                table_start
+            }
+        }
+
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c Header {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.timestamp != 0u64 {
+                        vtable.add_field(Header::VT_TIMESTAMP, Header::SIZE_TIMESTAMP, Header::ALIGNMENT_TIMESTAMP);
+                    }
+                    if self.number != 0u64 {
+                        vtable.add_field(Header::VT_NUMBER, Header::SIZE_NUMBER, Header::ALIGNMENT_NUMBER);
+                    }
+                    if self.nonce != 0u64 {
+                        vtable.add_field(Header::VT_NONCE, Header::SIZE_NONCE, Header::ALIGNMENT_NONCE);
+                    }
+                    if self.version != 0u32 {
+                        vtable.add_field(Header::VT_VERSION, Header::SIZE_VERSION, Header::ALIGNMENT_VERSION);
+                    }
+                    if self.difficulty.is_some() {
+                        vtable.add_field(Header::VT_DIFFICULTY, Header::SIZE_DIFFICULTY, Header::ALIGNMENT_DIFFICULTY);
+                    }
+                    if self.proof.is_some() {
+                        vtable.add_field(Header::VT_PROOF, Header::SIZE_PROOF, Header::ALIGNMENT_PROOF);
+                    }
+                    if self.uncles_count != 0u32 {
+                        vtable.add_field(Header::VT_UNCLES_COUNT, Header::SIZE_UNCLES_COUNT, Header::ALIGNMENT_UNCLES_COUNT);
+                    }
+                    if self.parent_hash.is_present() {
+                        vtable.add_field(Header::VT_PARENT_HASH, Header::SIZE_PARENT_HASH, Header::ALIGNMENT_PARENT_HASH);
+                    }
+                    if self.txs_commit.is_present() {
+                        vtable.add_field(Header::VT_TXS_COMMIT, Header::SIZE_TXS_COMMIT, Header::ALIGNMENT_TXS_COMMIT);
+                    }
+                    if self.txs_proposal.is_present() {
+                        vtable.add_field(Header::VT_TXS_PROPOSAL, Header::SIZE_TXS_PROPOSAL, Header::ALIGNMENT_TXS_PROPOSAL);
+                    }
+                    if self.cellbase_id.is_present() {
+                        vtable.add_field(Header::VT_CELLBASE_ID, Header::SIZE_CELLBASE_ID, Header::ALIGNMENT_CELLBASE_ID);
+                    }
+                    if self.uncles_hash.is_present() {
+                        vtable.add_field(Header::VT_UNCLES_HASH, Header::SIZE_UNCLES_HASH, Header::ALIGNMENT_UNCLES_HASH);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Header::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.timestamp != 0u64 {
+                    builder.align(Header::ALIGNMENT_TIMESTAMP);
+                    builder.push_scalar(self.timestamp);
+                }
+                if self.number != 0u64 {
+                    builder.align(Header::ALIGNMENT_NUMBER);
+                    builder.push_scalar(self.number);
+                }
+                if self.nonce != 0u64 {
+                    builder.align(Header::ALIGNMENT_NONCE);
+                    builder.push_scalar(self.nonce);
+                }
+                if self.version != 0u32 {
+                    builder.align(Header::ALIGNMENT_VERSION);
+                    builder.push_scalar(self.version);
+                }
+                if let Some(f) = &self.difficulty {
+                    builder.align(Header::ALIGNMENT_DIFFICULTY);
+                    let offset_position = builder.tell();
+                    builder.pad(Header::SIZE_DIFFICULTY);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if let Some(f) = &self.proof {
+                    builder.align(Header::ALIGNMENT_PROOF);
+                    let offset_position = builder.tell();
+                    builder.pad(Header::SIZE_PROOF);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if self.uncles_count != 0u32 {
+                    builder.align(Header::ALIGNMENT_UNCLES_COUNT);
+                    builder.push_scalar(self.uncles_count);
+                }
+                if self.parent_hash.is_present() {
+                    builder.align(Header::ALIGNMENT_PARENT_HASH);
+                    builder.push_scalar(self.parent_hash);
+                }
+                if self.txs_commit.is_present() {
+                    builder.align(Header::ALIGNMENT_TXS_COMMIT);
+                    builder.push_scalar(self.txs_commit);
+                }
+                if self.txs_proposal.is_present() {
+                    builder.align(Header::ALIGNMENT_TXS_PROPOSAL);
+                    builder.push_scalar(self.txs_proposal);
+                }
+                if self.cellbase_id.is_present() {
+                    builder.align(Header::ALIGNMENT_CELLBASE_ID);
+                    builder.push_scalar(self.cellbase_id);
+                }
+                if self.uncles_hash.is_present() {
+                    builder.align(Header::ALIGNMENT_UNCLES_HASH);
+                    builder.push_scalar(self.uncles_hash);
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `Header` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct HeaderRef<'a> {
+            pub version: u32,
+            pub parent_hash: H256,
+            pub timestamp: u64,
+            pub number: u64,
+            pub txs_commit: H256,
+            pub txs_proposal: H256,
+            pub difficulty: Option<BytesRef<'a>>,
+            pub nonce: u64,
+            pub proof: Option<BytesRef<'a>>,
+            pub cellbase_id: H256,
+            pub uncles_hash: H256,
+            pub uncles_count: u32,
+        }
+
+        impl<'c> Component<'c> for HeaderRef<'c> {
             fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
                 let vtable_start = {
                     let mut vtable = builder.start_vtable();
                     if self.timestamp != 0u64 {
-                        vtable.add_field(Self::VT_TIMESTAMP, Self::SIZE_TIMESTAMP, Self::ALIGNMENT_TIMESTAMP);
+                        vtable.add_field(Header::VT_TIMESTAMP, Header::SIZE_TIMESTAMP, Header::ALIGNMENT_TIMESTAMP);
                     }
                     if self.number != 0u64 {
-                        vtable.add_field(Self::VT_NUMBER, Self::SIZE_NUMBER, Self::ALIGNMENT_NUMBER);
+                        vtable.add_field(Header::VT_NUMBER, Header::SIZE_NUMBER, Header::ALIGNMENT_NUMBER);
                     }
                     if self.nonce != 0u64 {
-                        vtable.add_field(Self::VT_NONCE, Self::SIZE_NONCE, Self::ALIGNMENT_NONCE);
+                        vtable.add_field(Header::VT_NONCE, Header::SIZE_NONCE, Header::ALIGNMENT_NONCE);
                     }
                     if self.version != 0u32 {
-                        vtable.add_field(Self::VT_VERSION, Self::SIZE_VERSION, Self::ALIGNMENT_VERSION);
+                        vtable.add_field(Header::VT_VERSION, Header::SIZE_VERSION, Header::ALIGNMENT_VERSION);
                     }
                     if self.difficulty.is_some() {
-                        vtable.add_field(Self::VT_DIFFICULTY, Self::SIZE_DIFFICULTY, Self::ALIGNMENT_DIFFICULTY);
+                        vtable.add_field(Header::VT_DIFFICULTY, Header::SIZE_DIFFICULTY, Header::ALIGNMENT_DIFFICULTY);
                     }
                     if self.proof.is_some() {
-                        vtable.add_field(Self::VT_PROOF, Self::SIZE_PROOF, Self::ALIGNMENT_PROOF);
+                        vtable.add_field(Header::VT_PROOF, Header::SIZE_PROOF, Header::ALIGNMENT_PROOF);
                     }
                     if self.uncles_count != 0u32 {
-                        vtable.add_field(Self::VT_UNCLES_COUNT, Self::SIZE_UNCLES_COUNT, Self::ALIGNMENT_UNCLES_COUNT);
+                        vtable.add_field(Header::VT_UNCLES_COUNT, Header::SIZE_UNCLES_COUNT, Header::ALIGNMENT_UNCLES_COUNT);
                     }
                     if self.parent_hash.is_present() {
-                        vtable.add_field(Self::VT_PARENT_HASH, Self::SIZE_PARENT_HASH, Self::ALIGNMENT_PARENT_HASH);
+                        vtable.add_field(Header::VT_PARENT_HASH, Header::SIZE_PARENT_HASH, Header::ALIGNMENT_PARENT_HASH);
                     }
                     if self.txs_commit.is_present() {
-                        vtable.add_field(Self::VT_TXS_COMMIT, Self::SIZE_TXS_COMMIT, Self::ALIGNMENT_TXS_COMMIT);
+                        vtable.add_field(Header::VT_TXS_COMMIT, Header::SIZE_TXS_COMMIT, Header::ALIGNMENT_TXS_COMMIT);
                     }
                     if self.txs_proposal.is_present() {
-                        vtable.add_field(Self::VT_TXS_PROPOSAL, Self::SIZE_TXS_PROPOSAL, Self::ALIGNMENT_TXS_PROPOSAL);
+                        vtable.add_field(Header::VT_TXS_PROPOSAL, Header::SIZE_TXS_PROPOSAL, Header::ALIGNMENT_TXS_PROPOSAL);
                     }
                     if self.cellbase_id.is_present() {
-                        vtable.add_field(Self::VT_CELLBASE_ID, Self::SIZE_CELLBASE_ID, Self::ALIGNMENT_CELLBASE_ID);
+                        vtable.add_field(Header::VT_CELLBASE_ID, Header::SIZE_CELLBASE_ID, Header::ALIGNMENT_CELLBASE_ID);
                     }
                     if self.uncles_hash.is_present() {
-                        vtable.add_field(Self::VT_UNCLES_HASH, Self::SIZE_UNCLES_HASH, Self::ALIGNMENT_UNCLES_HASH);
+                        vtable.add_field(Header::VT_UNCLES_HASH, Header::SIZE_UNCLES_HASH, Header::ALIGNMENT_UNCLES_HASH);
                     }
                     vtable.finish()
                 };
 
-                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+                builder.align_after(SIZE_OF_SOFFSET, Header::ALIGNMENT);
 
                 let table_start = builder.tell();
                 builder.push_scalar((table_start - vtable_start) as SOffset);
                 if self.timestamp != 0u64 {
-                    builder.align(Self::ALIGNMENT_TIMESTAMP);
+                    builder.align(Header::ALIGNMENT_TIMESTAMP);
                     builder.push_scalar(self.timestamp);
                 }
                 if self.number != 0u64 {
-                    builder.align(Self::ALIGNMENT_NUMBER);
+                    builder.align(Header::ALIGNMENT_NUMBER);
                     builder.push_scalar(self.number);
                 }
                 if self.nonce != 0u64 {
-                    builder.align(Self::ALIGNMENT_NONCE);
+                    builder.align(Header::ALIGNMENT_NONCE);
                     builder.push_scalar(self.nonce);
                 }
                 if self.version != 0u32 {
-                    builder.align(Self::ALIGNMENT_VERSION);
+                    builder.align(Header::ALIGNMENT_VERSION);
                     builder.push_scalar(self.version);
                 }
                 if let Some(f) = self.difficulty {
-                    builder.align(Self::ALIGNMENT_DIFFICULTY);
+                    builder.align(Header::ALIGNMENT_DIFFICULTY);
                     let offset_position = builder.tell();
-                    builder.pad(Self::SIZE_DIFFICULTY);
+                    builder.pad(Header::SIZE_DIFFICULTY);
                     builder.push_component(DesignatedComponent::new(
                         offset_position,
                         Box::new(f),
                     ));
                 }
                 if let Some(f) = self.proof {
-                    builder.align(Self::ALIGNMENT_PROOF);
+                    builder.align(Header::ALIGNMENT_PROOF);
                     let offset_position = builder.tell();
-                    builder.pad(Self::SIZE_PROOF);
+                    builder.pad(Header::SIZE_PROOF);
                     builder.push_component(DesignatedComponent::new(
                         offset_position,
                         Box::new(f),
                     ));
                 }
                 if self.uncles_count != 0u32 {
-                    builder.align(Self::ALIGNMENT_UNCLES_COUNT);
+                    builder.align(Header::ALIGNMENT_UNCLES_COUNT);
                     builder.push_scalar(self.uncles_count);
                 }
                 if self.parent_hash.is_present() {
-                    builder.align(Self::ALIGNMENT_PARENT_HASH);
+                    builder.align(Header::ALIGNMENT_PARENT_HASH);
                     builder.push_scalar(self.parent_hash);
                 }
                 if self.txs_commit.is_present() {
-                    builder.align(Self::ALIGNMENT_TXS_COMMIT);
+                    builder.align(Header::ALIGNMENT_TXS_COMMIT);
                     builder.push_scalar(self.txs_commit);
                 }
                 if self.txs_proposal.is_present() {
-                    builder.align(Self::ALIGNMENT_TXS_PROPOSAL);
+                    builder.align(Header::ALIGNMENT_TXS_PROPOSAL);
                     builder.push_scalar(self.txs_proposal);
                 }
                 if self.cellbase_id.is_present() {
-                    builder.align(Self::ALIGNMENT_CELLBASE_ID);
+                    builder.align(Header::ALIGNMENT_CELLBASE_ID);
                     builder.push_scalar(self.cellbase_id);
                 }
                 if self.uncles_hash.is_present() {
-                    builder.align(Self::ALIGNMENT_UNCLES_HASH);
+                    builder.align(Header::ALIGNMENT_UNCLES_HASH);
                     builder.push_scalar(self.uncles_hash);
                 }
 
@@ -1176,6 +3256,48 @@ pub mod ckb {
             }
         }
 
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for Header {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    (
+                        any::<u32>(),
+                        any::<H256>(),
+                        any::<u64>(),
+                        any::<u64>(),
+                        any::<H256>(),
+                        any::<H256>(),
+                        strategy::option(&config, any_with::<Bytes>(config.clone())),
+                        any::<u64>(),
+                    ),
+                    (
+                        strategy::option(&config, any_with::<Bytes>(config.clone())),
+                        any::<H256>(),
+                        any::<H256>(),
+                        any::<u32>(),
+                    ),
+                )
+                    .prop_map(|((version, parent_hash, timestamp, number, txs_commit, txs_proposal, difficulty, nonce), (proof, cellbase_id, uncles_hash, uncles_count))| Header {
+                        version,
+                        parent_hash,
+                        timestamp,
+                        number,
+                        txs_commit,
+                        txs_proposal,
+                        difficulty,
+                        nonce,
+                        proof,
+                        cellbase_id,
+                        uncles_hash,
+                        uncles_count,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct Headers {
             pub headers: Vec<Header>,
@@ -1216,6 +3338,85 @@ pub mod ckb {
             }
         }
 
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c Headers {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.headers.is_empty() {
+                        vtable.add_field(Headers::VT_HEADERS, Headers::SIZE_HEADERS, Headers::ALIGNMENT_HEADERS);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Headers::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.headers.is_empty() {
+                    builder.align(Headers::ALIGNMENT_HEADERS);
+                    let offset_position = builder.tell();
+                    builder.pad(Headers::SIZE_HEADERS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.headers.iter())),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `Headers` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct HeadersRef<'a> {
+            pub headers: &'a [HeaderRef<'a>],
+        }
+
+        impl<'c> Component<'c> for HeadersRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.headers.is_empty() {
+                        vtable.add_field(Headers::VT_HEADERS, Headers::SIZE_HEADERS, Headers::ALIGNMENT_HEADERS);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Headers::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.headers.is_empty() {
+                    builder.align(Headers::ALIGNMENT_HEADERS);
+                    let offset_position = builder.tell();
+                    builder.pad(Headers::SIZE_HEADERS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.headers.iter().copied())),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for Headers {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    vec(any_with::<Header>(config.clone()), config.vector_len.clone()),
+                )
+                    .prop_map(|(headers,)| Headers {
+                        headers,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct IndexTransaction {
             pub index: u32,
@@ -1232,34 +3433,227 @@ pub mod ckb {
             const ALIGNMENT: usize = 4;
         }
 
-        impl<'c> Component<'c> for IndexTransaction {
+        impl<'c> Component<'c> for IndexTransaction {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.index != 0u32 {
+                        vtable.add_field(Self::VT_INDEX, Self::SIZE_INDEX, Self::ALIGNMENT_INDEX);
+                    }
+                    if self.transaction.is_some() {
+                        vtable.add_field(Self::VT_TRANSACTION, Self::SIZE_TRANSACTION, Self::ALIGNMENT_TRANSACTION);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.index != 0u32 {
+                    builder.align(Self::ALIGNMENT_INDEX);
+                    builder.push_scalar(self.index);
+                }
+                if let Some(f) = self.transaction {
+                    builder.align(Self::ALIGNMENT_TRANSACTION);
+                    let offset_position = builder.tell();
+                    builder.pad(Self::SIZE_TRANSACTION);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c IndexTransaction {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.index != 0u32 {
+                        vtable.add_field(IndexTransaction::VT_INDEX, IndexTransaction::SIZE_INDEX, IndexTransaction::ALIGNMENT_INDEX);
+                    }
+                    if self.transaction.is_some() {
+                        vtable.add_field(IndexTransaction::VT_TRANSACTION, IndexTransaction::SIZE_TRANSACTION, IndexTransaction::ALIGNMENT_TRANSACTION);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, IndexTransaction::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.index != 0u32 {
+                    builder.align(IndexTransaction::ALIGNMENT_INDEX);
+                    builder.push_scalar(self.index);
+                }
+                if let Some(f) = &self.transaction {
+                    builder.align(IndexTransaction::ALIGNMENT_TRANSACTION);
+                    let offset_position = builder.tell();
+                    builder.pad(IndexTransaction::SIZE_TRANSACTION);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `IndexTransaction` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct IndexTransactionRef<'a> {
+            pub index: u32,
+            pub transaction: Option<TransactionRef<'a>>,
+        }
+
+        impl<'c> Component<'c> for IndexTransactionRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.index != 0u32 {
+                        vtable.add_field(IndexTransaction::VT_INDEX, IndexTransaction::SIZE_INDEX, IndexTransaction::ALIGNMENT_INDEX);
+                    }
+                    if self.transaction.is_some() {
+                        vtable.add_field(IndexTransaction::VT_TRANSACTION, IndexTransaction::SIZE_TRANSACTION, IndexTransaction::ALIGNMENT_TRANSACTION);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, IndexTransaction::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.index != 0u32 {
+                    builder.align(IndexTransaction::ALIGNMENT_INDEX);
+                    builder.push_scalar(self.index);
+                }
+                if let Some(f) = self.transaction {
+                    builder.align(IndexTransaction::ALIGNMENT_TRANSACTION);
+                    let offset_position = builder.tell();
+                    builder.pad(IndexTransaction::SIZE_TRANSACTION);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for IndexTransaction {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    any::<u32>(),
+                    strategy::option(&config, any_with::<Transaction>(config.clone())),
+                )
+                    .prop_map(|(index, transaction)| IndexTransaction {
+                        index,
+                        transaction,
+                    })
+                    .boxed()
+            }
+        }
+
+        #[derive(Default, Clone, Debug, PartialEq)]
+        pub struct MerkleProof {
+            pub indices: Vec<u32>,
+            pub lemmas: Vec<H256>,
+        }
+
+        impl MerkleProof {
+            const VT_INDICES: usize = 4;
+            const SIZE_INDICES: usize = 4;
+            const ALIGNMENT_INDICES: usize = 4;
+            const VT_LEMMAS: usize = 6;
+            const SIZE_LEMMAS: usize = 4;
+            const ALIGNMENT_LEMMAS: usize = 4;
+            const ALIGNMENT: usize = 4;
+        }
+
+        impl<'c> Component<'c> for MerkleProof {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.indices.is_empty() {
+                        vtable.add_field(Self::VT_INDICES, Self::SIZE_INDICES, Self::ALIGNMENT_INDICES);
+                    }
+                    if !self.lemmas.is_empty() {
+                        vtable.add_field(Self::VT_LEMMAS, Self::SIZE_LEMMAS, Self::ALIGNMENT_LEMMAS);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.indices.is_empty() {
+                    builder.align(Self::ALIGNMENT_INDICES);
+                    let offset_position = builder.tell();
+                    builder.pad(Self::SIZE_INDICES);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.indices, 4)),
+                    ));
+                }
+                if !self.lemmas.is_empty() {
+                    builder.align(Self::ALIGNMENT_LEMMAS);
+                    let offset_position = builder.tell();
+                    builder.pad(Self::SIZE_LEMMAS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.lemmas, 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c MerkleProof {
             fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
                 let vtable_start = {
                     let mut vtable = builder.start_vtable();
-                    if self.index != 0u32 {
-                        vtable.add_field(Self::VT_INDEX, Self::SIZE_INDEX, Self::ALIGNMENT_INDEX);
+                    if !self.indices.is_empty() {
+                        vtable.add_field(MerkleProof::VT_INDICES, MerkleProof::SIZE_INDICES, MerkleProof::ALIGNMENT_INDICES);
                     }
-                    if self.transaction.is_some() {
-                        vtable.add_field(Self::VT_TRANSACTION, Self::SIZE_TRANSACTION, Self::ALIGNMENT_TRANSACTION);
+                    if !self.lemmas.is_empty() {
+                        vtable.add_field(MerkleProof::VT_LEMMAS, MerkleProof::SIZE_LEMMAS, MerkleProof::ALIGNMENT_LEMMAS);
                     }
                     vtable.finish()
                 };
 
-                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+                builder.align_after(SIZE_OF_SOFFSET, MerkleProof::ALIGNMENT);
 
                 let table_start = builder.tell();
                 builder.push_scalar((table_start - vtable_start) as SOffset);
-                if self.index != 0u32 {
-                    builder.align(Self::ALIGNMENT_INDEX);
-                    builder.push_scalar(self.index);
+                if !self.indices.is_empty() {
+                    builder.align(MerkleProof::ALIGNMENT_INDICES);
+                    let offset_position = builder.tell();
+                    builder.pad(MerkleProof::SIZE_INDICES);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.indices.iter().copied(), 4)),
+                    ));
                 }
-                if let Some(f) = self.transaction {
-                    builder.align(Self::ALIGNMENT_TRANSACTION);
+                if !self.lemmas.is_empty() {
+                    builder.align(MerkleProof::ALIGNMENT_LEMMAS);
                     let offset_position = builder.tell();
-                    builder.pad(Self::SIZE_TRANSACTION);
+                    builder.pad(MerkleProof::SIZE_LEMMAS);
                     builder.push_component(DesignatedComponent::new(
                         offset_position,
-                        Box::new(f),
+                        Box::new(ScalarVectorComponent::new(self.lemmas.iter().copied(), 1)),
                     ));
                 }
 
@@ -1267,55 +3661,46 @@ pub mod ckb {
             }
         }
 
-        #[derive(Default, Clone, Debug, PartialEq)]
-        pub struct MerkleProof {
-            pub indices: Vec<u32>,
-            pub lemmas: Vec<H256>,
-        }
-
-        impl MerkleProof {
-            const VT_INDICES: usize = 4;
-            const SIZE_INDICES: usize = 4;
-            const ALIGNMENT_INDICES: usize = 4;
-            const VT_LEMMAS: usize = 6;
-            const SIZE_LEMMAS: usize = 4;
-            const ALIGNMENT_LEMMAS: usize = 4;
-            const ALIGNMENT: usize = 4;
+        /// The variant of `MerkleProof` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct MerkleProofRef<'a> {
+            pub indices: &'a [u32],
+            pub lemmas: &'a [H256],
         }
 
-        impl<'c> Component<'c> for MerkleProof {
+        impl<'c> Component<'c> for MerkleProofRef<'c> {
             fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
                 let vtable_start = {
                     let mut vtable = builder.start_vtable();
                     if !self.indices.is_empty() {
-                        vtable.add_field(Self::VT_INDICES, Self::SIZE_INDICES, Self::ALIGNMENT_INDICES);
+                        vtable.add_field(MerkleProof::VT_INDICES, MerkleProof::SIZE_INDICES, MerkleProof::ALIGNMENT_INDICES);
                     }
                     if !self.lemmas.is_empty() {
-                        vtable.add_field(Self::VT_LEMMAS, Self::SIZE_LEMMAS, Self::ALIGNMENT_LEMMAS);
+                        vtable.add_field(MerkleProof::VT_LEMMAS, MerkleProof::SIZE_LEMMAS, MerkleProof::ALIGNMENT_LEMMAS);
                     }
                     vtable.finish()
                 };
 
-                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+                builder.align_after(SIZE_OF_SOFFSET, MerkleProof::ALIGNMENT);
 
                 let table_start = builder.tell();
                 builder.push_scalar((table_start - vtable_start) as SOffset);
                 if !self.indices.is_empty() {
-                    builder.align(Self::ALIGNMENT_INDICES);
+                    builder.align(MerkleProof::ALIGNMENT_INDICES);
                     let offset_position = builder.tell();
-                    builder.pad(Self::SIZE_INDICES);
+                    builder.pad(MerkleProof::SIZE_INDICES);
                     builder.push_component(DesignatedComponent::new(
                         offset_position,
-                        Box::new(ScalarVectorComponent::new(self.indices, 4)),
+                        Box::new(ScalarVectorComponent::new(self.indices.iter().copied(), 4)),
                     ));
                 }
                 if !self.lemmas.is_empty() {
-                    builder.align(Self::ALIGNMENT_LEMMAS);
+                    builder.align(MerkleProof::ALIGNMENT_LEMMAS);
                     let offset_position = builder.tell();
-                    builder.pad(Self::SIZE_LEMMAS);
+                    builder.pad(MerkleProof::SIZE_LEMMAS);
                     builder.push_component(DesignatedComponent::new(
                         offset_position,
-                        Box::new(ScalarVectorComponent::new(self.lemmas, 1)),
+                        Box::new(ScalarVectorComponent::new(self.lemmas.iter().copied(), 1)),
                     ));
                 }
 
@@ -1323,6 +3708,24 @@ pub mod ckb {
             }
         }
 
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for MerkleProof {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    vec(any::<u32>(), config.scalar_vector_len.clone()),
+                    vec(any::<H256>(), config.scalar_vector_len.clone()),
+                )
+                    .prop_map(|(indices, lemmas)| MerkleProof {
+                        indices,
+                        lemmas,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct OutPoint {
             pub hash: H256,
@@ -1369,8 +3772,94 @@ pub mod ckb {
             }
         }
 
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c OutPoint {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.index != 0u32 {
+                        vtable.add_field(OutPoint::VT_INDEX, OutPoint::SIZE_INDEX, OutPoint::ALIGNMENT_INDEX);
+                    }
+                    if self.hash.is_present() {
+                        vtable.add_field(OutPoint::VT_HASH, OutPoint::SIZE_HASH, OutPoint::ALIGNMENT_HASH);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, OutPoint::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.index != 0u32 {
+                    builder.align(OutPoint::ALIGNMENT_INDEX);
+                    builder.push_scalar(self.index);
+                }
+                if self.hash.is_present() {
+                    builder.align(OutPoint::ALIGNMENT_HASH);
+                    builder.push_scalar(self.hash);
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `OutPoint` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct OutPointRef {
+            pub hash: H256,
+            pub index: u32,
+        }
+
+        impl<'c> Component<'c> for OutPointRef {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.index != 0u32 {
+                        vtable.add_field(OutPoint::VT_INDEX, OutPoint::SIZE_INDEX, OutPoint::ALIGNMENT_INDEX);
+                    }
+                    if self.hash.is_present() {
+                        vtable.add_field(OutPoint::VT_HASH, OutPoint::SIZE_HASH, OutPoint::ALIGNMENT_HASH);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, OutPoint::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.index != 0u32 {
+                    builder.align(OutPoint::ALIGNMENT_INDEX);
+                    builder.push_scalar(self.index);
+                }
+                if self.hash.is_present() {
+                    builder.align(OutPoint::ALIGNMENT_HASH);
+                    builder.push_scalar(self.hash);
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for OutPoint {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                (
+                    any::<H256>(),
+                    any::<u32>(),
+                )
+                    .prop_map(|(hash, index)| OutPoint {
+                        hash,
+                        index,
+                    })
+                    .boxed()
+            }
+        }
+
         #[repr(C, align(1))]
-        #[derive(Default, Clone, Debug, PartialEq)]
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
         pub struct ProposalShortId {
             pub u0: u8,
             pub u1: u8,
@@ -1432,6 +3921,44 @@ pub mod ckb {
             }
         }
 
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for ProposalShortId {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                (
+                    (
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                        any::<u8>(),
+                    ),
+                    (
+                        any::<u8>(),
+                        any::<u8>(),
+                    ),
+                )
+                    .prop_map(|((u0, u1, u2, u3, u4, u5, u6, u7), (u8_, u9))| ProposalShortId {
+                        u0,
+                        u1,
+                        u2,
+                        u3,
+                        u4,
+                        u5,
+                        u6,
+                        u7,
+                        u8_,
+                        u9,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct RelayMessage {
             pub payload: Option<RelayPayload>,
@@ -1469,80 +3996,254 @@ pub mod ckb {
                     builder.align(Self::ALIGNMENT_PAYLOAD);
                     let offset_position = builder.tell();
                     builder.pad(Self::SIZE_PAYLOAD);
-                    let component: Box<dyn Component<'c> + 'c> = match f {
-                        RelayPayload::CompactBlock(v) => Box::new(v),
-                        RelayPayload::ValidTransaction(v) => Box::new(v),
-                        RelayPayload::GetBlockTransactions(v) => Box::new(v),
-                        RelayPayload::BlockTransactions(v) => Box::new(v),
-                        RelayPayload::GetBlockProposal(v) => Box::new(v),
-                        RelayPayload::BlockProposal(v) => Box::new(v),
-                    };
-                    builder.push_component(DesignatedComponent::new(offset_position, component));
+                    builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
+                }
+                if let Some(f) = payload_type {
+                    builder.align(Self::ALIGNMENT_PAYLOAD_TYPE);
+                    builder.push_scalar(f);
+                }
+
+                table_start
+            }
+        }
+
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c RelayMessage {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.payload.is_some() {
+                        vtable.add_field(RelayMessage::VT_PAYLOAD, RelayMessage::SIZE_PAYLOAD, RelayMessage::ALIGNMENT_PAYLOAD);
+                    }
+                    if self.payload.is_some() {
+                        vtable.add_field(RelayMessage::VT_PAYLOAD_TYPE, RelayMessage::SIZE_PAYLOAD_TYPE, RelayMessage::ALIGNMENT_PAYLOAD_TYPE);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, RelayMessage::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                let payload_type = self.payload.as_ref().map(|v| v.union_type());
+                if let Some(f) = &self.payload {
+                    builder.align(RelayMessage::ALIGNMENT_PAYLOAD);
+                    let offset_position = builder.tell();
+                    builder.pad(RelayMessage::SIZE_PAYLOAD);
+                    builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
+                }
+                if let Some(f) = payload_type {
+                    builder.align(RelayMessage::ALIGNMENT_PAYLOAD_TYPE);
+                    builder.push_scalar(f);
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `RelayMessage` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct RelayMessageRef<'a> {
+            pub payload: Option<RelayPayloadRef<'a>>,
+        }
+
+        impl<'c> Component<'c> for RelayMessageRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.payload.is_some() {
+                        vtable.add_field(RelayMessage::VT_PAYLOAD, RelayMessage::SIZE_PAYLOAD, RelayMessage::ALIGNMENT_PAYLOAD);
+                    }
+                    if self.payload.is_some() {
+                        vtable.add_field(RelayMessage::VT_PAYLOAD_TYPE, RelayMessage::SIZE_PAYLOAD_TYPE, RelayMessage::ALIGNMENT_PAYLOAD_TYPE);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, RelayMessage::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                let payload_type = self.payload.as_ref().map(|v| v.union_type());
+                if let Some(f) = self.payload {
+                    builder.align(RelayMessage::ALIGNMENT_PAYLOAD);
+                    let offset_position = builder.tell();
+                    builder.pad(RelayMessage::SIZE_PAYLOAD);
+                    builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
+                }
+                if let Some(f) = payload_type {
+                    builder.align(RelayMessage::ALIGNMENT_PAYLOAD_TYPE);
+                    builder.push_scalar(f);
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for RelayMessage {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    strategy::option(&config, any_with::<RelayPayload>(config.clone())),
+                )
+                    .prop_map(|(payload,)| RelayMessage {
+                        payload,
+                    })
+                    .boxed()
+            }
+        }
+
+        #[derive(Default, Clone, Debug, PartialEq)]
+        pub struct Script {
+            pub version: u8,
+            pub args: Vec<Bytes>,
+            pub binary_hash: H256,
+        }
+
+        impl Script {
+            const VT_VERSION: usize = 4;
+            const SIZE_VERSION: usize = 1;
+            const ALIGNMENT_VERSION: usize = 1;
+            const VT_ARGS: usize = 6;
+            const SIZE_ARGS: usize = 4;
+            const ALIGNMENT_ARGS: usize = 4;
+            const VT_BINARY_HASH: usize = 8;
+            const SIZE_BINARY_HASH: usize = 32;
+            const ALIGNMENT_BINARY_HASH: usize = 1;
+            const ALIGNMENT: usize = 4;
+        }
+
+        impl<'c> Component<'c> for Script {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.args.is_empty() {
+                        vtable.add_field(Self::VT_ARGS, Self::SIZE_ARGS, Self::ALIGNMENT_ARGS);
+                    }
+                    if self.binary_hash.is_present() {
+                        vtable.add_field(Self::VT_BINARY_HASH, Self::SIZE_BINARY_HASH, Self::ALIGNMENT_BINARY_HASH);
+                    }
+                    if self.version != 0u8 {
+                        vtable.add_field(Self::VT_VERSION, Self::SIZE_VERSION, Self::ALIGNMENT_VERSION);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.args.is_empty() {
+                    builder.align(Self::ALIGNMENT_ARGS);
+                    let offset_position = builder.tell();
+                    builder.pad(Self::SIZE_ARGS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.args)),
+                    ));
+                }
+                if self.binary_hash.is_present() {
+                    builder.align(Self::ALIGNMENT_BINARY_HASH);
+                    builder.push_scalar(self.binary_hash);
+                }
+                if self.version != 0u8 {
+                    builder.align(Self::ALIGNMENT_VERSION);
+                    builder.push_scalar(self.version);
+                }
+
+                table_start
+            }
+        }
+
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c Script {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.args.is_empty() {
+                        vtable.add_field(Script::VT_ARGS, Script::SIZE_ARGS, Script::ALIGNMENT_ARGS);
+                    }
+                    if self.binary_hash.is_present() {
+                        vtable.add_field(Script::VT_BINARY_HASH, Script::SIZE_BINARY_HASH, Script::ALIGNMENT_BINARY_HASH);
+                    }
+                    if self.version != 0u8 {
+                        vtable.add_field(Script::VT_VERSION, Script::SIZE_VERSION, Script::ALIGNMENT_VERSION);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Script::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.args.is_empty() {
+                    builder.align(Script::ALIGNMENT_ARGS);
+                    let offset_position = builder.tell();
+                    builder.pad(Script::SIZE_ARGS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.args.iter())),
+                    ));
                 }
-                if let Some(f) = payload_type {
-                    builder.align(Self::ALIGNMENT_PAYLOAD_TYPE);
-                    builder.push_scalar(f);
+                if self.binary_hash.is_present() {
+                    builder.align(Script::ALIGNMENT_BINARY_HASH);
+                    builder.push_scalar(self.binary_hash);
+                }
+                if self.version != 0u8 {
+                    builder.align(Script::ALIGNMENT_VERSION);
+                    builder.push_scalar(self.version);
                 }
 
                 table_start
             }
         }
 
-        #[derive(Default, Clone, Debug, PartialEq)]
-        pub struct Script {
+        /// The variant of `Script` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct ScriptRef<'a> {
             pub version: u8,
-            pub args: Vec<Bytes>,
+            pub args: &'a [BytesRef<'a>],
             pub binary_hash: H256,
         }
 
-        impl Script {
-            const VT_VERSION: usize = 4;
-            const SIZE_VERSION: usize = 1;
-            const ALIGNMENT_VERSION: usize = 1;
-            const VT_ARGS: usize = 6;
-            const SIZE_ARGS: usize = 4;
-            const ALIGNMENT_ARGS: usize = 4;
-            const VT_BINARY_HASH: usize = 8;
-            const SIZE_BINARY_HASH: usize = 32;
-            const ALIGNMENT_BINARY_HASH: usize = 1;
-            const ALIGNMENT: usize = 4;
-        }
-
-        impl<'c> Component<'c> for Script {
+        impl<'c> Component<'c> for ScriptRef<'c> {
             fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
                 let vtable_start = {
                     let mut vtable = builder.start_vtable();
                     if !self.args.is_empty() {
-                        vtable.add_field(Self::VT_ARGS, Self::SIZE_ARGS, Self::ALIGNMENT_ARGS);
+                        vtable.add_field(Script::VT_ARGS, Script::SIZE_ARGS, Script::ALIGNMENT_ARGS);
                     }
                     if self.binary_hash.is_present() {
-                        vtable.add_field(Self::VT_BINARY_HASH, Self::SIZE_BINARY_HASH, Self::ALIGNMENT_BINARY_HASH);
+                        vtable.add_field(Script::VT_BINARY_HASH, Script::SIZE_BINARY_HASH, Script::ALIGNMENT_BINARY_HASH);
                     }
                     if self.version != 0u8 {
-                        vtable.add_field(Self::VT_VERSION, Self::SIZE_VERSION, Self::ALIGNMENT_VERSION);
+                        vtable.add_field(Script::VT_VERSION, Script::SIZE_VERSION, Script::ALIGNMENT_VERSION);
                     }
                     vtable.finish()
                 };
 
-                builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+                builder.align_after(SIZE_OF_SOFFSET, Script::ALIGNMENT);
 
                 let table_start = builder.tell();
                 builder.push_scalar((table_start - vtable_start) as SOffset);
                 if !self.args.is_empty() {
-                    builder.align(Self::ALIGNMENT_ARGS);
+                    builder.align(Script::ALIGNMENT_ARGS);
                     let offset_position = builder.tell();
-                    builder.pad(Self::SIZE_ARGS);
+                    builder.pad(Script::SIZE_ARGS);
                     builder.push_component(DesignatedComponent::new(
                         offset_position,
-                        Box::new(ReferenceVectorComponent::new(self.args)),
+                        Box::new(ReferenceVectorComponent::new(self.args.iter().copied())),
                     ));
                 }
                 if self.binary_hash.is_present() {
-                    builder.align(Self::ALIGNMENT_BINARY_HASH);
+                    builder.align(Script::ALIGNMENT_BINARY_HASH);
                     builder.push_scalar(self.binary_hash);
                 }
                 if self.version != 0u8 {
-                    builder.align(Self::ALIGNMENT_VERSION);
+                    builder.align(Script::ALIGNMENT_VERSION);
                     builder.push_scalar(self.version);
                 }
 
@@ -1550,6 +4251,26 @@ pub mod ckb {
             }
         }
 
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for Script {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    any::<u8>(),
+                    vec(any_with::<Bytes>(config.clone()), config.vector_len.clone()),
+                    any::<H256>(),
+                )
+                    .prop_map(|(version, args, binary_hash)| Script {
+                        version,
+                        args,
+                        binary_hash,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct SetFilter {
             pub filter: Vec<u8>,
@@ -1612,6 +4333,119 @@ pub mod ckb {
             }
         }
 
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c SetFilter {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.filter.is_empty() {
+                        vtable.add_field(SetFilter::VT_FILTER, SetFilter::SIZE_FILTER, SetFilter::ALIGNMENT_FILTER);
+                    }
+                    if self.hash_seed != 0u32 {
+                        vtable.add_field(SetFilter::VT_HASH_SEED, SetFilter::SIZE_HASH_SEED, SetFilter::ALIGNMENT_HASH_SEED);
+                    }
+                    if self.num_hashes != 0u8 {
+                        vtable.add_field(SetFilter::VT_NUM_HASHES, SetFilter::SIZE_NUM_HASHES, SetFilter::ALIGNMENT_NUM_HASHES);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, SetFilter::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.filter.is_empty() {
+                    builder.align(SetFilter::ALIGNMENT_FILTER);
+                    let offset_position = builder.tell();
+                    builder.pad(SetFilter::SIZE_FILTER);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.filter.iter().copied(), 1)),
+                    ));
+                }
+                if self.hash_seed != 0u32 {
+                    builder.align(SetFilter::ALIGNMENT_HASH_SEED);
+                    builder.push_scalar(self.hash_seed);
+                }
+                if self.num_hashes != 0u8 {
+                    builder.align(SetFilter::ALIGNMENT_NUM_HASHES);
+                    builder.push_scalar(self.num_hashes);
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `SetFilter` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct SetFilterRef<'a> {
+            pub filter: &'a [u8],
+            pub num_hashes: u8,
+            pub hash_seed: u32,
+        }
+
+        impl<'c> Component<'c> for SetFilterRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if !self.filter.is_empty() {
+                        vtable.add_field(SetFilter::VT_FILTER, SetFilter::SIZE_FILTER, SetFilter::ALIGNMENT_FILTER);
+                    }
+                    if self.hash_seed != 0u32 {
+                        vtable.add_field(SetFilter::VT_HASH_SEED, SetFilter::SIZE_HASH_SEED, SetFilter::ALIGNMENT_HASH_SEED);
+                    }
+                    if self.num_hashes != 0u8 {
+                        vtable.add_field(SetFilter::VT_NUM_HASHES, SetFilter::SIZE_NUM_HASHES, SetFilter::ALIGNMENT_NUM_HASHES);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, SetFilter::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if !self.filter.is_empty() {
+                    builder.align(SetFilter::ALIGNMENT_FILTER);
+                    let offset_position = builder.tell();
+                    builder.pad(SetFilter::SIZE_FILTER);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.filter.iter().copied(), 1)),
+                    ));
+                }
+                if self.hash_seed != 0u32 {
+                    builder.align(SetFilter::ALIGNMENT_HASH_SEED);
+                    builder.push_scalar(self.hash_seed);
+                }
+                if self.num_hashes != 0u8 {
+                    builder.align(SetFilter::ALIGNMENT_NUM_HASHES);
+                    builder.push_scalar(self.num_hashes);
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for SetFilter {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    vec(any::<u8>(), config.scalar_vector_len.clone()),
+                    any::<u8>(),
+                    any::<u32>(),
+                )
+                    .prop_map(|(filter, num_hashes, hash_seed)| SetFilter {
+                        filter,
+                        num_hashes,
+                        hash_seed,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct SyncMessage {
             pub payload: Option<SyncPayload>,
@@ -1649,17 +4483,7 @@ pub mod ckb {
                     builder.align(Self::ALIGNMENT_PAYLOAD);
                     let offset_position = builder.tell();
                     builder.pad(Self::SIZE_PAYLOAD);
-                    let component: Box<dyn Component<'c> + 'c> = match f {
-                        SyncPayload::GetHeaders(v) => Box::new(v),
-                        SyncPayload::Headers(v) => Box::new(v),
-                        SyncPayload::GetBlocks(v) => Box::new(v),
-                        SyncPayload::Block(v) => Box::new(v),
-                        SyncPayload::SetFilter(v) => Box::new(v),
-                        SyncPayload::AddFilter(v) => Box::new(v),
-                        SyncPayload::ClearFilter(v) => Box::new(v),
-                        SyncPayload::FilteredBlock(v) => Box::new(v),
-                    };
-                    builder.push_component(DesignatedComponent::new(offset_position, component));
+                    builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
                 }
                 if let Some(f) = payload_type {
                     builder.align(Self::ALIGNMENT_PAYLOAD_TYPE);
@@ -1670,6 +4494,95 @@ pub mod ckb {
             }
         }
 
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c SyncMessage {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.payload.is_some() {
+                        vtable.add_field(SyncMessage::VT_PAYLOAD, SyncMessage::SIZE_PAYLOAD, SyncMessage::ALIGNMENT_PAYLOAD);
+                    }
+                    if self.payload.is_some() {
+                        vtable.add_field(SyncMessage::VT_PAYLOAD_TYPE, SyncMessage::SIZE_PAYLOAD_TYPE, SyncMessage::ALIGNMENT_PAYLOAD_TYPE);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, SyncMessage::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                let payload_type = self.payload.as_ref().map(|v| v.union_type());
+                if let Some(f) = &self.payload {
+                    builder.align(SyncMessage::ALIGNMENT_PAYLOAD);
+                    let offset_position = builder.tell();
+                    builder.pad(SyncMessage::SIZE_PAYLOAD);
+                    builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
+                }
+                if let Some(f) = payload_type {
+                    builder.align(SyncMessage::ALIGNMENT_PAYLOAD_TYPE);
+                    builder.push_scalar(f);
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `SyncMessage` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct SyncMessageRef<'a> {
+            pub payload: Option<SyncPayloadRef<'a>>,
+        }
+
+        impl<'c> Component<'c> for SyncMessageRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.payload.is_some() {
+                        vtable.add_field(SyncMessage::VT_PAYLOAD, SyncMessage::SIZE_PAYLOAD, SyncMessage::ALIGNMENT_PAYLOAD);
+                    }
+                    if self.payload.is_some() {
+                        vtable.add_field(SyncMessage::VT_PAYLOAD_TYPE, SyncMessage::SIZE_PAYLOAD_TYPE, SyncMessage::ALIGNMENT_PAYLOAD_TYPE);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, SyncMessage::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                let payload_type = self.payload.as_ref().map(|v| v.union_type());
+                if let Some(f) = self.payload {
+                    builder.align(SyncMessage::ALIGNMENT_PAYLOAD);
+                    let offset_position = builder.tell();
+                    builder.pad(SyncMessage::SIZE_PAYLOAD);
+                    builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
+                }
+                if let Some(f) = payload_type {
+                    builder.align(SyncMessage::ALIGNMENT_PAYLOAD_TYPE);
+                    builder.push_scalar(f);
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for SyncMessage {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    strategy::option(&config, any_with::<SyncPayload>(config.clone())),
+                )
+                    .prop_map(|(payload,)| SyncMessage {
+                        payload,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct Time {
             pub timestamp: u64,
@@ -1705,6 +4618,75 @@ pub mod ckb {
             }
         }
 
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c Time {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.timestamp != 0u64 {
+                        vtable.add_field(Time::VT_TIMESTAMP, Time::SIZE_TIMESTAMP, Time::ALIGNMENT_TIMESTAMP);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Time::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.timestamp != 0u64 {
+                    builder.align(Time::ALIGNMENT_TIMESTAMP);
+                    builder.push_scalar(self.timestamp);
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `Time` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct TimeRef {
+            pub timestamp: u64,
+        }
+
+        impl<'c> Component<'c> for TimeRef {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.timestamp != 0u64 {
+                        vtable.add_field(Time::VT_TIMESTAMP, Time::SIZE_TIMESTAMP, Time::ALIGNMENT_TIMESTAMP);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Time::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.timestamp != 0u64 {
+                    builder.align(Time::ALIGNMENT_TIMESTAMP);
+                    builder.push_scalar(self.timestamp);
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for Time {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                (
+                    any::<u64>(),
+                )
+                    .prop_map(|(timestamp,)| Time {
+                        timestamp,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct TimeMessage {
             pub payload: Option<Time>,
@@ -1734,7 +4716,70 @@ pub mod ckb {
                 if let Some(f) = self.payload {
                     builder.align(Self::ALIGNMENT_PAYLOAD);
                     let offset_position = builder.tell();
-                    builder.pad(Self::SIZE_PAYLOAD);
+                    builder.pad(Self::SIZE_PAYLOAD);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c TimeMessage {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.payload.is_some() {
+                        vtable.add_field(TimeMessage::VT_PAYLOAD, TimeMessage::SIZE_PAYLOAD, TimeMessage::ALIGNMENT_PAYLOAD);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, TimeMessage::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if let Some(f) = &self.payload {
+                    builder.align(TimeMessage::ALIGNMENT_PAYLOAD);
+                    let offset_position = builder.tell();
+                    builder.pad(TimeMessage::SIZE_PAYLOAD);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `TimeMessage` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct TimeMessageRef {
+            pub payload: Option<TimeRef>,
+        }
+
+        impl<'c> Component<'c> for TimeMessageRef {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.payload.is_some() {
+                        vtable.add_field(TimeMessage::VT_PAYLOAD, TimeMessage::SIZE_PAYLOAD, TimeMessage::ALIGNMENT_PAYLOAD);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, TimeMessage::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if let Some(f) = self.payload {
+                    builder.align(TimeMessage::ALIGNMENT_PAYLOAD);
+                    let offset_position = builder.tell();
+                    builder.pad(TimeMessage::SIZE_PAYLOAD);
                     builder.push_component(DesignatedComponent::new(
                         offset_position,
                         Box::new(f),
@@ -1745,6 +4790,22 @@ pub mod ckb {
             }
         }
 
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for TimeMessage {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    strategy::option(&config, any_with::<Time>(config.clone())),
+                )
+                    .prop_map(|(payload,)| TimeMessage {
+                        payload,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct Transaction {
             pub version: u32,
@@ -1844,6 +4905,183 @@ pub mod ckb {
             }
         }
 
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c Transaction {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.version != 0u32 {
+                        vtable.add_field(Transaction::VT_VERSION, Transaction::SIZE_VERSION, Transaction::ALIGNMENT_VERSION);
+                    }
+                    if !self.deps.is_empty() {
+                        vtable.add_field(Transaction::VT_DEPS, Transaction::SIZE_DEPS, Transaction::ALIGNMENT_DEPS);
+                    }
+                    if !self.inputs.is_empty() {
+                        vtable.add_field(Transaction::VT_INPUTS, Transaction::SIZE_INPUTS, Transaction::ALIGNMENT_INPUTS);
+                    }
+                    if !self.outputs.is_empty() {
+                        vtable.add_field(Transaction::VT_OUTPUTS, Transaction::SIZE_OUTPUTS, Transaction::ALIGNMENT_OUTPUTS);
+                    }
+                    if !self.embeds.is_empty() {
+                        vtable.add_field(Transaction::VT_EMBEDS, Transaction::SIZE_EMBEDS, Transaction::ALIGNMENT_EMBEDS);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Transaction::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.version != 0u32 {
+                    builder.align(Transaction::ALIGNMENT_VERSION);
+                    builder.push_scalar(self.version);
+                }
+                if !self.deps.is_empty() {
+                    builder.align(Transaction::ALIGNMENT_DEPS);
+                    let offset_position = builder.tell();
+                    builder.pad(Transaction::SIZE_DEPS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.deps.iter())),
+                    ));
+                }
+                if !self.inputs.is_empty() {
+                    builder.align(Transaction::ALIGNMENT_INPUTS);
+                    let offset_position = builder.tell();
+                    builder.pad(Transaction::SIZE_INPUTS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.inputs.iter())),
+                    ));
+                }
+                if !self.outputs.is_empty() {
+                    builder.align(Transaction::ALIGNMENT_OUTPUTS);
+                    let offset_position = builder.tell();
+                    builder.pad(Transaction::SIZE_OUTPUTS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.outputs.iter())),
+                    ));
+                }
+                if !self.embeds.is_empty() {
+                    builder.align(Transaction::ALIGNMENT_EMBEDS);
+                    let offset_position = builder.tell();
+                    builder.pad(Transaction::SIZE_EMBEDS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.embeds.iter())),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `Transaction` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct TransactionRef<'a> {
+            pub version: u32,
+            pub deps: &'a [OutPointRef],
+            pub inputs: &'a [CellInputRef<'a>],
+            pub outputs: &'a [CellOutputRef<'a>],
+            pub embeds: &'a [BytesRef<'a>],
+        }
+
+        impl<'c> Component<'c> for TransactionRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.version != 0u32 {
+                        vtable.add_field(Transaction::VT_VERSION, Transaction::SIZE_VERSION, Transaction::ALIGNMENT_VERSION);
+                    }
+                    if !self.deps.is_empty() {
+                        vtable.add_field(Transaction::VT_DEPS, Transaction::SIZE_DEPS, Transaction::ALIGNMENT_DEPS);
+                    }
+                    if !self.inputs.is_empty() {
+                        vtable.add_field(Transaction::VT_INPUTS, Transaction::SIZE_INPUTS, Transaction::ALIGNMENT_INPUTS);
+                    }
+                    if !self.outputs.is_empty() {
+                        vtable.add_field(Transaction::VT_OUTPUTS, Transaction::SIZE_OUTPUTS, Transaction::ALIGNMENT_OUTPUTS);
+                    }
+                    if !self.embeds.is_empty() {
+                        vtable.add_field(Transaction::VT_EMBEDS, Transaction::SIZE_EMBEDS, Transaction::ALIGNMENT_EMBEDS);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, Transaction::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.version != 0u32 {
+                    builder.align(Transaction::ALIGNMENT_VERSION);
+                    builder.push_scalar(self.version);
+                }
+                if !self.deps.is_empty() {
+                    builder.align(Transaction::ALIGNMENT_DEPS);
+                    let offset_position = builder.tell();
+                    builder.pad(Transaction::SIZE_DEPS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.deps.iter().copied())),
+                    ));
+                }
+                if !self.inputs.is_empty() {
+                    builder.align(Transaction::ALIGNMENT_INPUTS);
+                    let offset_position = builder.tell();
+                    builder.pad(Transaction::SIZE_INPUTS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.inputs.iter().copied())),
+                    ));
+                }
+                if !self.outputs.is_empty() {
+                    builder.align(Transaction::ALIGNMENT_OUTPUTS);
+                    let offset_position = builder.tell();
+                    builder.pad(Transaction::SIZE_OUTPUTS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.outputs.iter().copied())),
+                    ));
+                }
+                if !self.embeds.is_empty() {
+                    builder.align(Transaction::ALIGNMENT_EMBEDS);
+                    let offset_position = builder.tell();
+                    builder.pad(Transaction::SIZE_EMBEDS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ReferenceVectorComponent::new(self.embeds.iter().copied())),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for Transaction {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    any::<u32>(),
+                    vec(any_with::<OutPoint>(config.clone()), config.vector_len.clone()),
+                    vec(any_with::<CellInput>(config.clone()), config.vector_len.clone()),
+                    vec(any_with::<CellOutput>(config.clone()), config.vector_len.clone()),
+                    vec(any_with::<Bytes>(config.clone()), config.vector_len.clone()),
+                )
+                    .prop_map(|(version, deps, inputs, outputs, embeds)| Transaction {
+                        version,
+                        deps,
+                        inputs,
+                        outputs,
+                        embeds,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct UncleBlock {
             pub header: Option<Header>,
@@ -1916,6 +5154,139 @@ pub mod ckb {
             }
         }
 
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c UncleBlock {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.header.is_some() {
+                        vtable.add_field(UncleBlock::VT_HEADER, UncleBlock::SIZE_HEADER, UncleBlock::ALIGNMENT_HEADER);
+                    }
+                    if self.cellbase.is_some() {
+                        vtable.add_field(UncleBlock::VT_CELLBASE, UncleBlock::SIZE_CELLBASE, UncleBlock::ALIGNMENT_CELLBASE);
+                    }
+                    if !self.proposal_transactions.is_empty() {
+                        vtable.add_field(UncleBlock::VT_PROPOSAL_TRANSACTIONS, UncleBlock::SIZE_PROPOSAL_TRANSACTIONS, UncleBlock::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, UncleBlock::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if let Some(f) = &self.header {
+                    builder.align(UncleBlock::ALIGNMENT_HEADER);
+                    let offset_position = builder.tell();
+                    builder.pad(UncleBlock::SIZE_HEADER);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if let Some(f) = &self.cellbase {
+                    builder.align(UncleBlock::ALIGNMENT_CELLBASE);
+                    let offset_position = builder.tell();
+                    builder.pad(UncleBlock::SIZE_CELLBASE);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if !self.proposal_transactions.is_empty() {
+                    builder.align(UncleBlock::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(UncleBlock::SIZE_PROPOSAL_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.proposal_transactions.iter().copied(), 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `UncleBlock` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct UncleBlockRef<'a> {
+            pub header: Option<HeaderRef<'a>>,
+            pub cellbase: Option<TransactionRef<'a>>,
+            pub proposal_transactions: &'a [ProposalShortId],
+        }
+
+        impl<'c> Component<'c> for UncleBlockRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.header.is_some() {
+                        vtable.add_field(UncleBlock::VT_HEADER, UncleBlock::SIZE_HEADER, UncleBlock::ALIGNMENT_HEADER);
+                    }
+                    if self.cellbase.is_some() {
+                        vtable.add_field(UncleBlock::VT_CELLBASE, UncleBlock::SIZE_CELLBASE, UncleBlock::ALIGNMENT_CELLBASE);
+                    }
+                    if !self.proposal_transactions.is_empty() {
+                        vtable.add_field(UncleBlock::VT_PROPOSAL_TRANSACTIONS, UncleBlock::SIZE_PROPOSAL_TRANSACTIONS, UncleBlock::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, UncleBlock::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if let Some(f) = self.header {
+                    builder.align(UncleBlock::ALIGNMENT_HEADER);
+                    let offset_position = builder.tell();
+                    builder.pad(UncleBlock::SIZE_HEADER);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if let Some(f) = self.cellbase {
+                    builder.align(UncleBlock::ALIGNMENT_CELLBASE);
+                    let offset_position = builder.tell();
+                    builder.pad(UncleBlock::SIZE_CELLBASE);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+                if !self.proposal_transactions.is_empty() {
+                    builder.align(UncleBlock::ALIGNMENT_PROPOSAL_TRANSACTIONS);
+                    let offset_position = builder.tell();
+                    builder.pad(UncleBlock::SIZE_PROPOSAL_TRANSACTIONS);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(ScalarVectorComponent::new(self.proposal_transactions.iter().copied(), 1)),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for UncleBlock {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    strategy::option(&config, any_with::<Header>(config.clone())),
+                    strategy::option(&config, any_with::<Transaction>(config.clone())),
+                    vec(any::<ProposalShortId>(), config.scalar_vector_len.clone()),
+                )
+                    .prop_map(|(header, cellbase, proposal_transactions)| UncleBlock {
+                        header,
+                        cellbase,
+                        proposal_transactions,
+                    })
+                    .boxed()
+            }
+        }
+
         #[derive(Default, Clone, Debug, PartialEq)]
         pub struct ValidTransaction {
             pub cycles: u64,
@@ -1966,6 +5337,102 @@ pub mod ckb {
                 table_start
             }
         }
+
+        /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+        impl<'c> Component<'c> for &'c ValidTransaction {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.cycles != 0u64 {
+                        vtable.add_field(ValidTransaction::VT_CYCLES, ValidTransaction::SIZE_CYCLES, ValidTransaction::ALIGNMENT_CYCLES);
+                    }
+                    if self.transaction.is_some() {
+                        vtable.add_field(ValidTransaction::VT_TRANSACTION, ValidTransaction::SIZE_TRANSACTION, ValidTransaction::ALIGNMENT_TRANSACTION);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, ValidTransaction::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.cycles != 0u64 {
+                    builder.align(ValidTransaction::ALIGNMENT_CYCLES);
+                    builder.push_scalar(self.cycles);
+                }
+                if let Some(f) = &self.transaction {
+                    builder.align(ValidTransaction::ALIGNMENT_TRANSACTION);
+                    let offset_position = builder.tell();
+                    builder.pad(ValidTransaction::SIZE_TRANSACTION);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        /// The variant of `ValidTransaction` which borrows its strings and vectors, see `--borrowed`.
+        #[derive(Default, Clone, Copy, Debug, PartialEq)]
+        pub struct ValidTransactionRef<'a> {
+            pub cycles: u64,
+            pub transaction: Option<TransactionRef<'a>>,
+        }
+
+        impl<'c> Component<'c> for ValidTransactionRef<'c> {
+            fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+                let vtable_start = {
+                    let mut vtable = builder.start_vtable();
+                    if self.cycles != 0u64 {
+                        vtable.add_field(ValidTransaction::VT_CYCLES, ValidTransaction::SIZE_CYCLES, ValidTransaction::ALIGNMENT_CYCLES);
+                    }
+                    if self.transaction.is_some() {
+                        vtable.add_field(ValidTransaction::VT_TRANSACTION, ValidTransaction::SIZE_TRANSACTION, ValidTransaction::ALIGNMENT_TRANSACTION);
+                    }
+                    vtable.finish()
+                };
+
+                builder.align_after(SIZE_OF_SOFFSET, ValidTransaction::ALIGNMENT);
+
+                let table_start = builder.tell();
+                builder.push_scalar((table_start - vtable_start) as SOffset);
+                if self.cycles != 0u64 {
+                    builder.align(ValidTransaction::ALIGNMENT_CYCLES);
+                    builder.push_scalar(self.cycles);
+                }
+                if let Some(f) = self.transaction {
+                    builder.align(ValidTransaction::ALIGNMENT_TRANSACTION);
+                    let offset_position = builder.tell();
+                    builder.pad(ValidTransaction::SIZE_TRANSACTION);
+                    builder.push_component(DesignatedComponent::new(
+                        offset_position,
+                        Box::new(f),
+                    ));
+                }
+
+                table_start
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl Arbitrary for ValidTransaction {
+            type Parameters = strategy::Config;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+                (
+                    any::<u64>(),
+                    strategy::option(&config, any_with::<Transaction>(config.clone())),
+                )
+                    .prop_map(|(cycles, transaction)| ValidTransaction {
+                        cycles,
+                        transaction,
+                    })
+                    .boxed()
+            }
+        }
     }
 
 }
@@ -1,2947 +1,1677 @@
 //! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
 use super::ckb_generated as reader;
 
 pub mod ckb {
     #![allow(unused_imports)]
 
     use super::reader::ckb as reader;
-    use flatbuffers::{self, Follow};
-    use flatbuffers_verifier::{
-        try_follow_uoffset, Error, Result, StringVerifier, VectorVerifier, Verify,
-        MAX_OFFSET_LOC,
-    };
     pub mod protocol {
         #![allow(unused_imports)]
 
         use super::reader::protocol as reader;
         use flatbuffers::{self, Follow};
         use flatbuffers_verifier::{
-            try_follow_uoffset, Error, Result, StringVerifier, VectorVerifier, Verify,
-            MAX_OFFSET_LOC,
+            lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+            verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+            TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
         };
+        use std::cmp::Ordering;
+        use std::result;
 
         impl<'a> Verify for reader::AddFilter<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(6)?;
+                table.field_vector(Self::VT_FILTER, "filter", 1, |vector| {
+                    vector.verify_scalar_elements(1, 1)
+                })?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_FILTER as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_FILTER) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let filter_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        filter_verifier.verify_scalar_elements(1)?;
-                    }
-                }
+        impl Root for reader::AddFilter<'_> {
+            type Reader<'a> = reader::AddFilter<'a>;
+        }
 
-                Ok(())
+        impl<'a> reader::AddFilter<'a> {
+            pub fn try_filter(&self) -> result::Result<Option<&'a [u8]>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_FILTER,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_scalar_elements(1, 1)?;
+                }
+                Ok(self.filter())
             }
         }
 
         impl<'a> Verify for reader::Block<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
-
-                if Self::VT_HEADER as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_HEADER) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(12)?;
+                table.field_table::<reader::Header>(Self::VT_HEADER, "header")?;
+                table.field_vector(Self::VT_UNCLES, "uncles", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::UncleBlock>(verification)
+                })?;
+                table.field_vector(Self::VT_COMMIT_TRANSACTIONS, "commit_transactions", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::Transaction>(verification)
+                })?;
+                table.field_vector(Self::VT_PROPOSAL_TRANSACTIONS, "proposal_transactions", 10, |vector| {
+                    vector.verify_scalar_elements(10, 1)
+                })?;
+                Ok(())
+            }
+        }
 
-                        if let Some(f) = self.header() {
-                            f.verify()?;
-                        }
-                    }
-                }
+        impl Root for reader::Block<'_> {
+            type Reader<'a> = reader::Block<'a>;
+        }
 
-                if Self::VT_UNCLES as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_UNCLES) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let uncles_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        uncles_verifier
-                            .verify_reference_elements::<reader::UncleBlock>()?;
-                    }
-                }
+        impl<'a> reader::Block<'a> {
+            pub fn try_header(&self) -> result::Result<Option<reader::Header<'a>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_HEADER,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.header())
+            }
 
-                if Self::VT_COMMIT_TRANSACTIONS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_COMMIT_TRANSACTIONS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let commit_transactions_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        commit_transactions_verifier
-                            .verify_reference_elements::<reader::Transaction>()?;
-                    }
-                }
+            pub fn try_uncles(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::UncleBlock<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_UNCLES,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.uncles())
+            }
 
-                if Self::VT_PROPOSAL_TRANSACTIONS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_PROPOSAL_TRANSACTIONS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let proposal_transactions_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        proposal_transactions_verifier.verify_scalar_elements(10)?;
-                    }
-                }
+            pub fn try_commit_transactions(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::Transaction<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_COMMIT_TRANSACTIONS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.commit_transactions())
+            }
 
-                Ok(())
+            pub fn try_proposal_transactions(&self) -> result::Result<Option<&'a [reader::ProposalShortId]>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PROPOSAL_TRANSACTIONS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_scalar_elements(10, 1)?;
+                }
+                Ok(self.proposal_transactions())
             }
         }
 
         impl<'a> Verify for reader::BlockProposal<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(6)?;
+                table.field_vector(Self::VT_TRANSACTIONS, "transactions", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::Transaction>(verification)
+                })?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_TRANSACTIONS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_TRANSACTIONS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let transactions_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        transactions_verifier
-                            .verify_reference_elements::<reader::Transaction>()?;
-                    }
-                }
+        impl Root for reader::BlockProposal<'_> {
+            type Reader<'a> = reader::BlockProposal<'a>;
+        }
 
-                Ok(())
+        impl<'a> reader::BlockProposal<'a> {
+            pub fn try_transactions(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::Transaction<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_TRANSACTIONS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.transactions())
             }
         }
 
         impl<'a> Verify for reader::BlockTransactions<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(8)?;
+                table.field_struct(Self::VT_HASH, 32, 1)?;
+                table.field_vector(Self::VT_TRANSACTIONS, "transactions", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::Transaction>(verification)
+                })?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_HASH as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_HASH) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 32 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::BlockTransactions<'_> {
+            type Reader<'a> = reader::BlockTransactions<'a>;
+        }
 
-                if Self::VT_TRANSACTIONS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_TRANSACTIONS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let transactions_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        transactions_verifier
-                            .verify_reference_elements::<reader::Transaction>()?;
-                    }
-                }
+        impl<'a> reader::BlockTransactions<'a> {
+            pub fn try_hash(&self) -> result::Result<Option<&'a reader::H256>, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_HASH,
+                    32,
+                    1,
+                )?;
+                Ok(self.hash())
+            }
 
-                Ok(())
+            pub fn try_transactions(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::Transaction<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_TRANSACTIONS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.transactions())
             }
         }
 
         impl<'a> Verify for reader::Bytes<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(6)?;
+                table.field_vector(Self::VT_SEQ, "seq", 1, |vector| {
+                    vector.verify_scalar_elements(1, 1)
+                })?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_SEQ as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_SEQ) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let seq_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        seq_verifier.verify_scalar_elements(1)?;
-                    }
-                }
+        impl Root for reader::Bytes<'_> {
+            type Reader<'a> = reader::Bytes<'a>;
+        }
 
-                Ok(())
+        impl<'a> reader::Bytes<'a> {
+            pub fn try_seq(&self) -> result::Result<Option<&'a [u8]>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_SEQ,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_scalar_elements(1, 1)?;
+                }
+                Ok(self.seq())
             }
         }
 
         impl<'a> Verify for reader::CellInput<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(10)?;
+                table.field_struct(Self::VT_HASH, 32, 1)?;
+                table.field_scalar::<u32>(Self::VT_INDEX)?;
+                table.field_default(Self::VT_INDEX, |value: u32| value == 0u32)?;
+                table.field_vector(Self::VT_ARGS, "args", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::Bytes>(verification)
+                })?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_HASH as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_HASH) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 32 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::CellInput<'_> {
+            type Reader<'a> = reader::CellInput<'a>;
+        }
 
-                if Self::VT_INDEX as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_INDEX) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 4 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl<'a> reader::CellInput<'a> {
+            pub fn try_hash(&self) -> result::Result<Option<&'a reader::H256>, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_HASH,
+                    32,
+                    1,
+                )?;
+                Ok(self.hash())
+            }
 
-                if Self::VT_ARGS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_ARGS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let args_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        args_verifier
-                            .verify_reference_elements::<reader::Bytes>()?;
-                    }
-                }
+            pub fn try_index(&self) -> result::Result<u32, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_INDEX,
+                    4,
+                    4,
+                )?;
+                Ok(self.index())
+            }
 
-                Ok(())
+            pub fn try_args(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::Bytes<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_ARGS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.args())
             }
         }
 
         impl<'a> Verify for reader::CellOutput<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(12)?;
+                table.field_scalar::<u64>(Self::VT_CAPACITY)?;
+                table.field_default(Self::VT_CAPACITY, |value: u64| value == 0u64)?;
+                table.field_table::<reader::Bytes>(Self::VT_DATA, "data")?;
+                table.field_table::<reader::Script>(Self::VT_LOCK, "lock")?;
+                table.field_table::<reader::Script>(Self::VT_TYPE_, "type")?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_CAPACITY as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_CAPACITY) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 8 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::CellOutput<'_> {
+            type Reader<'a> = reader::CellOutput<'a>;
+        }
 
-                if Self::VT_DATA as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_DATA) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
+        impl<'a> reader::CellOutput<'a> {
+            pub fn try_capacity(&self) -> result::Result<u64, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_CAPACITY,
+                    8,
+                    8,
+                )?;
+                Ok(self.capacity())
+            }
 
-                        if let Some(f) = self.data() {
-                            f.verify()?;
-                        }
-                    }
+            pub fn try_data(&self) -> result::Result<Option<reader::Bytes<'a>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_DATA,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
                 }
+                Ok(self.data())
+            }
 
-                if Self::VT_LOCK as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_LOCK) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        if let Some(f) = self.lock() {
-                            f.verify()?;
-                        }
-                    }
+            pub fn try_lock(&self) -> result::Result<Option<reader::Script<'a>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_LOCK,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
                 }
+                Ok(self.lock())
+            }
 
-                if Self::VT_TYPE_ as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_TYPE_) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        if let Some(f) = self.type_() {
-                            f.verify()?;
-                        }
-                    }
+            pub fn try_type_(&self) -> result::Result<Option<reader::Script<'a>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_TYPE_,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
                 }
-
-                Ok(())
+                Ok(self.type_())
             }
         }
 
         impl<'a> Verify for reader::ClearFilter<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
-
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(4)?;
                 Ok(())
             }
         }
 
-        impl<'a> Verify for reader::CompactBlock<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::ClearFilter<'_> {
+            type Reader<'a> = reader::ClearFilter<'a>;
+        }
 
-                if Self::VT_HEADER as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_HEADER) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
+        impl<'a> Verify for reader::CompactBlock<'a> {
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(16)?;
+                table.field_table::<reader::Header>(Self::VT_HEADER, "header")?;
+                table.field_scalar::<u64>(Self::VT_NONCE)?;
+                table.field_default(Self::VT_NONCE, |value: u64| value == 0u64)?;
+                table.field_vector(Self::VT_SHORT_IDS, "short_ids", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::Bytes>(verification)
+                })?;
+                table.field_vector(Self::VT_PREFILLED_TRANSACTIONS, "prefilled_transactions", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::IndexTransaction>(verification)
+                })?;
+                table.field_vector(Self::VT_UNCLES, "uncles", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::UncleBlock>(verification)
+                })?;
+                table.field_vector(Self::VT_PROPOSAL_TRANSACTIONS, "proposal_transactions", 10, |vector| {
+                    vector.verify_scalar_elements(10, 1)
+                })?;
+                Ok(())
+            }
+        }
 
-                        if let Some(f) = self.header() {
-                            f.verify()?;
-                        }
-                    }
-                }
+        impl Root for reader::CompactBlock<'_> {
+            type Reader<'a> = reader::CompactBlock<'a>;
+        }
 
-                if Self::VT_NONCE as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_NONCE) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 8 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl<'a> reader::CompactBlock<'a> {
+            pub fn try_header(&self) -> result::Result<Option<reader::Header<'a>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_HEADER,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.header())
+            }
 
-                if Self::VT_SHORT_IDS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_SHORT_IDS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let short_ids_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        short_ids_verifier
-                            .verify_reference_elements::<reader::Bytes>()?;
-                    }
-                }
+            pub fn try_nonce(&self) -> result::Result<u64, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_NONCE,
+                    8,
+                    8,
+                )?;
+                Ok(self.nonce())
+            }
 
-                if Self::VT_PREFILLED_TRANSACTIONS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_PREFILLED_TRANSACTIONS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let prefilled_transactions_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        prefilled_transactions_verifier
-                            .verify_reference_elements::<reader::IndexTransaction>()?;
-                    }
-                }
+            pub fn try_short_ids(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::Bytes<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_SHORT_IDS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.short_ids())
+            }
 
-                if Self::VT_UNCLES as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_UNCLES) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let uncles_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        uncles_verifier
-                            .verify_reference_elements::<reader::UncleBlock>()?;
-                    }
-                }
+            pub fn try_prefilled_transactions(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::IndexTransaction<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PREFILLED_TRANSACTIONS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.prefilled_transactions())
+            }
 
-                if Self::VT_PROPOSAL_TRANSACTIONS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_PROPOSAL_TRANSACTIONS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let proposal_transactions_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        proposal_transactions_verifier.verify_scalar_elements(10)?;
-                    }
-                }
+            pub fn try_uncles(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::UncleBlock<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_UNCLES,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.uncles())
+            }
 
-                Ok(())
+            pub fn try_proposal_transactions(&self) -> result::Result<Option<&'a [reader::ProposalShortId]>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PROPOSAL_TRANSACTIONS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_scalar_elements(10, 1)?;
+                }
+                Ok(self.proposal_transactions())
             }
         }
 
         impl<'a> Verify for reader::FilteredBlock<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
-
-                if Self::VT_HEADER as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_HEADER) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(10)?;
+                table.field_table::<reader::Header>(Self::VT_HEADER, "header")?;
+                table.field_vector(Self::VT_TRANSACTIONS, "transactions", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::Transaction>(verification)
+                })?;
+                table.field_table::<reader::MerkleProof>(Self::VT_PROOF, "proof")?;
+                Ok(())
+            }
+        }
 
-                        if let Some(f) = self.header() {
-                            f.verify()?;
-                        }
-                    }
-                }
+        impl Root for reader::FilteredBlock<'_> {
+            type Reader<'a> = reader::FilteredBlock<'a>;
+        }
 
-                if Self::VT_TRANSACTIONS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_TRANSACTIONS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let transactions_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        transactions_verifier
-                            .verify_reference_elements::<reader::Transaction>()?;
-                    }
-                }
+        impl<'a> reader::FilteredBlock<'a> {
+            pub fn try_header(&self) -> result::Result<Option<reader::Header<'a>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_HEADER,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.header())
+            }
 
-                if Self::VT_PROOF as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_PROOF) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
+            pub fn try_transactions(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::Transaction<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_TRANSACTIONS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.transactions())
+            }
 
-                        if let Some(f) = self.proof() {
-                            f.verify()?;
-                        }
-                    }
+            pub fn try_proof(&self) -> result::Result<Option<reader::MerkleProof<'a>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PROOF,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
                 }
-
-                Ok(())
+                Ok(self.proof())
             }
         }
 
         impl<'a> Verify for reader::GetBlockProposal<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(8)?;
+                table.field_scalar::<u64>(Self::VT_BLOCK_NUMBER)?;
+                table.field_default(Self::VT_BLOCK_NUMBER, |value: u64| value == 0u64)?;
+                table.field_vector(Self::VT_PROPOSAL_TRANSACTIONS, "proposal_transactions", 10, |vector| {
+                    vector.verify_scalar_elements(10, 1)
+                })?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_BLOCK_NUMBER as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_BLOCK_NUMBER) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 8 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::GetBlockProposal<'_> {
+            type Reader<'a> = reader::GetBlockProposal<'a>;
+        }
 
-                if Self::VT_PROPOSAL_TRANSACTIONS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_PROPOSAL_TRANSACTIONS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let proposal_transactions_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        proposal_transactions_verifier.verify_scalar_elements(10)?;
-                    }
-                }
+        impl<'a> reader::GetBlockProposal<'a> {
+            pub fn try_block_number(&self) -> result::Result<u64, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_BLOCK_NUMBER,
+                    8,
+                    8,
+                )?;
+                Ok(self.block_number())
+            }
 
-                Ok(())
+            pub fn try_proposal_transactions(&self) -> result::Result<Option<&'a [reader::ProposalShortId]>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PROPOSAL_TRANSACTIONS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_scalar_elements(10, 1)?;
+                }
+                Ok(self.proposal_transactions())
             }
         }
 
         impl<'a> Verify for reader::GetBlockTransactions<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(8)?;
+                table.field_struct(Self::VT_HASH, 32, 1)?;
+                table.field_vector(Self::VT_INDEXES, "indexes", 4, |vector| {
+                    vector.verify_scalar_elements(4, 4)
+                })?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_HASH as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_HASH) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 32 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::GetBlockTransactions<'_> {
+            type Reader<'a> = reader::GetBlockTransactions<'a>;
+        }
 
-                if Self::VT_INDEXES as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_INDEXES) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let indexes_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        indexes_verifier.verify_scalar_elements(4)?;
-                    }
-                }
+        impl<'a> reader::GetBlockTransactions<'a> {
+            pub fn try_hash(&self) -> result::Result<Option<&'a reader::H256>, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_HASH,
+                    32,
+                    1,
+                )?;
+                Ok(self.hash())
+            }
 
-                Ok(())
+            pub fn try_indexes(&self) -> result::Result<Option<flatbuffers::Vector<'a, u32>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_INDEXES,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_scalar_elements(4, 4)?;
+                }
+                Ok(self.indexes())
             }
         }
 
         impl<'a> Verify for reader::GetBlocks<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(6)?;
+                table.field_vector(Self::VT_BLOCK_HASHES, "block_hashes", 32, |vector| {
+                    vector.verify_scalar_elements(32, 1)
+                })?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_BLOCK_HASHES as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_BLOCK_HASHES) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let block_hashes_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        block_hashes_verifier.verify_scalar_elements(32)?;
-                    }
-                }
+        impl Root for reader::GetBlocks<'_> {
+            type Reader<'a> = reader::GetBlocks<'a>;
+        }
 
-                Ok(())
+        impl<'a> reader::GetBlocks<'a> {
+            pub fn try_block_hashes(&self) -> result::Result<Option<&'a [reader::H256]>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_BLOCK_HASHES,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_scalar_elements(32, 1)?;
+                }
+                Ok(self.block_hashes())
             }
         }
 
         impl<'a> Verify for reader::GetHeaders<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(10)?;
+                table.field_scalar::<u32>(Self::VT_VERSION)?;
+                table.field_default(Self::VT_VERSION, |value: u32| value == 0u32)?;
+                table.field_vector(Self::VT_BLOCK_LOCATOR_HASHES, "block_locator_hashes", 32, |vector| {
+                    vector.verify_scalar_elements(32, 1)
+                })?;
+                table.field_struct(Self::VT_HASH_STOP, 32, 1)?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_VERSION as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_VERSION) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 4 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::GetHeaders<'_> {
+            type Reader<'a> = reader::GetHeaders<'a>;
+        }
 
-                if Self::VT_BLOCK_LOCATOR_HASHES as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_BLOCK_LOCATOR_HASHES) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let block_locator_hashes_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        block_locator_hashes_verifier.verify_scalar_elements(32)?;
-                    }
-                }
+        impl<'a> reader::GetHeaders<'a> {
+            pub fn try_version(&self) -> result::Result<u32, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_VERSION,
+                    4,
+                    4,
+                )?;
+                Ok(self.version())
+            }
 
-                if Self::VT_HASH_STOP as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_HASH_STOP) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 32 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            pub fn try_block_locator_hashes(&self) -> result::Result<Option<&'a [reader::H256]>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_BLOCK_LOCATOR_HASHES,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_scalar_elements(32, 1)?;
+                }
+                Ok(self.block_locator_hashes())
+            }
 
-                Ok(())
+            pub fn try_hash_stop(&self) -> result::Result<Option<&'a reader::H256>, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_HASH_STOP,
+                    32,
+                    1,
+                )?;
+                Ok(self.hash_stop())
             }
         }
 
         impl<'a> Verify for reader::Header<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
-
-                if Self::VT_VERSION as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_VERSION) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 4 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(28)?;
+                table.field_scalar::<u32>(Self::VT_VERSION)?;
+                table.field_default(Self::VT_VERSION, |value: u32| value == 0u32)?;
+                table.field_struct(Self::VT_PARENT_HASH, 32, 1)?;
+                table.field_scalar::<u64>(Self::VT_TIMESTAMP)?;
+                table.field_default(Self::VT_TIMESTAMP, |value: u64| value == 0u64)?;
+                table.field_scalar::<u64>(Self::VT_NUMBER)?;
+                table.field_default(Self::VT_NUMBER, |value: u64| value == 0u64)?;
+                table.field_struct(Self::VT_TXS_COMMIT, 32, 1)?;
+                table.field_struct(Self::VT_TXS_PROPOSAL, 32, 1)?;
+                table.field_table::<reader::Bytes>(Self::VT_DIFFICULTY, "difficulty")?;
+                table.field_scalar::<u64>(Self::VT_NONCE)?;
+                table.field_default(Self::VT_NONCE, |value: u64| value == 0u64)?;
+                table.field_table::<reader::Bytes>(Self::VT_PROOF, "proof")?;
+                table.field_struct(Self::VT_CELLBASE_ID, 32, 1)?;
+                table.field_struct(Self::VT_UNCLES_HASH, 32, 1)?;
+                table.field_scalar::<u32>(Self::VT_UNCLES_COUNT)?;
+                table.field_default(Self::VT_UNCLES_COUNT, |value: u32| value == 0u32)?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_PARENT_HASH as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_PARENT_HASH) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 32 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::Header<'_> {
+            type Reader<'a> = reader::Header<'a>;
+        }
 
-                if Self::VT_TIMESTAMP as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_TIMESTAMP) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 8 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl<'a> reader::Header<'a> {
+            pub fn try_version(&self) -> result::Result<u32, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_VERSION,
+                    4,
+                    4,
+                )?;
+                Ok(self.version())
+            }
 
-                if Self::VT_NUMBER as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_NUMBER) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 8 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            pub fn try_parent_hash(&self) -> result::Result<Option<&'a reader::H256>, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_PARENT_HASH,
+                    32,
+                    1,
+                )?;
+                Ok(self.parent_hash())
+            }
 
-                if Self::VT_TXS_COMMIT as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_TXS_COMMIT) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 32 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            pub fn try_timestamp(&self) -> result::Result<u64, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_TIMESTAMP,
+                    8,
+                    8,
+                )?;
+                Ok(self.timestamp())
+            }
 
-                if Self::VT_TXS_PROPOSAL as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_TXS_PROPOSAL) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 32 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            pub fn try_number(&self) -> result::Result<u64, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_NUMBER,
+                    8,
+                    8,
+                )?;
+                Ok(self.number())
+            }
 
-                if Self::VT_DIFFICULTY as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_DIFFICULTY) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
+            pub fn try_txs_commit(&self) -> result::Result<Option<&'a reader::H256>, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_TXS_COMMIT,
+                    32,
+                    1,
+                )?;
+                Ok(self.txs_commit())
+            }
 
-                        if let Some(f) = self.difficulty() {
-                            f.verify()?;
-                        }
-                    }
-                }
+            pub fn try_txs_proposal(&self) -> result::Result<Option<&'a reader::H256>, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_TXS_PROPOSAL,
+                    32,
+                    1,
+                )?;
+                Ok(self.txs_proposal())
+            }
 
-                if Self::VT_NONCE as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_NONCE) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 8 {
-                        return Err(Error::OutOfBounds);
-                    }
+            pub fn try_difficulty(&self) -> result::Result<Option<reader::Bytes<'a>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_DIFFICULTY,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
                 }
+                Ok(self.difficulty())
+            }
 
-                if Self::VT_PROOF as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_PROOF) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        if let Some(f) = self.proof() {
-                            f.verify()?;
-                        }
-                    }
-                }
+            pub fn try_nonce(&self) -> result::Result<u64, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_NONCE,
+                    8,
+                    8,
+                )?;
+                Ok(self.nonce())
+            }
 
-                if Self::VT_CELLBASE_ID as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_CELLBASE_ID) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 32 {
-                        return Err(Error::OutOfBounds);
-                    }
+            pub fn try_proof(&self) -> result::Result<Option<reader::Bytes<'a>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PROOF,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
                 }
+                Ok(self.proof())
+            }
 
-                if Self::VT_UNCLES_HASH as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_UNCLES_HASH) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 32 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            pub fn try_cellbase_id(&self) -> result::Result<Option<&'a reader::H256>, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_CELLBASE_ID,
+                    32,
+                    1,
+                )?;
+                Ok(self.cellbase_id())
+            }
 
-                if Self::VT_UNCLES_COUNT as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_UNCLES_COUNT) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 4 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            pub fn try_uncles_hash(&self) -> result::Result<Option<&'a reader::H256>, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_UNCLES_HASH,
+                    32,
+                    1,
+                )?;
+                Ok(self.uncles_hash())
+            }
 
-                Ok(())
+            pub fn try_uncles_count(&self) -> result::Result<u32, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_UNCLES_COUNT,
+                    4,
+                    4,
+                )?;
+                Ok(self.uncles_count())
             }
         }
 
         impl<'a> Verify for reader::Headers<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(6)?;
+                table.field_vector(Self::VT_HEADERS, "headers", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::Header>(verification)
+                })?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_HEADERS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_HEADERS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let headers_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        headers_verifier
-                            .verify_reference_elements::<reader::Header>()?;
-                    }
-                }
+        impl Root for reader::Headers<'_> {
+            type Reader<'a> = reader::Headers<'a>;
+        }
 
-                Ok(())
+        impl<'a> reader::Headers<'a> {
+            pub fn try_headers(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::Header<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_HEADERS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.headers())
             }
         }
 
         impl<'a> Verify for reader::IndexTransaction<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(8)?;
+                table.field_scalar::<u32>(Self::VT_INDEX)?;
+                table.field_default(Self::VT_INDEX, |value: u32| value == 0u32)?;
+                table.field_table::<reader::Transaction>(Self::VT_TRANSACTION, "transaction")?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_INDEX as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_INDEX) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 4 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::IndexTransaction<'_> {
+            type Reader<'a> = reader::IndexTransaction<'a>;
+        }
 
-                if Self::VT_TRANSACTION as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_TRANSACTION) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
+        impl<'a> reader::IndexTransaction<'a> {
+            pub fn try_index(&self) -> result::Result<u32, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_INDEX,
+                    4,
+                    4,
+                )?;
+                Ok(self.index())
+            }
 
-                        if let Some(f) = self.transaction() {
-                            f.verify()?;
-                        }
-                    }
+            pub fn try_transaction(&self) -> result::Result<Option<reader::Transaction<'a>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_TRANSACTION,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
                 }
+                Ok(self.transaction())
+            }
+        }
 
+        impl<'a> Verify for reader::MerkleProof<'a> {
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(8)?;
+                table.field_vector(Self::VT_INDICES, "indices", 4, |vector| {
+                    vector.verify_scalar_elements(4, 4)
+                })?;
+                table.field_vector(Self::VT_LEMMAS, "lemmas", 32, |vector| {
+                    vector.verify_scalar_elements(32, 1)
+                })?;
                 Ok(())
             }
         }
 
-        impl<'a> Verify for reader::MerkleProof<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::MerkleProof<'_> {
+            type Reader<'a> = reader::MerkleProof<'a>;
+        }
 
-                if Self::VT_INDICES as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_INDICES) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let indices_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        indices_verifier.verify_scalar_elements(4)?;
-                    }
-                }
+        impl<'a> reader::MerkleProof<'a> {
+            pub fn try_indices(&self) -> result::Result<Option<flatbuffers::Vector<'a, u32>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_INDICES,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_scalar_elements(4, 4)?;
+                }
+                Ok(self.indices())
+            }
 
-                if Self::VT_LEMMAS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_LEMMAS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let lemmas_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        lemmas_verifier.verify_scalar_elements(32)?;
-                    }
-                }
+            pub fn try_lemmas(&self) -> result::Result<Option<&'a [reader::H256]>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_LEMMAS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_scalar_elements(32, 1)?;
+                }
+                Ok(self.lemmas())
+            }
+        }
 
+        impl<'a> Verify for reader::OutPoint<'a> {
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(8)?;
+                table.field_struct(Self::VT_HASH, 32, 1)?;
+                table.field_scalar::<u32>(Self::VT_INDEX)?;
+                table.field_default(Self::VT_INDEX, |value: u32| value == 0u32)?;
                 Ok(())
             }
         }
 
-        impl<'a> Verify for reader::OutPoint<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::OutPoint<'_> {
+            type Reader<'a> = reader::OutPoint<'a>;
+        }
 
-                if Self::VT_HASH as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_HASH) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 32 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl<'a> reader::OutPoint<'a> {
+            pub fn try_hash(&self) -> result::Result<Option<&'a reader::H256>, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_HASH,
+                    32,
+                    1,
+                )?;
+                Ok(self.hash())
+            }
 
-                if Self::VT_INDEX as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_INDEX) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 4 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            pub fn try_index(&self) -> result::Result<u32, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_INDEX,
+                    4,
+                    4,
+                )?;
+                Ok(self.index())
+            }
+        }
 
+        impl<'a> Verify for reader::RelayMessage<'a> {
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(8)?;
+                table.field_enum(Self::VT_PAYLOAD_TYPE, |value: u8| matches!(value, 0..=6))?;
+                table.field_union(Self::VT_PAYLOAD, "payload", |loc| match self.try_payload_type() {
+                    Ok(reader::RelayPayload::CompactBlock) => reader::CompactBlock::follow(self._tab.buf, loc).verify_in(verification),
+                    Ok(reader::RelayPayload::ValidTransaction) => reader::ValidTransaction::follow(self._tab.buf, loc).verify_in(verification),
+                    Ok(reader::RelayPayload::GetBlockTransactions) => reader::GetBlockTransactions::follow(self._tab.buf, loc).verify_in(verification),
+                    Ok(reader::RelayPayload::BlockTransactions) => reader::BlockTransactions::follow(self._tab.buf, loc).verify_in(verification),
+                    Ok(reader::RelayPayload::GetBlockProposal) => reader::GetBlockProposal::follow(self._tab.buf, loc).verify_in(verification),
+                    Ok(reader::RelayPayload::BlockProposal) => reader::BlockProposal::follow(self._tab.buf, loc).verify_in(verification),
+                    Ok(reader::RelayPayload::NONE) => Err(Error::UnmatchedUnion),
+                    Err(Error::UnknownEnumValue) => verification.unknown_enum_value(),
+                    Err(err) => Err(err),
+                })?;
                 Ok(())
             }
         }
 
-        impl<'a> Verify for reader::RelayMessage<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::RelayMessage<'_> {
+            type Reader<'a> = reader::RelayMessage<'a>;
+        }
 
-                if Self::VT_PAYLOAD_TYPE as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_PAYLOAD_TYPE) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 1 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl<'a> reader::RelayMessage<'a> {
+            pub fn try_payload_type(&self) -> result::Result<reader::RelayPayload, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD_TYPE,
+                    1,
+                    1,
+                )? {
+                    if !matches!(
+                        read_scalar_at::<u8>(self._tab.buf, loc),
+                        0..=6
+                    ) {
+                        return Err(Error::UnknownEnumValue);
+                    }
+                }
+                Ok(self.payload_type())
+            }
 
-                if Self::VT_PAYLOAD as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_PAYLOAD) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        match self.payload_type() {
-                            reader::RelayPayload::CompactBlock => self
-                                .payload_as_compact_block()
-                                .ok_or(Error::UnmatchedUnion)?
-                                .verify()?,
-                            reader::RelayPayload::ValidTransaction => self
-                                .payload_as_valid_transaction()
-                                .ok_or(Error::UnmatchedUnion)?
-                                .verify()?,
-                            reader::RelayPayload::GetBlockTransactions => self
-                                .payload_as_get_block_transactions()
-                                .ok_or(Error::UnmatchedUnion)?
-                                .verify()?,
-                            reader::RelayPayload::BlockTransactions => self
-                                .payload_as_block_transactions()
-                                .ok_or(Error::UnmatchedUnion)?
-                                .verify()?,
-                            reader::RelayPayload::GetBlockProposal => self
-                                .payload_as_get_block_proposal()
-                                .ok_or(Error::UnmatchedUnion)?
-                                .verify()?,
-                            reader::RelayPayload::BlockProposal => self
-                                .payload_as_block_proposal()
-                                .ok_or(Error::UnmatchedUnion)?
-                                .verify()?,
-                            reader::RelayPayload::NONE => return Err(Error::UnmatchedUnion),
-                        }
-                    }
-                }
+            /// Reads `payload_type` as the underlying value of the enum, which may be undeclared in
+            /// the schema, see `Options::accept_unknown_enum_values`.
+            pub fn raw_payload_type(&self) -> u8 {
+                self._tab
+                    .get::<u8>(Self::VT_PAYLOAD_TYPE, Some(0))
+                    .unwrap()
+            }
 
-                Ok(())
+            pub fn try_payload_as_compact_block(
+                &self,
+            ) -> result::Result<Option<reader::CompactBlock<'a>>, Error> {
+                if self.try_payload_type()? != reader::RelayPayload::CompactBlock {
+                    return Ok(None);
+                }
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload().map(reader::CompactBlock::init_from_table))
+            }
+
+            pub fn try_payload_as_valid_transaction(
+                &self,
+            ) -> result::Result<Option<reader::ValidTransaction<'a>>, Error> {
+                if self.try_payload_type()? != reader::RelayPayload::ValidTransaction {
+                    return Ok(None);
+                }
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload().map(reader::ValidTransaction::init_from_table))
+            }
+
+            pub fn try_payload_as_get_block_transactions(
+                &self,
+            ) -> result::Result<Option<reader::GetBlockTransactions<'a>>, Error> {
+                if self.try_payload_type()? != reader::RelayPayload::GetBlockTransactions {
+                    return Ok(None);
+                }
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload().map(reader::GetBlockTransactions::init_from_table))
+            }
+
+            pub fn try_payload_as_block_transactions(
+                &self,
+            ) -> result::Result<Option<reader::BlockTransactions<'a>>, Error> {
+                if self.try_payload_type()? != reader::RelayPayload::BlockTransactions {
+                    return Ok(None);
+                }
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload().map(reader::BlockTransactions::init_from_table))
+            }
+
+            pub fn try_payload_as_get_block_proposal(
+                &self,
+            ) -> result::Result<Option<reader::GetBlockProposal<'a>>, Error> {
+                if self.try_payload_type()? != reader::RelayPayload::GetBlockProposal {
+                    return Ok(None);
+                }
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload().map(reader::GetBlockProposal::init_from_table))
+            }
+
+            pub fn try_payload_as_block_proposal(
+                &self,
+            ) -> result::Result<Option<reader::BlockProposal<'a>>, Error> {
+                if self.try_payload_type()? != reader::RelayPayload::BlockProposal {
+                    return Ok(None);
+                }
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload().map(reader::BlockProposal::init_from_table))
             }
         }
 
         impl<'a> Verify for reader::Script<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(10)?;
+                table.field_scalar::<u8>(Self::VT_VERSION)?;
+                table.field_default(Self::VT_VERSION, |value: u8| value == 0u8)?;
+                table.field_vector(Self::VT_ARGS, "args", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::Bytes>(verification)
+                })?;
+                table.field_struct(Self::VT_BINARY_HASH, 32, 1)?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_VERSION as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_VERSION) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 1 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::Script<'_> {
+            type Reader<'a> = reader::Script<'a>;
+        }
 
-                if Self::VT_ARGS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_ARGS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let args_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        args_verifier
-                            .verify_reference_elements::<reader::Bytes>()?;
-                    }
-                }
+        impl<'a> reader::Script<'a> {
+            pub fn try_version(&self) -> result::Result<u8, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_VERSION,
+                    1,
+                    1,
+                )?;
+                Ok(self.version())
+            }
 
-                if Self::VT_BINARY_HASH as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_BINARY_HASH) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 32 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            pub fn try_args(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::Bytes<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_ARGS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.args())
+            }
 
-                Ok(())
+            pub fn try_binary_hash(&self) -> result::Result<Option<&'a reader::H256>, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_BINARY_HASH,
+                    32,
+                    1,
+                )?;
+                Ok(self.binary_hash())
             }
         }
 
         impl<'a> Verify for reader::SetFilter<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(10)?;
+                table.field_vector(Self::VT_FILTER, "filter", 1, |vector| {
+                    vector.verify_scalar_elements(1, 1)
+                })?;
+                table.field_scalar::<u8>(Self::VT_NUM_HASHES)?;
+                table.field_default(Self::VT_NUM_HASHES, |value: u8| value == 0u8)?;
+                table.field_scalar::<u32>(Self::VT_HASH_SEED)?;
+                table.field_default(Self::VT_HASH_SEED, |value: u32| value == 0u32)?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_FILTER as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_FILTER) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let filter_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        filter_verifier.verify_scalar_elements(1)?;
-                    }
-                }
+        impl Root for reader::SetFilter<'_> {
+            type Reader<'a> = reader::SetFilter<'a>;
+        }
 
-                if Self::VT_NUM_HASHES as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_NUM_HASHES) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 1 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl<'a> reader::SetFilter<'a> {
+            pub fn try_filter(&self) -> result::Result<Option<&'a [u8]>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_FILTER,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_scalar_elements(1, 1)?;
+                }
+                Ok(self.filter())
+            }
 
-                if Self::VT_HASH_SEED as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_HASH_SEED) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 4 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            pub fn try_num_hashes(&self) -> result::Result<u8, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_NUM_HASHES,
+                    1,
+                    1,
+                )?;
+                Ok(self.num_hashes())
+            }
 
-                Ok(())
+            pub fn try_hash_seed(&self) -> result::Result<u32, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_HASH_SEED,
+                    4,
+                    4,
+                )?;
+                Ok(self.hash_seed())
             }
         }
 
         impl<'a> Verify for reader::SyncMessage<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(8)?;
+                table.field_enum(Self::VT_PAYLOAD_TYPE, |value: u8| matches!(value, 0..=8))?;
+                table.field_union(Self::VT_PAYLOAD, "payload", |loc| match self.try_payload_type() {
+                    Ok(reader::SyncPayload::GetHeaders) => reader::GetHeaders::follow(self._tab.buf, loc).verify_in(verification),
+                    Ok(reader::SyncPayload::Headers) => reader::Headers::follow(self._tab.buf, loc).verify_in(verification),
+                    Ok(reader::SyncPayload::GetBlocks) => reader::GetBlocks::follow(self._tab.buf, loc).verify_in(verification),
+                    Ok(reader::SyncPayload::Block) => reader::Block::follow(self._tab.buf, loc).verify_in(verification),
+                    Ok(reader::SyncPayload::SetFilter) => reader::SetFilter::follow(self._tab.buf, loc).verify_in(verification),
+                    Ok(reader::SyncPayload::AddFilter) => reader::AddFilter::follow(self._tab.buf, loc).verify_in(verification),
+                    Ok(reader::SyncPayload::ClearFilter) => reader::ClearFilter::follow(self._tab.buf, loc).verify_in(verification),
+                    Ok(reader::SyncPayload::FilteredBlock) => reader::FilteredBlock::follow(self._tab.buf, loc).verify_in(verification),
+                    Ok(reader::SyncPayload::NONE) => Err(Error::UnmatchedUnion),
+                    Err(Error::UnknownEnumValue) => verification.unknown_enum_value(),
+                    Err(err) => Err(err),
+                })?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_PAYLOAD_TYPE as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_PAYLOAD_TYPE) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 1 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::SyncMessage<'_> {
+            type Reader<'a> = reader::SyncMessage<'a>;
+        }
 
-                if Self::VT_PAYLOAD as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_PAYLOAD) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        match self.payload_type() {
-                            reader::SyncPayload::GetHeaders => self
-                                .payload_as_get_headers()
-                                .ok_or(Error::UnmatchedUnion)?
-                                .verify()?,
-                            reader::SyncPayload::Headers => self
-                                .payload_as_headers()
-                                .ok_or(Error::UnmatchedUnion)?
-                                .verify()?,
-                            reader::SyncPayload::GetBlocks => self
-                                .payload_as_get_blocks()
-                                .ok_or(Error::UnmatchedUnion)?
-                                .verify()?,
-                            reader::SyncPayload::Block => self
-                                .payload_as_block()
-                                .ok_or(Error::UnmatchedUnion)?
-                                .verify()?,
-                            reader::SyncPayload::SetFilter => self
-                                .payload_as_set_filter()
-                                .ok_or(Error::UnmatchedUnion)?
-                                .verify()?,
-                            reader::SyncPayload::AddFilter => self
-                                .payload_as_add_filter()
-                                .ok_or(Error::UnmatchedUnion)?
-                                .verify()?,
-                            reader::SyncPayload::ClearFilter => self
-                                .payload_as_clear_filter()
-                                .ok_or(Error::UnmatchedUnion)?
-                                .verify()?,
-                            reader::SyncPayload::FilteredBlock => self
-                                .payload_as_filtered_block()
-                                .ok_or(Error::UnmatchedUnion)?
-                                .verify()?,
-                            reader::SyncPayload::NONE => return Err(Error::UnmatchedUnion),
-                        }
-                    }
-                }
+        impl<'a> reader::SyncMessage<'a> {
+            pub fn try_payload_type(&self) -> result::Result<reader::SyncPayload, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD_TYPE,
+                    1,
+                    1,
+                )? {
+                    if !matches!(
+                        read_scalar_at::<u8>(self._tab.buf, loc),
+                        0..=8
+                    ) {
+                        return Err(Error::UnknownEnumValue);
+                    }
+                }
+                Ok(self.payload_type())
+            }
 
-                Ok(())
+            /// Reads `payload_type` as the underlying value of the enum, which may be undeclared in
+            /// the schema, see `Options::accept_unknown_enum_values`.
+            pub fn raw_payload_type(&self) -> u8 {
+                self._tab
+                    .get::<u8>(Self::VT_PAYLOAD_TYPE, Some(0))
+                    .unwrap()
+            }
+
+            pub fn try_payload_as_get_headers(
+                &self,
+            ) -> result::Result<Option<reader::GetHeaders<'a>>, Error> {
+                if self.try_payload_type()? != reader::SyncPayload::GetHeaders {
+                    return Ok(None);
+                }
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload().map(reader::GetHeaders::init_from_table))
+            }
+
+            pub fn try_payload_as_headers(
+                &self,
+            ) -> result::Result<Option<reader::Headers<'a>>, Error> {
+                if self.try_payload_type()? != reader::SyncPayload::Headers {
+                    return Ok(None);
+                }
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload().map(reader::Headers::init_from_table))
+            }
+
+            pub fn try_payload_as_get_blocks(
+                &self,
+            ) -> result::Result<Option<reader::GetBlocks<'a>>, Error> {
+                if self.try_payload_type()? != reader::SyncPayload::GetBlocks {
+                    return Ok(None);
+                }
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload().map(reader::GetBlocks::init_from_table))
+            }
+
+            pub fn try_payload_as_block(
+                &self,
+            ) -> result::Result<Option<reader::Block<'a>>, Error> {
+                if self.try_payload_type()? != reader::SyncPayload::Block {
+                    return Ok(None);
+                }
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload().map(reader::Block::init_from_table))
+            }
+
+            pub fn try_payload_as_set_filter(
+                &self,
+            ) -> result::Result<Option<reader::SetFilter<'a>>, Error> {
+                if self.try_payload_type()? != reader::SyncPayload::SetFilter {
+                    return Ok(None);
+                }
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload().map(reader::SetFilter::init_from_table))
+            }
+
+            pub fn try_payload_as_add_filter(
+                &self,
+            ) -> result::Result<Option<reader::AddFilter<'a>>, Error> {
+                if self.try_payload_type()? != reader::SyncPayload::AddFilter {
+                    return Ok(None);
+                }
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload().map(reader::AddFilter::init_from_table))
+            }
+
+            pub fn try_payload_as_clear_filter(
+                &self,
+            ) -> result::Result<Option<reader::ClearFilter<'a>>, Error> {
+                if self.try_payload_type()? != reader::SyncPayload::ClearFilter {
+                    return Ok(None);
+                }
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload().map(reader::ClearFilter::init_from_table))
+            }
+
+            pub fn try_payload_as_filtered_block(
+                &self,
+            ) -> result::Result<Option<reader::FilteredBlock<'a>>, Error> {
+                if self.try_payload_type()? != reader::SyncPayload::FilteredBlock {
+                    return Ok(None);
+                }
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload().map(reader::FilteredBlock::init_from_table))
             }
         }
 
         impl<'a> Verify for reader::Time<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(6)?;
+                table.field_scalar::<u64>(Self::VT_TIMESTAMP)?;
+                table.field_default(Self::VT_TIMESTAMP, |value: u64| value == 0u64)?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_TIMESTAMP as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_TIMESTAMP) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 8 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::Time<'_> {
+            type Reader<'a> = reader::Time<'a>;
+        }
 
-                Ok(())
+        impl<'a> reader::Time<'a> {
+            pub fn try_timestamp(&self) -> result::Result<u64, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_TIMESTAMP,
+                    8,
+                    8,
+                )?;
+                Ok(self.timestamp())
             }
         }
 
         impl<'a> Verify for reader::TimeMessage<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
-
-                if Self::VT_PAYLOAD as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_PAYLOAD) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(6)?;
+                table.field_table::<reader::Time>(Self::VT_PAYLOAD, "payload")?;
+                Ok(())
+            }
+        }
 
-                        if let Some(f) = self.payload() {
-                            f.verify()?;
-                        }
-                    }
-                }
+        impl Root for reader::TimeMessage<'_> {
+            type Reader<'a> = reader::TimeMessage<'a>;
+        }
 
-                Ok(())
+        impl<'a> reader::TimeMessage<'a> {
+            pub fn try_payload(&self) -> result::Result<Option<reader::Time<'a>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PAYLOAD,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.payload())
             }
         }
 
         impl<'a> Verify for reader::Transaction<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(14)?;
+                table.field_scalar::<u32>(Self::VT_VERSION)?;
+                table.field_default(Self::VT_VERSION, |value: u32| value == 0u32)?;
+                table.field_vector(Self::VT_DEPS, "deps", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::OutPoint>(verification)
+                })?;
+                table.field_vector(Self::VT_INPUTS, "inputs", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::CellInput>(verification)
+                })?;
+                table.field_vector(Self::VT_OUTPUTS, "outputs", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::CellOutput>(verification)
+                })?;
+                table.field_vector(Self::VT_EMBEDS, "embeds", flatbuffers::SIZE_UOFFSET, |vector| {
+                    vector.verify_reference_elements::<reader::Bytes>(verification)
+                })?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_VERSION as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_VERSION) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 4 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::Transaction<'_> {
+            type Reader<'a> = reader::Transaction<'a>;
+        }
 
-                if Self::VT_DEPS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_DEPS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let deps_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        deps_verifier
-                            .verify_reference_elements::<reader::OutPoint>()?;
-                    }
-                }
+        impl<'a> reader::Transaction<'a> {
+            pub fn try_version(&self) -> result::Result<u32, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_VERSION,
+                    4,
+                    4,
+                )?;
+                Ok(self.version())
+            }
 
-                if Self::VT_INPUTS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_INPUTS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let inputs_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        inputs_verifier
-                            .verify_reference_elements::<reader::CellInput>()?;
-                    }
-                }
+            pub fn try_deps(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::OutPoint<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_DEPS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.deps())
+            }
 
-                if Self::VT_OUTPUTS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_OUTPUTS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let outputs_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        outputs_verifier
-                            .verify_reference_elements::<reader::CellOutput>()?;
-                    }
-                }
+            pub fn try_inputs(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::CellInput<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_INPUTS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.inputs())
+            }
 
-                if Self::VT_EMBEDS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_EMBEDS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let embeds_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        embeds_verifier
-                            .verify_reference_elements::<reader::Bytes>()?;
-                    }
-                }
+            pub fn try_outputs(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::CellOutput<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_OUTPUTS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.outputs())
+            }
 
-                Ok(())
+            pub fn try_embeds(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::Bytes<'a>>>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_EMBEDS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_reference_offsets()?;
+                }
+                Ok(self.embeds())
             }
         }
 
         impl<'a> Verify for reader::UncleBlock<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
-
-                if Self::VT_HEADER as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_HEADER) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        if let Some(f) = self.header() {
-                            f.verify()?;
-                        }
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(10)?;
+                table.field_table::<reader::Header>(Self::VT_HEADER, "header")?;
+                table.field_table::<reader::Transaction>(Self::VT_CELLBASE, "cellbase")?;
+                table.field_vector(Self::VT_PROPOSAL_TRANSACTIONS, "proposal_transactions", 10, |vector| {
+                    vector.verify_scalar_elements(10, 1)
+                })?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_CELLBASE as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_CELLBASE) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
+        impl Root for reader::UncleBlock<'_> {
+            type Reader<'a> = reader::UncleBlock<'a>;
+        }
 
-                        if let Some(f) = self.cellbase() {
-                            f.verify()?;
-                        }
-                    }
-                }
+        impl<'a> reader::UncleBlock<'a> {
+            pub fn try_header(&self) -> result::Result<Option<reader::Header<'a>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_HEADER,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
+                }
+                Ok(self.header())
+            }
 
-                if Self::VT_PROPOSAL_TRANSACTIONS as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_PROPOSAL_TRANSACTIONS) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
-
-                        let proposal_transactions_verifier = VectorVerifier::follow(
-                            buf,
-                            try_follow_uoffset(buf, tab.loc + voffset)?,
-                        );
-                        proposal_transactions_verifier.verify_scalar_elements(10)?;
-                    }
+            pub fn try_cellbase(&self) -> result::Result<Option<reader::Transaction<'a>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_CELLBASE,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
                 }
+                Ok(self.cellbase())
+            }
 
-                Ok(())
+            pub fn try_proposal_transactions(&self) -> result::Result<Option<&'a [reader::ProposalShortId]>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_PROPOSAL_TRANSACTIONS,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                    verifier.verify_scalar_elements(10, 1)?;
+                }
+                Ok(self.proposal_transactions())
             }
         }
 
         impl<'a> Verify for reader::ValidTransaction<'a> {
-            fn verify(&self) -> Result {
-                let tab = self._tab;
-                let buf = tab.buf;
-                let buf_len = buf.len();
-
-                if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab_loc = {
-                    let soffset_slice = &buf[tab.loc..];
-                    let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                    if soffset >= 0 {
-                        tab.loc.checked_sub(soffset as usize)
-                    } else {
-                        soffset
-                            .checked_neg()
-                            .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                    }
-                }
-                .ok_or(Error::OutOfBounds)?;
-                if vtab_loc
-                    .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let vtab = tab.vtable();
-                let vtab_num_bytes = vtab.num_bytes();
-                let object_inline_num_bytes = vtab.object_inline_num_bytes();
-                if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                    || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if vtab_loc
-                    .checked_add(vtab_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-                if tab
-                    .loc
-                    .checked_add(object_inline_num_bytes)
-                    .filter(|loc| *loc <= buf_len)
-                    .is_none()
-                {
-                    return Err(Error::OutOfBounds);
-                }
-
-                for i in 0..vtab.num_fields() {
-                    let voffset = vtab.get_field(i) as usize;
-                    if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                        || voffset >= object_inline_num_bytes
-                    {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+            fn verify_in(&self, verification: &Verification) -> Result {
+                let table = TableVerifier::new(&self._tab, verification)?;
+                table.verify_known_fields(8)?;
+                table.field_scalar::<u64>(Self::VT_CYCLES)?;
+                table.field_default(Self::VT_CYCLES, |value: u64| value == 0u64)?;
+                table.field_table::<reader::Transaction>(Self::VT_TRANSACTION, "transaction")?;
+                Ok(())
+            }
+        }
 
-                if Self::VT_CYCLES as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_CYCLES) as usize;
-                    if voffset > 0 && object_inline_num_bytes - voffset < 8 {
-                        return Err(Error::OutOfBounds);
-                    }
-                }
+        impl Root for reader::ValidTransaction<'_> {
+            type Reader<'a> = reader::ValidTransaction<'a>;
+        }
 
-                if Self::VT_TRANSACTION as usize + flatbuffers::SIZE_VOFFSET
-                    <= vtab_num_bytes
-                {
-                    let voffset = vtab.get(Self::VT_TRANSACTION) as usize;
-                    if voffset > 0 {
-                        if voffset + 4 > object_inline_num_bytes {
-                            return Err(Error::OutOfBounds);
-                        }
+        impl<'a> reader::ValidTransaction<'a> {
+            pub fn try_cycles(&self) -> result::Result<u64, Error> {
+                try_field_loc(
+                    &self._tab,
+                    Self::VT_CYCLES,
+                    8,
+                    8,
+                )?;
+                Ok(self.cycles())
+            }
 
-                        if let Some(f) = self.transaction() {
-                            f.verify()?;
-                        }
-                    }
+            pub fn try_transaction(&self) -> result::Result<Option<reader::Transaction<'a>>, Error> {
+                if let Some(loc) = try_field_loc(
+                    &self._tab,
+                    Self::VT_TRANSACTION,
+                    flatbuffers::SIZE_UOFFSET,
+                    flatbuffers::SIZE_UOFFSET,
+                )? {
+                    try_follow_uoffset(self._tab.buf, loc)?;
                 }
-
-                Ok(())
+                Ok(self.transaction())
             }
         }
     }
@@ -1,14 +1,21 @@
 //! This file is auto-generated by cfbc.
-#![allow(unused_imports, clippy::large_enum_variant)]
+#![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
 
 use cfb::builder::{
     Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
-    ScalarVectorComponent, StringComponent,
+    ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
 };
 use cfb::scalar::Scalar;
 use cfb::types::{SOffset, SIZE_OF_SOFFSET};
-#[cfg(not(target_endian = "little"))]
-use std::mem::transmute;
+use std::cmp::Ordering;
+#[cfg(feature = "proptest")]
+use cfb::strategy;
+#[cfg(feature = "proptest")]
+use proptest::{
+    collection::vec,
+    prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+    sample::select,
+};
 
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct T1 {
@@ -141,6 +148,237 @@ impl<'c> Component<'c> for T1 {
     }
 }
 
+/// Builds the table without consuming it, so the same value can be encoded repeatedly.
+impl<'c> Component<'c> for &'c T1 {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.f1 != 0u64 {
+                vtable.add_field(T1::VT_F1, T1::SIZE_F1, T1::ALIGNMENT_F1);
+            }
+            if !self.s1.is_empty() {
+                vtable.add_field(T1::VT_S1, T1::SIZE_S1, T1::ALIGNMENT_S1);
+            }
+            if self.f2.is_some() {
+                vtable.add_field(T1::VT_F2, T1::SIZE_F2, T1::ALIGNMENT_F2);
+            }
+            if !self.s2.is_empty() {
+                vtable.add_field(T1::VT_S2, T1::SIZE_S2, T1::ALIGNMENT_S2);
+            }
+            if !self.f3.is_empty() {
+                vtable.add_field(T1::VT_F3, T1::SIZE_F3, T1::ALIGNMENT_F3);
+            }
+            if !self.s3.is_empty() {
+                vtable.add_field(T1::VT_S3, T1::SIZE_S3, T1::ALIGNMENT_S3);
+            }
+            if !self.f4.is_empty() {
+                vtable.add_field(T1::VT_F4, T1::SIZE_F4, T1::ALIGNMENT_F4);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, T1::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.f1 != 0u64 {
+            builder.align(T1::ALIGNMENT_F1);
+            builder.push_scalar(self.f1);
+        }
+        if !self.s1.is_empty() {
+            builder.align(T1::ALIGNMENT_S1);
+            let offset_position = builder.tell();
+            builder.pad(T1::SIZE_S1);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ScalarVectorComponent::new(self.s1.iter().copied(), 1)),
+            ));
+        }
+        if let Some(f) = &self.f2 {
+            builder.align(T1::ALIGNMENT_F2);
+            let offset_position = builder.tell();
+            builder.pad(T1::SIZE_F2);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(f),
+            ));
+        }
+        if !self.s2.is_empty() {
+            builder.align(T1::ALIGNMENT_S2);
+            let offset_position = builder.tell();
+            builder.pad(T1::SIZE_S2);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ScalarVectorComponent::new(self.s2.iter().copied(), 1)),
+            ));
+        }
+        if !self.f3.is_empty() {
+            builder.align(T1::ALIGNMENT_F3);
+            let offset_position = builder.tell();
+            builder.pad(T1::SIZE_F3);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ScalarVectorComponent::new(self.f3.iter().copied(), 8)),
+            ));
+        }
+        if !self.s3.is_empty() {
+            builder.align(T1::ALIGNMENT_S3);
+            let offset_position = builder.tell();
+            builder.pad(T1::SIZE_S3);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ScalarVectorComponent::new(self.s3.iter().copied(), 1)),
+            ));
+        }
+        if !self.f4.is_empty() {
+            builder.align(T1::ALIGNMENT_F4);
+            let offset_position = builder.tell();
+            builder.pad(T1::SIZE_F4);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(StringComponent::new(&self.f4))
+            ));
+        }
+
+        table_start
+    }
+}
+
+/// The variant of `T1` which borrows its strings and vectors, see `--borrowed`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct T1Ref<'a> {
+    pub f1: u64,
+    pub s1: &'a [u8],
+    pub f2: Option<T2Ref>,
+    pub s2: &'a [u8],
+    pub f3: &'a [u64],
+    pub s3: &'a [u8],
+    pub f4: &'a str,
+}
+
+impl<'c> Component<'c> for T1Ref<'c> {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.f1 != 0u64 {
+                vtable.add_field(T1::VT_F1, T1::SIZE_F1, T1::ALIGNMENT_F1);
+            }
+            if !self.s1.is_empty() {
+                vtable.add_field(T1::VT_S1, T1::SIZE_S1, T1::ALIGNMENT_S1);
+            }
+            if self.f2.is_some() {
+                vtable.add_field(T1::VT_F2, T1::SIZE_F2, T1::ALIGNMENT_F2);
+            }
+            if !self.s2.is_empty() {
+                vtable.add_field(T1::VT_S2, T1::SIZE_S2, T1::ALIGNMENT_S2);
+            }
+            if !self.f3.is_empty() {
+                vtable.add_field(T1::VT_F3, T1::SIZE_F3, T1::ALIGNMENT_F3);
+            }
+            if !self.s3.is_empty() {
+                vtable.add_field(T1::VT_S3, T1::SIZE_S3, T1::ALIGNMENT_S3);
+            }
+            if !self.f4.is_empty() {
+                vtable.add_field(T1::VT_F4, T1::SIZE_F4, T1::ALIGNMENT_F4);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, T1::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.f1 != 0u64 {
+            builder.align(T1::ALIGNMENT_F1);
+            builder.push_scalar(self.f1);
+        }
+        if !self.s1.is_empty() {
+            builder.align(T1::ALIGNMENT_S1);
+            let offset_position = builder.tell();
+            builder.pad(T1::SIZE_S1);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ScalarVectorComponent::new(self.s1.iter().copied(), 1)),
+            ));
+        }
+        if let Some(f) = self.f2 {
+            builder.align(T1::ALIGNMENT_F2);
+            let offset_position = builder.tell();
+            builder.pad(T1::SIZE_F2);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(f),
+            ));
+        }
+        if !self.s2.is_empty() {
+            builder.align(T1::ALIGNMENT_S2);
+            let offset_position = builder.tell();
+            builder.pad(T1::SIZE_S2);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ScalarVectorComponent::new(self.s2.iter().copied(), 1)),
+            ));
+        }
+        if !self.f3.is_empty() {
+            builder.align(T1::ALIGNMENT_F3);
+            let offset_position = builder.tell();
+            builder.pad(T1::SIZE_F3);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ScalarVectorComponent::new(self.f3.iter().copied(), 8)),
+            ));
+        }
+        if !self.s3.is_empty() {
+            builder.align(T1::ALIGNMENT_S3);
+            let offset_position = builder.tell();
+            builder.pad(T1::SIZE_S3);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ScalarVectorComponent::new(self.s3.iter().copied(), 1)),
+            ));
+        }
+        if !self.f4.is_empty() {
+            builder.align(T1::ALIGNMENT_F4);
+            let offset_position = builder.tell();
+            builder.pad(T1::SIZE_F4);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(StringComponent::new(self.f4))
+            ));
+        }
+
+        table_start
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for T1 {
+    type Parameters = strategy::Config;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+        (
+            any::<u64>(),
+            vec(any::<u8>(), config.scalar_vector_len.clone()),
+            strategy::option(&config, any_with::<T2>(config.clone())),
+            vec(any::<u8>(), config.scalar_vector_len.clone()),
+            vec(any::<u64>(), config.scalar_vector_len.clone()),
+            vec(any::<u8>(), config.scalar_vector_len.clone()),
+            strategy::string(&config),
+        )
+            .prop_map(|(f1, s1, f2, s2, f3, s3, f4)| T1 {
+                f1,
+                s1,
+                f2,
+                s2,
+                f3,
+                s3,
+                f4,
+            })
+            .boxed()
+    }
+}
+
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct T2 {
     pub f1: u8,
@@ -174,4 +412,73 @@ impl<'c> Component<'c> for T2 {
 
         table_start
     }
+}
+
+/// Builds the table without consuming it, so the same value can be encoded repeatedly.
+impl<'c> Component<'c> for &'c T2 {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.f1 != 0u8 {
+                vtable.add_field(T2::VT_F1, T2::SIZE_F1, T2::ALIGNMENT_F1);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, T2::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.f1 != 0u8 {
+            builder.align(T2::ALIGNMENT_F1);
+            builder.push_scalar(self.f1);
+        }
+
+        table_start
+    }
+}
+
+/// The variant of `T2` which borrows its strings and vectors, see `--borrowed`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct T2Ref {
+    pub f1: u8,
+}
+
+impl<'c> Component<'c> for T2Ref {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.f1 != 0u8 {
+                vtable.add_field(T2::VT_F1, T2::SIZE_F1, T2::ALIGNMENT_F1);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, T2::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.f1 != 0u8 {
+            builder.align(T2::ALIGNMENT_F1);
+            builder.push_scalar(self.f1);
+        }
+
+        table_start
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for T2 {
+    type Parameters = strategy::Config;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<u8>(),
+        )
+            .prop_map(|(f1,)| T2 {
+                f1,
+            })
+            .boxed()
+    }
 }
\ No newline at end of file
@@ -1,251 +1,155 @@
 //! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
 use super::data_alignment_generated as reader;
 
+use flatbuffers::{self, Follow};
+use flatbuffers_verifier::{
+    lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+    verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+    TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+};
+use std::cmp::Ordering;
+use std::result;
 
 impl<'a> Verify for reader::T1<'a> {
-    fn verify(&self) -> Result {
-        let tab = self._tab;
-        let buf = tab.buf;
-        let buf_len = buf.len();
-
-        if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab_loc = {
-            let soffset_slice = &buf[tab.loc..];
-            let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-            if soffset >= 0 {
-                tab.loc.checked_sub(soffset as usize)
-            } else {
-                soffset
-                    .checked_neg()
-                    .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-            }
-        }
-        .ok_or(Error::OutOfBounds)?;
-        if vtab_loc
-            .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab = tab.vtable();
-        let vtab_num_bytes = vtab.num_bytes();
-        let object_inline_num_bytes = vtab.object_inline_num_bytes();
-        if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-            || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if vtab_loc
-            .checked_add(vtab_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if tab
-            .loc
-            .checked_add(object_inline_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-
-        for i in 0..vtab.num_fields() {
-            let voffset = vtab.get_field(i) as usize;
-            if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                || voffset >= object_inline_num_bytes
-            {
-                return Err(Error::OutOfBounds);
-            }
-        }
-
-        if Self::VT_F1 as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_F1) as usize;
-            if voffset > 0 && object_inline_num_bytes - voffset < 8 {
-                return Err(Error::OutOfBounds);
-            }
-        }
-
-        if Self::VT_S1 as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_S1) as usize;
-            if voffset > 0 {
-                if voffset + 4 > object_inline_num_bytes {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let s1_verifier = VectorVerifier::follow(
-                    buf,
-                    try_follow_uoffset(buf, tab.loc + voffset)?,
-                );
-                s1_verifier.verify_scalar_elements(1)?;
-            }
-        }
-
-        if Self::VT_F2 as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_F2) as usize;
-            if voffset > 0 {
-                if voffset + 4 > object_inline_num_bytes {
-                    return Err(Error::OutOfBounds);
-                }
-
-                if let Some(f) = self.f2() {
-                    f.verify()?;
-                }
-            }
-        }
+    fn verify_in(&self, verification: &Verification) -> Result {
+        let table = TableVerifier::new(&self._tab, verification)?;
+        table.verify_known_fields(18)?;
+        table.field_scalar::<u64>(Self::VT_F1)?;
+        table.field_default(Self::VT_F1, |value: u64| value == 0u64)?;
+        table.field_vector(Self::VT_S1, "s1", 1, |vector| {
+            vector.verify_scalar_elements(1, 1)
+        })?;
+        table.field_table::<reader::T2>(Self::VT_F2, "f2")?;
+        table.field_vector(Self::VT_S2, "s2", 1, |vector| {
+            vector.verify_scalar_elements(1, 1)
+        })?;
+        table.field_vector(Self::VT_F3, "f3", 8, |vector| {
+            vector.verify_scalar_elements(8, 8)
+        })?;
+        table.field_vector(Self::VT_S3, "s3", 1, |vector| {
+            vector.verify_scalar_elements(1, 1)
+        })?;
+        table.field_string(Self::VT_F4)?;
+        Ok(())
+    }
+}
 
-        if Self::VT_S2 as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_S2) as usize;
-            if voffset > 0 {
-                if voffset + 4 > object_inline_num_bytes {
-                    return Err(Error::OutOfBounds);
-                }
+impl Root for reader::T1<'_> {
+    type Reader<'a> = reader::T1<'a>;
+}
 
-                let s2_verifier = VectorVerifier::follow(
-                    buf,
-                    try_follow_uoffset(buf, tab.loc + voffset)?,
-                );
-                s2_verifier.verify_scalar_elements(1)?;
-            }
-        }
+impl<'a> reader::T1<'a> {
+    pub fn try_f1(&self) -> result::Result<u64, Error> {
+        try_field_loc(
+            &self._tab,
+            Self::VT_F1,
+            8,
+            8,
+        )?;
+        Ok(self.f1())
+    }
 
-        if Self::VT_F3 as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_F3) as usize;
-            if voffset > 0 {
-                if voffset + 4 > object_inline_num_bytes {
-                    return Err(Error::OutOfBounds);
-                }
+    pub fn try_s1(&self) -> result::Result<Option<&'a [u8]>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_S1,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+            verifier.verify_scalar_elements(1, 1)?;
+        }
+        Ok(self.s1())
+    }
 
-                let f3_verifier = VectorVerifier::follow(
-                    buf,
-                    try_follow_uoffset(buf, tab.loc + voffset)?,
-                );
-                f3_verifier.verify_scalar_elements(8)?;
-            }
+    pub fn try_f2(&self) -> result::Result<Option<reader::T2<'a>>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_F2,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            try_follow_uoffset(self._tab.buf, loc)?;
         }
+        Ok(self.f2())
+    }
 
-        if Self::VT_S3 as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_S3) as usize;
-            if voffset > 0 {
-                if voffset + 4 > object_inline_num_bytes {
-                    return Err(Error::OutOfBounds);
-                }
+    pub fn try_s2(&self) -> result::Result<Option<&'a [u8]>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_S2,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+            verifier.verify_scalar_elements(1, 1)?;
+        }
+        Ok(self.s2())
+    }
 
-                let s3_verifier = VectorVerifier::follow(
-                    buf,
-                    try_follow_uoffset(buf, tab.loc + voffset)?,
-                );
-                s3_verifier.verify_scalar_elements(1)?;
-            }
-        }
+    pub fn try_f3(&self) -> result::Result<Option<flatbuffers::Vector<'a, u64>>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_F3,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+            verifier.verify_scalar_elements(8, 8)?;
+        }
+        Ok(self.f3())
+    }
 
-        if Self::VT_F4 as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_F4) as usize;
-            if voffset > 0 {
-                if voffset + 4 > object_inline_num_bytes {
-                    return Err(Error::OutOfBounds);
-                }
+    pub fn try_s3(&self) -> result::Result<Option<&'a [u8]>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_S3,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+            verifier.verify_scalar_elements(1, 1)?;
+        }
+        Ok(self.s3())
+    }
 
-                StringVerifier::follow(buf, try_follow_uoffset(buf, tab.loc + voffset)?).verify()?;
-            }
+    pub fn try_f4(&self) -> result::Result<Option<&'a str>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_F4,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            StringVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?).verify()?;
         }
-
-        Ok(())
+        Ok(self.f4())
     }
 }
 
 impl<'a> Verify for reader::T2<'a> {
-    fn verify(&self) -> Result {
-        let tab = self._tab;
-        let buf = tab.buf;
-        let buf_len = buf.len();
-
-        if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab_loc = {
-            let soffset_slice = &buf[tab.loc..];
-            let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-            if soffset >= 0 {
-                tab.loc.checked_sub(soffset as usize)
-            } else {
-                soffset
-                    .checked_neg()
-                    .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-            }
-        }
-        .ok_or(Error::OutOfBounds)?;
-        if vtab_loc
-            .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab = tab.vtable();
-        let vtab_num_bytes = vtab.num_bytes();
-        let object_inline_num_bytes = vtab.object_inline_num_bytes();
-        if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-            || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if vtab_loc
-            .checked_add(vtab_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if tab
-            .loc
-            .checked_add(object_inline_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-
-        for i in 0..vtab.num_fields() {
-            let voffset = vtab.get_field(i) as usize;
-            if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                || voffset >= object_inline_num_bytes
-            {
-                return Err(Error::OutOfBounds);
-            }
-        }
+    fn verify_in(&self, verification: &Verification) -> Result {
+        let table = TableVerifier::new(&self._tab, verification)?;
+        table.verify_known_fields(6)?;
+        table.field_scalar::<u8>(Self::VT_F1)?;
+        table.field_default(Self::VT_F1, |value: u8| value == 0u8)?;
+        Ok(())
+    }
+}
 
-        if Self::VT_F1 as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_F1) as usize;
-            if voffset > 0 && object_inline_num_bytes - voffset < 1 {
-                return Err(Error::OutOfBounds);
-            }
-        }
+impl Root for reader::T2<'_> {
+    type Reader<'a> = reader::T2<'a>;
+}
 
-        Ok(())
+impl<'a> reader::T2<'a> {
+    pub fn try_f1(&self) -> result::Result<u8, Error> {
+        try_field_loc(
+            &self._tab,
+            Self::VT_F1,
+            1,
+            1,
+        )?;
+        Ok(self.f1())
     }
 }
\ No newline at end of file
@@ -1,14 +1,21 @@
 //! This file is auto-generated by cfbc.
-#![allow(unused_imports, clippy::large_enum_variant)]
+#![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
 
 use cfb::builder::{
     Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
-    ScalarVectorComponent, StringComponent,
+    ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
 };
 use cfb::scalar::Scalar;
 use cfb::types::{SOffset, SIZE_OF_SOFFSET};
-#[cfg(not(target_endian = "little"))]
-use std::mem::transmute;
+use std::cmp::Ordering;
+#[cfg(feature = "proptest")]
+use cfb::strategy;
+#[cfg(feature = "proptest")]
+use proptest::{
+    collection::vec,
+    prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+    sample::select,
+};
 
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Item {
@@ -50,6 +57,85 @@ impl<'c> Component<'c> for Item {
     }
 }
 
+/// Builds the table without consuming it, so the same value can be encoded repeatedly.
+impl<'c> Component<'c> for &'c Item {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if !self.name.is_empty() {
+                vtable.add_field(Item::VT_NAME, Item::SIZE_NAME, Item::ALIGNMENT_NAME);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Item::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if !self.name.is_empty() {
+            builder.align(Item::ALIGNMENT_NAME);
+            let offset_position = builder.tell();
+            builder.pad(Item::SIZE_NAME);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(StringComponent::new(&self.name))
+            ));
+        }
+
+        table_start
+    }
+}
+
+/// The variant of `Item` which borrows its strings and vectors, see `--borrowed`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct ItemRef<'a> {
+    pub name: &'a str,
+}
+
+impl<'c> Component<'c> for ItemRef<'c> {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if !self.name.is_empty() {
+                vtable.add_field(Item::VT_NAME, Item::SIZE_NAME, Item::ALIGNMENT_NAME);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Item::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if !self.name.is_empty() {
+            builder.align(Item::ALIGNMENT_NAME);
+            let offset_position = builder.tell();
+            builder.pad(Item::SIZE_NAME);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(StringComponent::new(self.name))
+            ));
+        }
+
+        table_start
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Item {
+    type Parameters = strategy::Config;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+        (
+            strategy::string(&config),
+        )
+            .prop_map(|(name,)| Item {
+                name,
+            })
+            .boxed()
+    }
+}
+
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Monster {
     pub name: String,
@@ -122,6 +208,139 @@ impl<'c> Component<'c> for Monster {
     }
 }
 
+/// Builds the table without consuming it, so the same value can be encoded repeatedly.
+impl<'c> Component<'c> for &'c Monster {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if !self.name.is_empty() {
+                vtable.add_field(Monster::VT_NAME, Monster::SIZE_NAME, Monster::ALIGNMENT_NAME);
+            }
+            if self.stat.is_some() {
+                vtable.add_field(Monster::VT_STAT, Monster::SIZE_STAT, Monster::ALIGNMENT_STAT);
+            }
+            if !self.loots.is_empty() {
+                vtable.add_field(Monster::VT_LOOTS, Monster::SIZE_LOOTS, Monster::ALIGNMENT_LOOTS);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Monster::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if !self.name.is_empty() {
+            builder.align(Monster::ALIGNMENT_NAME);
+            let offset_position = builder.tell();
+            builder.pad(Monster::SIZE_NAME);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(StringComponent::new(&self.name))
+            ));
+        }
+        if let Some(f) = &self.stat {
+            builder.align(Monster::ALIGNMENT_STAT);
+            let offset_position = builder.tell();
+            builder.pad(Monster::SIZE_STAT);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(f),
+            ));
+        }
+        if !self.loots.is_empty() {
+            builder.align(Monster::ALIGNMENT_LOOTS);
+            let offset_position = builder.tell();
+            builder.pad(Monster::SIZE_LOOTS);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ReferenceVectorComponent::new(self.loots.iter())),
+            ));
+        }
+
+        table_start
+    }
+}
+
+/// The variant of `Monster` which borrows its strings and vectors, see `--borrowed`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct MonsterRef<'a> {
+    pub name: &'a str,
+    pub stat: Option<StatRef>,
+    pub loots: &'a [ItemRef<'a>],
+}
+
+impl<'c> Component<'c> for MonsterRef<'c> {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if !self.name.is_empty() {
+                vtable.add_field(Monster::VT_NAME, Monster::SIZE_NAME, Monster::ALIGNMENT_NAME);
+            }
+            if self.stat.is_some() {
+                vtable.add_field(Monster::VT_STAT, Monster::SIZE_STAT, Monster::ALIGNMENT_STAT);
+            }
+            if !self.loots.is_empty() {
+                vtable.add_field(Monster::VT_LOOTS, Monster::SIZE_LOOTS, Monster::ALIGNMENT_LOOTS);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Monster::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if !self.name.is_empty() {
+            builder.align(Monster::ALIGNMENT_NAME);
+            let offset_position = builder.tell();
+            builder.pad(Monster::SIZE_NAME);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(StringComponent::new(self.name))
+            ));
+        }
+        if let Some(f) = self.stat {
+            builder.align(Monster::ALIGNMENT_STAT);
+            let offset_position = builder.tell();
+            builder.pad(Monster::SIZE_STAT);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(f),
+            ));
+        }
+        if !self.loots.is_empty() {
+            builder.align(Monster::ALIGNMENT_LOOTS);
+            let offset_position = builder.tell();
+            builder.pad(Monster::SIZE_LOOTS);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ReferenceVectorComponent::new(self.loots.iter().copied())),
+            ));
+        }
+
+        table_start
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Monster {
+    type Parameters = strategy::Config;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+        (
+            strategy::string(&config),
+            strategy::option(&config, any_with::<Stat>(config.clone())),
+            vec(any_with::<Item>(config.clone()), config.vector_len.clone()),
+        )
+            .prop_map(|(name, stat, loots)| Monster {
+                name,
+                stat,
+                loots,
+            })
+            .boxed()
+    }
+}
+
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Stat {
     pub hp: u32,
@@ -166,4 +385,90 @@ impl<'c> Component<'c> for Stat {
 
         table_start
     }
+}
+
+/// Builds the table without consuming it, so the same value can be encoded repeatedly.
+impl<'c> Component<'c> for &'c Stat {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.hp != 0u32 {
+                vtable.add_field(Stat::VT_HP, Stat::SIZE_HP, Stat::ALIGNMENT_HP);
+            }
+            if self.mp != 0u32 {
+                vtable.add_field(Stat::VT_MP, Stat::SIZE_MP, Stat::ALIGNMENT_MP);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Stat::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.hp != 0u32 {
+            builder.align(Stat::ALIGNMENT_HP);
+            builder.push_scalar(self.hp);
+        }
+        if self.mp != 0u32 {
+            builder.align(Stat::ALIGNMENT_MP);
+            builder.push_scalar(self.mp);
+        }
+
+        table_start
+    }
+}
+
+/// The variant of `Stat` which borrows its strings and vectors, see `--borrowed`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct StatRef {
+    pub hp: u32,
+    pub mp: u32,
+}
+
+impl<'c> Component<'c> for StatRef {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.hp != 0u32 {
+                vtable.add_field(Stat::VT_HP, Stat::SIZE_HP, Stat::ALIGNMENT_HP);
+            }
+            if self.mp != 0u32 {
+                vtable.add_field(Stat::VT_MP, Stat::SIZE_MP, Stat::ALIGNMENT_MP);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Stat::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.hp != 0u32 {
+            builder.align(Stat::ALIGNMENT_HP);
+            builder.push_scalar(self.hp);
+        }
+        if self.mp != 0u32 {
+            builder.align(Stat::ALIGNMENT_MP);
+            builder.push_scalar(self.mp);
+        }
+
+        table_start
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Stat {
+    type Parameters = strategy::Config;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<u32>(),
+            any::<u32>(),
+        )
+            .prop_map(|(hp, mp)| Stat {
+                hp,
+                mp,
+            })
+            .boxed()
+    }
 }
\ No newline at end of file
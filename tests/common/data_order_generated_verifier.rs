@@ -1,281 +1,134 @@
 //! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
 use super::data_order_generated as reader;
 
+use flatbuffers::{self, Follow};
+use flatbuffers_verifier::{
+    lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+    verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+    TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+};
+use std::cmp::Ordering;
+use std::result;
 
 impl<'a> Verify for reader::Item<'a> {
-    fn verify(&self) -> Result {
-        let tab = self._tab;
-        let buf = tab.buf;
-        let buf_len = buf.len();
-
-        if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab_loc = {
-            let soffset_slice = &buf[tab.loc..];
-            let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-            if soffset >= 0 {
-                tab.loc.checked_sub(soffset as usize)
-            } else {
-                soffset
-                    .checked_neg()
-                    .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-            }
-        }
-        .ok_or(Error::OutOfBounds)?;
-        if vtab_loc
-            .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab = tab.vtable();
-        let vtab_num_bytes = vtab.num_bytes();
-        let object_inline_num_bytes = vtab.object_inline_num_bytes();
-        if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-            || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if vtab_loc
-            .checked_add(vtab_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if tab
-            .loc
-            .checked_add(object_inline_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-
-        for i in 0..vtab.num_fields() {
-            let voffset = vtab.get_field(i) as usize;
-            if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                || voffset >= object_inline_num_bytes
-            {
-                return Err(Error::OutOfBounds);
-            }
-        }
-
-        if Self::VT_NAME as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_NAME) as usize;
-            if voffset > 0 {
-                if voffset + 4 > object_inline_num_bytes {
-                    return Err(Error::OutOfBounds);
-                }
-
-                StringVerifier::follow(buf, try_follow_uoffset(buf, tab.loc + voffset)?).verify()?;
-            }
-        }
-
+    fn verify_in(&self, verification: &Verification) -> Result {
+        let table = TableVerifier::new(&self._tab, verification)?;
+        table.verify_known_fields(6)?;
+        table.field_string(Self::VT_NAME)?;
         Ok(())
     }
 }
 
-impl<'a> Verify for reader::Monster<'a> {
-    fn verify(&self) -> Result {
-        let tab = self._tab;
-        let buf = tab.buf;
-        let buf_len = buf.len();
-
-        if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab_loc = {
-            let soffset_slice = &buf[tab.loc..];
-            let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-            if soffset >= 0 {
-                tab.loc.checked_sub(soffset as usize)
-            } else {
-                soffset
-                    .checked_neg()
-                    .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-            }
-        }
-        .ok_or(Error::OutOfBounds)?;
-        if vtab_loc
-            .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab = tab.vtable();
-        let vtab_num_bytes = vtab.num_bytes();
-        let object_inline_num_bytes = vtab.object_inline_num_bytes();
-        if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-            || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if vtab_loc
-            .checked_add(vtab_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if tab
-            .loc
-            .checked_add(object_inline_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-
-        for i in 0..vtab.num_fields() {
-            let voffset = vtab.get_field(i) as usize;
-            if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                || voffset >= object_inline_num_bytes
-            {
-                return Err(Error::OutOfBounds);
-            }
-        }
-
-        if Self::VT_NAME as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_NAME) as usize;
-            if voffset > 0 {
-                if voffset + 4 > object_inline_num_bytes {
-                    return Err(Error::OutOfBounds);
-                }
-
-                StringVerifier::follow(buf, try_follow_uoffset(buf, tab.loc + voffset)?).verify()?;
-            }
-        }
-
-        if Self::VT_STAT as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_STAT) as usize;
-            if voffset > 0 {
-                if voffset + 4 > object_inline_num_bytes {
-                    return Err(Error::OutOfBounds);
-                }
-
-                if let Some(f) = self.stat() {
-                    f.verify()?;
-                }
-            }
-        }
-
-        if Self::VT_LOOTS as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_LOOTS) as usize;
-            if voffset > 0 {
-                if voffset + 4 > object_inline_num_bytes {
-                    return Err(Error::OutOfBounds);
-                }
+impl Root for reader::Item<'_> {
+    type Reader<'a> = reader::Item<'a>;
+}
 
-                let loots_verifier = VectorVerifier::follow(
-                    buf,
-                    try_follow_uoffset(buf, tab.loc + voffset)?,
-                );
-                loots_verifier
-                    .verify_reference_elements::<reader::Item>()?;
-            }
-        }
+impl<'a> reader::Item<'a> {
+    pub fn try_name(&self) -> result::Result<Option<&'a str>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_NAME,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            StringVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?).verify()?;
+        }
+        Ok(self.name())
+    }
+}
 
+impl<'a> Verify for reader::Monster<'a> {
+    fn verify_in(&self, verification: &Verification) -> Result {
+        let table = TableVerifier::new(&self._tab, verification)?;
+        table.verify_known_fields(10)?;
+        table.field_string(Self::VT_NAME)?;
+        table.field_table::<reader::Stat>(Self::VT_STAT, "stat")?;
+        table.field_vector(Self::VT_LOOTS, "loots", flatbuffers::SIZE_UOFFSET, |vector| {
+            vector.verify_reference_elements::<reader::Item>(verification)
+        })?;
         Ok(())
     }
 }
 
-impl<'a> Verify for reader::Stat<'a> {
-    fn verify(&self) -> Result {
-        let tab = self._tab;
-        let buf = tab.buf;
-        let buf_len = buf.len();
+impl Root for reader::Monster<'_> {
+    type Reader<'a> = reader::Monster<'a>;
+}
 
-        if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-            return Err(Error::OutOfBounds);
-        }
+impl<'a> reader::Monster<'a> {
+    pub fn try_name(&self) -> result::Result<Option<&'a str>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_NAME,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            StringVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?).verify()?;
+        }
+        Ok(self.name())
+    }
 
-        let vtab_loc = {
-            let soffset_slice = &buf[tab.loc..];
-            let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-            if soffset >= 0 {
-                tab.loc.checked_sub(soffset as usize)
-            } else {
-                soffset
-                    .checked_neg()
-                    .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-            }
-        }
-        .ok_or(Error::OutOfBounds)?;
-        if vtab_loc
-            .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
+    pub fn try_stat(&self) -> result::Result<Option<reader::Stat<'a>>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_STAT,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            try_follow_uoffset(self._tab.buf, loc)?;
         }
+        Ok(self.stat())
+    }
 
-        let vtab = tab.vtable();
-        let vtab_num_bytes = vtab.num_bytes();
-        let object_inline_num_bytes = vtab.object_inline_num_bytes();
-        if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-            || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if vtab_loc
-            .checked_add(vtab_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if tab
-            .loc
-            .checked_add(object_inline_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
+    pub fn try_loots(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::Item<'a>>>>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_LOOTS,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+            verifier.verify_reference_offsets()?;
+        }
+        Ok(self.loots())
+    }
+}
 
-        for i in 0..vtab.num_fields() {
-            let voffset = vtab.get_field(i) as usize;
-            if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                || voffset >= object_inline_num_bytes
-            {
-                return Err(Error::OutOfBounds);
-            }
-        }
+impl<'a> Verify for reader::Stat<'a> {
+    fn verify_in(&self, verification: &Verification) -> Result {
+        let table = TableVerifier::new(&self._tab, verification)?;
+        table.verify_known_fields(8)?;
+        table.field_scalar::<u32>(Self::VT_HP)?;
+        table.field_default(Self::VT_HP, |value: u32| value == 0u32)?;
+        table.field_scalar::<u32>(Self::VT_MP)?;
+        table.field_default(Self::VT_MP, |value: u32| value == 0u32)?;
+        Ok(())
+    }
+}
 
-        if Self::VT_HP as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_HP) as usize;
-            if voffset > 0 && object_inline_num_bytes - voffset < 4 {
-                return Err(Error::OutOfBounds);
-            }
-        }
+impl Root for reader::Stat<'_> {
+    type Reader<'a> = reader::Stat<'a>;
+}
 
-        if Self::VT_MP as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_MP) as usize;
-            if voffset > 0 && object_inline_num_bytes - voffset < 4 {
-                return Err(Error::OutOfBounds);
-            }
-        }
+impl<'a> reader::Stat<'a> {
+    pub fn try_hp(&self) -> result::Result<u32, Error> {
+        try_field_loc(
+            &self._tab,
+            Self::VT_HP,
+            4,
+            4,
+        )?;
+        Ok(self.hp())
+    }
 
-        Ok(())
+    pub fn try_mp(&self) -> result::Result<u32, Error> {
+        try_field_loc(
+            &self._tab,
+            Self::VT_MP,
+            4,
+            4,
+        )?;
+        Ok(self.mp())
     }
 }
\ No newline at end of file
@@ -0,0 +1,394 @@
+//! This file is auto-generated by cfbc.
+pub mod example {
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
+
+    use cfb::builder::{
+        Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
+    };
+    use cfb::scalar::Scalar;
+    use cfb::types::{SOffset, SIZE_OF_SOFFSET};
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
+
+    /// Values of the enum `Color`, including the ones not declared in the schema, such as values
+    /// added by newer schemas, so they are written back unchanged.
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Color(pub i8);
+
+    #[allow(non_upper_case_globals)]
+    impl Color {
+        pub const Red: Color = Color(0);
+        pub const Green: Color = Color(1);
+        pub const Blue: Color = Color(2);
+
+        /// Tests whether the value is declared in the schema.
+        pub const fn is_known(self) -> bool {
+            matches!(self.0, 0..=2)
+        }
+    }
+
+    impl Default for Color {
+        fn default() -> Self {
+            Color::Red
+        }
+    }
+
+    impl std::fmt::Debug for Color {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match *self {
+                Color::Red => f.write_str("Red"),
+                Color::Green => f.write_str("Green"),
+                Color::Blue => f.write_str("Blue"),
+                Color(raw) => f.debug_tuple("Unknown").field(&raw).finish(),
+            }
+        }
+    }
+
+    impl Scalar for Color {
+        fn to_le(self) -> Self {
+            Color(self.0.to_le())
+        }
+
+        fn from_le(x: Self) -> Self {
+            Color(i8::from_le(x.0))
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Color {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            select(vec![
+                Color::Red,
+                Color::Green,
+                Color::Blue,
+            ])
+            .boxed()
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Hero {
+        pub hp: u32,
+        pub mp: i16,
+        pub speed: f32,
+        pub alive: bool,
+        pub color: Color,
+        pub exp: u64,
+        pub name: String,
+    }
+
+    impl Default for Hero {
+        fn default() -> Self {
+            Hero {
+                hp: 100u32,
+                mp: -1i16,
+                speed: 1.5f32,
+                alive: true,
+                color: Color::Blue,
+                exp: Default::default(),
+                name: Default::default(),
+            }
+        }
+    }
+
+    impl Hero {
+        const VT_HP: usize = 4;
+        const SIZE_HP: usize = 4;
+        const ALIGNMENT_HP: usize = 4;
+        const VT_MP: usize = 6;
+        const SIZE_MP: usize = 2;
+        const ALIGNMENT_MP: usize = 2;
+        const VT_SPEED: usize = 8;
+        const SIZE_SPEED: usize = 4;
+        const ALIGNMENT_SPEED: usize = 4;
+        const VT_ALIVE: usize = 10;
+        const SIZE_ALIVE: usize = 1;
+        const ALIGNMENT_ALIVE: usize = 1;
+        const VT_COLOR: usize = 12;
+        const SIZE_COLOR: usize = 1;
+        const ALIGNMENT_COLOR: usize = 1;
+        const VT_EXP: usize = 14;
+        const SIZE_EXP: usize = 8;
+        const ALIGNMENT_EXP: usize = 8;
+        const VT_NAME: usize = 16;
+        const SIZE_NAME: usize = 4;
+        const ALIGNMENT_NAME: usize = 4;
+        const ALIGNMENT: usize = 8;
+    }
+
+    impl<'c> Component<'c> for Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.exp != 0u64 {
+                    vtable.add_field(Self::VT_EXP, Self::SIZE_EXP, Self::ALIGNMENT_EXP);
+                }
+                if self.hp != 100u32 {
+                    vtable.add_field(Self::VT_HP, Self::SIZE_HP, Self::ALIGNMENT_HP);
+                }
+                if self.speed != 1.5f32 {
+                    vtable.add_field(Self::VT_SPEED, Self::SIZE_SPEED, Self::ALIGNMENT_SPEED);
+                }
+                if !self.name.is_empty() {
+                    vtable.add_field(Self::VT_NAME, Self::SIZE_NAME, Self::ALIGNMENT_NAME);
+                }
+                if self.mp != -1i16 {
+                    vtable.add_field(Self::VT_MP, Self::SIZE_MP, Self::ALIGNMENT_MP);
+                }
+                if !self.alive {
+                    vtable.add_field(Self::VT_ALIVE, Self::SIZE_ALIVE, Self::ALIGNMENT_ALIVE);
+                }
+                if self.color != Color::Blue {
+                    vtable.add_field(Self::VT_COLOR, Self::SIZE_COLOR, Self::ALIGNMENT_COLOR);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.exp != 0u64 {
+                builder.align(Self::ALIGNMENT_EXP);
+                builder.push_scalar(self.exp);
+            }
+            if self.hp != 100u32 {
+                builder.align(Self::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+            if self.speed != 1.5f32 {
+                builder.align(Self::ALIGNMENT_SPEED);
+                builder.push_scalar(self.speed);
+            }
+            if !self.name.is_empty() {
+                builder.align(Self::ALIGNMENT_NAME);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_NAME);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(self.name))
+                ));
+            }
+            if self.mp != -1i16 {
+                builder.align(Self::ALIGNMENT_MP);
+                builder.push_scalar(self.mp);
+            }
+            if !self.alive {
+                builder.align(Self::ALIGNMENT_ALIVE);
+                builder.push_scalar(self.alive);
+            }
+            if self.color != Color::Blue {
+                builder.align(Self::ALIGNMENT_COLOR);
+                builder.push_scalar(self.color);
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.exp != 0u64 {
+                    vtable.add_field(Hero::VT_EXP, Hero::SIZE_EXP, Hero::ALIGNMENT_EXP);
+                }
+                if self.hp != 100u32 {
+                    vtable.add_field(Hero::VT_HP, Hero::SIZE_HP, Hero::ALIGNMENT_HP);
+                }
+                if self.speed != 1.5f32 {
+                    vtable.add_field(Hero::VT_SPEED, Hero::SIZE_SPEED, Hero::ALIGNMENT_SPEED);
+                }
+                if !self.name.is_empty() {
+                    vtable.add_field(Hero::VT_NAME, Hero::SIZE_NAME, Hero::ALIGNMENT_NAME);
+                }
+                if self.mp != -1i16 {
+                    vtable.add_field(Hero::VT_MP, Hero::SIZE_MP, Hero::ALIGNMENT_MP);
+                }
+                if !self.alive {
+                    vtable.add_field(Hero::VT_ALIVE, Hero::SIZE_ALIVE, Hero::ALIGNMENT_ALIVE);
+                }
+                if self.color != Color::Blue {
+                    vtable.add_field(Hero::VT_COLOR, Hero::SIZE_COLOR, Hero::ALIGNMENT_COLOR);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.exp != 0u64 {
+                builder.align(Hero::ALIGNMENT_EXP);
+                builder.push_scalar(self.exp);
+            }
+            if self.hp != 100u32 {
+                builder.align(Hero::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+            if self.speed != 1.5f32 {
+                builder.align(Hero::ALIGNMENT_SPEED);
+                builder.push_scalar(self.speed);
+            }
+            if !self.name.is_empty() {
+                builder.align(Hero::ALIGNMENT_NAME);
+                let offset_position = builder.tell();
+                builder.pad(Hero::SIZE_NAME);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(&self.name))
+                ));
+            }
+            if self.mp != -1i16 {
+                builder.align(Hero::ALIGNMENT_MP);
+                builder.push_scalar(self.mp);
+            }
+            if !self.alive {
+                builder.align(Hero::ALIGNMENT_ALIVE);
+                builder.push_scalar(self.alive);
+            }
+            if self.color != Color::Blue {
+                builder.align(Hero::ALIGNMENT_COLOR);
+                builder.push_scalar(self.color);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Hero` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct HeroRef<'a> {
+        pub hp: u32,
+        pub mp: i16,
+        pub speed: f32,
+        pub alive: bool,
+        pub color: Color,
+        pub exp: u64,
+        pub name: &'a str,
+    }
+
+    impl<'a> Default for HeroRef<'a> {
+        fn default() -> Self {
+            HeroRef {
+                hp: 100u32,
+                mp: -1i16,
+                speed: 1.5f32,
+                alive: true,
+                color: Color::Blue,
+                exp: Default::default(),
+                name: Default::default(),
+            }
+        }
+    }
+
+    impl<'c> Component<'c> for HeroRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.exp != 0u64 {
+                    vtable.add_field(Hero::VT_EXP, Hero::SIZE_EXP, Hero::ALIGNMENT_EXP);
+                }
+                if self.hp != 100u32 {
+                    vtable.add_field(Hero::VT_HP, Hero::SIZE_HP, Hero::ALIGNMENT_HP);
+                }
+                if self.speed != 1.5f32 {
+                    vtable.add_field(Hero::VT_SPEED, Hero::SIZE_SPEED, Hero::ALIGNMENT_SPEED);
+                }
+                if !self.name.is_empty() {
+                    vtable.add_field(Hero::VT_NAME, Hero::SIZE_NAME, Hero::ALIGNMENT_NAME);
+                }
+                if self.mp != -1i16 {
+                    vtable.add_field(Hero::VT_MP, Hero::SIZE_MP, Hero::ALIGNMENT_MP);
+                }
+                if !self.alive {
+                    vtable.add_field(Hero::VT_ALIVE, Hero::SIZE_ALIVE, Hero::ALIGNMENT_ALIVE);
+                }
+                if self.color != Color::Blue {
+                    vtable.add_field(Hero::VT_COLOR, Hero::SIZE_COLOR, Hero::ALIGNMENT_COLOR);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.exp != 0u64 {
+                builder.align(Hero::ALIGNMENT_EXP);
+                builder.push_scalar(self.exp);
+            }
+            if self.hp != 100u32 {
+                builder.align(Hero::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+            if self.speed != 1.5f32 {
+                builder.align(Hero::ALIGNMENT_SPEED);
+                builder.push_scalar(self.speed);
+            }
+            if !self.name.is_empty() {
+                builder.align(Hero::ALIGNMENT_NAME);
+                let offset_position = builder.tell();
+                builder.pad(Hero::SIZE_NAME);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(self.name))
+                ));
+            }
+            if self.mp != -1i16 {
+                builder.align(Hero::ALIGNMENT_MP);
+                builder.push_scalar(self.mp);
+            }
+            if !self.alive {
+                builder.align(Hero::ALIGNMENT_ALIVE);
+                builder.push_scalar(self.alive);
+            }
+            if self.color != Color::Blue {
+                builder.align(Hero::ALIGNMENT_COLOR);
+                builder.push_scalar(self.color);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Hero {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u32>(),
+                any::<i16>(),
+                any::<f32>(),
+                any::<bool>(),
+                any::<Color>(),
+                any::<u64>(),
+                strategy::string(&config),
+            )
+                .prop_map(|(hp, mp, speed, alive, color, exp, name)| Hero {
+                    hp,
+                    mp,
+                    speed,
+                    alive,
+                    color,
+                    exp,
+                    name,
+                })
+                .boxed()
+        }
+    }
+}
@@ -0,0 +1,247 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+pub mod example {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+
+#[allow(non_camel_case_types)]
+#[repr(i8)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Color {
+  Red = 0,
+  Green = 1,
+  Blue = 2,
+
+}
+
+const ENUM_MIN_COLOR: i8 = 0;
+const ENUM_MAX_COLOR: i8 = 2;
+
+impl<'a> flatbuffers::Follow<'a> for Color {
+  type Inner = Self;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::read_scalar_at::<Self>(buf, loc)
+  }
+}
+
+impl flatbuffers::EndianScalar for Color {
+  #[inline]
+  fn to_little_endian(self) -> Self {
+    let n = i8::to_le(self as i8);
+    let p = &n as *const i8 as *const Color;
+    unsafe { *p }
+  }
+  #[inline]
+  fn from_little_endian(self) -> Self {
+    let n = i8::from_le(self as i8);
+    let p = &n as *const i8 as *const Color;
+    unsafe { *p }
+  }
+}
+
+impl flatbuffers::Push for Color {
+    type Output = Color;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        flatbuffers::emplace_scalar::<Color>(dst, *self);
+    }
+}
+
+#[allow(non_camel_case_types)]
+const ENUM_VALUES_COLOR:[Color; 3] = [
+  Color::Red,
+  Color::Green,
+  Color::Blue
+];
+
+#[allow(non_camel_case_types)]
+const ENUM_NAMES_COLOR:[&'static str; 3] = [
+    "Red",
+    "Green",
+    "Blue"
+];
+
+pub fn enum_name_color(e: Color) -> &'static str {
+  let index: usize = e as usize;
+  ENUM_NAMES_COLOR[index]
+}
+
+pub enum HeroOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Hero<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Hero<'a> {
+    type Inner = Hero<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Hero<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Hero {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args HeroArgs<'args>) -> flatbuffers::WIPOffset<Hero<'bldr>> {
+      let mut builder = HeroBuilder::new(_fbb);
+      builder.add_exp(args.exp);
+      if let Some(x) = args.name { builder.add_name(x); }
+      builder.add_speed(args.speed);
+      builder.add_hp(args.hp);
+      builder.add_mp(args.mp);
+      builder.add_color(args.color);
+      builder.add_alive(args.alive);
+      builder.finish()
+    }
+
+    pub const VT_HP: flatbuffers::VOffsetT = 4;
+    pub const VT_MP: flatbuffers::VOffsetT = 6;
+    pub const VT_SPEED: flatbuffers::VOffsetT = 8;
+    pub const VT_ALIVE: flatbuffers::VOffsetT = 10;
+    pub const VT_COLOR: flatbuffers::VOffsetT = 12;
+    pub const VT_EXP: flatbuffers::VOffsetT = 14;
+    pub const VT_NAME: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub fn hp(&self) -> u32 {
+    self._tab.get::<u32>(Hero::VT_HP, Some(100)).unwrap()
+  }
+  #[inline]
+  pub fn mp(&self) -> i16 {
+    self._tab.get::<i16>(Hero::VT_MP, Some(-1)).unwrap()
+  }
+  #[inline]
+  pub fn speed(&self) -> f32 {
+    self._tab.get::<f32>(Hero::VT_SPEED, Some(1.5)).unwrap()
+  }
+  #[inline]
+  pub fn alive(&self) -> bool {
+    self._tab.get::<bool>(Hero::VT_ALIVE, Some(true)).unwrap()
+  }
+  #[inline]
+  pub fn color(&self) -> Color {
+    self._tab.get::<Color>(Hero::VT_COLOR, Some(Color::Blue)).unwrap()
+  }
+  #[inline]
+  pub fn exp(&self) -> u64 {
+    self._tab.get::<u64>(Hero::VT_EXP, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Hero::VT_NAME, None)
+  }
+}
+
+pub struct HeroArgs<'a> {
+    pub hp: u32,
+    pub mp: i16,
+    pub speed: f32,
+    pub alive: bool,
+    pub color: Color,
+    pub exp: u64,
+    pub name: Option<flatbuffers::WIPOffset<&'a  str>>,
+}
+impl<'a> Default for HeroArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        HeroArgs {
+            hp: 100,
+            mp: -1,
+            speed: 1.5,
+            alive: true,
+            color: Color::Blue,
+            exp: 0,
+            name: None,
+        }
+    }
+}
+pub struct HeroBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HeroBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_hp(&mut self, hp: u32) {
+    self.fbb_.push_slot::<u32>(Hero::VT_HP, hp, 100);
+  }
+  #[inline]
+  pub fn add_mp(&mut self, mp: i16) {
+    self.fbb_.push_slot::<i16>(Hero::VT_MP, mp, -1);
+  }
+  #[inline]
+  pub fn add_speed(&mut self, speed: f32) {
+    self.fbb_.push_slot::<f32>(Hero::VT_SPEED, speed, 1.5);
+  }
+  #[inline]
+  pub fn add_alive(&mut self, alive: bool) {
+    self.fbb_.push_slot::<bool>(Hero::VT_ALIVE, alive, true);
+  }
+  #[inline]
+  pub fn add_color(&mut self, color: Color) {
+    self.fbb_.push_slot::<Color>(Hero::VT_COLOR, color, Color::Blue);
+  }
+  #[inline]
+  pub fn add_exp(&mut self, exp: u64) {
+    self.fbb_.push_slot::<u64>(Hero::VT_EXP, exp, 0);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Hero::VT_NAME, name);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> HeroBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    HeroBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Hero<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+#[inline]
+pub fn get_root_as_hero<'a>(buf: &'a [u8]) -> Hero<'a> {
+  flatbuffers::get_root::<Hero<'a>>(buf)
+}
+
+#[inline]
+pub fn get_size_prefixed_root_as_hero<'a>(buf: &'a [u8]) -> Hero<'a> {
+  flatbuffers::get_size_prefixed_root::<Hero<'a>>(buf)
+}
+
+#[inline]
+pub fn finish_hero_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Hero<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_hero_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Hero<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
+}  // pub mod example
+
@@ -0,0 +1,138 @@
+//! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
+use super::default_value_generated as reader;
+
+pub mod example {
+    #![allow(unused_imports)]
+
+    use super::reader::example as reader;
+    use flatbuffers::{self, Follow};
+    use flatbuffers_verifier::{
+        lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+        verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+        TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+    };
+    use std::cmp::Ordering;
+    use std::result;
+
+    impl<'a> Verify for reader::Hero<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(18)?;
+            table.field_scalar::<u32>(Self::VT_HP)?;
+            table.field_default(Self::VT_HP, |value: u32| value == 100u32)?;
+            table.field_scalar::<i16>(Self::VT_MP)?;
+            table.field_default(Self::VT_MP, |value: i16| value == -1i16)?;
+            table.field_float::<f32>(Self::VT_SPEED)?;
+            table.field_default(Self::VT_SPEED, |value: f32| value == 1.5f32)?;
+            table.field_bool(Self::VT_ALIVE)?;
+            table.field_default(Self::VT_ALIVE, |value: u8| value == 1)?;
+            table.field_enum(Self::VT_COLOR, |value: i8| matches!(value, 0..=2))?;
+            table.field_default(Self::VT_COLOR, |value: i8| value == 2i8)?;
+            table.field_scalar::<u64>(Self::VT_EXP)?;
+            table.field_default(Self::VT_EXP, |value: u64| value == 0u64)?;
+            table.field_string(Self::VT_NAME)?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Hero<'_> {
+        type Reader<'a> = reader::Hero<'a>;
+    }
+
+    impl<'a> reader::Hero<'a> {
+        pub fn try_hp(&self) -> result::Result<u32, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_HP,
+                4,
+                4,
+            )?;
+            Ok(self.hp())
+        }
+
+        pub fn try_mp(&self) -> result::Result<i16, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_MP,
+                2,
+                2,
+            )?;
+            Ok(self.mp())
+        }
+
+        pub fn try_speed(&self) -> result::Result<f32, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_SPEED,
+                4,
+                4,
+            )? {
+                verify_float::<f32>(self._tab.buf, loc)?;
+            }
+            Ok(self.speed())
+        }
+
+        pub fn try_alive(&self) -> result::Result<bool, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_ALIVE,
+                1,
+                1,
+            )? {
+                if self._tab.buf[loc] > 1 {
+                    return Err(Error::InvalidBool);
+                }
+            }
+            Ok(self.alive())
+        }
+
+        pub fn try_color(&self) -> result::Result<reader::Color, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_COLOR,
+                1,
+                1,
+            )? {
+                if !matches!(
+                    read_scalar_at::<i8>(self._tab.buf, loc),
+                    0..=2
+                ) {
+                    return Err(Error::UnknownEnumValue);
+                }
+            }
+            Ok(self.color())
+        }
+
+        /// Reads `color` as the underlying value of the enum, which may be undeclared in
+        /// the schema, see `Options::accept_unknown_enum_values`.
+        pub fn raw_color(&self) -> i8 {
+            self._tab
+                .get::<i8>(Self::VT_COLOR, Some(2))
+                .unwrap()
+        }
+
+        pub fn try_exp(&self) -> result::Result<u64, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_EXP,
+                8,
+                8,
+            )?;
+            Ok(self.exp())
+        }
+
+        pub fn try_name(&self) -> result::Result<Option<&'a str>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_NAME,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                StringVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?).verify()?;
+            }
+            Ok(self.name())
+        }
+    }
+}
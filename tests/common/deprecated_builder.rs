@@ -0,0 +1,152 @@
+//! This file is auto-generated by cfbc.
+pub mod example {
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
+
+    use cfb::builder::{
+        Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
+    };
+    use cfb::scalar::Scalar;
+    use cfb::types::{SOffset, SIZE_OF_SOFFSET};
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Hero {
+        pub hp: u32,
+        pub mp: i16,
+    }
+
+    impl Hero {
+        const VT_HP: usize = 6;
+        const SIZE_HP: usize = 4;
+        const ALIGNMENT_HP: usize = 4;
+        const VT_MP: usize = 10;
+        const SIZE_MP: usize = 2;
+        const ALIGNMENT_MP: usize = 2;
+        const ALIGNMENT: usize = 4;
+    }
+
+    impl<'c> Component<'c> for Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Self::VT_HP, Self::SIZE_HP, Self::ALIGNMENT_HP);
+                }
+                if self.mp != 0i16 {
+                    vtable.add_field(Self::VT_MP, Self::SIZE_MP, Self::ALIGNMENT_MP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Self::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+            if self.mp != 0i16 {
+                builder.align(Self::ALIGNMENT_MP);
+                builder.push_scalar(self.mp);
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Hero::VT_HP, Hero::SIZE_HP, Hero::ALIGNMENT_HP);
+                }
+                if self.mp != 0i16 {
+                    vtable.add_field(Hero::VT_MP, Hero::SIZE_MP, Hero::ALIGNMENT_MP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Hero::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+            if self.mp != 0i16 {
+                builder.align(Hero::ALIGNMENT_MP);
+                builder.push_scalar(self.mp);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Hero` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct HeroRef {
+        pub hp: u32,
+        pub mp: i16,
+    }
+
+    impl<'c> Component<'c> for HeroRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Hero::VT_HP, Hero::SIZE_HP, Hero::ALIGNMENT_HP);
+                }
+                if self.mp != 0i16 {
+                    vtable.add_field(Hero::VT_MP, Hero::SIZE_MP, Hero::ALIGNMENT_MP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Hero::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+            if self.mp != 0i16 {
+                builder.align(Hero::ALIGNMENT_MP);
+                builder.push_scalar(self.mp);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Hero {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u32>(),
+                any::<i16>(),
+            )
+                .prop_map(|(hp, mp)| Hero {
+                    hp,
+                    mp,
+                })
+                .boxed()
+        }
+    }
+}
@@ -0,0 +1,124 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+pub mod example {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+
+pub enum HeroOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Hero<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Hero<'a> {
+    type Inner = Hero<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Hero<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Hero {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args HeroArgs) -> flatbuffers::WIPOffset<Hero<'bldr>> {
+      let mut builder = HeroBuilder::new(_fbb);
+      builder.add_hp(args.hp);
+      builder.add_mp(args.mp);
+      builder.finish()
+    }
+
+    pub const VT_HP: flatbuffers::VOffsetT = 6;
+    pub const VT_MP: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub fn hp(&self) -> u32 {
+    self._tab.get::<u32>(Hero::VT_HP, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn mp(&self) -> i16 {
+    self._tab.get::<i16>(Hero::VT_MP, Some(0)).unwrap()
+  }
+}
+
+pub struct HeroArgs {
+    pub hp: u32,
+    pub mp: i16,
+}
+impl<'a> Default for HeroArgs {
+    #[inline]
+    fn default() -> Self {
+        HeroArgs {
+            hp: 0,
+            mp: 0,
+        }
+    }
+}
+pub struct HeroBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HeroBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_hp(&mut self, hp: u32) {
+    self.fbb_.push_slot::<u32>(Hero::VT_HP, hp, 0);
+  }
+  #[inline]
+  pub fn add_mp(&mut self, mp: i16) {
+    self.fbb_.push_slot::<i16>(Hero::VT_MP, mp, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> HeroBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    HeroBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Hero<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+#[inline]
+pub fn get_root_as_hero<'a>(buf: &'a [u8]) -> Hero<'a> {
+  flatbuffers::get_root::<Hero<'a>>(buf)
+}
+
+#[inline]
+pub fn get_size_prefixed_root_as_hero<'a>(buf: &'a [u8]) -> Hero<'a> {
+  flatbuffers::get_size_prefixed_root::<Hero<'a>>(buf)
+}
+
+#[inline]
+pub fn finish_hero_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Hero<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_hero_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Hero<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
+}  // pub mod example
+
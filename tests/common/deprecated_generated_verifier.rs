@@ -0,0 +1,62 @@
+//! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
+use super::deprecated_generated as reader;
+
+pub mod example {
+    #![allow(unused_imports)]
+
+    use super::reader::example as reader;
+    use flatbuffers::{self, Follow};
+    use flatbuffers_verifier::{
+        lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+        verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+        TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+    };
+    use std::cmp::Ordering;
+    use std::result;
+
+    impl<'a> Verify for reader::Hero<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(12)?;
+
+            // The flatbuffers reader has no accessor for the deprecated field `name`.
+            table.field_deprecated(4)?;
+
+            // The flatbuffers reader has no accessor for the deprecated field `old_mp`.
+            table.field_deprecated(8)?;
+            table.field_scalar::<u32>(Self::VT_HP)?;
+            table.field_default(Self::VT_HP, |value: u32| value == 0u32)?;
+            table.field_scalar::<i16>(Self::VT_MP)?;
+            table.field_default(Self::VT_MP, |value: i16| value == 0i16)?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Hero<'_> {
+        type Reader<'a> = reader::Hero<'a>;
+    }
+
+    impl<'a> reader::Hero<'a> {
+        pub fn try_hp(&self) -> result::Result<u32, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_HP,
+                4,
+                4,
+            )?;
+            Ok(self.hp())
+        }
+
+        pub fn try_mp(&self) -> result::Result<i16, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_MP,
+                2,
+                2,
+            )?;
+            Ok(self.mp())
+        }
+    }
+}
@@ -1,22 +1,38 @@
 //! This file is auto-generated by cfbc.
 pub mod example {
-    #![allow(unused_imports, clippy::large_enum_variant)]
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
 
     use cfb::builder::{
         Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
-        ScalarVectorComponent, StringComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
     };
     use cfb::scalar::Scalar;
     use cfb::types::{SOffset, SIZE_OF_SOFFSET};
-    #[cfg(not(target_endian = "little"))]
-    use std::mem::transmute;
-
-    #[repr(i8)]
-    #[derive(Clone, Copy, PartialEq, Debug)]
-    pub enum Color {
-        Red = 0,
-        Green = 1,
-        Blue = 2,
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
+
+    /// Values of the enum `Color`, including the ones not declared in the schema, such as values
+    /// added by newer schemas, so they are written back unchanged.
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Color(pub i8);
+
+    #[allow(non_upper_case_globals)]
+    impl Color {
+        pub const Red: Color = Color(0);
+        pub const Green: Color = Color(1);
+        pub const Blue: Color = Color(2);
+
+        /// Tests whether the value is declared in the schema.
+        pub const fn is_known(self) -> bool {
+            matches!(self.0, 0..=2)
+        }
     }
 
     impl Default for Color {
@@ -25,27 +41,39 @@ pub mod example {
         }
     }
 
+    impl std::fmt::Debug for Color {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match *self {
+                Color::Red => f.write_str("Red"),
+                Color::Green => f.write_str("Green"),
+                Color::Blue => f.write_str("Blue"),
+                Color(raw) => f.debug_tuple("Unknown").field(&raw).finish(),
+            }
+        }
+    }
+
     impl Scalar for Color {
         fn to_le(self) -> Self {
-            #[cfg(target_endian = "little")]
-            {
-                self
-            }
-            #[cfg(not(target_endian = "little"))]
-            {
-                unsafe { transmute((self as i8).swap_bytes()) }
-            }
+            Color(self.0.to_le())
         }
 
         fn from_le(x: Self) -> Self {
-            #[cfg(target_endian = "little")]
-            {
-                x
-            }
-            #[cfg(not(target_endian = "little"))]
-            {
-                unsafe { transmute((x as i8).swap_bytes()) }
-            }
+            Color(i8::from_le(x.0))
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Color {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            select(vec![
+                Color::Red,
+                Color::Green,
+                Color::Blue,
+            ])
+            .boxed()
         }
     }
 
@@ -88,4 +116,83 @@ pub mod example {
             table_start
         }
     }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Bag {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.colors.is_empty() {
+                    vtable.add_field(Bag::VT_COLORS, Bag::SIZE_COLORS, Bag::ALIGNMENT_COLORS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Bag::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.colors.is_empty() {
+                builder.align(Bag::ALIGNMENT_COLORS);
+                let offset_position = builder.tell();
+                builder.pad(Bag::SIZE_COLORS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.colors.iter().copied(), 1)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Bag` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct BagRef<'a> {
+        pub colors: &'a [Color],
+    }
+
+    impl<'c> Component<'c> for BagRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.colors.is_empty() {
+                    vtable.add_field(Bag::VT_COLORS, Bag::SIZE_COLORS, Bag::ALIGNMENT_COLORS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Bag::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.colors.is_empty() {
+                builder.align(Bag::ALIGNMENT_COLORS);
+                let offset_position = builder.tell();
+                builder.pad(Bag::SIZE_COLORS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.colors.iter().copied(), 1)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Bag {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                vec(any::<Color>(), config.scalar_vector_len.clone()),
+            )
+                .prop_map(|(colors,)| Bag {
+                    colors,
+                })
+                .boxed()
+        }
+    }
 }
@@ -0,0 +1,57 @@
+//! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
+use super::evolution_v1_generated as reader;
+
+pub mod example {
+    #![allow(unused_imports)]
+
+    use super::reader::example as reader;
+    use flatbuffers::{self, Follow};
+    use flatbuffers_verifier::{
+        lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+        verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+        TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+    };
+    use std::cmp::Ordering;
+    use std::result;
+
+    impl<'a> Verify for reader::Hero<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(8)?;
+            table.field_string(Self::VT_NAME)?;
+            table.field_scalar::<u32>(Self::VT_HP)?;
+            table.field_default(Self::VT_HP, |value: u32| value == 0u32)?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Hero<'_> {
+        type Reader<'a> = reader::Hero<'a>;
+    }
+
+    impl<'a> reader::Hero<'a> {
+        pub fn try_name(&self) -> result::Result<Option<&'a str>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_NAME,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                StringVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?).verify()?;
+            }
+            Ok(self.name())
+        }
+
+        pub fn try_hp(&self) -> result::Result<u32, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_HP,
+                4,
+                4,
+            )?;
+            Ok(self.hp())
+        }
+    }
+}
@@ -0,0 +1,241 @@
+//! This file is auto-generated by cfbc.
+pub mod example {
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
+
+    use cfb::builder::{
+        Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
+    };
+    use cfb::scalar::Scalar;
+    use cfb::types::{SOffset, SIZE_OF_SOFFSET};
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Hero {
+        pub name: String,
+        pub hp: u32,
+        pub mp: u32,
+        pub skills: Vec<String>,
+    }
+
+    impl Hero {
+        const VT_NAME: usize = 4;
+        const SIZE_NAME: usize = 4;
+        const ALIGNMENT_NAME: usize = 4;
+        const VT_HP: usize = 6;
+        const SIZE_HP: usize = 4;
+        const ALIGNMENT_HP: usize = 4;
+        const VT_MP: usize = 8;
+        const SIZE_MP: usize = 4;
+        const ALIGNMENT_MP: usize = 4;
+        const VT_SKILLS: usize = 10;
+        const SIZE_SKILLS: usize = 4;
+        const ALIGNMENT_SKILLS: usize = 4;
+        const ALIGNMENT: usize = 4;
+    }
+
+    impl<'c> Component<'c> for Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.name.is_empty() {
+                    vtable.add_field(Self::VT_NAME, Self::SIZE_NAME, Self::ALIGNMENT_NAME);
+                }
+                if self.hp != 0u32 {
+                    vtable.add_field(Self::VT_HP, Self::SIZE_HP, Self::ALIGNMENT_HP);
+                }
+                if self.mp != 0u32 {
+                    vtable.add_field(Self::VT_MP, Self::SIZE_MP, Self::ALIGNMENT_MP);
+                }
+                if !self.skills.is_empty() {
+                    vtable.add_field(Self::VT_SKILLS, Self::SIZE_SKILLS, Self::ALIGNMENT_SKILLS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.name.is_empty() {
+                builder.align(Self::ALIGNMENT_NAME);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_NAME);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(self.name))
+                ));
+            }
+            if self.hp != 0u32 {
+                builder.align(Self::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+            if self.mp != 0u32 {
+                builder.align(Self::ALIGNMENT_MP);
+                builder.push_scalar(self.mp);
+            }
+            if !self.skills.is_empty() {
+                builder.align(Self::ALIGNMENT_SKILLS);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_SKILLS);
+                let children = self.skills.into_iter().map(StringComponent::new);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(children)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.name.is_empty() {
+                    vtable.add_field(Hero::VT_NAME, Hero::SIZE_NAME, Hero::ALIGNMENT_NAME);
+                }
+                if self.hp != 0u32 {
+                    vtable.add_field(Hero::VT_HP, Hero::SIZE_HP, Hero::ALIGNMENT_HP);
+                }
+                if self.mp != 0u32 {
+                    vtable.add_field(Hero::VT_MP, Hero::SIZE_MP, Hero::ALIGNMENT_MP);
+                }
+                if !self.skills.is_empty() {
+                    vtable.add_field(Hero::VT_SKILLS, Hero::SIZE_SKILLS, Hero::ALIGNMENT_SKILLS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.name.is_empty() {
+                builder.align(Hero::ALIGNMENT_NAME);
+                let offset_position = builder.tell();
+                builder.pad(Hero::SIZE_NAME);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(&self.name))
+                ));
+            }
+            if self.hp != 0u32 {
+                builder.align(Hero::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+            if self.mp != 0u32 {
+                builder.align(Hero::ALIGNMENT_MP);
+                builder.push_scalar(self.mp);
+            }
+            if !self.skills.is_empty() {
+                builder.align(Hero::ALIGNMENT_SKILLS);
+                let offset_position = builder.tell();
+                builder.pad(Hero::SIZE_SKILLS);
+                let children = self.skills.iter().map(StringComponent::new);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(children)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Hero` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct HeroRef<'a> {
+        pub name: &'a str,
+        pub hp: u32,
+        pub mp: u32,
+        pub skills: &'a [&'a str],
+    }
+
+    impl<'c> Component<'c> for HeroRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.name.is_empty() {
+                    vtable.add_field(Hero::VT_NAME, Hero::SIZE_NAME, Hero::ALIGNMENT_NAME);
+                }
+                if self.hp != 0u32 {
+                    vtable.add_field(Hero::VT_HP, Hero::SIZE_HP, Hero::ALIGNMENT_HP);
+                }
+                if self.mp != 0u32 {
+                    vtable.add_field(Hero::VT_MP, Hero::SIZE_MP, Hero::ALIGNMENT_MP);
+                }
+                if !self.skills.is_empty() {
+                    vtable.add_field(Hero::VT_SKILLS, Hero::SIZE_SKILLS, Hero::ALIGNMENT_SKILLS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.name.is_empty() {
+                builder.align(Hero::ALIGNMENT_NAME);
+                let offset_position = builder.tell();
+                builder.pad(Hero::SIZE_NAME);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(self.name))
+                ));
+            }
+            if self.hp != 0u32 {
+                builder.align(Hero::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+            if self.mp != 0u32 {
+                builder.align(Hero::ALIGNMENT_MP);
+                builder.push_scalar(self.mp);
+            }
+            if !self.skills.is_empty() {
+                builder.align(Hero::ALIGNMENT_SKILLS);
+                let offset_position = builder.tell();
+                builder.pad(Hero::SIZE_SKILLS);
+                let children = self.skills.iter().map(StringComponent::new);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(children)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Hero {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                strategy::string(&config),
+                any::<u32>(),
+                any::<u32>(),
+                vec(strategy::string(&config), config.vector_len.clone()),
+            )
+                .prop_map(|(name, hp, mp, skills)| Hero {
+                    name,
+                    hp,
+                    mp,
+                    skills,
+                })
+                .boxed()
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+pub mod example {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+
+pub enum HeroOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Hero<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Hero<'a> {
+    type Inner = Hero<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Hero<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Hero {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args HeroArgs<'args>) -> flatbuffers::WIPOffset<Hero<'bldr>> {
+      let mut builder = HeroBuilder::new(_fbb);
+      if let Some(x) = args.skills { builder.add_skills(x); }
+      builder.add_mp(args.mp);
+      builder.add_hp(args.hp);
+      if let Some(x) = args.name { builder.add_name(x); }
+      builder.finish()
+    }
+
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_HP: flatbuffers::VOffsetT = 6;
+    pub const VT_MP: flatbuffers::VOffsetT = 8;
+    pub const VT_SKILLS: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Hero::VT_NAME, None)
+  }
+  #[inline]
+  pub fn hp(&self) -> u32 {
+    self._tab.get::<u32>(Hero::VT_HP, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn mp(&self) -> u32 {
+    self._tab.get::<u32>(Hero::VT_MP, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn skills(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<&'a str>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<&'a str>>>>(Hero::VT_SKILLS, None)
+  }
+}
+
+pub struct HeroArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a  str>>,
+    pub hp: u32,
+    pub mp: u32,
+    pub skills: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<&'a  str>>>>,
+}
+impl<'a> Default for HeroArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        HeroArgs {
+            name: None,
+            hp: 0,
+            mp: 0,
+            skills: None,
+        }
+    }
+}
+pub struct HeroBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HeroBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Hero::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_hp(&mut self, hp: u32) {
+    self.fbb_.push_slot::<u32>(Hero::VT_HP, hp, 0);
+  }
+  #[inline]
+  pub fn add_mp(&mut self, mp: u32) {
+    self.fbb_.push_slot::<u32>(Hero::VT_MP, mp, 0);
+  }
+  #[inline]
+  pub fn add_skills(&mut self, skills: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Hero::VT_SKILLS, skills);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> HeroBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    HeroBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Hero<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+}  // pub mod example
+
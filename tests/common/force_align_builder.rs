@@ -0,0 +1,475 @@
+//! This file is auto-generated by cfbc.
+pub mod example {
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
+
+    use cfb::builder::{
+        Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
+    };
+    use cfb::scalar::Scalar;
+    use cfb::types::{SOffset, SIZE_OF_SOFFSET};
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Block {
+        pub position: Vec4,
+        pub bytes: Vec<u8>,
+        pub flags: Vec<bool>,
+        pub points: Vec<Vec4>,
+        pub header: Option<Header>,
+    }
+
+    impl Block {
+        const VT_POSITION: usize = 4;
+        const SIZE_POSITION: usize = 16;
+        const ALIGNMENT_POSITION: usize = 16;
+        const VT_BYTES: usize = 6;
+        const SIZE_BYTES: usize = 4;
+        const ALIGNMENT_BYTES: usize = 4;
+        const VT_FLAGS: usize = 8;
+        const SIZE_FLAGS: usize = 4;
+        const ALIGNMENT_FLAGS: usize = 4;
+        const VT_POINTS: usize = 10;
+        const SIZE_POINTS: usize = 4;
+        const ALIGNMENT_POINTS: usize = 4;
+        const VT_HEADER: usize = 12;
+        const SIZE_HEADER: usize = 4;
+        const ALIGNMENT_HEADER: usize = 4;
+        const ALIGNMENT: usize = 16;
+    }
+
+    impl<'c> Component<'c> for Block {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.position.is_present() {
+                    vtable.add_field(Self::VT_POSITION, Self::SIZE_POSITION, Self::ALIGNMENT_POSITION);
+                }
+                if !self.bytes.is_empty() {
+                    vtable.add_field(Self::VT_BYTES, Self::SIZE_BYTES, Self::ALIGNMENT_BYTES);
+                }
+                if !self.flags.is_empty() {
+                    vtable.add_field(Self::VT_FLAGS, Self::SIZE_FLAGS, Self::ALIGNMENT_FLAGS);
+                }
+                if !self.points.is_empty() {
+                    vtable.add_field(Self::VT_POINTS, Self::SIZE_POINTS, Self::ALIGNMENT_POINTS);
+                }
+                if self.header.is_some() {
+                    vtable.add_field(Self::VT_HEADER, Self::SIZE_HEADER, Self::ALIGNMENT_HEADER);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.position.is_present() {
+                builder.align(Self::ALIGNMENT_POSITION);
+                builder.push_scalar(self.position);
+            }
+            if !self.bytes.is_empty() {
+                builder.align(Self::ALIGNMENT_BYTES);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_BYTES);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.bytes, 32)),
+                ));
+            }
+            if !self.flags.is_empty() {
+                builder.align(Self::ALIGNMENT_FLAGS);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_FLAGS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.flags, 16)),
+                ));
+            }
+            if !self.points.is_empty() {
+                builder.align(Self::ALIGNMENT_POINTS);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_POINTS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.points, 16)),
+                ));
+            }
+            if let Some(f) = self.header {
+                builder.align(Self::ALIGNMENT_HEADER);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_HEADER);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(NestedBufferComponent::with_alignment(f, 16)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Block {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.position.is_present() {
+                    vtable.add_field(Block::VT_POSITION, Block::SIZE_POSITION, Block::ALIGNMENT_POSITION);
+                }
+                if !self.bytes.is_empty() {
+                    vtable.add_field(Block::VT_BYTES, Block::SIZE_BYTES, Block::ALIGNMENT_BYTES);
+                }
+                if !self.flags.is_empty() {
+                    vtable.add_field(Block::VT_FLAGS, Block::SIZE_FLAGS, Block::ALIGNMENT_FLAGS);
+                }
+                if !self.points.is_empty() {
+                    vtable.add_field(Block::VT_POINTS, Block::SIZE_POINTS, Block::ALIGNMENT_POINTS);
+                }
+                if self.header.is_some() {
+                    vtable.add_field(Block::VT_HEADER, Block::SIZE_HEADER, Block::ALIGNMENT_HEADER);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Block::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.position.is_present() {
+                builder.align(Block::ALIGNMENT_POSITION);
+                builder.push_scalar(self.position);
+            }
+            if !self.bytes.is_empty() {
+                builder.align(Block::ALIGNMENT_BYTES);
+                let offset_position = builder.tell();
+                builder.pad(Block::SIZE_BYTES);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.bytes.iter().copied(), 32)),
+                ));
+            }
+            if !self.flags.is_empty() {
+                builder.align(Block::ALIGNMENT_FLAGS);
+                let offset_position = builder.tell();
+                builder.pad(Block::SIZE_FLAGS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.flags.iter().copied(), 16)),
+                ));
+            }
+            if !self.points.is_empty() {
+                builder.align(Block::ALIGNMENT_POINTS);
+                let offset_position = builder.tell();
+                builder.pad(Block::SIZE_POINTS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.points.iter().copied(), 16)),
+                ));
+            }
+            if let Some(f) = &self.header {
+                builder.align(Block::ALIGNMENT_HEADER);
+                let offset_position = builder.tell();
+                builder.pad(Block::SIZE_HEADER);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(NestedBufferComponent::with_alignment(f, 16)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Block` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct BlockRef<'a> {
+        pub position: Vec4,
+        pub bytes: &'a [u8],
+        pub flags: &'a [bool],
+        pub points: &'a [Vec4],
+        pub header: Option<HeaderRef>,
+    }
+
+    impl<'c> Component<'c> for BlockRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.position.is_present() {
+                    vtable.add_field(Block::VT_POSITION, Block::SIZE_POSITION, Block::ALIGNMENT_POSITION);
+                }
+                if !self.bytes.is_empty() {
+                    vtable.add_field(Block::VT_BYTES, Block::SIZE_BYTES, Block::ALIGNMENT_BYTES);
+                }
+                if !self.flags.is_empty() {
+                    vtable.add_field(Block::VT_FLAGS, Block::SIZE_FLAGS, Block::ALIGNMENT_FLAGS);
+                }
+                if !self.points.is_empty() {
+                    vtable.add_field(Block::VT_POINTS, Block::SIZE_POINTS, Block::ALIGNMENT_POINTS);
+                }
+                if self.header.is_some() {
+                    vtable.add_field(Block::VT_HEADER, Block::SIZE_HEADER, Block::ALIGNMENT_HEADER);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Block::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.position.is_present() {
+                builder.align(Block::ALIGNMENT_POSITION);
+                builder.push_scalar(self.position);
+            }
+            if !self.bytes.is_empty() {
+                builder.align(Block::ALIGNMENT_BYTES);
+                let offset_position = builder.tell();
+                builder.pad(Block::SIZE_BYTES);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.bytes.iter().copied(), 32)),
+                ));
+            }
+            if !self.flags.is_empty() {
+                builder.align(Block::ALIGNMENT_FLAGS);
+                let offset_position = builder.tell();
+                builder.pad(Block::SIZE_FLAGS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.flags.iter().copied(), 16)),
+                ));
+            }
+            if !self.points.is_empty() {
+                builder.align(Block::ALIGNMENT_POINTS);
+                let offset_position = builder.tell();
+                builder.pad(Block::SIZE_POINTS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.points.iter().copied(), 16)),
+                ));
+            }
+            if let Some(f) = self.header {
+                builder.align(Block::ALIGNMENT_HEADER);
+                let offset_position = builder.tell();
+                builder.pad(Block::SIZE_HEADER);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(NestedBufferComponent::with_alignment(f, 16)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Block {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                any::<Vec4>(),
+                vec(any::<u8>(), config.scalar_vector_len.clone()),
+                vec(any::<bool>(), config.scalar_vector_len.clone()),
+                vec(any::<Vec4>(), config.scalar_vector_len.clone()),
+                strategy::option(&config, any_with::<Header>(config.clone())),
+            )
+                .prop_map(|(position, bytes, flags, points, header)| Block {
+                    position,
+                    bytes,
+                    flags,
+                    points,
+                    header,
+                })
+                .boxed()
+        }
+    }
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Header {
+        pub number: u64,
+    }
+
+    impl Header {
+        const VT_NUMBER: usize = 4;
+        const SIZE_NUMBER: usize = 8;
+        const ALIGNMENT_NUMBER: usize = 8;
+        const ALIGNMENT: usize = 8;
+    }
+
+    impl<'c> Component<'c> for Header {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.number != 0u64 {
+                    vtable.add_field(Self::VT_NUMBER, Self::SIZE_NUMBER, Self::ALIGNMENT_NUMBER);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.number != 0u64 {
+                builder.align(Self::ALIGNMENT_NUMBER);
+                builder.push_scalar(self.number);
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Header {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.number != 0u64 {
+                    vtable.add_field(Header::VT_NUMBER, Header::SIZE_NUMBER, Header::ALIGNMENT_NUMBER);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Header::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.number != 0u64 {
+                builder.align(Header::ALIGNMENT_NUMBER);
+                builder.push_scalar(self.number);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Header` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct HeaderRef {
+        pub number: u64,
+    }
+
+    impl<'c> Component<'c> for HeaderRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.number != 0u64 {
+                    vtable.add_field(Header::VT_NUMBER, Header::SIZE_NUMBER, Header::ALIGNMENT_NUMBER);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Header::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.number != 0u64 {
+                builder.align(Header::ALIGNMENT_NUMBER);
+                builder.push_scalar(self.number);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Header {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u64>(),
+            )
+                .prop_map(|(number,)| Header {
+                    number,
+                })
+                .boxed()
+        }
+    }
+
+    #[repr(C, align(16))]
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct Vec4 {
+        pub x: f32,
+        pub y: f32,
+        pub z: f32,
+        pub w: f32,
+    }
+
+    impl Vec4 {
+        pub fn is_present(&self) -> bool {
+            self.x != 0f32 || self.y != 0f32 || self.z != 0f32 || self.w != 0f32
+        }
+    }
+
+    impl Scalar for Vec4 {
+        #[cfg(target_endian = "little")]
+        fn to_le(self) -> Self {
+            self
+        }
+
+        #[cfg(target_endian = "little")]
+        fn from_le(x: Self) -> Self {
+            x
+        }
+
+        #[cfg(not(target_endian = "little"))]
+        fn to_le(mut self) -> Self {
+            self.x = self.x.to_le();
+            self.y = self.y.to_le();
+            self.z = self.z.to_le();
+            self.w = self.w.to_le();
+            self
+        }
+
+        #[cfg(not(target_endian = "little"))]
+        fn from_le(mut x: Self) -> Self {
+            x.x = Scalar::from_le(x.x);
+            x.y = Scalar::from_le(x.y);
+            x.z = Scalar::from_le(x.z);
+            x.w = Scalar::from_le(x.w);
+            x
+        }
+
+        fn canonicalize(mut self) -> Self {
+            self.x = self.x.canonicalize();
+            self.y = self.y.canonicalize();
+            self.z = self.z.canonicalize();
+            self.w = self.w.canonicalize();
+            self
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Vec4 {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<f32>(),
+                any::<f32>(),
+                any::<f32>(),
+                any::<f32>(),
+            )
+                .prop_map(|(x, y, z, w)| Vec4 {
+                    x,
+                    y,
+                    z,
+                    w,
+                })
+                .boxed()
+        }
+    }
+}
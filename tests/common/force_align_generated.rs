@@ -0,0 +1,306 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+pub mod example {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+
+// struct Vec4, aligned to 16
+#[repr(C, align(16))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vec4 {
+  x_: f32,
+  y_: f32,
+  z_: f32,
+  w_: f32,
+} // pub struct Vec4
+impl flatbuffers::SafeSliceAccess for Vec4 {}
+impl<'a> flatbuffers::Follow<'a> for Vec4 {
+  type Inner = &'a Vec4;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a Vec4>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Vec4 {
+  type Inner = &'a Vec4;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<Vec4>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for Vec4 {
+    type Output = Vec4;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(self as *const Vec4 as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+impl<'b> flatbuffers::Push for &'b Vec4 {
+    type Output = Vec4;
+
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(*self as *const Vec4 as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+
+
+impl Vec4 {
+  pub fn new<'a>(_x: f32, _y: f32, _z: f32, _w: f32) -> Self {
+    Vec4 {
+      x_: _x.to_little_endian(),
+      y_: _y.to_little_endian(),
+      z_: _z.to_little_endian(),
+      w_: _w.to_little_endian(),
+
+    }
+  }
+  pub fn x<'a>(&'a self) -> f32 {
+    self.x_.from_little_endian()
+  }
+  pub fn y<'a>(&'a self) -> f32 {
+    self.y_.from_little_endian()
+  }
+  pub fn z<'a>(&'a self) -> f32 {
+    self.z_.from_little_endian()
+  }
+  pub fn w<'a>(&'a self) -> f32 {
+    self.w_.from_little_endian()
+  }
+}
+
+pub enum HeaderOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Header<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Header<'a> {
+    type Inner = Header<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Header<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Header {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args HeaderArgs) -> flatbuffers::WIPOffset<Header<'bldr>> {
+      let mut builder = HeaderBuilder::new(_fbb);
+      builder.add_number(args.number);
+      builder.finish()
+    }
+
+    pub const VT_NUMBER: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn number(&self) -> u64 {
+    self._tab.get::<u64>(Header::VT_NUMBER, Some(0)).unwrap()
+  }
+}
+
+pub struct HeaderArgs {
+    pub number: u64,
+}
+impl<'a> Default for HeaderArgs {
+    #[inline]
+    fn default() -> Self {
+        HeaderArgs {
+            number: 0,
+        }
+    }
+}
+pub struct HeaderBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HeaderBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_number(&mut self, number: u64) {
+    self.fbb_.push_slot::<u64>(Header::VT_NUMBER, number, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> HeaderBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    HeaderBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Header<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum BlockOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Block<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Block<'a> {
+    type Inner = Block<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Block<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Block {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args BlockArgs<'args>) -> flatbuffers::WIPOffset<Block<'bldr>> {
+      let mut builder = BlockBuilder::new(_fbb);
+      if let Some(x) = args.header { builder.add_header(x); }
+      if let Some(x) = args.points { builder.add_points(x); }
+      if let Some(x) = args.flags { builder.add_flags(x); }
+      if let Some(x) = args.bytes { builder.add_bytes(x); }
+      if let Some(x) = args.position { builder.add_position(x); }
+      builder.finish()
+    }
+
+    pub const VT_POSITION: flatbuffers::VOffsetT = 4;
+    pub const VT_BYTES: flatbuffers::VOffsetT = 6;
+    pub const VT_FLAGS: flatbuffers::VOffsetT = 8;
+    pub const VT_POINTS: flatbuffers::VOffsetT = 10;
+    pub const VT_HEADER: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub fn position(&self) -> Option<&'a Vec4> {
+    self._tab.get::<Vec4>(Block::VT_POSITION, None)
+  }
+  #[inline]
+  pub fn bytes(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Block::VT_BYTES, None).map(|v| v.safe_slice())
+  }
+  #[inline]
+  pub fn flags(&self) -> Option<&'a [bool]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, bool>>>(Block::VT_FLAGS, None).map(|v| v.safe_slice())
+  }
+  #[inline]
+  pub fn points(&self) -> Option<&'a [Vec4]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<Vec4>>>(Block::VT_POINTS, None).map(|v| v.safe_slice() )
+  }
+  #[inline]
+  pub fn header(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Block::VT_HEADER, None).map(|v| v.safe_slice())
+  }
+}
+
+pub struct BlockArgs<'a> {
+    pub position: Option<&'a  Vec4>,
+    pub bytes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+    pub flags: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  bool>>>,
+    pub points: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , Vec4>>>,
+    pub header: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+}
+impl<'a> Default for BlockArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        BlockArgs {
+            position: None,
+            bytes: None,
+            flags: None,
+            points: None,
+            header: None,
+        }
+    }
+}
+pub struct BlockBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BlockBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_position(&mut self, position: &'b  Vec4) {
+    self.fbb_.push_slot_always::<&Vec4>(Block::VT_POSITION, position);
+  }
+  #[inline]
+  pub fn add_bytes(&mut self, bytes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Block::VT_BYTES, bytes);
+  }
+  #[inline]
+  pub fn add_flags(&mut self, flags: flatbuffers::WIPOffset<flatbuffers::Vector<'b , bool>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Block::VT_FLAGS, flags);
+  }
+  #[inline]
+  pub fn add_points(&mut self, points: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Vec4>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Block::VT_POINTS, points);
+  }
+  #[inline]
+  pub fn add_header(&mut self, header: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Block::VT_HEADER, header);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BlockBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BlockBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Block<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+#[inline]
+pub fn get_root_as_block<'a>(buf: &'a [u8]) -> Block<'a> {
+  flatbuffers::get_root::<Block<'a>>(buf)
+}
+
+#[inline]
+pub fn get_size_prefixed_root_as_block<'a>(buf: &'a [u8]) -> Block<'a> {
+  flatbuffers::get_size_prefixed_root::<Block<'a>>(buf)
+}
+
+#[inline]
+pub fn finish_block_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Block<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_block_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Block<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
+}  // pub mod example
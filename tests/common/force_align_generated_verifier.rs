@@ -0,0 +1,155 @@
+//! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
+use super::force_align_generated as reader;
+
+pub mod example {
+    #![allow(unused_imports)]
+
+    use super::reader::example as reader;
+    use flatbuffers::{self, Follow};
+    use flatbuffers_verifier::{
+        lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+        verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+        TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+    };
+    use std::cmp::Ordering;
+    use std::result;
+
+    impl<'a> Verify for reader::Block<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(14)?;
+            table.field_struct_with(Self::VT_POSITION, 16, 16, |loc| {
+                reader::Vec4::verify_canonical_floats(self._tab.buf, loc)
+            })?;
+            table.field_vector(Self::VT_BYTES, "bytes", 1, |vector| {
+                vector.verify_scalar_elements(1, 32)
+            })?;
+            table.field_vector(Self::VT_FLAGS, "flags", 1, |vector| {
+                vector.verify_bool_elements()?;
+                vector.verify_scalar_elements(1, 16)
+            })?;
+            table.field_vector(Self::VT_POINTS, "points", 16, |vector| {
+                vector.verify_scalar_elements_with(16, 16, |loc| {
+                    reader::Vec4::verify_canonical_floats(self._tab.buf, loc)
+                })
+            })?;
+            table.field_vector(Self::VT_HEADER, "header", 1, |vector| {
+                vector.verify_scalar_elements(1, 16)
+            })?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Block<'_> {
+        type Reader<'a> = reader::Block<'a>;
+    }
+
+    impl<'a> reader::Block<'a> {
+        pub fn try_position(&self) -> result::Result<Option<&'a reader::Vec4>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_POSITION,
+                16,
+                16,
+            )? {
+                reader::Vec4::verify_canonical_floats(self._tab.buf, loc)?;
+            }
+            Ok(self.position())
+        }
+
+        pub fn try_bytes(&self) -> result::Result<Option<&'a [u8]>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_BYTES,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                verifier.verify_scalar_elements(1, 32)?;
+            }
+            Ok(self.bytes())
+        }
+
+        pub fn try_flags(&self) -> result::Result<Option<&'a [bool]>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_FLAGS,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                verifier.verify_bool_elements()?;
+                verifier.verify_scalar_elements(1, 16)?;
+            }
+            Ok(self.flags())
+        }
+
+        pub fn try_points(&self) -> result::Result<Option<&'a [reader::Vec4]>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_POINTS,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                verifier.verify_scalar_elements_with(16, 16, |loc| {
+                    reader::Vec4::verify_canonical_floats(self._tab.buf, loc)
+                })?;
+            }
+            Ok(self.points())
+        }
+
+        pub fn try_header(&self) -> result::Result<Option<&'a [u8]>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_HEADER,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                verifier.verify_scalar_elements(1, 16)?;
+            }
+            Ok(self.header())
+        }
+    }
+
+    impl<'a> Verify for reader::Header<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(6)?;
+            table.field_scalar::<u64>(Self::VT_NUMBER)?;
+            table.field_default(Self::VT_NUMBER, |value: u64| value == 0u64)?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Header<'_> {
+        type Reader<'a> = reader::Header<'a>;
+    }
+
+    impl<'a> reader::Header<'a> {
+        pub fn try_number(&self) -> result::Result<u64, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_NUMBER,
+                8,
+                8,
+            )?;
+            Ok(self.number())
+        }
+    }
+
+    impl reader::Vec4 {
+        /// Verifies that the floats in the struct at `loc` are canonical. The struct must be within
+        /// the buffer.
+        pub fn verify_canonical_floats(buf: &[u8], loc: usize) -> Result {
+            verify_float::<f32>(buf, loc)?;
+            verify_float::<f32>(buf, loc + 4)?;
+            verify_float::<f32>(buf, loc + 8)?;
+            verify_float::<f32>(buf, loc + 12)?;
+            Ok(())
+        }
+    }
+}
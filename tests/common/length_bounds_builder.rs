@@ -0,0 +1,219 @@
+//! This file is auto-generated by cfbc.
+pub mod example {
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
+
+    use cfb::builder::{
+        Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
+    };
+    use cfb::scalar::Scalar;
+    use cfb::types::{SOffset, SIZE_OF_SOFFSET};
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Script {
+        pub code_hash: String,
+        pub args: Vec<String>,
+        pub hash_type: u32,
+    }
+
+    impl Script {
+        const VT_CODE_HASH: usize = 4;
+        const SIZE_CODE_HASH: usize = 4;
+        const ALIGNMENT_CODE_HASH: usize = 4;
+        const VT_ARGS: usize = 6;
+        const SIZE_ARGS: usize = 4;
+        const ALIGNMENT_ARGS: usize = 4;
+        const VT_HASH_TYPE: usize = 8;
+        const SIZE_HASH_TYPE: usize = 4;
+        const ALIGNMENT_HASH_TYPE: usize = 4;
+        const ALIGNMENT: usize = 4;
+    }
+
+    impl<'c> Component<'c> for Script {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            builder.check_len("Script.code_hash", self.code_hash.len(), 1, 8);
+            builder.check_len("Script.args", self.args.len(), 0, 3);
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.code_hash.is_empty() {
+                    vtable.add_field(Self::VT_CODE_HASH, Self::SIZE_CODE_HASH, Self::ALIGNMENT_CODE_HASH);
+                }
+                if !self.args.is_empty() {
+                    vtable.add_field(Self::VT_ARGS, Self::SIZE_ARGS, Self::ALIGNMENT_ARGS);
+                }
+                if self.hash_type != 0u32 {
+                    vtable.add_field(Self::VT_HASH_TYPE, Self::SIZE_HASH_TYPE, Self::ALIGNMENT_HASH_TYPE);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.code_hash.is_empty() {
+                builder.align(Self::ALIGNMENT_CODE_HASH);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_CODE_HASH);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(self.code_hash))
+                ));
+            }
+            if !self.args.is_empty() {
+                builder.align(Self::ALIGNMENT_ARGS);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_ARGS);
+                let children = self.args.into_iter().map(StringComponent::new);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(children)),
+                ));
+            }
+            if self.hash_type != 0u32 {
+                builder.align(Self::ALIGNMENT_HASH_TYPE);
+                builder.push_scalar(self.hash_type);
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Script {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            builder.check_len("Script.code_hash", self.code_hash.len(), 1, 8);
+            builder.check_len("Script.args", self.args.len(), 0, 3);
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.code_hash.is_empty() {
+                    vtable.add_field(Script::VT_CODE_HASH, Script::SIZE_CODE_HASH, Script::ALIGNMENT_CODE_HASH);
+                }
+                if !self.args.is_empty() {
+                    vtable.add_field(Script::VT_ARGS, Script::SIZE_ARGS, Script::ALIGNMENT_ARGS);
+                }
+                if self.hash_type != 0u32 {
+                    vtable.add_field(Script::VT_HASH_TYPE, Script::SIZE_HASH_TYPE, Script::ALIGNMENT_HASH_TYPE);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Script::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.code_hash.is_empty() {
+                builder.align(Script::ALIGNMENT_CODE_HASH);
+                let offset_position = builder.tell();
+                builder.pad(Script::SIZE_CODE_HASH);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(&self.code_hash))
+                ));
+            }
+            if !self.args.is_empty() {
+                builder.align(Script::ALIGNMENT_ARGS);
+                let offset_position = builder.tell();
+                builder.pad(Script::SIZE_ARGS);
+                let children = self.args.iter().map(StringComponent::new);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(children)),
+                ));
+            }
+            if self.hash_type != 0u32 {
+                builder.align(Script::ALIGNMENT_HASH_TYPE);
+                builder.push_scalar(self.hash_type);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Script` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct ScriptRef<'a> {
+        pub code_hash: &'a str,
+        pub args: &'a [&'a str],
+        pub hash_type: u32,
+    }
+
+    impl<'c> Component<'c> for ScriptRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            builder.check_len("Script.code_hash", self.code_hash.len(), 1, 8);
+            builder.check_len("Script.args", self.args.len(), 0, 3);
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.code_hash.is_empty() {
+                    vtable.add_field(Script::VT_CODE_HASH, Script::SIZE_CODE_HASH, Script::ALIGNMENT_CODE_HASH);
+                }
+                if !self.args.is_empty() {
+                    vtable.add_field(Script::VT_ARGS, Script::SIZE_ARGS, Script::ALIGNMENT_ARGS);
+                }
+                if self.hash_type != 0u32 {
+                    vtable.add_field(Script::VT_HASH_TYPE, Script::SIZE_HASH_TYPE, Script::ALIGNMENT_HASH_TYPE);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Script::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.code_hash.is_empty() {
+                builder.align(Script::ALIGNMENT_CODE_HASH);
+                let offset_position = builder.tell();
+                builder.pad(Script::SIZE_CODE_HASH);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(self.code_hash))
+                ));
+            }
+            if !self.args.is_empty() {
+                builder.align(Script::ALIGNMENT_ARGS);
+                let offset_position = builder.tell();
+                builder.pad(Script::SIZE_ARGS);
+                let children = self.args.iter().map(StringComponent::new);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(children)),
+                ));
+            }
+            if self.hash_type != 0u32 {
+                builder.align(Script::ALIGNMENT_HASH_TYPE);
+                builder.push_scalar(self.hash_type);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Script {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                strategy::bounded_string(&config, 1, 8),
+                strategy::bounded_vec(strategy::string(&config), config.vector_len.clone(), 0, 3),
+                any::<u32>(),
+            )
+                .prop_map(|(code_hash, args, hash_type)| Script {
+                    code_hash,
+                    args,
+                    hash_type,
+                })
+                .boxed()
+        }
+    }
+}
@@ -0,0 +1,115 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+pub mod example {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+
+pub enum ScriptOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Script<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Script<'a> {
+    type Inner = Script<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Script<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Script {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ScriptArgs<'args>) -> flatbuffers::WIPOffset<Script<'bldr>> {
+      let mut builder = ScriptBuilder::new(_fbb);
+      builder.add_hash_type(args.hash_type);
+      if let Some(x) = args.args { builder.add_args(x); }
+      if let Some(x) = args.code_hash { builder.add_code_hash(x); }
+      builder.finish()
+    }
+
+    pub const VT_CODE_HASH: flatbuffers::VOffsetT = 4;
+    pub const VT_ARGS: flatbuffers::VOffsetT = 6;
+    pub const VT_HASH_TYPE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub fn code_hash(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Script::VT_CODE_HASH, None)
+  }
+  #[inline]
+  pub fn args(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<&'a str>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<&'a str>>>>(Script::VT_ARGS, None)
+  }
+  #[inline]
+  pub fn hash_type(&self) -> u32 {
+    self._tab.get::<u32>(Script::VT_HASH_TYPE, Some(0)).unwrap()
+  }
+}
+
+pub struct ScriptArgs<'a> {
+    pub code_hash: Option<flatbuffers::WIPOffset<&'a  str>>,
+    pub args: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<&'a  str>>>>,
+    pub hash_type: u32,
+}
+impl<'a> Default for ScriptArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        ScriptArgs {
+            code_hash: None,
+            args: None,
+            hash_type: 0,
+        }
+    }
+}
+pub struct ScriptBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ScriptBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_code_hash(&mut self, code_hash: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Script::VT_CODE_HASH, code_hash);
+  }
+  #[inline]
+  pub fn add_args(&mut self, args: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Script::VT_ARGS, args);
+  }
+  #[inline]
+  pub fn add_hash_type(&mut self, hash_type: u32) {
+    self.fbb_.push_slot::<u32>(Script::VT_HASH_TYPE, hash_type, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ScriptBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ScriptBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Script<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+}  // pub mod example
+
@@ -0,0 +1,75 @@
+//! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
+use super::length_bounds_generated as reader;
+
+pub mod example {
+    #![allow(unused_imports)]
+
+    use super::reader::example as reader;
+    use flatbuffers::{self, Follow};
+    use flatbuffers_verifier::{
+        lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+        verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+        TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+    };
+    use std::cmp::Ordering;
+    use std::result;
+
+    impl<'a> Verify for reader::Script<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(10)?;
+            table.field_string(Self::VT_CODE_HASH)?;
+            table.field_len(Self::VT_CODE_HASH, "Script.code_hash", 1, 8)?;
+            table.field_vector(Self::VT_ARGS, "args", flatbuffers::SIZE_UOFFSET, |vector| {
+                vector.verify_reference_elements::<StringVerifier>(verification)
+            })?;
+            table.field_len(Self::VT_ARGS, "Script.args", 0, 3)?;
+            table.field_scalar::<u32>(Self::VT_HASH_TYPE)?;
+            table.field_default(Self::VT_HASH_TYPE, |value: u32| value == 0u32)?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Script<'_> {
+        type Reader<'a> = reader::Script<'a>;
+    }
+
+    impl<'a> reader::Script<'a> {
+        pub fn try_code_hash(&self) -> result::Result<Option<&'a str>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_CODE_HASH,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                StringVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?).verify()?;
+            }
+            Ok(self.code_hash())
+        }
+
+        pub fn try_args(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'a str>>>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_ARGS,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                verifier.verify_reference_elements::<StringVerifier>(&Verification::default())?;
+            }
+            Ok(self.args())
+        }
+
+        pub fn try_hash_type(&self) -> result::Result<u32, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_HASH_TYPE,
+                4,
+                4,
+            )?;
+            Ok(self.hash_type())
+        }
+    }
+}
@@ -1,95 +1,229 @@
 #![macro_use]
 
+#[rustfmt::skip]
+pub mod bit_flags_builder;
+#[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod bit_flags_generated;
+#[rustfmt::skip]
+pub mod bit_flags_generated_verifier;
 #[rustfmt::skip]
 pub mod ckb_builder;
 #[rustfmt::skip]
-#[allow(clippy::all)]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
 pub mod ckb_generated;
 #[rustfmt::skip]
 pub mod ckb_generated_verifier;
 #[rustfmt::skip]
 pub mod data_alignment_builder;
 #[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod data_alignment_generated;
+#[rustfmt::skip]
+pub mod data_alignment_generated_verifier;
+#[rustfmt::skip]
 pub mod data_order_builder;
 #[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod data_order_generated;
+#[rustfmt::skip]
+pub mod data_order_generated_verifier;
+#[rustfmt::skip]
+pub mod default_value_builder;
+#[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod default_value_generated;
+#[rustfmt::skip]
+pub mod default_value_generated_verifier;
+#[rustfmt::skip]
+pub mod deprecated_builder;
+#[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod deprecated_generated;
+#[rustfmt::skip]
+pub mod deprecated_generated_verifier;
+#[rustfmt::skip]
 pub mod enum_builder;
 #[rustfmt::skip]
-#[allow(clippy::all)]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
 pub mod enum_generated;
 #[rustfmt::skip]
+pub mod enum_generated_verifier;
+#[rustfmt::skip]
 pub mod enum_vector_builder;
 #[rustfmt::skip]
-#[allow(clippy::all)]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
 pub mod enum_vector_generated;
 #[rustfmt::skip]
+pub mod enum_vector_generated_verifier;
+#[rustfmt::skip]
+pub mod evolution_v1_builder;
+#[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod evolution_v1_generated;
+#[rustfmt::skip]
+pub mod evolution_v1_generated_verifier;
+#[rustfmt::skip]
+pub mod evolution_v2_builder;
+#[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod evolution_v2_generated;
+#[rustfmt::skip]
+pub mod evolution_v2_generated_verifier;
+#[rustfmt::skip]
+pub mod force_align_builder;
+#[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod force_align_generated;
+#[rustfmt::skip]
+pub mod force_align_generated_verifier;
+#[rustfmt::skip]
+pub mod length_bounds_builder;
+#[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod length_bounds_generated;
+#[rustfmt::skip]
+pub mod length_bounds_generated_verifier;
+#[rustfmt::skip]
 pub mod nested_buffer_builder;
 #[rustfmt::skip]
-#[allow(clippy::all)]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
 pub mod nested_buffer_generated;
 #[rustfmt::skip]
+pub mod nested_buffer_generated_verifier;
+#[rustfmt::skip]
+pub mod offset64_builder;
+#[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod offset64_generated;
+#[rustfmt::skip]
+pub mod offset64_generated_verifier;
+#[rustfmt::skip]
+pub mod optional_builder;
+#[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod optional_generated;
+#[rustfmt::skip]
+pub mod optional_generated_verifier;
+#[rustfmt::skip]
 pub mod scalar_vector_builder;
 #[rustfmt::skip]
-#[allow(clippy::all)]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
 pub mod scalar_vector_generated;
 #[rustfmt::skip]
 pub mod scalar_vector_generated_verifier;
 #[rustfmt::skip]
 pub mod scalars_with_different_size_builder;
 #[rustfmt::skip]
-#[allow(clippy::all)]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
 pub mod scalars_with_different_size_generated;
 #[rustfmt::skip]
+pub mod scalars_with_different_size_generated_verifier;
+#[rustfmt::skip]
 pub mod scalars_with_same_size_builder;
 #[rustfmt::skip]
-#[allow(clippy::all)]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
 pub mod scalars_with_same_size_generated;
 #[rustfmt::skip]
+pub mod scalars_with_same_size_generated_verifier;
+#[rustfmt::skip]
+pub mod sorted_vector_builder;
+#[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod sorted_vector_generated;
+#[rustfmt::skip]
+pub mod sorted_vector_generated_verifier;
+#[rustfmt::skip]
 pub mod string_builder;
 #[rustfmt::skip]
-#[allow(clippy::all)]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
 pub mod string_generated;
 #[rustfmt::skip]
 pub mod string_generated_verifier;
 #[rustfmt::skip]
 pub mod string_vector_builder;
 #[rustfmt::skip]
-#[allow(clippy::all)]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
 pub mod string_vector_generated;
 #[rustfmt::skip]
 pub mod string_vector_generated_verifier;
 #[rustfmt::skip]
+pub mod struct_array_builder;
+#[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod struct_array_generated;
+#[rustfmt::skip]
+pub mod struct_array_generated_verifier;
+#[rustfmt::skip]
 pub mod struct_builder;
 #[rustfmt::skip]
-#[allow(clippy::all)]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
 pub mod struct_generated;
 #[rustfmt::skip]
+pub mod struct_generated_verifier;
+#[rustfmt::skip]
 pub mod struct_vector_builder;
 #[rustfmt::skip]
-#[allow(clippy::all)]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
 pub mod struct_vector_generated;
 #[rustfmt::skip]
+pub mod struct_vector_generated_verifier;
+#[rustfmt::skip]
 pub mod table_field_builder;
 #[rustfmt::skip]
-#[allow(clippy::all)]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
 pub mod table_field_generated;
 #[rustfmt::skip]
 pub mod table_field_generated_verifier;
 #[rustfmt::skip]
 pub mod table_fields_order_builder;
 #[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod table_fields_order_generated {
+    #![allow(dead_code)]
+
+    // flatc does not import `EndianScalar` for definitions outside of any namespace.
+    use flatbuffers::EndianScalar;
+    include!("table_fields_order_generated.rs");
+}
+#[rustfmt::skip]
+pub mod table_fields_order_generated_verifier;
+#[rustfmt::skip]
 pub mod table_vector_builder;
 #[rustfmt::skip]
-#[allow(clippy::all)]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
 pub mod table_vector_generated;
 #[rustfmt::skip]
 pub mod table_vector_generated_verifier;
 #[rustfmt::skip]
 pub mod union_builder;
 #[rustfmt::skip]
-#[allow(clippy::all)]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
 pub mod union_generated;
 #[rustfmt::skip]
 pub mod union_generated_verifier;
+#[rustfmt::skip]
+pub mod union_variants_builder;
+#[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod union_variants_generated;
+#[rustfmt::skip]
+pub mod union_variants_generated_verifier;
+#[rustfmt::skip]
+pub mod union_vector_builder;
+#[rustfmt::skip]
+#[allow(clippy::all, mismatched_lifetime_syntaxes)]
+pub mod union_vector_generated;
+#[rustfmt::skip]
+pub mod union_vector_generated_verifier;
+#[rustfmt::skip]
+pub mod validate_builder;
+#[rustfmt::skip]
+#[allow(clippy::all, dead_code, mismatched_lifetime_syntaxes)]
+pub mod validate_generated;
+#[rustfmt::skip]
+pub mod validate_generated_verifier;
+pub mod validate_rules;
 
 use flatbuffers::{Follow, Vector};
 
@@ -1,14 +1,21 @@
 //! This file is auto-generated by cfbc.
-#![allow(unused_imports, clippy::large_enum_variant)]
+#![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
 
 use cfb::builder::{
     Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
-    ScalarVectorComponent, StringComponent,
+    ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
 };
 use cfb::scalar::Scalar;
 use cfb::types::{SOffset, SIZE_OF_SOFFSET};
-#[cfg(not(target_endian = "little"))]
-use std::mem::transmute;
+use std::cmp::Ordering;
+#[cfg(feature = "proptest")]
+use cfb::strategy;
+#[cfg(feature = "proptest")]
+use proptest::{
+    collection::vec,
+    prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+    sample::select,
+};
 
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Block {
@@ -50,6 +57,85 @@ impl<'c> Component<'c> for Block {
     }
 }
 
+/// Builds the table without consuming it, so the same value can be encoded repeatedly.
+impl<'c> Component<'c> for &'c Block {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.header.is_some() {
+                vtable.add_field(Block::VT_HEADER, Block::SIZE_HEADER, Block::ALIGNMENT_HEADER);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Block::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if let Some(f) = &self.header {
+            builder.align(Block::ALIGNMENT_HEADER);
+            let offset_position = builder.tell();
+            builder.pad(Block::SIZE_HEADER);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(NestedBufferComponent::new(f)),
+            ));
+        }
+
+        table_start
+    }
+}
+
+/// The variant of `Block` which borrows its strings and vectors, see `--borrowed`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct BlockRef {
+    pub header: Option<HeaderRef>,
+}
+
+impl<'c> Component<'c> for BlockRef {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.header.is_some() {
+                vtable.add_field(Block::VT_HEADER, Block::SIZE_HEADER, Block::ALIGNMENT_HEADER);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Block::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if let Some(f) = self.header {
+            builder.align(Block::ALIGNMENT_HEADER);
+            let offset_position = builder.tell();
+            builder.pad(Block::SIZE_HEADER);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(NestedBufferComponent::new(f)),
+            ));
+        }
+
+        table_start
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Block {
+    type Parameters = strategy::Config;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+        (
+            strategy::option(&config, any_with::<Header>(config.clone())),
+        )
+            .prop_map(|(header,)| Block {
+                header,
+            })
+            .boxed()
+    }
+}
+
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Header {
     pub number: u64,
@@ -83,4 +169,73 @@ impl<'c> Component<'c> for Header {
 
         table_start
     }
+}
+
+/// Builds the table without consuming it, so the same value can be encoded repeatedly.
+impl<'c> Component<'c> for &'c Header {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.number != 0u64 {
+                vtable.add_field(Header::VT_NUMBER, Header::SIZE_NUMBER, Header::ALIGNMENT_NUMBER);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Header::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.number != 0u64 {
+            builder.align(Header::ALIGNMENT_NUMBER);
+            builder.push_scalar(self.number);
+        }
+
+        table_start
+    }
+}
+
+/// The variant of `Header` which borrows its strings and vectors, see `--borrowed`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct HeaderRef {
+    pub number: u64,
+}
+
+impl<'c> Component<'c> for HeaderRef {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.number != 0u64 {
+                vtable.add_field(Header::VT_NUMBER, Header::SIZE_NUMBER, Header::ALIGNMENT_NUMBER);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Header::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.number != 0u64 {
+            builder.align(Header::ALIGNMENT_NUMBER);
+            builder.push_scalar(self.number);
+        }
+
+        table_start
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Header {
+    type Parameters = strategy::Config;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<u64>(),
+        )
+            .prop_map(|(number,)| Header {
+                number,
+            })
+            .boxed()
+    }
 }
\ No newline at end of file
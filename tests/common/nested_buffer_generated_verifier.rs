@@ -1,163 +1,69 @@
 //! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
 use super::nested_buffer_generated as reader;
 
+use flatbuffers::{self, Follow};
+use flatbuffers_verifier::{
+    lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+    verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+    TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+};
+use std::cmp::Ordering;
+use std::result;
 
 impl<'a> Verify for reader::Block<'a> {
-    fn verify(&self) -> Result {
-        let tab = self._tab;
-        let buf = tab.buf;
-        let buf_len = buf.len();
-
-        if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab_loc = {
-            let soffset_slice = &buf[tab.loc..];
-            let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-            if soffset >= 0 {
-                tab.loc.checked_sub(soffset as usize)
-            } else {
-                soffset
-                    .checked_neg()
-                    .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-            }
-        }
-        .ok_or(Error::OutOfBounds)?;
-        if vtab_loc
-            .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab = tab.vtable();
-        let vtab_num_bytes = vtab.num_bytes();
-        let object_inline_num_bytes = vtab.object_inline_num_bytes();
-        if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-            || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if vtab_loc
-            .checked_add(vtab_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if tab
-            .loc
-            .checked_add(object_inline_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-
-        for i in 0..vtab.num_fields() {
-            let voffset = vtab.get_field(i) as usize;
-            if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                || voffset >= object_inline_num_bytes
-            {
-                return Err(Error::OutOfBounds);
-            }
-        }
-
-        if Self::VT_HEADER as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_HEADER) as usize;
-            if voffset > 0 {
-                if voffset + 4 > object_inline_num_bytes {
-                    return Err(Error::OutOfBounds);
-                }
-
-                let header_verifier = VectorVerifier::follow(
-                    buf,
-                    try_follow_uoffset(buf, tab.loc + voffset)?,
-                );
-                header_verifier.verify_scalar_elements(1)?;
-            }
-        }
-
+    fn verify_in(&self, verification: &Verification) -> Result {
+        let table = TableVerifier::new(&self._tab, verification)?;
+        table.verify_known_fields(6)?;
+        table.field_vector(Self::VT_HEADER, "header", 1, |vector| {
+            vector.verify_scalar_elements(1, 1)
+        })?;
         Ok(())
     }
 }
 
-impl<'a> Verify for reader::Header<'a> {
-    fn verify(&self) -> Result {
-        let tab = self._tab;
-        let buf = tab.buf;
-        let buf_len = buf.len();
-
-        if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab_loc = {
-            let soffset_slice = &buf[tab.loc..];
-            let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-            if soffset >= 0 {
-                tab.loc.checked_sub(soffset as usize)
-            } else {
-                soffset
-                    .checked_neg()
-                    .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-            }
-        }
-        .ok_or(Error::OutOfBounds)?;
-        if vtab_loc
-            .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
+impl Root for reader::Block<'_> {
+    type Reader<'a> = reader::Block<'a>;
+}
 
-        let vtab = tab.vtable();
-        let vtab_num_bytes = vtab.num_bytes();
-        let object_inline_num_bytes = vtab.object_inline_num_bytes();
-        if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-            || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if vtab_loc
-            .checked_add(vtab_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if tab
-            .loc
-            .checked_add(object_inline_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
+impl<'a> reader::Block<'a> {
+    pub fn try_header(&self) -> result::Result<Option<&'a [u8]>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_HEADER,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+            verifier.verify_scalar_elements(1, 1)?;
+        }
+        Ok(self.header())
+    }
+}
 
-        for i in 0..vtab.num_fields() {
-            let voffset = vtab.get_field(i) as usize;
-            if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                || voffset >= object_inline_num_bytes
-            {
-                return Err(Error::OutOfBounds);
-            }
-        }
+impl<'a> Verify for reader::Header<'a> {
+    fn verify_in(&self, verification: &Verification) -> Result {
+        let table = TableVerifier::new(&self._tab, verification)?;
+        table.verify_known_fields(6)?;
+        table.field_scalar::<u64>(Self::VT_NUMBER)?;
+        table.field_default(Self::VT_NUMBER, |value: u64| value == 0u64)?;
+        Ok(())
+    }
+}
 
-        if Self::VT_NUMBER as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_NUMBER) as usize;
-            if voffset > 0 && object_inline_num_bytes - voffset < 8 {
-                return Err(Error::OutOfBounds);
-            }
-        }
+impl Root for reader::Header<'_> {
+    type Reader<'a> = reader::Header<'a>;
+}
 
-        Ok(())
+impl<'a> reader::Header<'a> {
+    pub fn try_number(&self) -> result::Result<u64, Error> {
+        try_field_loc(
+            &self._tab,
+            Self::VT_NUMBER,
+            8,
+            8,
+        )?;
+        Ok(self.number())
     }
 }
\ No newline at end of file
@@ -0,0 +1,325 @@
+//! This file is auto-generated by cfbc.
+pub mod example {
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
+
+    use cfb::builder::{
+        Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
+    };
+    use cfb::scalar::Scalar;
+    use cfb::types::{SOffset, SIZE_OF_SOFFSET};
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
+
+    #[repr(C, align(4))]
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+
+    impl Point {
+        pub fn is_present(&self) -> bool {
+            self.x != 0i32 || self.y != 0i32
+        }
+    }
+
+    impl Scalar for Point {
+        #[cfg(target_endian = "little")]
+        fn to_le(self) -> Self {
+            self
+        }
+
+        #[cfg(target_endian = "little")]
+        fn from_le(x: Self) -> Self {
+            x
+        }
+
+        #[cfg(not(target_endian = "little"))]
+        fn to_le(mut self) -> Self {
+            self.x = self.x.to_le();
+            self.y = self.y.to_le();
+            self
+        }
+
+        #[cfg(not(target_endian = "little"))]
+        fn from_le(mut x: Self) -> Self {
+            x.x = Scalar::from_le(x.x);
+            x.y = Scalar::from_le(x.y);
+            x
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Point {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<i32>(),
+                any::<i32>(),
+            )
+                .prop_map(|(x, y)| Point {
+                    x,
+                    y,
+                })
+                .boxed()
+        }
+    }
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Snapshot {
+        pub name: String,
+        pub blocks: Vec<u8>,
+        pub points: Vec<Point>,
+        pub ids: Vec<u32>,
+    }
+
+    impl Snapshot {
+        const VT_NAME: usize = 4;
+        const SIZE_NAME: usize = 4;
+        const ALIGNMENT_NAME: usize = 4;
+        const VT_BLOCKS: usize = 6;
+        const SIZE_BLOCKS: usize = 8;
+        const ALIGNMENT_BLOCKS: usize = 8;
+        const VT_POINTS: usize = 8;
+        const SIZE_POINTS: usize = 8;
+        const ALIGNMENT_POINTS: usize = 8;
+        const VT_IDS: usize = 10;
+        const SIZE_IDS: usize = 4;
+        const ALIGNMENT_IDS: usize = 4;
+        const ALIGNMENT: usize = 8;
+    }
+
+    impl<'c> Component<'c> for Snapshot {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.blocks.is_empty() {
+                    vtable.add_field(Self::VT_BLOCKS, Self::SIZE_BLOCKS, Self::ALIGNMENT_BLOCKS);
+                }
+                if !self.points.is_empty() {
+                    vtable.add_field(Self::VT_POINTS, Self::SIZE_POINTS, Self::ALIGNMENT_POINTS);
+                }
+                if !self.name.is_empty() {
+                    vtable.add_field(Self::VT_NAME, Self::SIZE_NAME, Self::ALIGNMENT_NAME);
+                }
+                if !self.ids.is_empty() {
+                    vtable.add_field(Self::VT_IDS, Self::SIZE_IDS, Self::ALIGNMENT_IDS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.blocks.is_empty() {
+                builder.align(Self::ALIGNMENT_BLOCKS);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_BLOCKS);
+                builder.push_component(DesignatedComponent::with_offset64(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.blocks, 1)),
+                ));
+            }
+            if !self.points.is_empty() {
+                builder.align(Self::ALIGNMENT_POINTS);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_POINTS);
+                builder.push_component(DesignatedComponent::with_offset64(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.points, 4)),
+                ));
+            }
+            if !self.name.is_empty() {
+                builder.align(Self::ALIGNMENT_NAME);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_NAME);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(self.name))
+                ));
+            }
+            if !self.ids.is_empty() {
+                builder.align(Self::ALIGNMENT_IDS);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_IDS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.ids, 4)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Snapshot {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.blocks.is_empty() {
+                    vtable.add_field(Snapshot::VT_BLOCKS, Snapshot::SIZE_BLOCKS, Snapshot::ALIGNMENT_BLOCKS);
+                }
+                if !self.points.is_empty() {
+                    vtable.add_field(Snapshot::VT_POINTS, Snapshot::SIZE_POINTS, Snapshot::ALIGNMENT_POINTS);
+                }
+                if !self.name.is_empty() {
+                    vtable.add_field(Snapshot::VT_NAME, Snapshot::SIZE_NAME, Snapshot::ALIGNMENT_NAME);
+                }
+                if !self.ids.is_empty() {
+                    vtable.add_field(Snapshot::VT_IDS, Snapshot::SIZE_IDS, Snapshot::ALIGNMENT_IDS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Snapshot::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.blocks.is_empty() {
+                builder.align(Snapshot::ALIGNMENT_BLOCKS);
+                let offset_position = builder.tell();
+                builder.pad(Snapshot::SIZE_BLOCKS);
+                builder.push_component(DesignatedComponent::with_offset64(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.blocks.iter().copied(), 1)),
+                ));
+            }
+            if !self.points.is_empty() {
+                builder.align(Snapshot::ALIGNMENT_POINTS);
+                let offset_position = builder.tell();
+                builder.pad(Snapshot::SIZE_POINTS);
+                builder.push_component(DesignatedComponent::with_offset64(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.points.iter().copied(), 4)),
+                ));
+            }
+            if !self.name.is_empty() {
+                builder.align(Snapshot::ALIGNMENT_NAME);
+                let offset_position = builder.tell();
+                builder.pad(Snapshot::SIZE_NAME);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(&self.name))
+                ));
+            }
+            if !self.ids.is_empty() {
+                builder.align(Snapshot::ALIGNMENT_IDS);
+                let offset_position = builder.tell();
+                builder.pad(Snapshot::SIZE_IDS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.ids.iter().copied(), 4)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Snapshot` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct SnapshotRef<'a> {
+        pub name: &'a str,
+        pub blocks: &'a [u8],
+        pub points: &'a [Point],
+        pub ids: &'a [u32],
+    }
+
+    impl<'c> Component<'c> for SnapshotRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.blocks.is_empty() {
+                    vtable.add_field(Snapshot::VT_BLOCKS, Snapshot::SIZE_BLOCKS, Snapshot::ALIGNMENT_BLOCKS);
+                }
+                if !self.points.is_empty() {
+                    vtable.add_field(Snapshot::VT_POINTS, Snapshot::SIZE_POINTS, Snapshot::ALIGNMENT_POINTS);
+                }
+                if !self.name.is_empty() {
+                    vtable.add_field(Snapshot::VT_NAME, Snapshot::SIZE_NAME, Snapshot::ALIGNMENT_NAME);
+                }
+                if !self.ids.is_empty() {
+                    vtable.add_field(Snapshot::VT_IDS, Snapshot::SIZE_IDS, Snapshot::ALIGNMENT_IDS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Snapshot::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.blocks.is_empty() {
+                builder.align(Snapshot::ALIGNMENT_BLOCKS);
+                let offset_position = builder.tell();
+                builder.pad(Snapshot::SIZE_BLOCKS);
+                builder.push_component(DesignatedComponent::with_offset64(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.blocks.iter().copied(), 1)),
+                ));
+            }
+            if !self.points.is_empty() {
+                builder.align(Snapshot::ALIGNMENT_POINTS);
+                let offset_position = builder.tell();
+                builder.pad(Snapshot::SIZE_POINTS);
+                builder.push_component(DesignatedComponent::with_offset64(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.points.iter().copied(), 4)),
+                ));
+            }
+            if !self.name.is_empty() {
+                builder.align(Snapshot::ALIGNMENT_NAME);
+                let offset_position = builder.tell();
+                builder.pad(Snapshot::SIZE_NAME);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(self.name))
+                ));
+            }
+            if !self.ids.is_empty() {
+                builder.align(Snapshot::ALIGNMENT_IDS);
+                let offset_position = builder.tell();
+                builder.pad(Snapshot::SIZE_IDS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.ids.iter().copied(), 4)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Snapshot {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                strategy::string(&config),
+                vec(any::<u8>(), config.scalar_vector_len.clone()),
+                vec(any::<Point>(), config.scalar_vector_len.clone()),
+                vec(any::<u32>(), config.scalar_vector_len.clone()),
+            )
+                .prop_map(|(name, blocks, points, ids)| Snapshot {
+                    name,
+                    blocks,
+                    points,
+                    ids,
+                })
+                .boxed()
+        }
+    }
+}
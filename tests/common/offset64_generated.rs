@@ -0,0 +1,141 @@
+// Written by hand in the style of the flatc output, since flatc 0.6 does not support the attribute
+// `offset64`. `make gen` does not regenerate it, so keep it in sync with the schema.
+
+
+pub mod example {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+  use std::convert::TryFrom;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+
+// struct Point, aligned to 4
+#[repr(C, align(4))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Point {
+  x_: i32,
+  y_: i32,
+} // pub struct Point
+impl flatbuffers::SafeSliceAccess for Point {}
+impl<'a> flatbuffers::Follow<'a> for Point {
+  type Inner = &'a Point;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a Point>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Point {
+  type Inner = &'a Point;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<Point>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for Point {
+    type Output = Point;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(self as *const Point as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+impl<'b> flatbuffers::Push for &'b Point {
+    type Output = Point;
+
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(*self as *const Point as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+
+
+impl Point {
+  pub fn new<'a>(_x: i32, _y: i32) -> Self {
+    Point {
+      x_: _x.to_little_endian(),
+      y_: _y.to_little_endian(),
+
+    }
+  }
+  pub fn x<'a>(&'a self) -> i32 {
+    self.x_.from_little_endian()
+  }
+  pub fn y<'a>(&'a self) -> i32 {
+    self.y_.from_little_endian()
+  }
+}
+
+// The FlatBuffers 0.6 compiler does not support the attribute `offset64`, so the accessors of
+// `blocks` and `points` are written by hand and there is no builder.
+pub enum SnapshotOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Snapshot<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Snapshot<'a> {
+    type Inner = Snapshot<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Snapshot<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Snapshot {
+            _tab: table,
+        }
+    }
+
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_BLOCKS: flatbuffers::VOffsetT = 6;
+    pub const VT_POINTS: flatbuffers::VOffsetT = 8;
+    pub const VT_IDS: flatbuffers::VOffsetT = 10;
+
+  /// Follows the 64-bit uoffset stored in the field at `slot` to the vector it references.
+  #[inline]
+  fn follow_offset64(&self, slot: flatbuffers::VOffsetT) -> Option<usize> {
+    let field_loc = match self._tab.vtable().get(slot) {
+      0 => return None,
+      voffset => self._tab.loc + voffset as usize,
+    };
+    let offset = flatbuffers::read_scalar_at::<u64>(self._tab.buf, field_loc);
+    let offset = usize::try_from(offset).expect("uoffset64 does not fit in usize");
+    Some(field_loc + offset)
+  }
+
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Snapshot::VT_NAME, None)
+  }
+  #[inline]
+  pub fn blocks(&self) -> Option<&'a [u8]> {
+    self.follow_offset64(Snapshot::VT_BLOCKS)
+      .map(|loc| flatbuffers::Vector::<'a, u8>::new(self._tab.buf, loc).safe_slice())
+  }
+  #[inline]
+  pub fn points(&self) -> Option<&'a [Point]> {
+    self.follow_offset64(Snapshot::VT_POINTS)
+      .map(|loc| flatbuffers::Vector::<'a, Point>::new(self._tab.buf, loc).safe_slice())
+  }
+  #[inline]
+  pub fn ids(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(Snapshot::VT_IDS, None)
+  }
+}
+
+}  // pub mod example
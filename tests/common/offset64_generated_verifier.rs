@@ -0,0 +1,93 @@
+//! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
+use super::offset64_generated as reader;
+
+pub mod example {
+    #![allow(unused_imports)]
+
+    use super::reader::example as reader;
+    use flatbuffers::{self, Follow};
+    use flatbuffers_verifier::{
+        lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+        verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+        TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+    };
+    use std::cmp::Ordering;
+    use std::result;
+
+    impl<'a> Verify for reader::Snapshot<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(12)?;
+            table.field_string(Self::VT_NAME)?;
+            table.field_vector64(Self::VT_BLOCKS, "blocks", 1, |vector| {
+                vector.verify_scalar_elements(1, 1)
+            })?;
+            table.field_vector64(Self::VT_POINTS, "points", 8, |vector| {
+                vector.verify_scalar_elements(8, 4)
+            })?;
+            table.field_vector(Self::VT_IDS, "ids", 4, |vector| {
+                vector.verify_scalar_elements(4, 4)
+            })?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Snapshot<'_> {
+        type Reader<'a> = reader::Snapshot<'a>;
+    }
+
+    impl<'a> reader::Snapshot<'a> {
+        pub fn try_name(&self) -> result::Result<Option<&'a str>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_NAME,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                StringVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?).verify()?;
+            }
+            Ok(self.name())
+        }
+
+        pub fn try_blocks(&self) -> result::Result<Option<&'a [u8]>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_BLOCKS,
+                SIZE_UOFFSET64,
+                SIZE_UOFFSET64,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset64(self._tab.buf, loc)?);
+                verifier.verify_scalar_elements(1, 1)?;
+            }
+            Ok(self.blocks())
+        }
+
+        pub fn try_points(&self) -> result::Result<Option<&'a [reader::Point]>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_POINTS,
+                SIZE_UOFFSET64,
+                SIZE_UOFFSET64,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset64(self._tab.buf, loc)?);
+                verifier.verify_scalar_elements(8, 4)?;
+            }
+            Ok(self.points())
+        }
+
+        pub fn try_ids(&self) -> result::Result<Option<flatbuffers::Vector<'a, u32>>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_IDS,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                verifier.verify_scalar_elements(4, 4)?;
+            }
+            Ok(self.ids())
+        }
+    }
+}
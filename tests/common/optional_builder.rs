@@ -0,0 +1,352 @@
+//! This file is auto-generated by cfbc.
+pub mod example {
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
+
+    use cfb::builder::{
+        Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
+    };
+    use cfb::scalar::Scalar;
+    use cfb::types::{SOffset, SIZE_OF_SOFFSET};
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
+
+    /// Values of the enum `Color`, including the ones not declared in the schema, such as values
+    /// added by newer schemas, so they are written back unchanged.
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Color(pub i8);
+
+    #[allow(non_upper_case_globals)]
+    impl Color {
+        pub const Red: Color = Color(0);
+        pub const Green: Color = Color(1);
+        pub const Blue: Color = Color(2);
+
+        /// Tests whether the value is declared in the schema.
+        pub const fn is_known(self) -> bool {
+            matches!(self.0, 0..=2)
+        }
+    }
+
+    impl Default for Color {
+        fn default() -> Self {
+            Color::Red
+        }
+    }
+
+    impl std::fmt::Debug for Color {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match *self {
+                Color::Red => f.write_str("Red"),
+                Color::Green => f.write_str("Green"),
+                Color::Blue => f.write_str("Blue"),
+                Color(raw) => f.debug_tuple("Unknown").field(&raw).finish(),
+            }
+        }
+    }
+
+    impl Scalar for Color {
+        fn to_le(self) -> Self {
+            Color(self.0.to_le())
+        }
+
+        fn from_le(x: Self) -> Self {
+            Color(i8::from_le(x.0))
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Color {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            select(vec![
+                Color::Red,
+                Color::Green,
+                Color::Blue,
+            ])
+            .boxed()
+        }
+    }
+
+    #[repr(C, align(8))]
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct Hash {
+        pub a: u64,
+        pub b: u64,
+    }
+
+    impl Hash {
+        pub fn is_present(&self) -> bool {
+            self.a != 0u64 || self.b != 0u64
+        }
+    }
+
+    impl Scalar for Hash {
+        #[cfg(target_endian = "little")]
+        fn to_le(self) -> Self {
+            self
+        }
+
+        #[cfg(target_endian = "little")]
+        fn from_le(x: Self) -> Self {
+            x
+        }
+
+        #[cfg(not(target_endian = "little"))]
+        fn to_le(mut self) -> Self {
+            self.a = self.a.to_le();
+            self.b = self.b.to_le();
+            self
+        }
+
+        #[cfg(not(target_endian = "little"))]
+        fn from_le(mut x: Self) -> Self {
+            x.a = Scalar::from_le(x.a);
+            x.b = Scalar::from_le(x.b);
+            x
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Hash {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u64>(),
+                any::<u64>(),
+            )
+                .prop_map(|(a, b)| Hash {
+                    a,
+                    b,
+                })
+                .boxed()
+        }
+    }
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Hero {
+        pub hp: Option<u32>,
+        pub alive: Option<bool>,
+        pub color: Option<Color>,
+        pub hash: Option<Hash>,
+        pub checksum: Hash,
+    }
+
+    impl Hero {
+        const VT_HP: usize = 4;
+        const SIZE_HP: usize = 4;
+        const ALIGNMENT_HP: usize = 4;
+        const VT_ALIVE: usize = 6;
+        const SIZE_ALIVE: usize = 1;
+        const ALIGNMENT_ALIVE: usize = 1;
+        const VT_COLOR: usize = 8;
+        const SIZE_COLOR: usize = 1;
+        const ALIGNMENT_COLOR: usize = 1;
+        const VT_HASH: usize = 10;
+        const SIZE_HASH: usize = 16;
+        const ALIGNMENT_HASH: usize = 8;
+        const VT_CHECKSUM: usize = 12;
+        const SIZE_CHECKSUM: usize = 16;
+        const ALIGNMENT_CHECKSUM: usize = 8;
+        const ALIGNMENT: usize = 8;
+    }
+
+    impl<'c> Component<'c> for Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hash.is_some() {
+                    vtable.add_field(Self::VT_HASH, Self::SIZE_HASH, Self::ALIGNMENT_HASH);
+                }
+                if self.checksum.is_present() {
+                    vtable.add_field(Self::VT_CHECKSUM, Self::SIZE_CHECKSUM, Self::ALIGNMENT_CHECKSUM);
+                }
+                if self.hp.is_some() {
+                    vtable.add_field(Self::VT_HP, Self::SIZE_HP, Self::ALIGNMENT_HP);
+                }
+                if self.alive.is_some() {
+                    vtable.add_field(Self::VT_ALIVE, Self::SIZE_ALIVE, Self::ALIGNMENT_ALIVE);
+                }
+                if self.color.is_some() {
+                    vtable.add_field(Self::VT_COLOR, Self::SIZE_COLOR, Self::ALIGNMENT_COLOR);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if let Some(f) = self.hash {
+                builder.align(Self::ALIGNMENT_HASH);
+                builder.push_scalar(f);
+            }
+            if self.checksum.is_present() {
+                builder.align(Self::ALIGNMENT_CHECKSUM);
+                builder.push_scalar(self.checksum);
+            }
+            if let Some(f) = self.hp {
+                builder.align(Self::ALIGNMENT_HP);
+                builder.push_scalar(f);
+            }
+            if let Some(f) = self.alive {
+                builder.align(Self::ALIGNMENT_ALIVE);
+                builder.push_scalar(f);
+            }
+            if let Some(f) = self.color {
+                builder.align(Self::ALIGNMENT_COLOR);
+                builder.push_scalar(f);
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hash.is_some() {
+                    vtable.add_field(Hero::VT_HASH, Hero::SIZE_HASH, Hero::ALIGNMENT_HASH);
+                }
+                if self.checksum.is_present() {
+                    vtable.add_field(Hero::VT_CHECKSUM, Hero::SIZE_CHECKSUM, Hero::ALIGNMENT_CHECKSUM);
+                }
+                if self.hp.is_some() {
+                    vtable.add_field(Hero::VT_HP, Hero::SIZE_HP, Hero::ALIGNMENT_HP);
+                }
+                if self.alive.is_some() {
+                    vtable.add_field(Hero::VT_ALIVE, Hero::SIZE_ALIVE, Hero::ALIGNMENT_ALIVE);
+                }
+                if self.color.is_some() {
+                    vtable.add_field(Hero::VT_COLOR, Hero::SIZE_COLOR, Hero::ALIGNMENT_COLOR);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if let Some(f) = self.hash {
+                builder.align(Hero::ALIGNMENT_HASH);
+                builder.push_scalar(f);
+            }
+            if self.checksum.is_present() {
+                builder.align(Hero::ALIGNMENT_CHECKSUM);
+                builder.push_scalar(self.checksum);
+            }
+            if let Some(f) = self.hp {
+                builder.align(Hero::ALIGNMENT_HP);
+                builder.push_scalar(f);
+            }
+            if let Some(f) = self.alive {
+                builder.align(Hero::ALIGNMENT_ALIVE);
+                builder.push_scalar(f);
+            }
+            if let Some(f) = self.color {
+                builder.align(Hero::ALIGNMENT_COLOR);
+                builder.push_scalar(f);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Hero` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct HeroRef {
+        pub hp: Option<u32>,
+        pub alive: Option<bool>,
+        pub color: Option<Color>,
+        pub hash: Option<Hash>,
+        pub checksum: Hash,
+    }
+
+    impl<'c> Component<'c> for HeroRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hash.is_some() {
+                    vtable.add_field(Hero::VT_HASH, Hero::SIZE_HASH, Hero::ALIGNMENT_HASH);
+                }
+                if self.checksum.is_present() {
+                    vtable.add_field(Hero::VT_CHECKSUM, Hero::SIZE_CHECKSUM, Hero::ALIGNMENT_CHECKSUM);
+                }
+                if self.hp.is_some() {
+                    vtable.add_field(Hero::VT_HP, Hero::SIZE_HP, Hero::ALIGNMENT_HP);
+                }
+                if self.alive.is_some() {
+                    vtable.add_field(Hero::VT_ALIVE, Hero::SIZE_ALIVE, Hero::ALIGNMENT_ALIVE);
+                }
+                if self.color.is_some() {
+                    vtable.add_field(Hero::VT_COLOR, Hero::SIZE_COLOR, Hero::ALIGNMENT_COLOR);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if let Some(f) = self.hash {
+                builder.align(Hero::ALIGNMENT_HASH);
+                builder.push_scalar(f);
+            }
+            if self.checksum.is_present() {
+                builder.align(Hero::ALIGNMENT_CHECKSUM);
+                builder.push_scalar(self.checksum);
+            }
+            if let Some(f) = self.hp {
+                builder.align(Hero::ALIGNMENT_HP);
+                builder.push_scalar(f);
+            }
+            if let Some(f) = self.alive {
+                builder.align(Hero::ALIGNMENT_ALIVE);
+                builder.push_scalar(f);
+            }
+            if let Some(f) = self.color {
+                builder.align(Hero::ALIGNMENT_COLOR);
+                builder.push_scalar(f);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Hero {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                strategy::option(&config, any::<u32>()),
+                strategy::option(&config, any::<bool>()),
+                strategy::option(&config, any::<Color>()),
+                strategy::option(&config, any::<Hash>()),
+                any::<Hash>(),
+            )
+                .prop_map(|(hp, alive, color, hash, checksum)| Hero {
+                    hp,
+                    alive,
+                    color,
+                    hash,
+                    checksum,
+                })
+                .boxed()
+        }
+    }
+}
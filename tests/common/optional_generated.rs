@@ -0,0 +1,286 @@
+// Written by hand in the style of the flatc output, since flatc 0.6 cannot parse the `= null`
+// default of optional scalars. `make gen` does not regenerate it, so keep it in sync with the
+// schema.
+
+
+pub mod example {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+
+#[allow(non_camel_case_types)]
+#[repr(i8)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Color {
+  Red = 0,
+  Green = 1,
+  Blue = 2,
+
+}
+
+const ENUM_MIN_COLOR: i8 = 0;
+const ENUM_MAX_COLOR: i8 = 2;
+
+impl<'a> flatbuffers::Follow<'a> for Color {
+  type Inner = Self;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::read_scalar_at::<Self>(buf, loc)
+  }
+}
+
+impl flatbuffers::EndianScalar for Color {
+  #[inline]
+  fn to_little_endian(self) -> Self {
+    let n = i8::to_le(self as i8);
+    let p = &n as *const i8 as *const Color;
+    unsafe { *p }
+  }
+  #[inline]
+  fn from_little_endian(self) -> Self {
+    let n = i8::from_le(self as i8);
+    let p = &n as *const i8 as *const Color;
+    unsafe { *p }
+  }
+}
+
+impl flatbuffers::Push for Color {
+    type Output = Color;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        flatbuffers::emplace_scalar::<Color>(dst, *self);
+    }
+}
+
+#[allow(non_camel_case_types)]
+const ENUM_VALUES_COLOR:[Color; 3] = [
+  Color::Red,
+  Color::Green,
+  Color::Blue
+];
+
+#[allow(non_camel_case_types)]
+const ENUM_NAMES_COLOR:[&'static str; 3] = [
+    "Red",
+    "Green",
+    "Blue"
+];
+
+pub fn enum_name_color(e: Color) -> &'static str {
+  let index: usize = e as usize;
+  ENUM_NAMES_COLOR[index]
+}
+
+// struct Hash, aligned to 8
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hash {
+  a_: u64,
+  b_: u64,
+} // pub struct Hash
+impl flatbuffers::SafeSliceAccess for Hash {}
+impl<'a> flatbuffers::Follow<'a> for Hash {
+  type Inner = &'a Hash;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a Hash>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Hash {
+  type Inner = &'a Hash;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<Hash>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for Hash {
+    type Output = Hash;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(self as *const Hash as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+impl<'b> flatbuffers::Push for &'b Hash {
+    type Output = Hash;
+
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(*self as *const Hash as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+
+
+impl Hash {
+  pub fn new<'a>(_a: u64, _b: u64) -> Self {
+    Hash {
+      a_: _a.to_little_endian(),
+      b_: _b.to_little_endian(),
+
+    }
+  }
+  pub fn a<'a>(&'a self) -> u64 {
+    self.a_.from_little_endian()
+  }
+  pub fn b<'a>(&'a self) -> u64 {
+    self.b_.from_little_endian()
+  }
+}
+
+pub enum HeroOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Hero<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Hero<'a> {
+    type Inner = Hero<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Hero<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Hero {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args HeroArgs<'args>) -> flatbuffers::WIPOffset<Hero<'bldr>> {
+      let mut builder = HeroBuilder::new(_fbb);
+      if let Some(x) = args.checksum { builder.add_checksum(x); }
+      if let Some(x) = args.hash { builder.add_hash(x); }
+      if let Some(x) = args.hp { builder.add_hp(x); }
+      if let Some(x) = args.color { builder.add_color(x); }
+      if let Some(x) = args.alive { builder.add_alive(x); }
+      builder.finish()
+    }
+
+    pub const VT_HP: flatbuffers::VOffsetT = 4;
+    pub const VT_ALIVE: flatbuffers::VOffsetT = 6;
+    pub const VT_COLOR: flatbuffers::VOffsetT = 8;
+    pub const VT_HASH: flatbuffers::VOffsetT = 10;
+    pub const VT_CHECKSUM: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub fn hp(&self) -> Option<u32> {
+    self._tab.get::<u32>(Hero::VT_HP, None)
+  }
+  #[inline]
+  pub fn alive(&self) -> Option<bool> {
+    self._tab.get::<bool>(Hero::VT_ALIVE, None)
+  }
+  #[inline]
+  pub fn color(&self) -> Option<Color> {
+    self._tab.get::<Color>(Hero::VT_COLOR, None)
+  }
+  #[inline]
+  pub fn hash(&self) -> Option<&'a Hash> {
+    self._tab.get::<Hash>(Hero::VT_HASH, None)
+  }
+  #[inline]
+  pub fn checksum(&self) -> Option<&'a Hash> {
+    self._tab.get::<Hash>(Hero::VT_CHECKSUM, None)
+  }
+}
+
+pub struct HeroArgs<'a> {
+    pub hp: Option<u32>,
+    pub alive: Option<bool>,
+    pub color: Option<Color>,
+    pub hash: Option<&'a  Hash>,
+    pub checksum: Option<&'a  Hash>,
+}
+impl<'a> Default for HeroArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        HeroArgs {
+            hp: None,
+            alive: None,
+            color: None,
+            hash: None,
+            checksum: None,
+        }
+    }
+}
+pub struct HeroBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HeroBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_hp(&mut self, hp: u32) {
+    self.fbb_.push_slot_always::<u32>(Hero::VT_HP, hp);
+  }
+  #[inline]
+  pub fn add_alive(&mut self, alive: bool) {
+    self.fbb_.push_slot_always::<bool>(Hero::VT_ALIVE, alive);
+  }
+  #[inline]
+  pub fn add_color(&mut self, color: Color) {
+    self.fbb_.push_slot_always::<Color>(Hero::VT_COLOR, color);
+  }
+  #[inline]
+  pub fn add_hash(&mut self, hash: &'b  Hash) {
+    self.fbb_.push_slot_always::<&Hash>(Hero::VT_HASH, hash);
+  }
+  #[inline]
+  pub fn add_checksum(&mut self, checksum: &'b  Hash) {
+    self.fbb_.push_slot_always::<&Hash>(Hero::VT_CHECKSUM, checksum);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> HeroBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    HeroBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Hero<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+#[inline]
+pub fn get_root_as_hero<'a>(buf: &'a [u8]) -> Hero<'a> {
+  flatbuffers::get_root::<Hero<'a>>(buf)
+}
+
+#[inline]
+pub fn get_size_prefixed_root_as_hero<'a>(buf: &'a [u8]) -> Hero<'a> {
+  flatbuffers::get_size_prefixed_root::<Hero<'a>>(buf)
+}
+
+#[inline]
+pub fn finish_hero_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Hero<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_hero_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Hero<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
+}  // pub mod example
+
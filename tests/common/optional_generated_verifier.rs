@@ -0,0 +1,104 @@
+//! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
+use super::optional_generated as reader;
+
+pub mod example {
+    #![allow(unused_imports)]
+
+    use super::reader::example as reader;
+    use flatbuffers::{self, Follow};
+    use flatbuffers_verifier::{
+        lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+        verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+        TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+    };
+    use std::cmp::Ordering;
+    use std::result;
+
+    impl<'a> Verify for reader::Hero<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(14)?;
+            table.field_scalar::<u32>(Self::VT_HP)?;
+            table.field_bool(Self::VT_ALIVE)?;
+            table.field_enum(Self::VT_COLOR, |value: i8| matches!(value, 0..=2))?;
+            table.field_struct(Self::VT_HASH, 16, 8)?;
+            table.field_struct(Self::VT_CHECKSUM, 16, 8)?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Hero<'_> {
+        type Reader<'a> = reader::Hero<'a>;
+    }
+
+    impl<'a> reader::Hero<'a> {
+        pub fn try_hp(&self) -> result::Result<Option<u32>, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_HP,
+                4,
+                4,
+            )?;
+            Ok(self.hp())
+        }
+
+        pub fn try_alive(&self) -> result::Result<Option<bool>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_ALIVE,
+                1,
+                1,
+            )? {
+                if self._tab.buf[loc] > 1 {
+                    return Err(Error::InvalidBool);
+                }
+            }
+            Ok(self.alive())
+        }
+
+        pub fn try_color(&self) -> result::Result<Option<reader::Color>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_COLOR,
+                1,
+                1,
+            )? {
+                if !matches!(
+                    read_scalar_at::<i8>(self._tab.buf, loc),
+                    0..=2
+                ) {
+                    return Err(Error::UnknownEnumValue);
+                }
+            }
+            Ok(self.color())
+        }
+
+        /// Reads `color` as the underlying value of the enum, which may be undeclared in
+        /// the schema, see `Options::accept_unknown_enum_values`.
+        pub fn raw_color(&self) -> Option<i8> {
+            self._tab.get::<i8>(Self::VT_COLOR, None)
+        }
+
+        pub fn try_hash(&self) -> result::Result<Option<&'a reader::Hash>, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_HASH,
+                16,
+                8,
+            )?;
+            Ok(self.hash())
+        }
+
+        pub fn try_checksum(&self) -> result::Result<Option<&'a reader::Hash>, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_CHECKSUM,
+                16,
+                8,
+            )?;
+            Ok(self.checksum())
+        }
+    }
+}
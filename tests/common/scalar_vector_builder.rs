@@ -1,15 +1,22 @@
 //! This file is auto-generated by cfbc.
 pub mod example {
-    #![allow(unused_imports, clippy::large_enum_variant)]
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
 
     use cfb::builder::{
         Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
-        ScalarVectorComponent, StringComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
     };
     use cfb::scalar::Scalar;
     use cfb::types::{SOffset, SIZE_OF_SOFFSET};
-    #[cfg(not(target_endian = "little"))]
-    use std::mem::transmute;
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
 
     #[derive(Default, Clone, Debug, PartialEq)]
     pub struct Sensor {
@@ -50,4 +57,83 @@ pub mod example {
             table_start
         }
     }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Sensor {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.readings.is_empty() {
+                    vtable.add_field(Sensor::VT_READINGS, Sensor::SIZE_READINGS, Sensor::ALIGNMENT_READINGS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Sensor::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.readings.is_empty() {
+                builder.align(Sensor::ALIGNMENT_READINGS);
+                let offset_position = builder.tell();
+                builder.pad(Sensor::SIZE_READINGS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.readings.iter().copied(), 4)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Sensor` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct SensorRef<'a> {
+        pub readings: &'a [u32],
+    }
+
+    impl<'c> Component<'c> for SensorRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.readings.is_empty() {
+                    vtable.add_field(Sensor::VT_READINGS, Sensor::SIZE_READINGS, Sensor::ALIGNMENT_READINGS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Sensor::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.readings.is_empty() {
+                builder.align(Sensor::ALIGNMENT_READINGS);
+                let offset_position = builder.tell();
+                builder.pad(Sensor::SIZE_READINGS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.readings.iter().copied(), 4)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Sensor {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                vec(any::<u32>(), config.scalar_vector_len.clone()),
+            )
+                .prop_map(|(readings,)| Sensor {
+                    readings,
+                })
+                .boxed()
+        }
+    }
 }
@@ -1,15 +1,22 @@
 //! This file is auto-generated by cfbc.
 pub mod example {
-    #![allow(unused_imports, clippy::large_enum_variant)]
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
 
     use cfb::builder::{
         Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
-        ScalarVectorComponent, StringComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
     };
     use cfb::scalar::Scalar;
     use cfb::types::{SOffset, SIZE_OF_SOFFSET};
-    #[cfg(not(target_endian = "little"))]
-    use std::mem::transmute;
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
 
     #[derive(Default, Clone, Debug, PartialEq)]
     pub struct Account {
@@ -56,4 +63,90 @@ pub mod example {
             table_start
         }
     }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Account {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.balance != 0u64 {
+                    vtable.add_field(Account::VT_BALANCE, Account::SIZE_BALANCE, Account::ALIGNMENT_BALANCE);
+                }
+                if self.year != 0u32 {
+                    vtable.add_field(Account::VT_YEAR, Account::SIZE_YEAR, Account::ALIGNMENT_YEAR);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Account::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.balance != 0u64 {
+                builder.align(Account::ALIGNMENT_BALANCE);
+                builder.push_scalar(self.balance);
+            }
+            if self.year != 0u32 {
+                builder.align(Account::ALIGNMENT_YEAR);
+                builder.push_scalar(self.year);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Account` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct AccountRef {
+        pub year: u32,
+        pub balance: u64,
+    }
+
+    impl<'c> Component<'c> for AccountRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.balance != 0u64 {
+                    vtable.add_field(Account::VT_BALANCE, Account::SIZE_BALANCE, Account::ALIGNMENT_BALANCE);
+                }
+                if self.year != 0u32 {
+                    vtable.add_field(Account::VT_YEAR, Account::SIZE_YEAR, Account::ALIGNMENT_YEAR);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Account::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.balance != 0u64 {
+                builder.align(Account::ALIGNMENT_BALANCE);
+                builder.push_scalar(self.balance);
+            }
+            if self.year != 0u32 {
+                builder.align(Account::ALIGNMENT_YEAR);
+                builder.push_scalar(self.year);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Account {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u32>(),
+                any::<u64>(),
+            )
+                .prop_map(|(year, balance)| Account {
+                    year,
+                    balance,
+                })
+                .boxed()
+        }
+    }
 }
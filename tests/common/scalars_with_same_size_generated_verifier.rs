@@ -1,4 +1,6 @@
 //! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
 use super::scalars_with_same_size_generated as reader;
 
 pub mod example {
@@ -7,92 +9,48 @@ pub mod example {
     use super::reader::example as reader;
     use flatbuffers::{self, Follow};
     use flatbuffers_verifier::{
-        try_follow_uoffset, Error, Result, StringVerifier, VectorVerifier, Verify,
-        MAX_OFFSET_LOC,
+        lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+        verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+        TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
     };
+    use std::cmp::Ordering;
+    use std::result;
 
     impl<'a> Verify for reader::Point<'a> {
-        fn verify(&self) -> Result {
-            let tab = self._tab;
-            let buf = tab.buf;
-            let buf_len = buf.len();
-
-            if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-                return Err(Error::OutOfBounds);
-            }
-
-            let vtab_loc = {
-                let soffset_slice = &buf[tab.loc..];
-                let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-                if soffset >= 0 {
-                    tab.loc.checked_sub(soffset as usize)
-                } else {
-                    soffset
-                        .checked_neg()
-                        .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-                }
-            }
-            .ok_or(Error::OutOfBounds)?;
-            if vtab_loc
-                .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-                .filter(|loc| *loc <= buf_len)
-                .is_none()
-            {
-                return Err(Error::OutOfBounds);
-            }
-
-            let vtab = tab.vtable();
-            let vtab_num_bytes = vtab.num_bytes();
-            let object_inline_num_bytes = vtab.object_inline_num_bytes();
-            if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-                || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-            {
-                return Err(Error::OutOfBounds);
-            }
-            if vtab_loc
-                .checked_add(vtab_num_bytes)
-                .filter(|loc| *loc <= buf_len)
-                .is_none()
-            {
-                return Err(Error::OutOfBounds);
-            }
-            if tab
-                .loc
-                .checked_add(object_inline_num_bytes)
-                .filter(|loc| *loc <= buf_len)
-                .is_none()
-            {
-                return Err(Error::OutOfBounds);
-            }
-
-            for i in 0..vtab.num_fields() {
-                let voffset = vtab.get_field(i) as usize;
-                if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                    || voffset >= object_inline_num_bytes
-                {
-                    return Err(Error::OutOfBounds);
-                }
-            }
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(8)?;
+            table.field_scalar::<u64>(Self::VT_X)?;
+            table.field_default(Self::VT_X, |value: u64| value == 0u64)?;
+            table.field_scalar::<u64>(Self::VT_Y)?;
+            table.field_default(Self::VT_Y, |value: u64| value == 0u64)?;
+            Ok(())
+        }
+    }
 
-            if Self::VT_X as usize + flatbuffers::SIZE_VOFFSET
-                <= vtab_num_bytes
-            {
-                let voffset = vtab.get(Self::VT_X) as usize;
-                if voffset > 0 && object_inline_num_bytes - voffset < 8 {
-                    return Err(Error::OutOfBounds);
-                }
-            }
+    impl Root for reader::Point<'_> {
+        type Reader<'a> = reader::Point<'a>;
+    }
 
-            if Self::VT_Y as usize + flatbuffers::SIZE_VOFFSET
-                <= vtab_num_bytes
-            {
-                let voffset = vtab.get(Self::VT_Y) as usize;
-                if voffset > 0 && object_inline_num_bytes - voffset < 8 {
-                    return Err(Error::OutOfBounds);
-                }
-            }
+    impl<'a> reader::Point<'a> {
+        pub fn try_x(&self) -> result::Result<u64, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_X,
+                8,
+                8,
+            )?;
+            Ok(self.x())
+        }
 
-            Ok(())
+        pub fn try_y(&self) -> result::Result<u64, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_Y,
+                8,
+                8,
+            )?;
+            Ok(self.y())
         }
     }
 }
@@ -0,0 +1,509 @@
+//! This file is auto-generated by cfbc.
+pub mod example {
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
+
+    use cfb::builder::{
+        Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
+    };
+    use cfb::scalar::Scalar;
+    use cfb::types::{SOffset, SIZE_OF_SOFFSET};
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Index {
+        pub monsters: Vec<Monster>,
+        pub stats: Vec<Stat>,
+    }
+
+    impl Index {
+        const VT_MONSTERS: usize = 4;
+        const SIZE_MONSTERS: usize = 4;
+        const ALIGNMENT_MONSTERS: usize = 4;
+        const VT_STATS: usize = 6;
+        const SIZE_STATS: usize = 4;
+        const ALIGNMENT_STATS: usize = 4;
+        const ALIGNMENT: usize = 4;
+    }
+
+    impl<'c> Component<'c> for Index {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.monsters.is_empty() {
+                    vtable.add_field(Self::VT_MONSTERS, Self::SIZE_MONSTERS, Self::ALIGNMENT_MONSTERS);
+                }
+                if !self.stats.is_empty() {
+                    vtable.add_field(Self::VT_STATS, Self::SIZE_STATS, Self::ALIGNMENT_STATS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.monsters.is_empty() {
+                builder.align(Self::ALIGNMENT_MONSTERS);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_MONSTERS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::sorted_by(
+                        "Index.monsters",
+                        self.monsters,
+                        Monster::cmp_by_key,
+                    )),
+                ));
+            }
+            if !self.stats.is_empty() {
+                builder.align(Self::ALIGNMENT_STATS);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_STATS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::sorted_by(
+                        "Index.stats",
+                        self.stats,
+                        Stat::cmp_by_key,
+                    )),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Index {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.monsters.is_empty() {
+                    vtable.add_field(Index::VT_MONSTERS, Index::SIZE_MONSTERS, Index::ALIGNMENT_MONSTERS);
+                }
+                if !self.stats.is_empty() {
+                    vtable.add_field(Index::VT_STATS, Index::SIZE_STATS, Index::ALIGNMENT_STATS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Index::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.monsters.is_empty() {
+                builder.align(Index::ALIGNMENT_MONSTERS);
+                let offset_position = builder.tell();
+                builder.pad(Index::SIZE_MONSTERS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::sorted_by(
+                        "Index.monsters",
+                        self.monsters.iter().collect(),
+                        |a, b| a.cmp_by_key(b),
+                    )),
+                ));
+            }
+            if !self.stats.is_empty() {
+                builder.align(Index::ALIGNMENT_STATS);
+                let offset_position = builder.tell();
+                builder.pad(Index::SIZE_STATS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::sorted_by(
+                        "Index.stats",
+                        self.stats.iter().collect(),
+                        |a, b| a.cmp_by_key(b),
+                    )),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Index` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct IndexRef<'a> {
+        pub monsters: &'a [MonsterRef<'a>],
+        pub stats: &'a [StatRef],
+    }
+
+    impl<'c> Component<'c> for IndexRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.monsters.is_empty() {
+                    vtable.add_field(Index::VT_MONSTERS, Index::SIZE_MONSTERS, Index::ALIGNMENT_MONSTERS);
+                }
+                if !self.stats.is_empty() {
+                    vtable.add_field(Index::VT_STATS, Index::SIZE_STATS, Index::ALIGNMENT_STATS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Index::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.monsters.is_empty() {
+                builder.align(Index::ALIGNMENT_MONSTERS);
+                let offset_position = builder.tell();
+                builder.pad(Index::SIZE_MONSTERS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::sorted_by(
+                        "Index.monsters",
+                        self.monsters.to_vec(),
+                        MonsterRef::cmp_by_key,
+                    )),
+                ));
+            }
+            if !self.stats.is_empty() {
+                builder.align(Index::ALIGNMENT_STATS);
+                let offset_position = builder.tell();
+                builder.pad(Index::SIZE_STATS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::sorted_by(
+                        "Index.stats",
+                        self.stats.to_vec(),
+                        StatRef::cmp_by_key,
+                    )),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Index {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                strategy::unique_vec(any_with::<Monster>(config.clone()), config.vector_len.clone(), Monster::cmp_by_key),
+                strategy::unique_vec(any_with::<Stat>(config.clone()), config.vector_len.clone(), Stat::cmp_by_key),
+            )
+                .prop_map(|(monsters, stats)| Index {
+                    monsters,
+                    stats,
+                })
+                .boxed()
+        }
+    }
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Monster {
+        pub name: String,
+        pub hp: u32,
+    }
+
+    impl Monster {
+        const VT_NAME: usize = 4;
+        const SIZE_NAME: usize = 4;
+        const ALIGNMENT_NAME: usize = 4;
+        const VT_HP: usize = 6;
+        const SIZE_HP: usize = 4;
+        const ALIGNMENT_HP: usize = 4;
+        const ALIGNMENT: usize = 4;
+
+        /// Orders the tables by the key field `name`.
+        pub fn cmp_by_key(&self, other: &Self) -> Ordering {
+            self.name.as_str().cmp(other.name.as_str())
+        }
+    }
+
+    impl<'c> Component<'c> for Monster {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                {
+                    vtable.add_field(Self::VT_NAME, Self::SIZE_NAME, Self::ALIGNMENT_NAME);
+                }
+                if self.hp != 0u32 {
+                    vtable.add_field(Self::VT_HP, Self::SIZE_HP, Self::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            {
+                builder.align(Self::ALIGNMENT_NAME);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_NAME);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(self.name))
+                ));
+            }
+            if self.hp != 0u32 {
+                builder.align(Self::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Monster {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                {
+                    vtable.add_field(Monster::VT_NAME, Monster::SIZE_NAME, Monster::ALIGNMENT_NAME);
+                }
+                if self.hp != 0u32 {
+                    vtable.add_field(Monster::VT_HP, Monster::SIZE_HP, Monster::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Monster::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            {
+                builder.align(Monster::ALIGNMENT_NAME);
+                let offset_position = builder.tell();
+                builder.pad(Monster::SIZE_NAME);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(&self.name))
+                ));
+            }
+            if self.hp != 0u32 {
+                builder.align(Monster::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Monster` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct MonsterRef<'a> {
+        pub name: &'a str,
+        pub hp: u32,
+    }
+
+    impl<'a> MonsterRef<'a> {
+        /// Orders the tables by the key field `name`.
+        pub fn cmp_by_key(&self, other: &Self) -> Ordering {
+            self.name.cmp(other.name)
+        }
+    }
+
+    impl<'c> Component<'c> for MonsterRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                {
+                    vtable.add_field(Monster::VT_NAME, Monster::SIZE_NAME, Monster::ALIGNMENT_NAME);
+                }
+                if self.hp != 0u32 {
+                    vtable.add_field(Monster::VT_HP, Monster::SIZE_HP, Monster::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Monster::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            {
+                builder.align(Monster::ALIGNMENT_NAME);
+                let offset_position = builder.tell();
+                builder.pad(Monster::SIZE_NAME);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(StringComponent::new(self.name))
+                ));
+            }
+            if self.hp != 0u32 {
+                builder.align(Monster::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Monster {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                strategy::string(&config),
+                any::<u32>(),
+            )
+                .prop_map(|(name, hp)| Monster {
+                    name,
+                    hp,
+                })
+                .boxed()
+        }
+    }
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Stat {
+        pub id: u32,
+        pub value: i64,
+    }
+
+    impl Stat {
+        const VT_ID: usize = 4;
+        const SIZE_ID: usize = 4;
+        const ALIGNMENT_ID: usize = 4;
+        const VT_VALUE: usize = 6;
+        const SIZE_VALUE: usize = 8;
+        const ALIGNMENT_VALUE: usize = 8;
+        const ALIGNMENT: usize = 8;
+
+        /// Orders the tables by the key field `id`.
+        pub fn cmp_by_key(&self, other: &Self) -> Ordering {
+            self.id.cmp(&other.id)
+        }
+    }
+
+    impl<'c> Component<'c> for Stat {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.value != 0i64 {
+                    vtable.add_field(Self::VT_VALUE, Self::SIZE_VALUE, Self::ALIGNMENT_VALUE);
+                }
+                if self.id != 0u32 {
+                    vtable.add_field(Self::VT_ID, Self::SIZE_ID, Self::ALIGNMENT_ID);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.value != 0i64 {
+                builder.align(Self::ALIGNMENT_VALUE);
+                builder.push_scalar(self.value);
+            }
+            if self.id != 0u32 {
+                builder.align(Self::ALIGNMENT_ID);
+                builder.push_scalar(self.id);
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Stat {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.value != 0i64 {
+                    vtable.add_field(Stat::VT_VALUE, Stat::SIZE_VALUE, Stat::ALIGNMENT_VALUE);
+                }
+                if self.id != 0u32 {
+                    vtable.add_field(Stat::VT_ID, Stat::SIZE_ID, Stat::ALIGNMENT_ID);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Stat::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.value != 0i64 {
+                builder.align(Stat::ALIGNMENT_VALUE);
+                builder.push_scalar(self.value);
+            }
+            if self.id != 0u32 {
+                builder.align(Stat::ALIGNMENT_ID);
+                builder.push_scalar(self.id);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Stat` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct StatRef {
+        pub id: u32,
+        pub value: i64,
+    }
+
+    impl StatRef {
+        /// Orders the tables by the key field `id`.
+        pub fn cmp_by_key(&self, other: &Self) -> Ordering {
+            self.id.cmp(&other.id)
+        }
+    }
+
+    impl<'c> Component<'c> for StatRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.value != 0i64 {
+                    vtable.add_field(Stat::VT_VALUE, Stat::SIZE_VALUE, Stat::ALIGNMENT_VALUE);
+                }
+                if self.id != 0u32 {
+                    vtable.add_field(Stat::VT_ID, Stat::SIZE_ID, Stat::ALIGNMENT_ID);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Stat::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.value != 0i64 {
+                builder.align(Stat::ALIGNMENT_VALUE);
+                builder.push_scalar(self.value);
+            }
+            if self.id != 0u32 {
+                builder.align(Stat::ALIGNMENT_ID);
+                builder.push_scalar(self.id);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Stat {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u32>(),
+                any::<i64>(),
+            )
+                .prop_map(|(id, value)| Stat {
+                    id,
+                    value,
+                })
+                .boxed()
+        }
+    }
+}
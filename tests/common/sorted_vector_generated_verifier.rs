@@ -0,0 +1,180 @@
+//! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
+use super::sorted_vector_generated as reader;
+
+pub mod example {
+    #![allow(unused_imports)]
+
+    use super::reader::example as reader;
+    use flatbuffers::{self, Follow};
+    use flatbuffers_verifier::{
+        lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+        verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+        TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+    };
+    use std::cmp::Ordering;
+    use std::result;
+
+    impl<'a> Verify for reader::Index<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(8)?;
+            table.field_vector(Self::VT_MONSTERS, "monsters", flatbuffers::SIZE_UOFFSET, |vector| {
+                vector.verify_sorted_reference_elements::<reader::Monster, _>(verification, |a, b| {
+                    a.cmp_key(b.name())
+                })
+            })?;
+            table.field_vector(Self::VT_STATS, "stats", flatbuffers::SIZE_UOFFSET, |vector| {
+                vector.verify_sorted_reference_elements::<reader::Stat, _>(verification, |a, b| {
+                    a.cmp_key(b.id())
+                })
+            })?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Index<'_> {
+        type Reader<'a> = reader::Index<'a>;
+    }
+
+    impl<'a> reader::Index<'a> {
+        pub fn try_monsters(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::Monster<'a>>>>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_MONSTERS,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                verifier.verify_reference_offsets()?;
+            }
+            Ok(self.monsters())
+        }
+
+        pub fn try_stats(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::Stat<'a>>>>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_STATS,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                verifier.verify_reference_offsets()?;
+            }
+            Ok(self.stats())
+        }
+    }
+
+    impl<'a> Verify for reader::Monster<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(8)?;
+            table.field_required(Self::VT_NAME)?;
+            table.field_string(Self::VT_NAME)?;
+            table.field_scalar::<u32>(Self::VT_HP)?;
+            table.field_default(Self::VT_HP, |value: u32| value == 0u32)?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Monster<'_> {
+        type Reader<'a> = reader::Monster<'a>;
+    }
+
+    impl<'a> reader::Monster<'a> {
+        pub fn try_name(&self) -> result::Result<&'a str, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_NAME,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                StringVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?).verify()?;
+            } else {
+                return Err(Error::MissingRequiredField);
+            }
+            Ok(self.name())
+        }
+
+        pub fn try_hp(&self) -> result::Result<u32, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_HP,
+                4,
+                4,
+            )?;
+            Ok(self.hp())
+        }
+    }
+
+    impl<'a> reader::Monster<'a> {
+        /// Compares the key field `name` with `key`.
+        pub fn cmp_key(&self, key: &str) -> Ordering {
+            self.name().cmp(key)
+        }
+
+        /// Finds the table with the key `key` in a verified vector sorted by the key field
+        /// `name`.
+        pub fn lookup_by_key(
+            vector: flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<reader::Monster<'a>>>,
+            key: &str,
+        ) -> Option<reader::Monster<'a>> {
+            lookup_by_key(vector, |table| table.cmp_key(key))
+        }
+    }
+
+    impl<'a> Verify for reader::Stat<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(8)?;
+            table.field_scalar::<u32>(Self::VT_ID)?;
+            table.field_default(Self::VT_ID, |value: u32| value == 0u32)?;
+            table.field_scalar::<i64>(Self::VT_VALUE)?;
+            table.field_default(Self::VT_VALUE, |value: i64| value == 0i64)?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Stat<'_> {
+        type Reader<'a> = reader::Stat<'a>;
+    }
+
+    impl<'a> reader::Stat<'a> {
+        pub fn try_id(&self) -> result::Result<u32, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_ID,
+                4,
+                4,
+            )?;
+            Ok(self.id())
+        }
+
+        pub fn try_value(&self) -> result::Result<i64, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_VALUE,
+                8,
+                8,
+            )?;
+            Ok(self.value())
+        }
+    }
+
+    impl<'a> reader::Stat<'a> {
+        /// Compares the key field `id` with `key`.
+        pub fn cmp_key(&self, key: u32) -> Ordering {
+            self.id().cmp(&key)
+        }
+
+        /// Finds the table with the key `key` in a verified vector sorted by the key field
+        /// `id`.
+        pub fn lookup_by_key(
+            vector: flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<reader::Stat<'a>>>,
+            key: u32,
+        ) -> Option<reader::Stat<'a>> {
+            lookup_by_key(vector, |table| table.cmp_key(key))
+        }
+    }
+}
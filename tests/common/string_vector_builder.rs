@@ -1,15 +1,22 @@
 //! This file is auto-generated by cfbc.
 pub mod example {
-    #![allow(unused_imports, clippy::large_enum_variant)]
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
 
     use cfb::builder::{
         Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
-        ScalarVectorComponent, StringComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
     };
     use cfb::scalar::Scalar;
     use cfb::types::{SOffset, SIZE_OF_SOFFSET};
-    #[cfg(not(target_endian = "little"))]
-    use std::mem::transmute;
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
 
     #[derive(Default, Clone, Debug, PartialEq)]
     pub struct Text {
@@ -51,4 +58,85 @@ pub mod example {
             table_start
         }
     }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Text {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.lines.is_empty() {
+                    vtable.add_field(Text::VT_LINES, Text::SIZE_LINES, Text::ALIGNMENT_LINES);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Text::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.lines.is_empty() {
+                builder.align(Text::ALIGNMENT_LINES);
+                let offset_position = builder.tell();
+                builder.pad(Text::SIZE_LINES);
+                let children = self.lines.iter().map(StringComponent::new);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(children)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Text` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct TextRef<'a> {
+        pub lines: &'a [&'a str],
+    }
+
+    impl<'c> Component<'c> for TextRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.lines.is_empty() {
+                    vtable.add_field(Text::VT_LINES, Text::SIZE_LINES, Text::ALIGNMENT_LINES);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Text::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.lines.is_empty() {
+                builder.align(Text::ALIGNMENT_LINES);
+                let offset_position = builder.tell();
+                builder.pad(Text::SIZE_LINES);
+                let children = self.lines.iter().map(StringComponent::new);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(children)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Text {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                vec(strategy::string(&config), config.vector_len.clone()),
+            )
+                .prop_map(|(lines,)| Text {
+                    lines,
+                })
+                .boxed()
+        }
+    }
 }
@@ -0,0 +1,381 @@
+//! This file is auto-generated by cfbc.
+pub mod example {
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
+
+    use cfb::builder::{
+        Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
+    };
+    use cfb::scalar::Scalar;
+    use cfb::types::{SOffset, SIZE_OF_SOFFSET};
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
+
+    #[repr(C, align(4))]
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct Block {
+        pub version: u16,
+        pub padding0_: u16,
+        pub numbers: [u32; 3],
+        pub hash: [u8; 32],
+        pub corners: [Point; 2],
+    }
+
+    impl Block {
+        pub fn is_present(&self) -> bool {
+            self.version != 0u16 || self.numbers.iter().any(|v| *v != 0u32) || self.hash.iter().any(|v| *v != 0u8) || self.corners.iter().any(Point::is_present)
+        }
+    }
+
+    impl Scalar for Block {
+        #[cfg(target_endian = "little")]
+        fn to_le(self) -> Self {
+            self
+        }
+
+        #[cfg(target_endian = "little")]
+        fn from_le(x: Self) -> Self {
+            x
+        }
+
+        #[cfg(not(target_endian = "little"))]
+        fn to_le(mut self) -> Self {
+            self.version = self.version.to_le();
+            self.numbers = self.numbers.to_le();
+            self.hash = self.hash.to_le();
+            self.corners = self.corners.to_le();
+            self
+        }
+
+        #[cfg(not(target_endian = "little"))]
+        fn from_le(mut x: Self) -> Self {
+            x.version = Scalar::from_le(x.version);
+            x.numbers = Scalar::from_le(x.numbers);
+            x.hash = Scalar::from_le(x.hash);
+            x.corners = Scalar::from_le(x.corners);
+            x
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Block {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u16>(),
+                any::<[u32; 3]>(),
+                any::<[u8; 32]>(),
+                any::<[Point; 2]>(),
+            )
+                .prop_map(|(version, numbers, hash, corners)| Block {
+                    version,
+                    numbers,
+                    hash,
+                    corners,
+                    ..Default::default()
+                })
+                .boxed()
+        }
+    }
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Header {
+        pub block: Block,
+        pub blocks: Vec<Block>,
+        pub signature: Signature,
+    }
+
+    impl Header {
+        const VT_BLOCK: usize = 4;
+        const SIZE_BLOCK: usize = 56;
+        const ALIGNMENT_BLOCK: usize = 4;
+        const VT_BLOCKS: usize = 6;
+        const SIZE_BLOCKS: usize = 4;
+        const ALIGNMENT_BLOCKS: usize = 4;
+        const VT_SIGNATURE: usize = 8;
+        const SIZE_SIGNATURE: usize = 65;
+        const ALIGNMENT_SIGNATURE: usize = 1;
+        const ALIGNMENT: usize = 4;
+    }
+
+    impl<'c> Component<'c> for Header {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.block.is_present() {
+                    vtable.add_field(Self::VT_BLOCK, Self::SIZE_BLOCK, Self::ALIGNMENT_BLOCK);
+                }
+                if !self.blocks.is_empty() {
+                    vtable.add_field(Self::VT_BLOCKS, Self::SIZE_BLOCKS, Self::ALIGNMENT_BLOCKS);
+                }
+                if self.signature.is_present() {
+                    vtable.add_field(Self::VT_SIGNATURE, Self::SIZE_SIGNATURE, Self::ALIGNMENT_SIGNATURE);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.block.is_present() {
+                builder.align(Self::ALIGNMENT_BLOCK);
+                builder.push_scalar(self.block);
+            }
+            if !self.blocks.is_empty() {
+                builder.align(Self::ALIGNMENT_BLOCKS);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_BLOCKS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.blocks, 4)),
+                ));
+            }
+            if self.signature.is_present() {
+                builder.align(Self::ALIGNMENT_SIGNATURE);
+                builder.push_scalar(self.signature);
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Header {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.block.is_present() {
+                    vtable.add_field(Header::VT_BLOCK, Header::SIZE_BLOCK, Header::ALIGNMENT_BLOCK);
+                }
+                if !self.blocks.is_empty() {
+                    vtable.add_field(Header::VT_BLOCKS, Header::SIZE_BLOCKS, Header::ALIGNMENT_BLOCKS);
+                }
+                if self.signature.is_present() {
+                    vtable.add_field(Header::VT_SIGNATURE, Header::SIZE_SIGNATURE, Header::ALIGNMENT_SIGNATURE);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Header::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.block.is_present() {
+                builder.align(Header::ALIGNMENT_BLOCK);
+                builder.push_scalar(self.block);
+            }
+            if !self.blocks.is_empty() {
+                builder.align(Header::ALIGNMENT_BLOCKS);
+                let offset_position = builder.tell();
+                builder.pad(Header::SIZE_BLOCKS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.blocks.iter().copied(), 4)),
+                ));
+            }
+            if self.signature.is_present() {
+                builder.align(Header::ALIGNMENT_SIGNATURE);
+                builder.push_scalar(self.signature);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Header` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct HeaderRef<'a> {
+        pub block: Block,
+        pub blocks: &'a [Block],
+        pub signature: Signature,
+    }
+
+    impl<'c> Component<'c> for HeaderRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.block.is_present() {
+                    vtable.add_field(Header::VT_BLOCK, Header::SIZE_BLOCK, Header::ALIGNMENT_BLOCK);
+                }
+                if !self.blocks.is_empty() {
+                    vtable.add_field(Header::VT_BLOCKS, Header::SIZE_BLOCKS, Header::ALIGNMENT_BLOCKS);
+                }
+                if self.signature.is_present() {
+                    vtable.add_field(Header::VT_SIGNATURE, Header::SIZE_SIGNATURE, Header::ALIGNMENT_SIGNATURE);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Header::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.block.is_present() {
+                builder.align(Header::ALIGNMENT_BLOCK);
+                builder.push_scalar(self.block);
+            }
+            if !self.blocks.is_empty() {
+                builder.align(Header::ALIGNMENT_BLOCKS);
+                let offset_position = builder.tell();
+                builder.pad(Header::SIZE_BLOCKS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.blocks.iter().copied(), 4)),
+                ));
+            }
+            if self.signature.is_present() {
+                builder.align(Header::ALIGNMENT_SIGNATURE);
+                builder.push_scalar(self.signature);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Header {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                any::<Block>(),
+                vec(any::<Block>(), config.scalar_vector_len.clone()),
+                any::<Signature>(),
+            )
+                .prop_map(|(block, blocks, signature)| Header {
+                    block,
+                    blocks,
+                    signature,
+                })
+                .boxed()
+        }
+    }
+
+    #[repr(C, align(2))]
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct Point {
+        pub x: i16,
+        pub y: i16,
+    }
+
+    impl Point {
+        pub fn is_present(&self) -> bool {
+            self.x != 0i16 || self.y != 0i16
+        }
+    }
+
+    impl Scalar for Point {
+        #[cfg(target_endian = "little")]
+        fn to_le(self) -> Self {
+            self
+        }
+
+        #[cfg(target_endian = "little")]
+        fn from_le(x: Self) -> Self {
+            x
+        }
+
+        #[cfg(not(target_endian = "little"))]
+        fn to_le(mut self) -> Self {
+            self.x = self.x.to_le();
+            self.y = self.y.to_le();
+            self
+        }
+
+        #[cfg(not(target_endian = "little"))]
+        fn from_le(mut x: Self) -> Self {
+            x.x = Scalar::from_le(x.x);
+            x.y = Scalar::from_le(x.y);
+            x
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Point {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<i16>(),
+                any::<i16>(),
+            )
+                .prop_map(|(x, y)| Point {
+                    x,
+                    y,
+                })
+                .boxed()
+        }
+    }
+
+    #[repr(C, align(1))]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Signature {
+        pub data: [u8; 65],
+    }
+
+    impl Default for Signature {
+        fn default() -> Self {
+            Signature {
+                data: [Default::default(); 65],
+            }
+        }
+    }
+
+    impl Signature {
+        pub fn is_present(&self) -> bool {
+            self.data.iter().any(|v| *v != 0u8)
+        }
+    }
+
+    impl Scalar for Signature {
+        #[cfg(target_endian = "little")]
+        fn to_le(self) -> Self {
+            self
+        }
+
+        #[cfg(target_endian = "little")]
+        fn from_le(x: Self) -> Self {
+            x
+        }
+
+        #[cfg(not(target_endian = "little"))]
+        fn to_le(mut self) -> Self {
+            self.data = self.data.to_le();
+            self
+        }
+
+        #[cfg(not(target_endian = "little"))]
+        fn from_le(mut x: Self) -> Self {
+            x.data = Scalar::from_le(x.data);
+            x
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Signature {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                strategy::array::<_, 65>(any::<u8>()),
+            )
+                .prop_map(|(data,)| Signature {
+                    data,
+                })
+                .boxed()
+        }
+    }
+}
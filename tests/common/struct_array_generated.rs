@@ -0,0 +1,326 @@
+// Written by hand in the style of the flatc output, since flatc 0.6 cannot generate readers for
+// fixed-length arrays. `make gen` does not regenerate it, so keep it in sync with the schema.
+
+
+pub mod example {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+
+// struct Point, aligned to 2
+#[repr(C, align(2))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Point {
+  x_: i16,
+  y_: i16,
+} // pub struct Point
+impl flatbuffers::SafeSliceAccess for Point {}
+impl<'a> flatbuffers::Follow<'a> for Point {
+  type Inner = &'a Point;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a Point>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Point {
+  type Inner = &'a Point;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<Point>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for Point {
+    type Output = Point;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(self as *const Point as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+impl<'b> flatbuffers::Push for &'b Point {
+    type Output = Point;
+
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(*self as *const Point as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+
+
+impl Point {
+  pub fn new<'a>(_x: i16, _y: i16) -> Self {
+    Point {
+      x_: _x.to_little_endian(),
+      y_: _y.to_little_endian(),
+
+    }
+  }
+  pub fn x<'a>(&'a self) -> i16 {
+    self.x_.from_little_endian()
+  }
+  pub fn y<'a>(&'a self) -> i16 {
+    self.y_.from_little_endian()
+  }
+}
+
+// struct Block, aligned to 4
+#[repr(C, align(4))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Block {
+  version_: u16,
+  padding0__: u16,
+  numbers_: [u32; 3],
+  hash_: [u8; 32],
+  corners_: [Point; 2],
+} // pub struct Block
+impl flatbuffers::SafeSliceAccess for Block {}
+impl<'a> flatbuffers::Follow<'a> for Block {
+  type Inner = &'a Block;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a Block>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Block {
+  type Inner = &'a Block;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<Block>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for Block {
+    type Output = Block;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(self as *const Block as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+impl<'b> flatbuffers::Push for &'b Block {
+    type Output = Block;
+
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(*self as *const Block as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+
+
+impl Block {
+  pub fn new<'a>(_version: u16, _numbers: &'a [u32; 3], _hash: &'a [u8; 32], _corners: &'a [Point; 2]) -> Self {
+    Block {
+      version_: _version.to_little_endian(),
+      numbers_: [_numbers[0].to_little_endian(), _numbers[1].to_little_endian(), _numbers[2].to_little_endian()],
+      hash_: *_hash,
+      corners_: *_corners,
+      padding0__: 0,
+    }
+  }
+  pub fn version<'a>(&'a self) -> u16 {
+    self.version_.from_little_endian()
+  }
+  pub fn numbers<'a>(&'a self) -> [u32; 3] {
+    [self.numbers_[0].from_little_endian(), self.numbers_[1].from_little_endian(), self.numbers_[2].from_little_endian()]
+  }
+  pub fn hash<'a>(&'a self) -> &'a [u8; 32] {
+    &self.hash_
+  }
+  pub fn corners<'a>(&'a self) -> &'a [Point; 2] {
+    &self.corners_
+  }
+}
+
+// struct Signature, aligned to 1
+#[repr(C, align(1))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Signature {
+  data_: [u8; 65],
+} // pub struct Signature
+impl flatbuffers::SafeSliceAccess for Signature {}
+impl<'a> flatbuffers::Follow<'a> for Signature {
+  type Inner = &'a Signature;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a Signature>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Signature {
+  type Inner = &'a Signature;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<Signature>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for Signature {
+    type Output = Signature;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(self as *const Signature as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+impl<'b> flatbuffers::Push for &'b Signature {
+    type Output = Signature;
+
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(*self as *const Signature as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+
+
+impl Signature {
+  pub fn new<'a>(_data: &'a [u8; 65]) -> Self {
+    Signature {
+      data_: *_data,
+
+    }
+  }
+  pub fn data<'a>(&'a self) -> &'a [u8; 65] {
+    &self.data_
+  }
+}
+
+pub enum HeaderOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Header<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Header<'a> {
+    type Inner = Header<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Header<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Header {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args HeaderArgs<'args>) -> flatbuffers::WIPOffset<Header<'bldr>> {
+      let mut builder = HeaderBuilder::new(_fbb);
+      if let Some(x) = args.signature { builder.add_signature(x); }
+      if let Some(x) = args.blocks { builder.add_blocks(x); }
+      if let Some(x) = args.block { builder.add_block(x); }
+      builder.finish()
+    }
+
+    pub const VT_BLOCK: flatbuffers::VOffsetT = 4;
+    pub const VT_BLOCKS: flatbuffers::VOffsetT = 6;
+    pub const VT_SIGNATURE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub fn block(&self) -> Option<&'a Block> {
+    self._tab.get::<Block>(Header::VT_BLOCK, None)
+  }
+  #[inline]
+  pub fn blocks(&self) -> Option<&'a [Block]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<Block>>>(Header::VT_BLOCKS, None).map(|v| v.safe_slice() )
+  }
+  #[inline]
+  pub fn signature(&self) -> Option<&'a Signature> {
+    self._tab.get::<Signature>(Header::VT_SIGNATURE, None)
+  }
+}
+
+pub struct HeaderArgs<'a> {
+    pub block: Option<&'a  Block>,
+    pub blocks: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , Block>>>,
+    pub signature: Option<&'a  Signature>,
+}
+impl<'a> Default for HeaderArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        HeaderArgs {
+            block: None,
+            blocks: None,
+            signature: None,
+        }
+    }
+}
+pub struct HeaderBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HeaderBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_block(&mut self, block: &'b  Block) {
+    self.fbb_.push_slot_always::<&Block>(Header::VT_BLOCK, block);
+  }
+  #[inline]
+  pub fn add_blocks(&mut self, blocks: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Block>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Header::VT_BLOCKS, blocks);
+  }
+  #[inline]
+  pub fn add_signature(&mut self, signature: &'b  Signature) {
+    self.fbb_.push_slot_always::<&Signature>(Header::VT_SIGNATURE, signature);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> HeaderBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    HeaderBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Header<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+#[inline]
+pub fn get_root_as_header<'a>(buf: &'a [u8]) -> Header<'a> {
+  flatbuffers::get_root::<Header<'a>>(buf)
+}
+
+#[inline]
+pub fn get_size_prefixed_root_as_header<'a>(buf: &'a [u8]) -> Header<'a> {
+  flatbuffers::get_size_prefixed_root::<Header<'a>>(buf)
+}
+
+#[inline]
+pub fn finish_header_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Header<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_header_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Header<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
+}  // pub mod example
+
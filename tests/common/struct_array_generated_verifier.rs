@@ -0,0 +1,70 @@
+//! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
+use super::struct_array_generated as reader;
+
+pub mod example {
+    #![allow(unused_imports)]
+
+    use super::reader::example as reader;
+    use flatbuffers::{self, Follow};
+    use flatbuffers_verifier::{
+        lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+        verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+        TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+    };
+    use std::cmp::Ordering;
+    use std::result;
+
+    impl<'a> Verify for reader::Header<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(10)?;
+            table.field_struct(Self::VT_BLOCK, 56, 4)?;
+            table.field_vector(Self::VT_BLOCKS, "blocks", 56, |vector| {
+                vector.verify_scalar_elements(56, 4)
+            })?;
+            table.field_struct(Self::VT_SIGNATURE, 65, 1)?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Header<'_> {
+        type Reader<'a> = reader::Header<'a>;
+    }
+
+    impl<'a> reader::Header<'a> {
+        pub fn try_block(&self) -> result::Result<Option<&'a reader::Block>, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_BLOCK,
+                56,
+                4,
+            )?;
+            Ok(self.block())
+        }
+
+        pub fn try_blocks(&self) -> result::Result<Option<&'a [reader::Block]>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_BLOCKS,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                verifier.verify_scalar_elements(56, 4)?;
+            }
+            Ok(self.blocks())
+        }
+
+        pub fn try_signature(&self) -> result::Result<Option<&'a reader::Signature>, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_SIGNATURE,
+                65,
+                1,
+            )?;
+            Ok(self.signature())
+        }
+    }
+}
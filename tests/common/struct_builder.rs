@@ -1,15 +1,22 @@
 //! This file is auto-generated by cfbc.
 pub mod example {
-    #![allow(unused_imports, clippy::large_enum_variant)]
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
 
     use cfb::builder::{
         Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
-        ScalarVectorComponent, StringComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
     };
     use cfb::scalar::Scalar;
     use cfb::types::{SOffset, SIZE_OF_SOFFSET};
-    #[cfg(not(target_endian = "little"))]
-    use std::mem::transmute;
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
 
     #[derive(Default, Clone, Debug, PartialEq)]
     pub struct Point {
@@ -46,8 +53,77 @@ pub mod example {
         }
     }
 
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Point {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.position.is_present() {
+                    vtable.add_field(Point::VT_POSITION, Point::SIZE_POSITION, Point::ALIGNMENT_POSITION);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Point::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.position.is_present() {
+                builder.align(Point::ALIGNMENT_POSITION);
+                builder.push_scalar(self.position);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Point` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct PointRef {
+        pub position: Vec3,
+    }
+
+    impl<'c> Component<'c> for PointRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.position.is_present() {
+                    vtable.add_field(Point::VT_POSITION, Point::SIZE_POSITION, Point::ALIGNMENT_POSITION);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Point::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.position.is_present() {
+                builder.align(Point::ALIGNMENT_POSITION);
+                builder.push_scalar(self.position);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Point {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<Vec3>(),
+            )
+                .prop_map(|(position,)| Point {
+                    position,
+                })
+                .boxed()
+        }
+    }
+
     #[repr(C, align(8))]
-    #[derive(Default, Clone, Debug, PartialEq)]
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
     pub struct Vec3 {
         pub x: u64,
         pub y: u8,
@@ -90,4 +166,25 @@ pub mod example {
             x
         }
     }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Vec3 {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u64>(),
+                any::<u8>(),
+                any::<u64>(),
+            )
+                .prop_map(|(x, y, z)| Vec3 {
+                    x,
+                    y,
+                    z,
+                    ..Default::default()
+                })
+                .boxed()
+        }
+    }
 }
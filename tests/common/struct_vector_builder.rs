@@ -1,15 +1,22 @@
 //! This file is auto-generated by cfbc.
 pub mod example {
-    #![allow(unused_imports, clippy::large_enum_variant)]
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
 
     use cfb::builder::{
         Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
-        ScalarVectorComponent, StringComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
     };
     use cfb::scalar::Scalar;
     use cfb::types::{SOffset, SIZE_OF_SOFFSET};
-    #[cfg(not(target_endian = "little"))]
-    use std::mem::transmute;
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
 
     #[derive(Default, Clone, Debug, PartialEq)]
     pub struct Hero {
@@ -51,8 +58,87 @@ pub mod example {
         }
     }
 
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.stats.is_empty() {
+                    vtable.add_field(Hero::VT_STATS, Hero::SIZE_STATS, Hero::ALIGNMENT_STATS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.stats.is_empty() {
+                builder.align(Hero::ALIGNMENT_STATS);
+                let offset_position = builder.tell();
+                builder.pad(Hero::SIZE_STATS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.stats.iter().copied(), 4)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Hero` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct HeroRef<'a> {
+        pub stats: &'a [Stat],
+    }
+
+    impl<'c> Component<'c> for HeroRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.stats.is_empty() {
+                    vtable.add_field(Hero::VT_STATS, Hero::SIZE_STATS, Hero::ALIGNMENT_STATS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.stats.is_empty() {
+                builder.align(Hero::ALIGNMENT_STATS);
+                let offset_position = builder.tell();
+                builder.pad(Hero::SIZE_STATS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(self.stats.iter().copied(), 4)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Hero {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                vec(any::<Stat>(), config.scalar_vector_len.clone()),
+            )
+                .prop_map(|(stats,)| Hero {
+                    stats,
+                })
+                .boxed()
+        }
+    }
+
     #[repr(C, align(4))]
-    #[derive(Default, Clone, Debug, PartialEq)]
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
     pub struct Stat {
         pub hp: u32,
         pub mp: u8,
@@ -91,4 +177,23 @@ pub mod example {
             x
         }
     }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Stat {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u32>(),
+                any::<u8>(),
+            )
+                .prop_map(|(hp, mp)| Stat {
+                    hp,
+                    mp,
+                    ..Default::default()
+                })
+                .boxed()
+        }
+    }
 }
@@ -1,21 +1,37 @@
 //! This file is auto-generated by cfbc.
-#![allow(unused_imports, clippy::large_enum_variant)]
+#![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
 
 use cfb::builder::{
     Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
-    ScalarVectorComponent, StringComponent,
+    ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
 };
 use cfb::scalar::Scalar;
 use cfb::types::{SOffset, SIZE_OF_SOFFSET};
-#[cfg(not(target_endian = "little"))]
-use std::mem::transmute;
+use std::cmp::Ordering;
+#[cfg(feature = "proptest")]
+use cfb::strategy;
+#[cfg(feature = "proptest")]
+use proptest::{
+    collection::vec,
+    prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+    sample::select,
+};
 
-#[repr(i8)]
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub enum Color {
-    Red = 0,
-    Green = 1,
-    Blue = 2,
+/// Values of the enum `Color`, including the ones not declared in the schema, such as values
+/// added by newer schemas, so they are written back unchanged.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color(pub i8);
+
+#[allow(non_upper_case_globals)]
+impl Color {
+    pub const Red: Color = Color(0);
+    pub const Green: Color = Color(1);
+    pub const Blue: Color = Color(2);
+
+    /// Tests whether the value is declared in the schema.
+    pub const fn is_known(self) -> bool {
+        matches!(self.0, 0..=2)
+    }
 }
 
 impl Default for Color {
@@ -24,27 +40,39 @@ impl Default for Color {
     }
 }
 
+impl std::fmt::Debug for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Color::Red => f.write_str("Red"),
+            Color::Green => f.write_str("Green"),
+            Color::Blue => f.write_str("Blue"),
+            Color(raw) => f.debug_tuple("Unknown").field(&raw).finish(),
+        }
+    }
+}
+
 impl Scalar for Color {
     fn to_le(self) -> Self {
-        #[cfg(target_endian = "little")]
-        {
-            self
-        }
-        #[cfg(not(target_endian = "little"))]
-        {
-            unsafe { transmute((self as i8).swap_bytes()) }
-        }
+        Color(self.0.to_le())
     }
 
     fn from_le(x: Self) -> Self {
-        #[cfg(target_endian = "little")]
-        {
-            x
-        }
-        #[cfg(not(target_endian = "little"))]
-        {
-            unsafe { transmute((x as i8).swap_bytes()) }
-        }
+        Color(i8::from_le(x.0))
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Color {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        select(vec![
+            Color::Red,
+            Color::Green,
+            Color::Blue,
+        ])
+        .boxed()
     }
 }
 
@@ -52,6 +80,10 @@ impl Scalar for Color {
 pub enum Result {
     Ok(Ok),
     Err(Err),
+    /// A variant undeclared in the schema, such as one added by a newer schema, with its union
+    /// type. Its value cannot be written, so building it fails with
+    /// `BuildError::UnknownUnionVariant`.
+    Unknown(u8),
 }
 
 impl Result {
@@ -59,12 +91,80 @@ impl Result {
         match self {
             Result::Ok(_) => 1,
             Result::Err(_) => 2,
+            Result::Unknown(union_type) => *union_type,
+        }
+    }
+}
+
+impl<'c> Component<'c> for Result {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        match *self {
+            Result::Ok(v) => Box::new(v).build(builder),
+            Result::Err(v) => Box::new(v).build(builder),
+            Result::Unknown(union_type) => Box::new(UnknownUnionComponent::new(union_type)).build(builder),
+        }
+    }
+}
+
+/// Builds the union without consuming it, so the same value can be encoded repeatedly.
+impl<'c> Component<'c> for &'c Result {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        match *self {
+            Result::Ok(v) => Box::new(v).build(builder),
+            Result::Err(v) => Box::new(v).build(builder),
+            Result::Unknown(union_type) => Box::new(UnknownUnionComponent::new(*union_type)).build(builder),
+        }
+    }
+}
+
+/// The variant of `Result` which borrows its strings and tables, see `--borrowed`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ResultRef<'a> {
+    Ok(OkRef),
+    Err(ErrRef<'a>),
+    Unknown(u8),
+}
+
+impl<'a> ResultRef<'a> {
+    pub fn union_type(&self) -> u8 {
+        match self {
+            ResultRef::Ok(_) => 1,
+            ResultRef::Err(_) => 2,
+            ResultRef::Unknown(union_type) => *union_type,
         }
     }
 }
 
+impl<'c> Component<'c> for ResultRef<'c> {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        match *self {
+            ResultRef::Ok(v) => Box::new(v).build(builder),
+            ResultRef::Err(v) => Box::new(v).build(builder),
+            ResultRef::Unknown(union_type) => Box::new(UnknownUnionComponent::new(union_type)).build(builder),
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Result {
+    type Parameters = strategy::Config;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Union::new(vec![
+            any_with::<Ok>(config.clone())
+                .prop_map(Result::Ok)
+                .boxed(),
+            any_with::<Err>(config.clone())
+                .prop_map(Result::Err)
+                .boxed(),
+        ])
+        .boxed()
+    }
+}
+
 #[repr(C, align(8))]
-#[derive(Default, Clone, Debug, PartialEq)]
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
 pub struct Complex {
     pub a: u64,
     pub b: u64,
@@ -102,6 +202,24 @@ impl Scalar for Complex {
     }
 }
 
+#[cfg(feature = "proptest")]
+impl Arbitrary for Complex {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<u64>(),
+            any::<u64>(),
+        )
+            .prop_map(|(a, b)| Complex {
+                a,
+                b,
+            })
+            .boxed()
+    }
+}
+
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Err {
     pub reason: String,
@@ -142,6 +260,85 @@ impl<'c> Component<'c> for Err {
     }
 }
 
+/// Builds the table without consuming it, so the same value can be encoded repeatedly.
+impl<'c> Component<'c> for &'c Err {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if !self.reason.is_empty() {
+                vtable.add_field(Err::VT_REASON, Err::SIZE_REASON, Err::ALIGNMENT_REASON);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Err::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if !self.reason.is_empty() {
+            builder.align(Err::ALIGNMENT_REASON);
+            let offset_position = builder.tell();
+            builder.pad(Err::SIZE_REASON);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(StringComponent::new(&self.reason))
+            ));
+        }
+
+        table_start
+    }
+}
+
+/// The variant of `Err` which borrows its strings and vectors, see `--borrowed`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct ErrRef<'a> {
+    pub reason: &'a str,
+}
+
+impl<'c> Component<'c> for ErrRef<'c> {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if !self.reason.is_empty() {
+                vtable.add_field(Err::VT_REASON, Err::SIZE_REASON, Err::ALIGNMENT_REASON);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Err::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if !self.reason.is_empty() {
+            builder.align(Err::ALIGNMENT_REASON);
+            let offset_position = builder.tell();
+            builder.pad(Err::SIZE_REASON);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(StringComponent::new(self.reason))
+            ));
+        }
+
+        table_start
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Err {
+    type Parameters = strategy::Config;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+        (
+            strategy::string(&config),
+        )
+            .prop_map(|(reason,)| Err {
+                reason,
+            })
+            .boxed()
+    }
+}
+
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Ok {
     pub value: u32,
@@ -177,6 +374,75 @@ impl<'c> Component<'c> for Ok {
     }
 }
 
+/// Builds the table without consuming it, so the same value can be encoded repeatedly.
+impl<'c> Component<'c> for &'c Ok {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.value != 0u32 {
+                vtable.add_field(Ok::VT_VALUE, Ok::SIZE_VALUE, Ok::ALIGNMENT_VALUE);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Ok::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.value != 0u32 {
+            builder.align(Ok::ALIGNMENT_VALUE);
+            builder.push_scalar(self.value);
+        }
+
+        table_start
+    }
+}
+
+/// The variant of `Ok` which borrows its strings and vectors, see `--borrowed`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct OkRef {
+    pub value: u32,
+}
+
+impl<'c> Component<'c> for OkRef {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.value != 0u32 {
+                vtable.add_field(Ok::VT_VALUE, Ok::SIZE_VALUE, Ok::ALIGNMENT_VALUE);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Ok::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.value != 0u32 {
+            builder.align(Ok::ALIGNMENT_VALUE);
+            builder.push_scalar(self.value);
+        }
+
+        table_start
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Ok {
+    type Parameters = strategy::Config;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<u32>(),
+        )
+            .prop_map(|(value,)| Ok {
+                value,
+            })
+            .boxed()
+    }
+}
+
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct T {
     pub a_ubyte: u8,
@@ -268,11 +534,7 @@ impl<'c> Component<'c> for T {
             builder.align(Self::ALIGNMENT_RESULT);
             let offset_position = builder.tell();
             builder.pad(Self::SIZE_RESULT);
-            let component: Box<dyn Component<'c> + 'c> = match f {
-                Result::Ok(v) => Box::new(v),
-                Result::Err(v) => Box::new(v),
-            };
-            builder.push_component(DesignatedComponent::new(offset_position, component));
+            builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
         }
         if !self.uint16_array.is_empty() {
             builder.align(Self::ALIGNMENT_UINT16_ARRAY);
@@ -298,4 +560,205 @@ impl<'c> Component<'c> for T {
 
         table_start
     }
+}
+
+/// Builds the table without consuming it, so the same value can be encoded repeatedly.
+impl<'c> Component<'c> for &'c T {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.complex.is_present() {
+                vtable.add_field(T::VT_COMPLEX, T::SIZE_COMPLEX, T::ALIGNMENT_COMPLEX);
+            }
+            if self.a_uint64 != 0u64 {
+                vtable.add_field(T::VT_A_UINT64, T::SIZE_A_UINT64, T::ALIGNMENT_A_UINT64);
+            }
+            if self.a_uint32 != 0u32 {
+                vtable.add_field(T::VT_A_UINT32, T::SIZE_A_UINT32, T::ALIGNMENT_A_UINT32);
+            }
+            if self.result.is_some() {
+                vtable.add_field(T::VT_RESULT, T::SIZE_RESULT, T::ALIGNMENT_RESULT);
+            }
+            if !self.uint16_array.is_empty() {
+                vtable.add_field(T::VT_UINT16_ARRAY, T::SIZE_UINT16_ARRAY, T::ALIGNMENT_UINT16_ARRAY);
+            }
+            if self.a_ubyte != 0u8 {
+                vtable.add_field(T::VT_A_UBYTE, T::SIZE_A_UBYTE, T::ALIGNMENT_A_UBYTE);
+            }
+            if self.result.is_some() {
+                vtable.add_field(T::VT_RESULT_TYPE, T::SIZE_RESULT_TYPE, T::ALIGNMENT_RESULT_TYPE);
+            }
+            if self.color != Color::Red {
+                vtable.add_field(T::VT_COLOR, T::SIZE_COLOR, T::ALIGNMENT_COLOR);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, T::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.complex.is_present() {
+            builder.align(T::ALIGNMENT_COMPLEX);
+            builder.push_scalar(self.complex);
+        }
+        if self.a_uint64 != 0u64 {
+            builder.align(T::ALIGNMENT_A_UINT64);
+            builder.push_scalar(self.a_uint64);
+        }
+        if self.a_uint32 != 0u32 {
+            builder.align(T::ALIGNMENT_A_UINT32);
+            builder.push_scalar(self.a_uint32);
+        }
+        let result_type = self.result.as_ref().map(|v| v.union_type());
+        if let Some(f) = &self.result {
+            builder.align(T::ALIGNMENT_RESULT);
+            let offset_position = builder.tell();
+            builder.pad(T::SIZE_RESULT);
+            builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
+        }
+        if !self.uint16_array.is_empty() {
+            builder.align(T::ALIGNMENT_UINT16_ARRAY);
+            let offset_position = builder.tell();
+            builder.pad(T::SIZE_UINT16_ARRAY);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ScalarVectorComponent::new(self.uint16_array.iter().copied(), 2)),
+            ));
+        }
+        if self.a_ubyte != 0u8 {
+            builder.align(T::ALIGNMENT_A_UBYTE);
+            builder.push_scalar(self.a_ubyte);
+        }
+        if let Some(f) = result_type {
+            builder.align(T::ALIGNMENT_RESULT_TYPE);
+            builder.push_scalar(f);
+        }
+        if self.color != Color::Red {
+            builder.align(T::ALIGNMENT_COLOR);
+            builder.push_scalar(self.color);
+        }
+
+        table_start
+    }
+}
+
+/// The variant of `T` which borrows its strings and vectors, see `--borrowed`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct TRef<'a> {
+    pub a_ubyte: u8,
+    pub complex: Complex,
+    pub a_uint32: u32,
+    pub result: Option<ResultRef<'a>>,
+    pub a_uint64: u64,
+    pub uint16_array: &'a [u16],
+    pub color: Color,
+}
+
+impl<'c> Component<'c> for TRef<'c> {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.complex.is_present() {
+                vtable.add_field(T::VT_COMPLEX, T::SIZE_COMPLEX, T::ALIGNMENT_COMPLEX);
+            }
+            if self.a_uint64 != 0u64 {
+                vtable.add_field(T::VT_A_UINT64, T::SIZE_A_UINT64, T::ALIGNMENT_A_UINT64);
+            }
+            if self.a_uint32 != 0u32 {
+                vtable.add_field(T::VT_A_UINT32, T::SIZE_A_UINT32, T::ALIGNMENT_A_UINT32);
+            }
+            if self.result.is_some() {
+                vtable.add_field(T::VT_RESULT, T::SIZE_RESULT, T::ALIGNMENT_RESULT);
+            }
+            if !self.uint16_array.is_empty() {
+                vtable.add_field(T::VT_UINT16_ARRAY, T::SIZE_UINT16_ARRAY, T::ALIGNMENT_UINT16_ARRAY);
+            }
+            if self.a_ubyte != 0u8 {
+                vtable.add_field(T::VT_A_UBYTE, T::SIZE_A_UBYTE, T::ALIGNMENT_A_UBYTE);
+            }
+            if self.result.is_some() {
+                vtable.add_field(T::VT_RESULT_TYPE, T::SIZE_RESULT_TYPE, T::ALIGNMENT_RESULT_TYPE);
+            }
+            if self.color != Color::Red {
+                vtable.add_field(T::VT_COLOR, T::SIZE_COLOR, T::ALIGNMENT_COLOR);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, T::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.complex.is_present() {
+            builder.align(T::ALIGNMENT_COMPLEX);
+            builder.push_scalar(self.complex);
+        }
+        if self.a_uint64 != 0u64 {
+            builder.align(T::ALIGNMENT_A_UINT64);
+            builder.push_scalar(self.a_uint64);
+        }
+        if self.a_uint32 != 0u32 {
+            builder.align(T::ALIGNMENT_A_UINT32);
+            builder.push_scalar(self.a_uint32);
+        }
+        let result_type = self.result.as_ref().map(|v| v.union_type());
+        if let Some(f) = self.result {
+            builder.align(T::ALIGNMENT_RESULT);
+            let offset_position = builder.tell();
+            builder.pad(T::SIZE_RESULT);
+            builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
+        }
+        if !self.uint16_array.is_empty() {
+            builder.align(T::ALIGNMENT_UINT16_ARRAY);
+            let offset_position = builder.tell();
+            builder.pad(T::SIZE_UINT16_ARRAY);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ScalarVectorComponent::new(self.uint16_array.iter().copied(), 2)),
+            ));
+        }
+        if self.a_ubyte != 0u8 {
+            builder.align(T::ALIGNMENT_A_UBYTE);
+            builder.push_scalar(self.a_ubyte);
+        }
+        if let Some(f) = result_type {
+            builder.align(T::ALIGNMENT_RESULT_TYPE);
+            builder.push_scalar(f);
+        }
+        if self.color != Color::Red {
+            builder.align(T::ALIGNMENT_COLOR);
+            builder.push_scalar(self.color);
+        }
+
+        table_start
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for T {
+    type Parameters = strategy::Config;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+        (
+            any::<u8>(),
+            any::<Complex>(),
+            any::<u32>(),
+            strategy::option(&config, any_with::<Result>(config.clone())),
+            any::<u64>(),
+            vec(any::<u16>(), config.scalar_vector_len.clone()),
+            any::<Color>(),
+        )
+            .prop_map(|(a_ubyte, complex, a_uint32, result, a_uint64, uint16_array, color)| T {
+                a_ubyte,
+                complex,
+                a_uint32,
+                result,
+                a_uint64,
+                uint16_array,
+                color,
+            })
+            .boxed()
+    }
 }
\ No newline at end of file
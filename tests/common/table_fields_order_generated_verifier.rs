@@ -1,320 +1,237 @@
 //! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
 use super::table_fields_order_generated as reader;
 
+use flatbuffers::{self, Follow};
+use flatbuffers_verifier::{
+    lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+    verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+    TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+};
+use std::cmp::Ordering;
+use std::result;
 
 impl<'a> Verify for reader::Err<'a> {
-    fn verify(&self) -> Result {
-        let tab = self._tab;
-        let buf = tab.buf;
-        let buf_len = buf.len();
-
-        if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab_loc = {
-            let soffset_slice = &buf[tab.loc..];
-            let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-            if soffset >= 0 {
-                tab.loc.checked_sub(soffset as usize)
-            } else {
-                soffset
-                    .checked_neg()
-                    .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-            }
-        }
-        .ok_or(Error::OutOfBounds)?;
-        if vtab_loc
-            .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab = tab.vtable();
-        let vtab_num_bytes = vtab.num_bytes();
-        let object_inline_num_bytes = vtab.object_inline_num_bytes();
-        if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-            || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if vtab_loc
-            .checked_add(vtab_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if tab
-            .loc
-            .checked_add(object_inline_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-
-        for i in 0..vtab.num_fields() {
-            let voffset = vtab.get_field(i) as usize;
-            if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                || voffset >= object_inline_num_bytes
-            {
-                return Err(Error::OutOfBounds);
-            }
-        }
-
-        if Self::VT_REASON as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_REASON) as usize;
-            if voffset > 0 {
-                if voffset + 4 > object_inline_num_bytes {
-                    return Err(Error::OutOfBounds);
-                }
-
-                StringVerifier::follow(buf, try_follow_uoffset(buf, tab.loc + voffset)?).verify()?;
-            }
-        }
-
+    fn verify_in(&self, verification: &Verification) -> Result {
+        let table = TableVerifier::new(&self._tab, verification)?;
+        table.verify_known_fields(6)?;
+        table.field_string(Self::VT_REASON)?;
         Ok(())
     }
 }
 
-impl<'a> Verify for reader::Ok<'a> {
-    fn verify(&self) -> Result {
-        let tab = self._tab;
-        let buf = tab.buf;
-        let buf_len = buf.len();
-
-        if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab_loc = {
-            let soffset_slice = &buf[tab.loc..];
-            let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-            if soffset >= 0 {
-                tab.loc.checked_sub(soffset as usize)
-            } else {
-                soffset
-                    .checked_neg()
-                    .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-            }
-        }
-        .ok_or(Error::OutOfBounds)?;
-        if vtab_loc
-            .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-
-        let vtab = tab.vtable();
-        let vtab_num_bytes = vtab.num_bytes();
-        let object_inline_num_bytes = vtab.object_inline_num_bytes();
-        if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-            || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if vtab_loc
-            .checked_add(vtab_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if tab
-            .loc
-            .checked_add(object_inline_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-
-        for i in 0..vtab.num_fields() {
-            let voffset = vtab.get_field(i) as usize;
-            if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                || voffset >= object_inline_num_bytes
-            {
-                return Err(Error::OutOfBounds);
-            }
-        }
+impl Root for reader::Err<'_> {
+    type Reader<'a> = reader::Err<'a>;
+}
 
-        if Self::VT_VALUE as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_VALUE) as usize;
-            if voffset > 0 && object_inline_num_bytes - voffset < 4 {
-                return Err(Error::OutOfBounds);
-            }
-        }
+impl<'a> reader::Err<'a> {
+    pub fn try_reason(&self) -> result::Result<Option<&'a str>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_REASON,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            StringVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?).verify()?;
+        }
+        Ok(self.reason())
+    }
+}
 
+impl<'a> Verify for reader::Ok<'a> {
+    fn verify_in(&self, verification: &Verification) -> Result {
+        let table = TableVerifier::new(&self._tab, verification)?;
+        table.verify_known_fields(6)?;
+        table.field_scalar::<u32>(Self::VT_VALUE)?;
+        table.field_default(Self::VT_VALUE, |value: u32| value == 0u32)?;
         Ok(())
     }
 }
 
-impl<'a> Verify for reader::T<'a> {
-    fn verify(&self) -> Result {
-        let tab = self._tab;
-        let buf = tab.buf;
-        let buf_len = buf.len();
-
-        if tab.loc > MAX_OFFSET_LOC || tab.loc + flatbuffers::SIZE_SOFFSET > buf_len {
-            return Err(Error::OutOfBounds);
-        }
+impl Root for reader::Ok<'_> {
+    type Reader<'a> = reader::Ok<'a>;
+}
 
-        let vtab_loc = {
-            let soffset_slice = &buf[tab.loc..];
-            let soffset = flatbuffers::read_scalar::<flatbuffers::SOffsetT>(soffset_slice);
-            if soffset >= 0 {
-                tab.loc.checked_sub(soffset as usize)
-            } else {
-                soffset
-                    .checked_neg()
-                    .and_then(|foffset| tab.loc.checked_add(foffset as usize))
-            }
-        }
-        .ok_or(Error::OutOfBounds)?;
-        if vtab_loc
-            .checked_add(flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
+impl<'a> reader::Ok<'a> {
+    pub fn try_value(&self) -> result::Result<u32, Error> {
+        try_field_loc(
+            &self._tab,
+            Self::VT_VALUE,
+            4,
+            4,
+        )?;
+        Ok(self.value())
+    }
+}
 
-        let vtab = tab.vtable();
-        let vtab_num_bytes = vtab.num_bytes();
-        let object_inline_num_bytes = vtab.object_inline_num_bytes();
-        if vtab_num_bytes < flatbuffers::SIZE_VOFFSET + flatbuffers::SIZE_VOFFSET
-            || object_inline_num_bytes < flatbuffers::SIZE_SOFFSET
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if vtab_loc
-            .checked_add(vtab_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
-        if tab
-            .loc
-            .checked_add(object_inline_num_bytes)
-            .filter(|loc| *loc <= buf_len)
-            .is_none()
-        {
-            return Err(Error::OutOfBounds);
-        }
+impl<'a> Verify for reader::T<'a> {
+    fn verify_in(&self, verification: &Verification) -> Result {
+        let table = TableVerifier::new(&self._tab, verification)?;
+        table.verify_known_fields(20)?;
+        table.field_scalar::<u8>(Self::VT_A_UBYTE)?;
+        table.field_default(Self::VT_A_UBYTE, |value: u8| value == 0u8)?;
+        table.field_struct(Self::VT_COMPLEX, 16, 8)?;
+        table.field_scalar::<u32>(Self::VT_A_UINT32)?;
+        table.field_default(Self::VT_A_UINT32, |value: u32| value == 0u32)?;
+        table.field_enum(Self::VT_RESULT_TYPE, |value: u8| matches!(value, 0..=2))?;
+        table.field_union(Self::VT_RESULT, "result", |loc| match self.try_result_type() {
+            Ok(reader::Result::Ok) => reader::Ok::follow(self._tab.buf, loc).verify_in(verification),
+            Ok(reader::Result::Err) => reader::Err::follow(self._tab.buf, loc).verify_in(verification),
+            Ok(reader::Result::NONE) => Err(Error::UnmatchedUnion),
+            Err(Error::UnknownEnumValue) => verification.unknown_enum_value(),
+            Err(err) => Err(err),
+        })?;
+        table.field_scalar::<u64>(Self::VT_A_UINT64)?;
+        table.field_default(Self::VT_A_UINT64, |value: u64| value == 0u64)?;
+        table.field_vector(Self::VT_UINT16_ARRAY, "uint16_array", 2, |vector| {
+            vector.verify_scalar_elements(2, 2)
+        })?;
+        table.field_enum(Self::VT_COLOR, |value: i8| matches!(value, 0..=2))?;
+        table.field_default(Self::VT_COLOR, |value: i8| value == 0i8)?;
+        Ok(())
+    }
+}
 
-        for i in 0..vtab.num_fields() {
-            let voffset = vtab.get_field(i) as usize;
-            if (voffset > 0 && voffset < flatbuffers::SIZE_SOFFSET)
-                || voffset >= object_inline_num_bytes
-            {
-                return Err(Error::OutOfBounds);
-            }
-        }
+impl Root for reader::T<'_> {
+    type Reader<'a> = reader::T<'a>;
+}
 
-        if Self::VT_A_UBYTE as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_A_UBYTE) as usize;
-            if voffset > 0 && object_inline_num_bytes - voffset < 1 {
-                return Err(Error::OutOfBounds);
-            }
-        }
+impl<'a> reader::T<'a> {
+    pub fn try_a_ubyte(&self) -> result::Result<u8, Error> {
+        try_field_loc(
+            &self._tab,
+            Self::VT_A_UBYTE,
+            1,
+            1,
+        )?;
+        Ok(self.a_ubyte())
+    }
 
-        if Self::VT_COMPLEX as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_COMPLEX) as usize;
-            if voffset > 0 && object_inline_num_bytes - voffset < 16 {
-                return Err(Error::OutOfBounds);
-            }
-        }
+    pub fn try_complex(&self) -> result::Result<Option<&'a reader::Complex>, Error> {
+        try_field_loc(
+            &self._tab,
+            Self::VT_COMPLEX,
+            16,
+            8,
+        )?;
+        Ok(self.complex())
+    }
 
-        if Self::VT_A_UINT32 as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_A_UINT32) as usize;
-            if voffset > 0 && object_inline_num_bytes - voffset < 4 {
-                return Err(Error::OutOfBounds);
-            }
-        }
+    pub fn try_a_uint32(&self) -> result::Result<u32, Error> {
+        try_field_loc(
+            &self._tab,
+            Self::VT_A_UINT32,
+            4,
+            4,
+        )?;
+        Ok(self.a_uint32())
+    }
 
-        if Self::VT_RESULT_TYPE as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_RESULT_TYPE) as usize;
-            if voffset > 0 && object_inline_num_bytes - voffset < 1 {
-                return Err(Error::OutOfBounds);
-            }
-        }
+    pub fn try_result_type(&self) -> result::Result<reader::Result, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_RESULT_TYPE,
+            1,
+            1,
+        )? {
+            if !matches!(
+                read_scalar_at::<u8>(self._tab.buf, loc),
+                0..=2
+            ) {
+                return Err(Error::UnknownEnumValue);
+            }
+        }
+        Ok(self.result_type())
+    }
 
-        if Self::VT_RESULT as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_RESULT) as usize;
-            if voffset > 0 {
-                if voffset + 4 > object_inline_num_bytes {
-                    return Err(Error::OutOfBounds);
-                }
+    /// Reads `result_type` as the underlying value of the enum, which may be undeclared in
+    /// the schema, see `Options::accept_unknown_enum_values`.
+    pub fn raw_result_type(&self) -> u8 {
+        self._tab
+            .get::<u8>(Self::VT_RESULT_TYPE, Some(0))
+            .unwrap()
+    }
 
-                match self.result_type() {
-                    reader::Result::Ok => self
-                        .result_as_ok()
-                        .ok_or(Error::UnmatchedUnion)?
-                        .verify()?,
-                    reader::Result::Err => self
-                        .result_as_err()
-                        .ok_or(Error::UnmatchedUnion)?
-                        .verify()?,
-                    reader::Result::NONE => return Err(Error::UnmatchedUnion),
-                }
-            }
-        }
+    pub fn try_result_as_ok(
+        &self,
+    ) -> result::Result<Option<reader::Ok<'a>>, Error> {
+        if self.try_result_type()? != reader::Result::Ok {
+            return Ok(None);
+        }
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_RESULT,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            try_follow_uoffset(self._tab.buf, loc)?;
+        }
+        Ok(self.result().map(reader::Ok::init_from_table))
+    }
 
-        if Self::VT_A_UINT64 as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_A_UINT64) as usize;
-            if voffset > 0 && object_inline_num_bytes - voffset < 8 {
-                return Err(Error::OutOfBounds);
-            }
-        }
+    pub fn try_result_as_err(
+        &self,
+    ) -> result::Result<Option<reader::Err<'a>>, Error> {
+        if self.try_result_type()? != reader::Result::Err {
+            return Ok(None);
+        }
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_RESULT,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            try_follow_uoffset(self._tab.buf, loc)?;
+        }
+        Ok(self.result().map(reader::Err::init_from_table))
+    }
 
-        if Self::VT_UINT16_ARRAY as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_UINT16_ARRAY) as usize;
-            if voffset > 0 {
-                if voffset + 4 > object_inline_num_bytes {
-                    return Err(Error::OutOfBounds);
-                }
+    pub fn try_a_uint64(&self) -> result::Result<u64, Error> {
+        try_field_loc(
+            &self._tab,
+            Self::VT_A_UINT64,
+            8,
+            8,
+        )?;
+        Ok(self.a_uint64())
+    }
 
-                let uint16_array_verifier = VectorVerifier::follow(
-                    buf,
-                    try_follow_uoffset(buf, tab.loc + voffset)?,
-                );
-                uint16_array_verifier.verify_scalar_elements(2)?;
-            }
-        }
+    pub fn try_uint16_array(&self) -> result::Result<Option<flatbuffers::Vector<'a, u16>>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_UINT16_ARRAY,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+            verifier.verify_scalar_elements(2, 2)?;
+        }
+        Ok(self.uint16_array())
+    }
 
-        if Self::VT_COLOR as usize + flatbuffers::SIZE_VOFFSET
-            <= vtab_num_bytes
-        {
-            let voffset = vtab.get(Self::VT_COLOR) as usize;
-            if voffset > 0 && object_inline_num_bytes - voffset < 1 {
-                return Err(Error::OutOfBounds);
-            }
-        }
+    pub fn try_color(&self) -> result::Result<reader::Color, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_COLOR,
+            1,
+            1,
+        )? {
+            if !matches!(
+                read_scalar_at::<i8>(self._tab.buf, loc),
+                0..=2
+            ) {
+                return Err(Error::UnknownEnumValue);
+            }
+        }
+        Ok(self.color())
+    }
 
-        Ok(())
+    /// Reads `color` as the underlying value of the enum, which may be undeclared in
+    /// the schema, see `Options::accept_unknown_enum_values`.
+    pub fn raw_color(&self) -> i8 {
+        self._tab
+            .get::<i8>(Self::VT_COLOR, Some(0))
+            .unwrap()
     }
 }
\ No newline at end of file
@@ -1,15 +1,22 @@
 //! This file is auto-generated by cfbc.
 pub mod example {
-    #![allow(unused_imports, clippy::large_enum_variant)]
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
 
     use cfb::builder::{
         Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
-        ScalarVectorComponent, StringComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
     };
     use cfb::scalar::Scalar;
     use cfb::types::{SOffset, SIZE_OF_SOFFSET};
-    #[cfg(not(target_endian = "little"))]
-    use std::mem::transmute;
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
 
     #[derive(Default, Clone, Debug, PartialEq)]
     pub struct Hero {
@@ -51,6 +58,85 @@ pub mod example {
         }
     }
 
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.stats.is_empty() {
+                    vtable.add_field(Hero::VT_STATS, Hero::SIZE_STATS, Hero::ALIGNMENT_STATS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.stats.is_empty() {
+                builder.align(Hero::ALIGNMENT_STATS);
+                let offset_position = builder.tell();
+                builder.pad(Hero::SIZE_STATS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(self.stats.iter())),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Hero` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct HeroRef<'a> {
+        pub stats: &'a [StatRef],
+    }
+
+    impl<'c> Component<'c> for HeroRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.stats.is_empty() {
+                    vtable.add_field(Hero::VT_STATS, Hero::SIZE_STATS, Hero::ALIGNMENT_STATS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if !self.stats.is_empty() {
+                builder.align(Hero::ALIGNMENT_STATS);
+                let offset_position = builder.tell();
+                builder.pad(Hero::SIZE_STATS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(self.stats.iter().copied())),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Hero {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                vec(any_with::<Stat>(config.clone()), config.vector_len.clone()),
+            )
+                .prop_map(|(stats,)| Hero {
+                    stats,
+                })
+                .boxed()
+        }
+    }
+
     #[derive(Default, Clone, Debug, PartialEq)]
     pub struct Stat {
         pub hp: u32,
@@ -85,4 +171,73 @@ pub mod example {
             table_start
         }
     }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Stat {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Stat::VT_HP, Stat::SIZE_HP, Stat::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Stat::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Stat::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Stat` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct StatRef {
+        pub hp: u32,
+    }
+
+    impl<'c> Component<'c> for StatRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Stat::VT_HP, Stat::SIZE_HP, Stat::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Stat::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Stat::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Stat {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u32>(),
+            )
+                .prop_map(|(hp,)| Stat {
+                    hp,
+                })
+                .boxed()
+        }
+    }
 }
@@ -1,20 +1,31 @@
 //! This file is auto-generated by cfbc.
 pub mod example {
-    #![allow(unused_imports, clippy::large_enum_variant)]
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
 
     use cfb::builder::{
         Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
-        ScalarVectorComponent, StringComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
     };
     use cfb::scalar::Scalar;
     use cfb::types::{SOffset, SIZE_OF_SOFFSET};
-    #[cfg(not(target_endian = "little"))]
-    use std::mem::transmute;
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
 
     #[derive(Clone, PartialEq, Debug)]
     pub enum Role {
         Hero(Hero),
         Monster(Monster),
+        /// A variant undeclared in the schema, such as one added by a newer schema, with its union
+        /// type. Its value cannot be written, so building it fails with
+        /// `BuildError::UnknownUnionVariant`.
+        Unknown(u8),
     }
 
     impl Role {
@@ -22,10 +33,78 @@ pub mod example {
             match self {
                 Role::Hero(_) => 1,
                 Role::Monster(_) => 2,
+                Role::Unknown(union_type) => *union_type,
+            }
+        }
+    }
+
+    impl<'c> Component<'c> for Role {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            match *self {
+                Role::Hero(v) => Box::new(v).build(builder),
+                Role::Monster(v) => Box::new(v).build(builder),
+                Role::Unknown(union_type) => Box::new(UnknownUnionComponent::new(union_type)).build(builder),
+            }
+        }
+    }
+
+    /// Builds the union without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Role {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            match *self {
+                Role::Hero(v) => Box::new(v).build(builder),
+                Role::Monster(v) => Box::new(v).build(builder),
+                Role::Unknown(union_type) => Box::new(UnknownUnionComponent::new(*union_type)).build(builder),
+            }
+        }
+    }
+
+    /// The variant of `Role` which borrows its strings and tables, see `--borrowed`.
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    pub enum RoleRef {
+        Hero(HeroRef),
+        Monster(MonsterRef),
+        Unknown(u8),
+    }
+
+    impl RoleRef {
+        pub fn union_type(&self) -> u8 {
+            match self {
+                RoleRef::Hero(_) => 1,
+                RoleRef::Monster(_) => 2,
+                RoleRef::Unknown(union_type) => *union_type,
+            }
+        }
+    }
+
+    impl<'c> Component<'c> for RoleRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            match *self {
+                RoleRef::Hero(v) => Box::new(v).build(builder),
+                RoleRef::Monster(v) => Box::new(v).build(builder),
+                RoleRef::Unknown(union_type) => Box::new(UnknownUnionComponent::new(union_type)).build(builder),
             }
         }
     }
 
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Role {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            proptest::strategy::Union::new(vec![
+                any_with::<Hero>(config.clone())
+                    .prop_map(Role::Hero)
+                    .boxed(),
+                any_with::<Monster>(config.clone())
+                    .prop_map(Role::Monster)
+                    .boxed(),
+            ])
+            .boxed()
+        }
+    }
+
     #[derive(Default, Clone, Debug, PartialEq)]
     pub struct Hero {
         pub hp: u32,
@@ -61,6 +140,75 @@ pub mod example {
         }
     }
 
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Hero::VT_HP, Hero::SIZE_HP, Hero::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Hero::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Hero` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct HeroRef {
+        pub hp: u32,
+    }
+
+    impl<'c> Component<'c> for HeroRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Hero::VT_HP, Hero::SIZE_HP, Hero::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Hero::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Hero {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u32>(),
+            )
+                .prop_map(|(hp,)| Hero {
+                    hp,
+                })
+                .boxed()
+        }
+    }
+
     #[derive(Default, Clone, Debug, PartialEq)]
     pub struct Monster {
         pub hp: u32,
@@ -96,6 +244,75 @@ pub mod example {
         }
     }
 
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Monster {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Monster::VT_HP, Monster::SIZE_HP, Monster::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Monster::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Monster::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Monster` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct MonsterRef {
+        pub hp: u32,
+    }
+
+    impl<'c> Component<'c> for MonsterRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Monster::VT_HP, Monster::SIZE_HP, Monster::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Monster::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Monster::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Monster {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u32>(),
+            )
+                .prop_map(|(hp,)| Monster {
+                    hp,
+                })
+                .boxed()
+        }
+    }
+
     #[derive(Default, Clone, Debug, PartialEq)]
     pub struct Player {
         pub role: Option<Role>,
@@ -133,11 +350,7 @@ pub mod example {
                 builder.align(Self::ALIGNMENT_ROLE);
                 let offset_position = builder.tell();
                 builder.pad(Self::SIZE_ROLE);
-                let component: Box<dyn Component<'c> + 'c> = match f {
-                    Role::Hero(v) => Box::new(v),
-                    Role::Monster(v) => Box::new(v),
-                };
-                builder.push_component(DesignatedComponent::new(offset_position, component));
+                builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
             }
             if let Some(f) = role_type {
                 builder.align(Self::ALIGNMENT_ROLE_TYPE);
@@ -147,4 +360,93 @@ pub mod example {
             table_start
         }
     }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Player {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.role.is_some() {
+                    vtable.add_field(Player::VT_ROLE, Player::SIZE_ROLE, Player::ALIGNMENT_ROLE);
+                }
+                if self.role.is_some() {
+                    vtable.add_field(Player::VT_ROLE_TYPE, Player::SIZE_ROLE_TYPE, Player::ALIGNMENT_ROLE_TYPE);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Player::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            let role_type = self.role.as_ref().map(|v| v.union_type());
+            if let Some(f) = &self.role {
+                builder.align(Player::ALIGNMENT_ROLE);
+                let offset_position = builder.tell();
+                builder.pad(Player::SIZE_ROLE);
+                builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
+            }
+            if let Some(f) = role_type {
+                builder.align(Player::ALIGNMENT_ROLE_TYPE);
+                builder.push_scalar(f);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Player` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct PlayerRef {
+        pub role: Option<RoleRef>,
+    }
+
+    impl<'c> Component<'c> for PlayerRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.role.is_some() {
+                    vtable.add_field(Player::VT_ROLE, Player::SIZE_ROLE, Player::ALIGNMENT_ROLE);
+                }
+                if self.role.is_some() {
+                    vtable.add_field(Player::VT_ROLE_TYPE, Player::SIZE_ROLE_TYPE, Player::ALIGNMENT_ROLE_TYPE);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Player::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            let role_type = self.role.as_ref().map(|v| v.union_type());
+            if let Some(f) = self.role {
+                builder.align(Player::ALIGNMENT_ROLE);
+                let offset_position = builder.tell();
+                builder.pad(Player::SIZE_ROLE);
+                builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
+            }
+            if let Some(f) = role_type {
+                builder.align(Player::ALIGNMENT_ROLE_TYPE);
+                builder.push_scalar(f);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Player {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                strategy::option(&config, any_with::<Role>(config.clone())),
+            )
+                .prop_map(|(role,)| Player {
+                    role,
+                })
+                .boxed()
+        }
+    }
 }
@@ -0,0 +1,502 @@
+//! This file is auto-generated by cfbc.
+pub mod example {
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
+
+    use cfb::builder::{
+        Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
+    };
+    use cfb::scalar::Scalar;
+    use cfb::types::{SOffset, SIZE_OF_SOFFSET};
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
+
+    #[derive(Clone, PartialEq, Debug)]
+    pub enum Role {
+        Hero(Hero),
+        Point(Point),
+        Name(String),
+        /// A variant undeclared in the schema, such as one added by a newer schema, with its union
+        /// type. Its value cannot be written, so building it fails with
+        /// `BuildError::UnknownUnionVariant`.
+        Unknown(u8),
+    }
+
+    impl Role {
+        pub fn union_type(&self) -> u8 {
+            match self {
+                Role::Hero(_) => 1,
+                Role::Point(_) => 2,
+                Role::Name(_) => 3,
+                Role::Unknown(union_type) => *union_type,
+            }
+        }
+    }
+
+    impl<'c> Component<'c> for Role {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            match *self {
+                Role::Hero(v) => Box::new(v).build(builder),
+                Role::Point(v) => Box::new(StructComponent::new(v, 4)).build(builder),
+                Role::Name(v) => Box::new(StringComponent::new(v)).build(builder),
+                Role::Unknown(union_type) => Box::new(UnknownUnionComponent::new(union_type)).build(builder),
+            }
+        }
+    }
+
+    /// Builds the union without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Role {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            match *self {
+                Role::Hero(v) => Box::new(v).build(builder),
+                Role::Point(v) => Box::new(StructComponent::new(*v, 4)).build(builder),
+                Role::Name(v) => Box::new(StringComponent::new(v)).build(builder),
+                Role::Unknown(union_type) => Box::new(UnknownUnionComponent::new(*union_type)).build(builder),
+            }
+        }
+    }
+
+    /// The variant of `Role` which borrows its strings and tables, see `--borrowed`.
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    pub enum RoleRef<'a> {
+        Hero(HeroRef),
+        Point(Point),
+        Name(&'a str),
+        Unknown(u8),
+    }
+
+    impl<'a> RoleRef<'a> {
+        pub fn union_type(&self) -> u8 {
+            match self {
+                RoleRef::Hero(_) => 1,
+                RoleRef::Point(_) => 2,
+                RoleRef::Name(_) => 3,
+                RoleRef::Unknown(union_type) => *union_type,
+            }
+        }
+    }
+
+    impl<'c> Component<'c> for RoleRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            match *self {
+                RoleRef::Hero(v) => Box::new(v).build(builder),
+                RoleRef::Point(v) => Box::new(StructComponent::new(v, 4)).build(builder),
+                RoleRef::Name(v) => Box::new(StringComponent::new(v)).build(builder),
+                RoleRef::Unknown(union_type) => Box::new(UnknownUnionComponent::new(union_type)).build(builder),
+            }
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Role {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            proptest::strategy::Union::new(vec![
+                any_with::<Hero>(config.clone())
+                    .prop_map(Role::Hero)
+                    .boxed(),
+                any::<Point>()
+                    .prop_map(Role::Point)
+                    .boxed(),
+                strategy::string(&config)
+                    .prop_map(Role::Name)
+                    .boxed(),
+            ])
+            .boxed()
+        }
+    }
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Hero {
+        pub hp: u32,
+    }
+
+    impl Hero {
+        const VT_HP: usize = 4;
+        const SIZE_HP: usize = 4;
+        const ALIGNMENT_HP: usize = 4;
+        const ALIGNMENT: usize = 4;
+    }
+
+    impl<'c> Component<'c> for Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Self::VT_HP, Self::SIZE_HP, Self::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Self::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Hero::VT_HP, Hero::SIZE_HP, Hero::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Hero::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Hero` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct HeroRef {
+        pub hp: u32,
+    }
+
+    impl<'c> Component<'c> for HeroRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Hero::VT_HP, Hero::SIZE_HP, Hero::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Hero::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Hero {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u32>(),
+            )
+                .prop_map(|(hp,)| Hero {
+                    hp,
+                })
+                .boxed()
+        }
+    }
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Player {
+        pub role: Option<Role>,
+        pub roles: Vec<Role>,
+    }
+
+    impl Player {
+        const VT_ROLE_TYPE: usize = 4;
+        const SIZE_ROLE_TYPE: usize = 1;
+        const ALIGNMENT_ROLE_TYPE: usize = 1;
+        const VT_ROLE: usize = 6;
+        const SIZE_ROLE: usize = 4;
+        const ALIGNMENT_ROLE: usize = 4;
+        const VT_ROLES_TYPE: usize = 8;
+        const SIZE_ROLES_TYPE: usize = 4;
+        const ALIGNMENT_ROLES_TYPE: usize = 4;
+        const VT_ROLES: usize = 10;
+        const SIZE_ROLES: usize = 4;
+        const ALIGNMENT_ROLES: usize = 4;
+        const ALIGNMENT: usize = 4;
+    }
+
+    impl<'c> Component<'c> for Player {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.role.is_some() {
+                    vtable.add_field(Self::VT_ROLE, Self::SIZE_ROLE, Self::ALIGNMENT_ROLE);
+                }
+                if !self.roles.is_empty() {
+                    vtable.add_field(Self::VT_ROLES_TYPE, Self::SIZE_ROLES_TYPE, Self::ALIGNMENT_ROLES_TYPE);
+                }
+                if !self.roles.is_empty() {
+                    vtable.add_field(Self::VT_ROLES, Self::SIZE_ROLES, Self::ALIGNMENT_ROLES);
+                }
+                if self.role.is_some() {
+                    vtable.add_field(Self::VT_ROLE_TYPE, Self::SIZE_ROLE_TYPE, Self::ALIGNMENT_ROLE_TYPE);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            let roles_type: Vec<u8> = self.roles.iter().map(|v| v.union_type()).collect();
+            let role_type = self.role.as_ref().map(|v| v.union_type());
+            if let Some(f) = self.role {
+                builder.align(Self::ALIGNMENT_ROLE);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_ROLE);
+                builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
+            }
+            if !roles_type.is_empty() {
+                builder.align(Self::ALIGNMENT_ROLES_TYPE);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_ROLES_TYPE);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(roles_type, 1)),
+                ));
+            }
+            if !self.roles.is_empty() {
+                builder.align(Self::ALIGNMENT_ROLES);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_ROLES);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(self.roles)),
+                ));
+            }
+            if let Some(f) = role_type {
+                builder.align(Self::ALIGNMENT_ROLE_TYPE);
+                builder.push_scalar(f);
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Player {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.role.is_some() {
+                    vtable.add_field(Player::VT_ROLE, Player::SIZE_ROLE, Player::ALIGNMENT_ROLE);
+                }
+                if !self.roles.is_empty() {
+                    vtable.add_field(Player::VT_ROLES_TYPE, Player::SIZE_ROLES_TYPE, Player::ALIGNMENT_ROLES_TYPE);
+                }
+                if !self.roles.is_empty() {
+                    vtable.add_field(Player::VT_ROLES, Player::SIZE_ROLES, Player::ALIGNMENT_ROLES);
+                }
+                if self.role.is_some() {
+                    vtable.add_field(Player::VT_ROLE_TYPE, Player::SIZE_ROLE_TYPE, Player::ALIGNMENT_ROLE_TYPE);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Player::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            let roles_type: Vec<u8> = self.roles.iter().map(|v| v.union_type()).collect();
+            let role_type = self.role.as_ref().map(|v| v.union_type());
+            if let Some(f) = &self.role {
+                builder.align(Player::ALIGNMENT_ROLE);
+                let offset_position = builder.tell();
+                builder.pad(Player::SIZE_ROLE);
+                builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
+            }
+            if !roles_type.is_empty() {
+                builder.align(Player::ALIGNMENT_ROLES_TYPE);
+                let offset_position = builder.tell();
+                builder.pad(Player::SIZE_ROLES_TYPE);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(roles_type, 1)),
+                ));
+            }
+            if !self.roles.is_empty() {
+                builder.align(Player::ALIGNMENT_ROLES);
+                let offset_position = builder.tell();
+                builder.pad(Player::SIZE_ROLES);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(self.roles.iter())),
+                ));
+            }
+            if let Some(f) = role_type {
+                builder.align(Player::ALIGNMENT_ROLE_TYPE);
+                builder.push_scalar(f);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Player` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct PlayerRef<'a> {
+        pub role: Option<RoleRef<'a>>,
+        pub roles: &'a [RoleRef<'a>],
+    }
+
+    impl<'c> Component<'c> for PlayerRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.role.is_some() {
+                    vtable.add_field(Player::VT_ROLE, Player::SIZE_ROLE, Player::ALIGNMENT_ROLE);
+                }
+                if !self.roles.is_empty() {
+                    vtable.add_field(Player::VT_ROLES_TYPE, Player::SIZE_ROLES_TYPE, Player::ALIGNMENT_ROLES_TYPE);
+                }
+                if !self.roles.is_empty() {
+                    vtable.add_field(Player::VT_ROLES, Player::SIZE_ROLES, Player::ALIGNMENT_ROLES);
+                }
+                if self.role.is_some() {
+                    vtable.add_field(Player::VT_ROLE_TYPE, Player::SIZE_ROLE_TYPE, Player::ALIGNMENT_ROLE_TYPE);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Player::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            let roles_type: Vec<u8> = self.roles.iter().map(|v| v.union_type()).collect();
+            let role_type = self.role.as_ref().map(|v| v.union_type());
+            if let Some(f) = self.role {
+                builder.align(Player::ALIGNMENT_ROLE);
+                let offset_position = builder.tell();
+                builder.pad(Player::SIZE_ROLE);
+                builder.push_component(DesignatedComponent::new(offset_position, Box::new(f)));
+            }
+            if !roles_type.is_empty() {
+                builder.align(Player::ALIGNMENT_ROLES_TYPE);
+                let offset_position = builder.tell();
+                builder.pad(Player::SIZE_ROLES_TYPE);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(roles_type, 1)),
+                ));
+            }
+            if !self.roles.is_empty() {
+                builder.align(Player::ALIGNMENT_ROLES);
+                let offset_position = builder.tell();
+                builder.pad(Player::SIZE_ROLES);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(self.roles.iter().copied())),
+                ));
+            }
+            if let Some(f) = role_type {
+                builder.align(Player::ALIGNMENT_ROLE_TYPE);
+                builder.push_scalar(f);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Player {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                strategy::option(&config, any_with::<Role>(config.clone())),
+                vec(any_with::<Role>(config.clone()), config.vector_len.clone()),
+            )
+                .prop_map(|(role, roles)| Player {
+                    role,
+                    roles,
+                })
+                .boxed()
+        }
+    }
+
+    #[repr(C, align(4))]
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct Point {
+        pub x: i16,
+        pub padding0_: u16,
+        pub y: i32,
+    }
+
+    impl Point {
+        pub fn is_present(&self) -> bool {
+            self.x != 0i16 || self.y != 0i32
+        }
+    }
+
+    impl Scalar for Point {
+        #[cfg(target_endian = "little")]
+        fn to_le(self) -> Self {
+            self
+        }
+
+        #[cfg(target_endian = "little")]
+        fn from_le(x: Self) -> Self {
+            x
+        }
+
+        #[cfg(not(target_endian = "little"))]
+        fn to_le(mut self) -> Self {
+            self.x = self.x.to_le();
+            self.y = self.y.to_le();
+            self
+        }
+
+        #[cfg(not(target_endian = "little"))]
+        fn from_le(mut x: Self) -> Self {
+            x.x = Scalar::from_le(x.x);
+            x.y = Scalar::from_le(x.y);
+            x
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Point {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<i16>(),
+                any::<i32>(),
+            )
+                .prop_map(|(x, y)| Point {
+                    x,
+                    y,
+                    ..Default::default()
+                })
+                .boxed()
+        }
+    }
+}
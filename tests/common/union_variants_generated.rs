@@ -0,0 +1,366 @@
+// Written by hand in the style of the flatc output, since the Rust code generator of flatc 0.6
+// rejects unions of structs and strings. `make gen` does not regenerate it, so keep it in sync with
+// the schema.
+
+
+pub mod example {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Role {
+  NONE = 0,
+  Hero = 1,
+  Point = 2,
+  Name = 3,
+
+}
+
+const ENUM_MIN_ROLE: u8 = 0;
+const ENUM_MAX_ROLE: u8 = 3;
+
+impl<'a> flatbuffers::Follow<'a> for Role {
+  type Inner = Self;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::read_scalar_at::<Self>(buf, loc)
+  }
+}
+
+impl flatbuffers::EndianScalar for Role {
+  #[inline]
+  fn to_little_endian(self) -> Self {
+    let n = u8::to_le(self as u8);
+    let p = &n as *const u8 as *const Role;
+    unsafe { *p }
+  }
+  #[inline]
+  fn from_little_endian(self) -> Self {
+    let n = u8::from_le(self as u8);
+    let p = &n as *const u8 as *const Role;
+    unsafe { *p }
+  }
+}
+
+impl flatbuffers::Push for Role {
+    type Output = Role;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        flatbuffers::emplace_scalar::<Role>(dst, *self);
+    }
+}
+
+#[allow(non_camel_case_types)]
+const ENUM_VALUES_ROLE:[Role; 4] = [
+  Role::NONE,
+  Role::Hero,
+  Role::Point,
+  Role::Name
+];
+
+#[allow(non_camel_case_types)]
+const ENUM_NAMES_ROLE:[&'static str; 4] = [
+    "NONE",
+    "Hero",
+    "Point",
+    "Name"
+];
+
+pub fn enum_name_role(e: Role) -> &'static str {
+  let index: usize = e as usize;
+  ENUM_NAMES_ROLE[index]
+}
+
+pub struct RoleUnionTableOffset {}
+// struct Point, aligned to 4
+#[repr(C, align(4))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Point {
+  x_: i16,
+  padding0__: u16,
+  y_: i32,
+} // pub struct Point
+impl flatbuffers::SafeSliceAccess for Point {}
+impl<'a> flatbuffers::Follow<'a> for Point {
+  type Inner = &'a Point;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a Point>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Point {
+  type Inner = &'a Point;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<Point>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for Point {
+    type Output = Point;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(self as *const Point as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+impl<'b> flatbuffers::Push for &'b Point {
+    type Output = Point;
+
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(*self as *const Point as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+
+
+impl Point {
+  pub fn new<'a>(_x: i16, _y: i32) -> Self {
+    Point {
+      x_: _x.to_little_endian(),
+      y_: _y.to_little_endian(),
+
+      padding0__: 0,
+    }
+  }
+  pub fn x<'a>(&'a self) -> i16 {
+    self.x_.from_little_endian()
+  }
+  pub fn y<'a>(&'a self) -> i32 {
+    self.y_.from_little_endian()
+  }
+}
+
+pub enum HeroOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Hero<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Hero<'a> {
+    type Inner = Hero<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Hero<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Hero {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args HeroArgs) -> flatbuffers::WIPOffset<Hero<'bldr>> {
+      let mut builder = HeroBuilder::new(_fbb);
+      builder.add_hp(args.hp);
+      builder.finish()
+    }
+
+    pub const VT_HP: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn hp(&self) -> u32 {
+    self._tab.get::<u32>(Hero::VT_HP, Some(0)).unwrap()
+  }
+}
+
+pub struct HeroArgs {
+    pub hp: u32,
+}
+impl<'a> Default for HeroArgs {
+    #[inline]
+    fn default() -> Self {
+        HeroArgs {
+            hp: 0,
+        }
+    }
+}
+pub struct HeroBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HeroBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_hp(&mut self, hp: u32) {
+    self.fbb_.push_slot::<u32>(Hero::VT_HP, hp, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> HeroBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    HeroBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Hero<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum PlayerOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Player<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Player<'a> {
+    type Inner = Player<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Player<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Player {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args PlayerArgs<'args>) -> flatbuffers::WIPOffset<Player<'bldr>> {
+      let mut builder = PlayerBuilder::new(_fbb);
+      if let Some(x) = args.roles { builder.add_roles(x); }
+      if let Some(x) = args.roles_type { builder.add_roles_type(x); }
+      if let Some(x) = args.role { builder.add_role(x); }
+      builder.add_role_type(args.role_type);
+      builder.finish()
+    }
+
+    pub const VT_ROLE_TYPE: flatbuffers::VOffsetT = 4;
+    pub const VT_ROLE: flatbuffers::VOffsetT = 6;
+    pub const VT_ROLES_TYPE: flatbuffers::VOffsetT = 8;
+    pub const VT_ROLES: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub fn role_type(&self) -> Role {
+    self._tab.get::<Role>(Player::VT_ROLE_TYPE, Some(Role::NONE)).unwrap()
+  }
+  #[inline]
+  pub fn role(&self) -> Option<flatbuffers::Table<'a>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Player::VT_ROLE, None)
+  }
+  #[inline]
+  pub fn roles_type(&self) -> Option<flatbuffers::Vector<'a, Role>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Role>>>(Player::VT_ROLES_TYPE, None)
+  }
+  #[inline]
+  pub fn roles(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>>>(Player::VT_ROLES, None)
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn role_as_hero(&self) -> Option<Hero<'a>> {
+    if self.role_type() == Role::Hero {
+      self.role().map(|u| Hero::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+}
+
+pub struct PlayerArgs<'a> {
+    pub role_type: Role,
+    pub role: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub roles_type: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , Role>>>,
+    pub roles: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<flatbuffers::UnionWIPOffset>>>>,
+}
+impl<'a> Default for PlayerArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        PlayerArgs {
+            role_type: Role::NONE,
+            role: None,
+            roles_type: None,
+            roles: None,
+        }
+    }
+}
+pub struct PlayerBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlayerBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_role_type(&mut self, role_type: Role) {
+    self.fbb_.push_slot::<Role>(Player::VT_ROLE_TYPE, role_type, Role::NONE);
+  }
+  #[inline]
+  pub fn add_role(&mut self, role: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Player::VT_ROLE, role);
+  }
+  #[inline]
+  pub fn add_roles_type(&mut self, roles_type: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Role>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Player::VT_ROLES_TYPE, roles_type);
+  }
+  #[inline]
+  pub fn add_roles(&mut self, roles: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<flatbuffers::UnionWIPOffset>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Player::VT_ROLES, roles);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlayerBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlayerBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Player<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+#[inline]
+pub fn get_root_as_player<'a>(buf: &'a [u8]) -> Player<'a> {
+  flatbuffers::get_root::<Player<'a>>(buf)
+}
+
+#[inline]
+pub fn get_size_prefixed_root_as_player<'a>(buf: &'a [u8]) -> Player<'a> {
+  flatbuffers::get_size_prefixed_root::<Player<'a>>(buf)
+}
+
+#[inline]
+pub fn finish_player_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Player<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_player_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Player<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
+}  // pub mod example
+
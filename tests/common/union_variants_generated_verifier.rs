@@ -0,0 +1,191 @@
+//! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
+use super::union_variants_generated as reader;
+
+pub mod example {
+    #![allow(unused_imports)]
+
+    use super::reader::example as reader;
+    use flatbuffers::{self, Follow};
+    use flatbuffers_verifier::{
+        lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+        verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+        TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+    };
+    use std::cmp::Ordering;
+    use std::result;
+
+    impl<'a> Verify for reader::Hero<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(6)?;
+            table.field_scalar::<u32>(Self::VT_HP)?;
+            table.field_default(Self::VT_HP, |value: u32| value == 0u32)?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Hero<'_> {
+        type Reader<'a> = reader::Hero<'a>;
+    }
+
+    impl<'a> reader::Hero<'a> {
+        pub fn try_hp(&self) -> result::Result<u32, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_HP,
+                4,
+                4,
+            )?;
+            Ok(self.hp())
+        }
+    }
+
+    impl<'a> Verify for reader::Player<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(12)?;
+            table.field_enum(Self::VT_ROLE_TYPE, |value: u8| matches!(value, 0..=3))?;
+            table.field_union(Self::VT_ROLE, "role", |loc| match self.try_role_type() {
+                Ok(reader::Role::Hero) => reader::Hero::follow(self._tab.buf, loc).verify_in(verification),
+                Ok(reader::Role::Point) => verify_struct(self._tab.buf, loc, 8, 4).and_then(|_| verification.visit(loc, loc + 8)),
+                Ok(reader::Role::Name) => StringVerifier::follow(self._tab.buf, loc).verify_in(verification),
+                Ok(reader::Role::NONE) => Err(Error::UnmatchedUnion),
+                Err(Error::UnknownEnumValue) => verification.unknown_enum_value(),
+                Err(err) => Err(err),
+            })?;
+            table.field_vector(Self::VT_ROLES_TYPE, "roles_type", 1, |vector| {
+                vector.verify_enum_elements(verification, |value: u8| matches!(value, 0..=3))
+            })?;
+            table.field_union_vector(Self::VT_ROLES_TYPE, Self::VT_ROLES, "roles", |union_type, loc| match union_type {
+                1 => reader::Hero::follow(self._tab.buf, loc).verify_in(verification),
+                2 => verify_struct(self._tab.buf, loc, 8, 4).and_then(|_| verification.visit(loc, loc + 8)),
+                3 => StringVerifier::follow(self._tab.buf, loc).verify_in(verification),
+                0 => Err(Error::UnmatchedUnion),
+                _ => verification.unknown_enum_value(),
+            })?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Player<'_> {
+        type Reader<'a> = reader::Player<'a>;
+    }
+
+    impl<'a> reader::Player<'a> {
+        pub fn try_role_type(&self) -> result::Result<reader::Role, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_ROLE_TYPE,
+                1,
+                1,
+            )? {
+                if !matches!(
+                    read_scalar_at::<u8>(self._tab.buf, loc),
+                    0..=3
+                ) {
+                    return Err(Error::UnknownEnumValue);
+                }
+            }
+            Ok(self.role_type())
+        }
+
+        /// Reads `role_type` as the underlying value of the enum, which may be undeclared in
+        /// the schema, see `Options::accept_unknown_enum_values`.
+        pub fn raw_role_type(&self) -> u8 {
+            self._tab
+                .get::<u8>(Self::VT_ROLE_TYPE, Some(0))
+                .unwrap()
+        }
+
+        pub fn try_role_as_hero(
+            &self,
+        ) -> result::Result<Option<reader::Hero<'a>>, Error> {
+            if self.try_role_type()? != reader::Role::Hero {
+                return Ok(None);
+            }
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_ROLE,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                try_follow_uoffset(self._tab.buf, loc)?;
+            }
+            Ok(self.role().map(reader::Hero::init_from_table))
+        }
+
+        pub fn try_role_as_point(
+            &self,
+        ) -> result::Result<Option<&'a reader::Point>, Error> {
+            if self.try_role_type()? != reader::Role::Point {
+                return Ok(None);
+            }
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_ROLE,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                verify_struct(
+                    self._tab.buf,
+                    try_follow_uoffset(self._tab.buf, loc)?,
+                    8,
+                    4,
+                )?;
+            }
+            Ok(self.role().map(|t| <&reader::Point>::follow(t.buf, t.loc)))
+        }
+
+        pub fn try_role_as_name(
+            &self,
+        ) -> result::Result<Option<&'a str>, Error> {
+            if self.try_role_type()? != reader::Role::Name {
+                return Ok(None);
+            }
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_ROLE,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                StringVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?).verify()?;
+            }
+            Ok(self.role().map(|t| <&str>::follow(t.buf, t.loc)))
+        }
+
+        pub fn try_roles_type(&self) -> result::Result<Option<flatbuffers::Vector<'a, reader::Role>>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_ROLES_TYPE,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                verifier.verify_enum_elements(&Verification::default(), |value: u8| matches!(value, 0..=3))?;
+            }
+            Ok(self.roles_type())
+        }
+
+        /// Reads `roles_type` as the underlying values of the enum, which may be undeclared in
+        /// the schema, see `Options::accept_unknown_enum_values`.
+        pub fn raw_roles_type(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Self::VT_ROLES_TYPE, None)
+        }
+
+        pub fn try_roles(&self) -> result::Result<Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_ROLES,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                verifier.verify_reference_offsets()?;
+            }
+            Ok(self.roles())
+        }
+    }
+}
@@ -0,0 +1,476 @@
+//! This file is auto-generated by cfbc.
+pub mod example {
+    #![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
+
+    use cfb::builder::{
+        Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
+        ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
+    };
+    use cfb::scalar::Scalar;
+    use cfb::types::{SOffset, SIZE_OF_SOFFSET};
+    use std::cmp::Ordering;
+    #[cfg(feature = "proptest")]
+    use cfb::strategy;
+    #[cfg(feature = "proptest")]
+    use proptest::{
+        collection::vec,
+        prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+        sample::select,
+    };
+
+    #[derive(Clone, PartialEq, Debug)]
+    pub enum Role {
+        Hero(Hero),
+        Monster(Monster),
+        /// A variant undeclared in the schema, such as one added by a newer schema, with its union
+        /// type. Its value cannot be written, so building it fails with
+        /// `BuildError::UnknownUnionVariant`.
+        Unknown(u8),
+    }
+
+    impl Role {
+        pub fn union_type(&self) -> u8 {
+            match self {
+                Role::Hero(_) => 1,
+                Role::Monster(_) => 2,
+                Role::Unknown(union_type) => *union_type,
+            }
+        }
+    }
+
+    impl<'c> Component<'c> for Role {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            match *self {
+                Role::Hero(v) => Box::new(v).build(builder),
+                Role::Monster(v) => Box::new(v).build(builder),
+                Role::Unknown(union_type) => Box::new(UnknownUnionComponent::new(union_type)).build(builder),
+            }
+        }
+    }
+
+    /// Builds the union without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Role {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            match *self {
+                Role::Hero(v) => Box::new(v).build(builder),
+                Role::Monster(v) => Box::new(v).build(builder),
+                Role::Unknown(union_type) => Box::new(UnknownUnionComponent::new(*union_type)).build(builder),
+            }
+        }
+    }
+
+    /// The variant of `Role` which borrows its strings and tables, see `--borrowed`.
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    pub enum RoleRef {
+        Hero(HeroRef),
+        Monster(MonsterRef),
+        Unknown(u8),
+    }
+
+    impl RoleRef {
+        pub fn union_type(&self) -> u8 {
+            match self {
+                RoleRef::Hero(_) => 1,
+                RoleRef::Monster(_) => 2,
+                RoleRef::Unknown(union_type) => *union_type,
+            }
+        }
+    }
+
+    impl<'c> Component<'c> for RoleRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            match *self {
+                RoleRef::Hero(v) => Box::new(v).build(builder),
+                RoleRef::Monster(v) => Box::new(v).build(builder),
+                RoleRef::Unknown(union_type) => Box::new(UnknownUnionComponent::new(union_type)).build(builder),
+            }
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Role {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            proptest::strategy::Union::new(vec![
+                any_with::<Hero>(config.clone())
+                    .prop_map(Role::Hero)
+                    .boxed(),
+                any_with::<Monster>(config.clone())
+                    .prop_map(Role::Monster)
+                    .boxed(),
+            ])
+            .boxed()
+        }
+    }
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Hero {
+        pub hp: u32,
+    }
+
+    impl Hero {
+        const VT_HP: usize = 4;
+        const SIZE_HP: usize = 4;
+        const ALIGNMENT_HP: usize = 4;
+        const ALIGNMENT: usize = 4;
+    }
+
+    impl<'c> Component<'c> for Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Self::VT_HP, Self::SIZE_HP, Self::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Self::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Hero {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Hero::VT_HP, Hero::SIZE_HP, Hero::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Hero::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Hero` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct HeroRef {
+        pub hp: u32,
+    }
+
+    impl<'c> Component<'c> for HeroRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Hero::VT_HP, Hero::SIZE_HP, Hero::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Hero::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Hero::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Hero {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u32>(),
+            )
+                .prop_map(|(hp,)| Hero {
+                    hp,
+                })
+                .boxed()
+        }
+    }
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Monster {
+        pub hp: u32,
+    }
+
+    impl Monster {
+        const VT_HP: usize = 4;
+        const SIZE_HP: usize = 4;
+        const ALIGNMENT_HP: usize = 4;
+        const ALIGNMENT: usize = 4;
+    }
+
+    impl<'c> Component<'c> for Monster {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Self::VT_HP, Self::SIZE_HP, Self::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Self::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Monster {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Monster::VT_HP, Monster::SIZE_HP, Monster::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Monster::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Monster::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Monster` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct MonsterRef {
+        pub hp: u32,
+    }
+
+    impl<'c> Component<'c> for MonsterRef {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if self.hp != 0u32 {
+                    vtable.add_field(Monster::VT_HP, Monster::SIZE_HP, Monster::ALIGNMENT_HP);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Monster::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            if self.hp != 0u32 {
+                builder.align(Monster::ALIGNMENT_HP);
+                builder.push_scalar(self.hp);
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Monster {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (
+                any::<u32>(),
+            )
+                .prop_map(|(hp,)| Monster {
+                    hp,
+                })
+                .boxed()
+        }
+    }
+
+    #[derive(Default, Clone, Debug, PartialEq)]
+    pub struct Party {
+        pub members: Vec<Role>,
+    }
+
+    impl Party {
+        const VT_MEMBERS_TYPE: usize = 4;
+        const SIZE_MEMBERS_TYPE: usize = 4;
+        const ALIGNMENT_MEMBERS_TYPE: usize = 4;
+        const VT_MEMBERS: usize = 6;
+        const SIZE_MEMBERS: usize = 4;
+        const ALIGNMENT_MEMBERS: usize = 4;
+        const ALIGNMENT: usize = 4;
+    }
+
+    impl<'c> Component<'c> for Party {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.members.is_empty() {
+                    vtable.add_field(Self::VT_MEMBERS_TYPE, Self::SIZE_MEMBERS_TYPE, Self::ALIGNMENT_MEMBERS_TYPE);
+                }
+                if !self.members.is_empty() {
+                    vtable.add_field(Self::VT_MEMBERS, Self::SIZE_MEMBERS, Self::ALIGNMENT_MEMBERS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            let members_type: Vec<u8> = self.members.iter().map(|v| v.union_type()).collect();
+            if !members_type.is_empty() {
+                builder.align(Self::ALIGNMENT_MEMBERS_TYPE);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_MEMBERS_TYPE);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(members_type, 1)),
+                ));
+            }
+            if !self.members.is_empty() {
+                builder.align(Self::ALIGNMENT_MEMBERS);
+                let offset_position = builder.tell();
+                builder.pad(Self::SIZE_MEMBERS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(self.members)),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// Builds the table without consuming it, so the same value can be encoded repeatedly.
+    impl<'c> Component<'c> for &'c Party {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.members.is_empty() {
+                    vtable.add_field(Party::VT_MEMBERS_TYPE, Party::SIZE_MEMBERS_TYPE, Party::ALIGNMENT_MEMBERS_TYPE);
+                }
+                if !self.members.is_empty() {
+                    vtable.add_field(Party::VT_MEMBERS, Party::SIZE_MEMBERS, Party::ALIGNMENT_MEMBERS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Party::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            let members_type: Vec<u8> = self.members.iter().map(|v| v.union_type()).collect();
+            if !members_type.is_empty() {
+                builder.align(Party::ALIGNMENT_MEMBERS_TYPE);
+                let offset_position = builder.tell();
+                builder.pad(Party::SIZE_MEMBERS_TYPE);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(members_type, 1)),
+                ));
+            }
+            if !self.members.is_empty() {
+                builder.align(Party::ALIGNMENT_MEMBERS);
+                let offset_position = builder.tell();
+                builder.pad(Party::SIZE_MEMBERS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(self.members.iter())),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    /// The variant of `Party` which borrows its strings and vectors, see `--borrowed`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct PartyRef<'a> {
+        pub members: &'a [RoleRef],
+    }
+
+    impl<'c> Component<'c> for PartyRef<'c> {
+        fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+            let vtable_start = {
+                let mut vtable = builder.start_vtable();
+                if !self.members.is_empty() {
+                    vtable.add_field(Party::VT_MEMBERS_TYPE, Party::SIZE_MEMBERS_TYPE, Party::ALIGNMENT_MEMBERS_TYPE);
+                }
+                if !self.members.is_empty() {
+                    vtable.add_field(Party::VT_MEMBERS, Party::SIZE_MEMBERS, Party::ALIGNMENT_MEMBERS);
+                }
+                vtable.finish()
+            };
+
+            builder.align_after(SIZE_OF_SOFFSET, Party::ALIGNMENT);
+
+            let table_start = builder.tell();
+            builder.push_scalar((table_start - vtable_start) as SOffset);
+            let members_type: Vec<u8> = self.members.iter().map(|v| v.union_type()).collect();
+            if !members_type.is_empty() {
+                builder.align(Party::ALIGNMENT_MEMBERS_TYPE);
+                let offset_position = builder.tell();
+                builder.pad(Party::SIZE_MEMBERS_TYPE);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ScalarVectorComponent::new(members_type, 1)),
+                ));
+            }
+            if !self.members.is_empty() {
+                builder.align(Party::ALIGNMENT_MEMBERS);
+                let offset_position = builder.tell();
+                builder.pad(Party::SIZE_MEMBERS);
+                builder.push_component(DesignatedComponent::new(
+                    offset_position,
+                    Box::new(ReferenceVectorComponent::new(self.members.iter().copied())),
+                ));
+            }
+
+            table_start
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    impl Arbitrary for Party {
+        type Parameters = strategy::Config;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+            (
+                vec(any_with::<Role>(config.clone()), config.vector_len.clone()),
+            )
+                .prop_map(|(members,)| Party {
+                    members,
+                })
+                .boxed()
+        }
+    }
+}
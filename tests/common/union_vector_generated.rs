@@ -0,0 +1,341 @@
+// Written by hand in the style of the flatc output, since the Rust code generator of flatc 0.6
+// rejects vectors of unions. `make gen` does not regenerate it, so keep it in sync with the schema.
+
+
+pub mod example {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Role {
+  NONE = 0,
+  Hero = 1,
+  Monster = 2,
+
+}
+
+const ENUM_MIN_ROLE: u8 = 0;
+const ENUM_MAX_ROLE: u8 = 2;
+
+impl<'a> flatbuffers::Follow<'a> for Role {
+  type Inner = Self;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::read_scalar_at::<Self>(buf, loc)
+  }
+}
+
+impl flatbuffers::EndianScalar for Role {
+  #[inline]
+  fn to_little_endian(self) -> Self {
+    let n = u8::to_le(self as u8);
+    let p = &n as *const u8 as *const Role;
+    unsafe { *p }
+  }
+  #[inline]
+  fn from_little_endian(self) -> Self {
+    let n = u8::from_le(self as u8);
+    let p = &n as *const u8 as *const Role;
+    unsafe { *p }
+  }
+}
+
+impl flatbuffers::Push for Role {
+    type Output = Role;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        flatbuffers::emplace_scalar::<Role>(dst, *self);
+    }
+}
+
+#[allow(non_camel_case_types)]
+const ENUM_VALUES_ROLE:[Role; 3] = [
+  Role::NONE,
+  Role::Hero,
+  Role::Monster
+];
+
+#[allow(non_camel_case_types)]
+const ENUM_NAMES_ROLE:[&'static str; 3] = [
+    "NONE",
+    "Hero",
+    "Monster"
+];
+
+pub fn enum_name_role(e: Role) -> &'static str {
+  let index: usize = e as usize;
+  ENUM_NAMES_ROLE[index]
+}
+
+pub struct RoleUnionTableOffset {}
+pub enum HeroOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Hero<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Hero<'a> {
+    type Inner = Hero<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Hero<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Hero {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args HeroArgs) -> flatbuffers::WIPOffset<Hero<'bldr>> {
+      let mut builder = HeroBuilder::new(_fbb);
+      builder.add_hp(args.hp);
+      builder.finish()
+    }
+
+    pub const VT_HP: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn hp(&self) -> u32 {
+    self._tab.get::<u32>(Hero::VT_HP, Some(0)).unwrap()
+  }
+}
+
+pub struct HeroArgs {
+    pub hp: u32,
+}
+impl<'a> Default for HeroArgs {
+    #[inline]
+    fn default() -> Self {
+        HeroArgs {
+            hp: 0,
+        }
+    }
+}
+pub struct HeroBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HeroBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_hp(&mut self, hp: u32) {
+    self.fbb_.push_slot::<u32>(Hero::VT_HP, hp, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> HeroBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    HeroBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Hero<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum MonsterOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Monster<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Monster<'a> {
+    type Inner = Monster<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Monster<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Monster {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args MonsterArgs) -> flatbuffers::WIPOffset<Monster<'bldr>> {
+      let mut builder = MonsterBuilder::new(_fbb);
+      builder.add_hp(args.hp);
+      builder.finish()
+    }
+
+    pub const VT_HP: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn hp(&self) -> u32 {
+    self._tab.get::<u32>(Monster::VT_HP, Some(0)).unwrap()
+  }
+}
+
+pub struct MonsterArgs {
+    pub hp: u32,
+}
+impl<'a> Default for MonsterArgs {
+    #[inline]
+    fn default() -> Self {
+        MonsterArgs {
+            hp: 0,
+        }
+    }
+}
+pub struct MonsterBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> MonsterBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_hp(&mut self, hp: u32) {
+    self.fbb_.push_slot::<u32>(Monster::VT_HP, hp, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MonsterBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    MonsterBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Monster<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum PartyOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Party<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Party<'a> {
+    type Inner = Party<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Party<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Party {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args PartyArgs<'args>) -> flatbuffers::WIPOffset<Party<'bldr>> {
+      let mut builder = PartyBuilder::new(_fbb);
+      if let Some(x) = args.members { builder.add_members(x); }
+      if let Some(x) = args.members_type { builder.add_members_type(x); }
+      builder.finish()
+    }
+
+    pub const VT_MEMBERS_TYPE: flatbuffers::VOffsetT = 4;
+    pub const VT_MEMBERS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn members_type(&self) -> Option<flatbuffers::Vector<'a, Role>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Role>>>(Party::VT_MEMBERS_TYPE, None)
+  }
+  #[inline]
+  pub fn members(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>>>(Party::VT_MEMBERS, None)
+  }
+}
+
+pub struct PartyArgs<'a> {
+    pub members_type: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , Role>>>,
+    pub members: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<flatbuffers::UnionWIPOffset>>>>,
+}
+impl<'a> Default for PartyArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        PartyArgs {
+            members_type: None,
+            members: None,
+        }
+    }
+}
+pub struct PartyBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PartyBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_members_type(&mut self, members_type: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Role>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Party::VT_MEMBERS_TYPE, members_type);
+  }
+  #[inline]
+  pub fn add_members(&mut self, members: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<flatbuffers::UnionWIPOffset>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Party::VT_MEMBERS, members);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PartyBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PartyBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Party<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+#[inline]
+pub fn get_root_as_party<'a>(buf: &'a [u8]) -> Party<'a> {
+  flatbuffers::get_root::<Party<'a>>(buf)
+}
+
+#[inline]
+pub fn get_size_prefixed_root_as_party<'a>(buf: &'a [u8]) -> Party<'a> {
+  flatbuffers::get_size_prefixed_root::<Party<'a>>(buf)
+}
+
+#[inline]
+pub fn finish_party_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Party<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_party_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Party<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
+}  // pub mod example
+
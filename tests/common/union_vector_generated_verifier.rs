@@ -0,0 +1,126 @@
+//! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
+use super::union_vector_generated as reader;
+
+pub mod example {
+    #![allow(unused_imports)]
+
+    use super::reader::example as reader;
+    use flatbuffers::{self, Follow};
+    use flatbuffers_verifier::{
+        lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+        verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+        TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+    };
+    use std::cmp::Ordering;
+    use std::result;
+
+    impl<'a> Verify for reader::Hero<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(6)?;
+            table.field_scalar::<u32>(Self::VT_HP)?;
+            table.field_default(Self::VT_HP, |value: u32| value == 0u32)?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Hero<'_> {
+        type Reader<'a> = reader::Hero<'a>;
+    }
+
+    impl<'a> reader::Hero<'a> {
+        pub fn try_hp(&self) -> result::Result<u32, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_HP,
+                4,
+                4,
+            )?;
+            Ok(self.hp())
+        }
+    }
+
+    impl<'a> Verify for reader::Monster<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(6)?;
+            table.field_scalar::<u32>(Self::VT_HP)?;
+            table.field_default(Self::VT_HP, |value: u32| value == 0u32)?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Monster<'_> {
+        type Reader<'a> = reader::Monster<'a>;
+    }
+
+    impl<'a> reader::Monster<'a> {
+        pub fn try_hp(&self) -> result::Result<u32, Error> {
+            try_field_loc(
+                &self._tab,
+                Self::VT_HP,
+                4,
+                4,
+            )?;
+            Ok(self.hp())
+        }
+    }
+
+    impl<'a> Verify for reader::Party<'a> {
+        fn verify_in(&self, verification: &Verification) -> Result {
+            let table = TableVerifier::new(&self._tab, verification)?;
+            table.verify_known_fields(8)?;
+            table.field_vector(Self::VT_MEMBERS_TYPE, "members_type", 1, |vector| {
+                vector.verify_enum_elements(verification, |value: u8| matches!(value, 0..=2))
+            })?;
+            table.field_union_vector(Self::VT_MEMBERS_TYPE, Self::VT_MEMBERS, "members", |union_type, loc| match union_type {
+                1 => reader::Hero::follow(self._tab.buf, loc).verify_in(verification),
+                2 => reader::Monster::follow(self._tab.buf, loc).verify_in(verification),
+                0 => Err(Error::UnmatchedUnion),
+                _ => verification.unknown_enum_value(),
+            })?;
+            Ok(())
+        }
+    }
+
+    impl Root for reader::Party<'_> {
+        type Reader<'a> = reader::Party<'a>;
+    }
+
+    impl<'a> reader::Party<'a> {
+        pub fn try_members_type(&self) -> result::Result<Option<flatbuffers::Vector<'a, reader::Role>>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_MEMBERS_TYPE,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                verifier.verify_enum_elements(&Verification::default(), |value: u8| matches!(value, 0..=2))?;
+            }
+            Ok(self.members_type())
+        }
+
+        /// Reads `members_type` as the underlying values of the enum, which may be undeclared in
+        /// the schema, see `Options::accept_unknown_enum_values`.
+        pub fn raw_members_type(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Self::VT_MEMBERS_TYPE, None)
+        }
+
+        pub fn try_members(&self) -> result::Result<Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>>, Error> {
+            if let Some(loc) = try_field_loc(
+                &self._tab,
+                Self::VT_MEMBERS,
+                flatbuffers::SIZE_UOFFSET,
+                flatbuffers::SIZE_UOFFSET,
+            )? {
+                let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+                verifier.verify_reference_offsets()?;
+            }
+            Ok(self.members())
+        }
+    }
+}
@@ -0,0 +1,558 @@
+//! This file is auto-generated by cfbc.
+#![allow(unused_imports, clippy::large_enum_variant, clippy::derivable_impls)]
+
+use cfb::builder::{
+    Builder, Component, DesignatedComponent, NestedBufferComponent, ReferenceVectorComponent,
+    ScalarVectorComponent, StringComponent, StructComponent, UnknownUnionComponent,
+};
+use cfb::scalar::Scalar;
+use cfb::types::{SOffset, SIZE_OF_SOFFSET};
+use std::cmp::Ordering;
+#[cfg(feature = "proptest")]
+use cfb::strategy;
+#[cfg(feature = "proptest")]
+use proptest::{
+    collection::vec,
+    prelude::{any, any_with, Arbitrary, BoxedStrategy, Just, Strategy},
+    sample::select,
+};
+
+/// Values of the enum `Rarity`, including the ones not declared in the schema, such as values
+/// added by newer schemas, so they are written back unchanged.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rarity(pub u8);
+
+#[allow(non_upper_case_globals)]
+impl Rarity {
+    pub const Common: Rarity = Rarity(0);
+    pub const Rare: Rarity = Rarity(1);
+
+    /// Tests whether the value is declared in the schema.
+    pub const fn is_known(self) -> bool {
+        matches!(self.0, 0..=1)
+    }
+}
+
+impl Default for Rarity {
+    fn default() -> Self {
+        Rarity::Common
+    }
+}
+
+impl std::fmt::Debug for Rarity {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Rarity::Common => f.write_str("Common"),
+            Rarity::Rare => f.write_str("Rare"),
+            Rarity(raw) => f.debug_tuple("Unknown").field(&raw).finish(),
+        }
+    }
+}
+
+impl Scalar for Rarity {
+    fn to_le(self) -> Self {
+        Rarity(self.0.to_le())
+    }
+
+    fn from_le(x: Self) -> Self {
+        Rarity(u8::from_le(x.0))
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Rarity {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        select(vec![
+            Rarity::Common,
+            Rarity::Rare,
+        ])
+        .boxed()
+    }
+}
+
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct Item {
+    pub name: String,
+    pub rarity: Rarity,
+}
+
+impl Item {
+    const VT_NAME: usize = 4;
+    const SIZE_NAME: usize = 4;
+    const ALIGNMENT_NAME: usize = 4;
+    const VT_RARITY: usize = 6;
+    const SIZE_RARITY: usize = 1;
+    const ALIGNMENT_RARITY: usize = 1;
+    const ALIGNMENT: usize = 4;
+}
+
+impl<'c> Component<'c> for Item {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if !self.name.is_empty() {
+                vtable.add_field(Self::VT_NAME, Self::SIZE_NAME, Self::ALIGNMENT_NAME);
+            }
+            if self.rarity != Rarity::Common {
+                vtable.add_field(Self::VT_RARITY, Self::SIZE_RARITY, Self::ALIGNMENT_RARITY);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if !self.name.is_empty() {
+            builder.align(Self::ALIGNMENT_NAME);
+            let offset_position = builder.tell();
+            builder.pad(Self::SIZE_NAME);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(StringComponent::new(self.name))
+            ));
+        }
+        if self.rarity != Rarity::Common {
+            builder.align(Self::ALIGNMENT_RARITY);
+            builder.push_scalar(self.rarity);
+        }
+
+        table_start
+    }
+}
+
+/// Builds the table without consuming it, so the same value can be encoded repeatedly.
+impl<'c> Component<'c> for &'c Item {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if !self.name.is_empty() {
+                vtable.add_field(Item::VT_NAME, Item::SIZE_NAME, Item::ALIGNMENT_NAME);
+            }
+            if self.rarity != Rarity::Common {
+                vtable.add_field(Item::VT_RARITY, Item::SIZE_RARITY, Item::ALIGNMENT_RARITY);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Item::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if !self.name.is_empty() {
+            builder.align(Item::ALIGNMENT_NAME);
+            let offset_position = builder.tell();
+            builder.pad(Item::SIZE_NAME);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(StringComponent::new(&self.name))
+            ));
+        }
+        if self.rarity != Rarity::Common {
+            builder.align(Item::ALIGNMENT_RARITY);
+            builder.push_scalar(self.rarity);
+        }
+
+        table_start
+    }
+}
+
+/// The variant of `Item` which borrows its strings and vectors, see `--borrowed`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct ItemRef<'a> {
+    pub name: &'a str,
+    pub rarity: Rarity,
+}
+
+impl<'c> Component<'c> for ItemRef<'c> {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if !self.name.is_empty() {
+                vtable.add_field(Item::VT_NAME, Item::SIZE_NAME, Item::ALIGNMENT_NAME);
+            }
+            if self.rarity != Rarity::Common {
+                vtable.add_field(Item::VT_RARITY, Item::SIZE_RARITY, Item::ALIGNMENT_RARITY);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Item::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if !self.name.is_empty() {
+            builder.align(Item::ALIGNMENT_NAME);
+            let offset_position = builder.tell();
+            builder.pad(Item::SIZE_NAME);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(StringComponent::new(self.name))
+            ));
+        }
+        if self.rarity != Rarity::Common {
+            builder.align(Item::ALIGNMENT_RARITY);
+            builder.push_scalar(self.rarity);
+        }
+
+        table_start
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Item {
+    type Parameters = strategy::Config;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+        (
+            strategy::string(&config),
+            any::<Rarity>(),
+        )
+            .prop_map(|(name, rarity)| Item {
+                name,
+                rarity,
+            })
+            .boxed()
+    }
+}
+
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct Monster {
+    pub name: String,
+    pub stat: Option<Stat>,
+    pub loots: Vec<Item>,
+}
+
+impl Monster {
+    const VT_NAME: usize = 4;
+    const SIZE_NAME: usize = 4;
+    const ALIGNMENT_NAME: usize = 4;
+    const VT_STAT: usize = 6;
+    const SIZE_STAT: usize = 4;
+    const ALIGNMENT_STAT: usize = 4;
+    const VT_LOOTS: usize = 8;
+    const SIZE_LOOTS: usize = 4;
+    const ALIGNMENT_LOOTS: usize = 4;
+    const ALIGNMENT: usize = 4;
+}
+
+impl<'c> Component<'c> for Monster {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if !self.name.is_empty() {
+                vtable.add_field(Self::VT_NAME, Self::SIZE_NAME, Self::ALIGNMENT_NAME);
+            }
+            if self.stat.is_some() {
+                vtable.add_field(Self::VT_STAT, Self::SIZE_STAT, Self::ALIGNMENT_STAT);
+            }
+            if !self.loots.is_empty() {
+                vtable.add_field(Self::VT_LOOTS, Self::SIZE_LOOTS, Self::ALIGNMENT_LOOTS);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if !self.name.is_empty() {
+            builder.align(Self::ALIGNMENT_NAME);
+            let offset_position = builder.tell();
+            builder.pad(Self::SIZE_NAME);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(StringComponent::new(self.name))
+            ));
+        }
+        if let Some(f) = self.stat {
+            builder.align(Self::ALIGNMENT_STAT);
+            let offset_position = builder.tell();
+            builder.pad(Self::SIZE_STAT);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(f),
+            ));
+        }
+        if !self.loots.is_empty() {
+            builder.align(Self::ALIGNMENT_LOOTS);
+            let offset_position = builder.tell();
+            builder.pad(Self::SIZE_LOOTS);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ReferenceVectorComponent::new(self.loots)),
+            ));
+        }
+
+        table_start
+    }
+}
+
+/// Builds the table without consuming it, so the same value can be encoded repeatedly.
+impl<'c> Component<'c> for &'c Monster {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if !self.name.is_empty() {
+                vtable.add_field(Monster::VT_NAME, Monster::SIZE_NAME, Monster::ALIGNMENT_NAME);
+            }
+            if self.stat.is_some() {
+                vtable.add_field(Monster::VT_STAT, Monster::SIZE_STAT, Monster::ALIGNMENT_STAT);
+            }
+            if !self.loots.is_empty() {
+                vtable.add_field(Monster::VT_LOOTS, Monster::SIZE_LOOTS, Monster::ALIGNMENT_LOOTS);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Monster::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if !self.name.is_empty() {
+            builder.align(Monster::ALIGNMENT_NAME);
+            let offset_position = builder.tell();
+            builder.pad(Monster::SIZE_NAME);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(StringComponent::new(&self.name))
+            ));
+        }
+        if let Some(f) = &self.stat {
+            builder.align(Monster::ALIGNMENT_STAT);
+            let offset_position = builder.tell();
+            builder.pad(Monster::SIZE_STAT);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(f),
+            ));
+        }
+        if !self.loots.is_empty() {
+            builder.align(Monster::ALIGNMENT_LOOTS);
+            let offset_position = builder.tell();
+            builder.pad(Monster::SIZE_LOOTS);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ReferenceVectorComponent::new(self.loots.iter())),
+            ));
+        }
+
+        table_start
+    }
+}
+
+/// The variant of `Monster` which borrows its strings and vectors, see `--borrowed`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct MonsterRef<'a> {
+    pub name: &'a str,
+    pub stat: Option<StatRef>,
+    pub loots: &'a [ItemRef<'a>],
+}
+
+impl<'c> Component<'c> for MonsterRef<'c> {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if !self.name.is_empty() {
+                vtable.add_field(Monster::VT_NAME, Monster::SIZE_NAME, Monster::ALIGNMENT_NAME);
+            }
+            if self.stat.is_some() {
+                vtable.add_field(Monster::VT_STAT, Monster::SIZE_STAT, Monster::ALIGNMENT_STAT);
+            }
+            if !self.loots.is_empty() {
+                vtable.add_field(Monster::VT_LOOTS, Monster::SIZE_LOOTS, Monster::ALIGNMENT_LOOTS);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Monster::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if !self.name.is_empty() {
+            builder.align(Monster::ALIGNMENT_NAME);
+            let offset_position = builder.tell();
+            builder.pad(Monster::SIZE_NAME);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(StringComponent::new(self.name))
+            ));
+        }
+        if let Some(f) = self.stat {
+            builder.align(Monster::ALIGNMENT_STAT);
+            let offset_position = builder.tell();
+            builder.pad(Monster::SIZE_STAT);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(f),
+            ));
+        }
+        if !self.loots.is_empty() {
+            builder.align(Monster::ALIGNMENT_LOOTS);
+            let offset_position = builder.tell();
+            builder.pad(Monster::SIZE_LOOTS);
+            builder.push_component(DesignatedComponent::new(
+                offset_position,
+                Box::new(ReferenceVectorComponent::new(self.loots.iter().copied())),
+            ));
+        }
+
+        table_start
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Monster {
+    type Parameters = strategy::Config;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(config: Self::Parameters) -> Self::Strategy {
+        (
+            strategy::string(&config),
+            strategy::option(&config, any_with::<Stat>(config.clone())),
+            vec(any_with::<Item>(config.clone()), config.vector_len.clone()),
+        )
+            .prop_map(|(name, stat, loots)| Monster {
+                name,
+                stat,
+                loots,
+            })
+            .boxed()
+    }
+}
+
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct Stat {
+    pub hp: u32,
+    pub mp: u32,
+}
+
+impl Stat {
+    const VT_HP: usize = 4;
+    const SIZE_HP: usize = 4;
+    const ALIGNMENT_HP: usize = 4;
+    const VT_MP: usize = 6;
+    const SIZE_MP: usize = 4;
+    const ALIGNMENT_MP: usize = 4;
+    const ALIGNMENT: usize = 4;
+}
+
+impl<'c> Component<'c> for Stat {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.hp != 0u32 {
+                vtable.add_field(Self::VT_HP, Self::SIZE_HP, Self::ALIGNMENT_HP);
+            }
+            if self.mp != 0u32 {
+                vtable.add_field(Self::VT_MP, Self::SIZE_MP, Self::ALIGNMENT_MP);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Self::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.hp != 0u32 {
+            builder.align(Self::ALIGNMENT_HP);
+            builder.push_scalar(self.hp);
+        }
+        if self.mp != 0u32 {
+            builder.align(Self::ALIGNMENT_MP);
+            builder.push_scalar(self.mp);
+        }
+
+        table_start
+    }
+}
+
+/// Builds the table without consuming it, so the same value can be encoded repeatedly.
+impl<'c> Component<'c> for &'c Stat {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.hp != 0u32 {
+                vtable.add_field(Stat::VT_HP, Stat::SIZE_HP, Stat::ALIGNMENT_HP);
+            }
+            if self.mp != 0u32 {
+                vtable.add_field(Stat::VT_MP, Stat::SIZE_MP, Stat::ALIGNMENT_MP);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Stat::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.hp != 0u32 {
+            builder.align(Stat::ALIGNMENT_HP);
+            builder.push_scalar(self.hp);
+        }
+        if self.mp != 0u32 {
+            builder.align(Stat::ALIGNMENT_MP);
+            builder.push_scalar(self.mp);
+        }
+
+        table_start
+    }
+}
+
+/// The variant of `Stat` which borrows its strings and vectors, see `--borrowed`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct StatRef {
+    pub hp: u32,
+    pub mp: u32,
+}
+
+impl<'c> Component<'c> for StatRef {
+    fn build(self: Box<Self>, builder: &mut Builder<'c>) -> usize {
+        let vtable_start = {
+            let mut vtable = builder.start_vtable();
+            if self.hp != 0u32 {
+                vtable.add_field(Stat::VT_HP, Stat::SIZE_HP, Stat::ALIGNMENT_HP);
+            }
+            if self.mp != 0u32 {
+                vtable.add_field(Stat::VT_MP, Stat::SIZE_MP, Stat::ALIGNMENT_MP);
+            }
+            vtable.finish()
+        };
+
+        builder.align_after(SIZE_OF_SOFFSET, Stat::ALIGNMENT);
+
+        let table_start = builder.tell();
+        builder.push_scalar((table_start - vtable_start) as SOffset);
+        if self.hp != 0u32 {
+            builder.align(Stat::ALIGNMENT_HP);
+            builder.push_scalar(self.hp);
+        }
+        if self.mp != 0u32 {
+            builder.align(Stat::ALIGNMENT_MP);
+            builder.push_scalar(self.mp);
+        }
+
+        table_start
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Stat {
+    type Parameters = strategy::Config;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<u32>(),
+            any::<u32>(),
+        )
+            .prop_map(|(hp, mp)| Stat {
+                hp,
+                mp,
+            })
+            .boxed()
+    }
+}
\ No newline at end of file
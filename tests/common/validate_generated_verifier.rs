@@ -0,0 +1,164 @@
+//! This file is auto-generated by cfbc.
+#![allow(unused_imports)]
+
+use super::validate_generated as reader;
+
+use flatbuffers::{self, Follow};
+use flatbuffers_verifier::{
+    lookup_by_key, read_scalar_at, try_field_loc, try_follow_uoffset, try_follow_uoffset64,
+    verify_float, verify_struct, verify_union_vector, Error, Result, Root, StringVerifier,
+    TableVerifier, Verification, VectorVerifier, Verify, SIZE_UOFFSET64,
+};
+use std::cmp::Ordering;
+use std::result;
+
+impl<'a> Verify for reader::Item<'a> {
+    fn verify_in(&self, verification: &Verification) -> Result {
+        let table = TableVerifier::new(&self._tab, verification)?;
+        table.verify_known_fields(8)?;
+        table.field_string(Self::VT_NAME)?;
+        table.field_enum(Self::VT_RARITY, |value: u8| matches!(value, 0..=1))?;
+        table.field_default(Self::VT_RARITY, |value: u8| value == 0u8)?;
+        table.validate(self)?;
+        Ok(())
+    }
+}
+
+impl Root for reader::Item<'_> {
+    type Reader<'a> = reader::Item<'a>;
+}
+
+impl<'a> reader::Item<'a> {
+    pub fn try_name(&self) -> result::Result<Option<&'a str>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_NAME,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            StringVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?).verify()?;
+        }
+        Ok(self.name())
+    }
+
+    pub fn try_rarity(&self) -> result::Result<reader::Rarity, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_RARITY,
+            1,
+            1,
+        )? {
+            if !matches!(
+                read_scalar_at::<u8>(self._tab.buf, loc),
+                0..=1
+            ) {
+                return Err(Error::UnknownEnumValue);
+            }
+        }
+        Ok(self.rarity())
+    }
+
+    /// Reads `rarity` as the underlying value of the enum, which may be undeclared in
+    /// the schema, see `Options::accept_unknown_enum_values`.
+    pub fn raw_rarity(&self) -> u8 {
+        self._tab
+            .get::<u8>(Self::VT_RARITY, Some(0))
+            .unwrap()
+    }
+}
+
+impl<'a> Verify for reader::Monster<'a> {
+    fn verify_in(&self, verification: &Verification) -> Result {
+        let table = TableVerifier::new(&self._tab, verification)?;
+        table.verify_known_fields(10)?;
+        table.field_string(Self::VT_NAME)?;
+        table.field_table::<reader::Stat>(Self::VT_STAT, "stat")?;
+        table.field_vector(Self::VT_LOOTS, "loots", flatbuffers::SIZE_UOFFSET, |vector| {
+            vector.verify_reference_elements::<reader::Item>(verification)
+        })?;
+        table.validate(self)?;
+        Ok(())
+    }
+}
+
+impl Root for reader::Monster<'_> {
+    type Reader<'a> = reader::Monster<'a>;
+}
+
+impl<'a> reader::Monster<'a> {
+    pub fn try_name(&self) -> result::Result<Option<&'a str>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_NAME,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            StringVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?).verify()?;
+        }
+        Ok(self.name())
+    }
+
+    pub fn try_stat(&self) -> result::Result<Option<reader::Stat<'a>>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_STAT,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            try_follow_uoffset(self._tab.buf, loc)?;
+        }
+        Ok(self.stat())
+    }
+
+    pub fn try_loots(&self) -> result::Result<Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<reader::Item<'a>>>>, Error> {
+        if let Some(loc) = try_field_loc(
+            &self._tab,
+            Self::VT_LOOTS,
+            flatbuffers::SIZE_UOFFSET,
+            flatbuffers::SIZE_UOFFSET,
+        )? {
+            let verifier = VectorVerifier::follow(self._tab.buf, try_follow_uoffset(self._tab.buf, loc)?);
+            verifier.verify_reference_offsets()?;
+        }
+        Ok(self.loots())
+    }
+}
+
+impl<'a> Verify for reader::Stat<'a> {
+    fn verify_in(&self, verification: &Verification) -> Result {
+        let table = TableVerifier::new(&self._tab, verification)?;
+        table.verify_known_fields(8)?;
+        table.field_scalar::<u32>(Self::VT_HP)?;
+        table.field_default(Self::VT_HP, |value: u32| value == 0u32)?;
+        table.field_scalar::<u32>(Self::VT_MP)?;
+        table.field_default(Self::VT_MP, |value: u32| value == 0u32)?;
+        table.validate(self)?;
+        Ok(())
+    }
+}
+
+impl Root for reader::Stat<'_> {
+    type Reader<'a> = reader::Stat<'a>;
+}
+
+impl<'a> reader::Stat<'a> {
+    pub fn try_hp(&self) -> result::Result<u32, Error> {
+        try_field_loc(
+            &self._tab,
+            Self::VT_HP,
+            4,
+            4,
+        )?;
+        Ok(self.hp())
+    }
+
+    pub fn try_mp(&self) -> result::Result<u32, Error> {
+        try_field_loc(
+            &self._tab,
+            Self::VT_MP,
+            4,
+            4,
+        )?;
+        Ok(self.mp())
+    }
+}
\ No newline at end of file
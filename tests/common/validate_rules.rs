@@ -0,0 +1,42 @@
+//! The `Validate` impls of the tables in `validate.fbs`.
+
+use super::validate_generated::{Item, Monster, Stat};
+use flatbuffers_verifier::{FieldPath, Validate};
+use std::cell::Cell;
+
+/// The most loots a monster may carry.
+pub const MAX_LOOTS: usize = 3;
+
+thread_local! {
+    /// The number of items validated on this thread, to tell whether `validate` runs.
+    pub static VALIDATED_ITEMS: Cell<usize> = const { Cell::new(0) };
+}
+
+impl Validate for Monster<'_> {
+    fn validate(&self, _path: &FieldPath) -> Result<(), String> {
+        let loots = self.loots().map_or(0, |loots| loots.len());
+        if loots > MAX_LOOTS {
+            return Err(format!("{} loots exceed the limit {}", loots, MAX_LOOTS));
+        }
+        Ok(())
+    }
+}
+
+impl Validate for Stat<'_> {
+    fn validate(&self, _path: &FieldPath) -> Result<(), String> {
+        if self.mp() > self.hp() {
+            return Err(format!("mp {} exceeds hp {}", self.mp(), self.hp()));
+        }
+        Ok(())
+    }
+}
+
+impl Validate for Item<'_> {
+    fn validate(&self, _path: &FieldPath) -> Result<(), String> {
+        VALIDATED_ITEMS.with(|validated| validated.set(validated.get() + 1));
+        if self.name().unwrap_or_default().is_empty() {
+            return Err("item has no name".to_string());
+        }
+        Ok(())
+    }
+}
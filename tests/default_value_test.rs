@@ -0,0 +1,173 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use cfb::scalar::{Scalar, CANONICAL_NAN_F32_BITS};
+use cfb::strategy::Config;
+use common::default_value_builder::example as cfbe;
+use common::default_value_generated::example as fbe;
+use flatbuffers::FlatBufferBuilder;
+use flatbuffers_verifier::{get_root, Error, Options};
+use proptest::prelude::*;
+
+fn _test_default_value_builder(hero: cfbe::Hero) {
+    let buf = Builder::new(hero.clone()).build();
+    let root = get_root::<fbe::Hero>(&buf).expect("verified");
+
+    assert_eq!(hero.hp, root.hp());
+    assert_eq!(hero.mp, root.mp());
+    assert_eq!(hero.speed.canonicalize().to_bits(), root.speed().to_bits());
+    assert_eq!(hero.alive, root.alive());
+    assert_eq!(hero.color.0, root.color() as i8);
+    assert_eq!(hero.exp, root.exp());
+    assert_eq!(hero.name, root.name().unwrap_or_default());
+}
+
+#[test]
+fn test_default_value_builder_omits_declared_defaults() {
+    let hero = cfbe::Hero::default();
+    assert_eq!(hero.hp, 100);
+    assert_eq!(hero.mp, -1);
+    assert_eq!(hero.speed, 1.5);
+    assert!(hero.alive);
+    assert_eq!(hero.color, cfbe::Color::Blue);
+
+    let buf = Builder::new(hero.clone()).build();
+    let empty = [
+        le!(12u32),
+        // vtable
+        le!(4u16),
+        le!(4u16),
+        // padding
+        le!(0u32),
+        // table
+        le!(8i32),
+    ]
+    .concat();
+    assert_eq!(buf, empty);
+
+    _test_default_value_builder(hero);
+}
+
+#[test]
+fn test_default_value_builder_writes_zeros() {
+    _test_default_value_builder(cfbe::Hero {
+        hp: 0,
+        mp: 0,
+        speed: 0.0,
+        alive: false,
+        color: cfbe::Color::Red,
+        ..Default::default()
+    });
+}
+
+/// Builds a `Hero` with flatc, which writes the bits of `speed` as is.
+fn hero_with_speed(speed: f32) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let root = fbe::Hero::create(
+        &mut fbb,
+        &fbe::HeroArgs {
+            speed,
+            ..Default::default()
+        },
+    );
+    fbb.finish_minimal(root);
+    fbb.finished_data().to_vec()
+}
+
+#[test]
+fn test_default_value_canonical_floats() {
+    // Negative zero is written as zero, and every NaN as the same quiet NaN.
+    for (speed, expected) in [
+        (-0.0f32, 0.0f32),
+        (
+            f32::from_bits(0x7fc0_0001),
+            f32::from_bits(CANONICAL_NAN_F32_BITS),
+        ),
+        (
+            f32::from_bits(0xff80_0001),
+            f32::from_bits(CANONICAL_NAN_F32_BITS),
+        ),
+    ] {
+        let build = |speed| {
+            Builder::new(cfbe::Hero {
+                speed,
+                ..Default::default()
+            })
+            .build()
+        };
+        let buf = build(speed);
+        assert_eq!(buf, build(expected));
+        let root = get_root::<fbe::Hero>(&buf).expect("verified");
+        assert_eq!(expected.to_bits(), root.speed().to_bits());
+    }
+}
+
+#[test]
+fn test_default_value_verifier_rejects_non_canonical_floats() {
+    assert!(get_root::<fbe::Hero>(&hero_with_speed(0.0)).is_ok());
+    assert!(
+        get_root::<fbe::Hero>(&hero_with_speed(f32::from_bits(CANONICAL_NAN_F32_BITS))).is_ok()
+    );
+
+    for speed in [-0.0f32, f32::from_bits(0x7fc0_0001), -f32::NAN] {
+        let buf = hero_with_speed(speed);
+        assert_eq!(
+            get_root::<fbe::Hero>(&buf).map(|_| ()),
+            Err(Error::NonCanonicalFloat)
+        );
+        let root = flatbuffers::get_root::<fbe::Hero>(&buf);
+        assert_eq!(root.try_speed(), Err(Error::NonCanonicalFloat));
+    }
+}
+
+/// Builds a `Hero` with flatc, which stores `hp` even if it equals the default.
+fn hero_with_hp(hp: u32) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let start = fbb.start_table();
+    fbb.push_slot_always(fbe::Hero::VT_HP, hp);
+    let root = fbb.end_table(start);
+    fbb.finish_minimal(flatbuffers::WIPOffset::<fbe::Hero>::new(root.value()));
+    fbb.finished_data().to_vec()
+}
+
+#[test]
+fn test_default_value_verifier_rejects_default_scalars() {
+    let options = Options {
+        reject_default_scalars: true,
+        ..Default::default()
+    };
+    let buf = hero_with_hp(100);
+    assert!(get_root::<fbe::Hero>(&buf).is_ok());
+    assert_eq!(
+        options.get_root::<fbe::Hero>(&buf).map(|_| ()),
+        Err(Error::DefaultScalar)
+    );
+
+    // Zero is not the default of `hp`, and the builder output is always canonical.
+    assert!(options.get_root::<fbe::Hero>(&hero_with_hp(0)).is_ok());
+    let buf = Builder::new(cfbe::Hero {
+        hp: 0,
+        mp: 0,
+        speed: 0.0,
+        alive: false,
+        color: cfbe::Color::Red,
+        exp: 1,
+        name: "hero".to_string(),
+    })
+    .build();
+    assert!(options.get_root::<fbe::Hero>(&buf).is_ok());
+}
+
+proptest! {
+    #[test]
+    fn proptest_default_value_builder(hero in any_with::<cfbe::Hero>(Config::default())) {
+        _test_default_value_builder(hero.clone());
+
+        let options = Options {
+            reject_default_scalars: true,
+            ..Default::default()
+        };
+        let buf = Builder::new(hero).build();
+        prop_assert!(options.get_root::<fbe::Hero>(&buf).is_ok());
+    }
+}
@@ -0,0 +1,105 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use cfb::strategy::Config;
+use common::deprecated_builder::example as cfbe;
+use common::deprecated_generated::example as fbe;
+use flatbuffers::FlatBufferBuilder;
+use flatbuffers_verifier::{get_root, Error, Options};
+use proptest::prelude::*;
+
+const VT_NAME: flatbuffers::VOffsetT = 4;
+const VT_OLD_MP: flatbuffers::VOffsetT = 8;
+
+/// Builds a `Hero` which still contains the deprecated fields written by an old writer.
+fn hero_with_deprecated_fields(name: bool, old_mp: bool) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let name = if name {
+        Some(fbb.create_string("orc"))
+    } else {
+        None
+    };
+    let start = fbb.start_table();
+    fbb.push_slot::<u32>(fbe::Hero::VT_HP, 10, 0);
+    if let Some(name) = name {
+        fbb.push_slot_always(VT_NAME, name);
+    }
+    if old_mp {
+        fbb.push_slot::<i16>(VT_OLD_MP, 20, 0);
+    }
+    fbb.push_slot::<i16>(fbe::Hero::VT_MP, 30, 0);
+    let root = fbb.end_table(start);
+    fbb.finish_minimal(root);
+    fbb.finished_data().to_vec()
+}
+
+fn _test_deprecated_builder(hero: cfbe::Hero) {
+    let buf = Builder::new(hero.clone()).build();
+    let root = Options {
+        reject_deprecated_fields: true,
+        ..Default::default()
+    }
+    .get_root::<fbe::Hero>(&buf)
+    .expect("verified");
+
+    assert_eq!(hero.hp, root.hp());
+    assert_eq!(hero.mp, root.mp());
+}
+
+#[test]
+fn test_deprecated_builder_skips_deprecated_fields() {
+    let buf = Builder::new(cfbe::Hero { hp: 10, mp: 30 }).build();
+    let expected = [
+        le!(16u32),
+        // vtable
+        le!(12u16),
+        le!(10u16),
+        le!(0u16),
+        le!(4u16),
+        le!(0u16),
+        le!(8u16),
+        // table
+        le!(12i32),
+        le!(10u32),
+        le!(30i16),
+    ]
+    .concat();
+    assert_eq!(buf, expected);
+
+    _test_deprecated_builder(cfbe::Hero { hp: 10, mp: 30 });
+}
+
+#[test]
+fn test_deprecated_fields_are_ignored_by_default() {
+    for (name, old_mp) in [(true, false), (false, true), (true, true)] {
+        let buf = hero_with_deprecated_fields(name, old_mp);
+        let root = get_root::<fbe::Hero>(&buf).expect("verified");
+        assert_eq!(root.hp(), 10);
+        assert_eq!(root.mp(), 30);
+    }
+}
+
+#[test]
+fn test_deprecated_fields_are_rejected() {
+    let options = Options {
+        reject_deprecated_fields: true,
+        ..Default::default()
+    };
+    assert!(options
+        .get_root::<fbe::Hero>(&hero_with_deprecated_fields(false, false))
+        .is_ok());
+    for (name, old_mp) in [(true, false), (false, true), (true, true)] {
+        let buf = hero_with_deprecated_fields(name, old_mp);
+        assert_eq!(
+            options.get_root::<fbe::Hero>(&buf).map(|_| ()),
+            Err(Error::DeprecatedField)
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn proptest_deprecated_builder(hero in any_with::<cfbe::Hero>(Config::default())) {
+        _test_deprecated_builder(hero);
+    }
+}
@@ -1,15 +1,15 @@
 pub mod common;
 
 use cfb::builder::Builder;
+use flatbuffers_verifier::{get_lazy_root, get_root, Error, Options};
 use proptest::prelude::*;
-use std::mem::transmute;
 
 use common::enum_builder::example as cfbe;
 use common::enum_generated::example as fbe;
 
 prop_compose! {
     fn arb_color()(color in 0i8..3i8) -> cfbe::Color {
-        unsafe { transmute(color) }
+        cfbe::Color(color)
     }
 }
 
@@ -17,7 +17,26 @@ fn _test_enum_builder(color: cfbe::Color) {
     let buf = Builder::new(cfbe::Bag { color }).build();
 
     let root = flatbuffers::get_root::<fbe::Bag>(&buf[..]);
-    assert_eq!(color as i8, root.color() as i8);
+    assert_eq!(color.0, root.color() as i8);
+}
+
+fn accept_unknown() -> Options {
+    Options {
+        accept_unknown_enum_values: true,
+        ..Default::default()
+    }
+}
+
+/// Verifies a bag which may come from a newer peer, and decodes the color from the raw value,
+/// since the flatc reader cannot represent unknown values.
+fn decode(buf: &[u8]) -> cfbe::Bag {
+    accept_unknown()
+        .verify_root::<fbe::Bag>(buf)
+        .expect("verified");
+    let root = get_lazy_root::<fbe::Bag>(buf).unwrap();
+    cfbe::Bag {
+        color: cfbe::Color(root.raw_color()),
+    }
 }
 
 #[test]
@@ -25,9 +44,42 @@ fn test_enum_builder() {
     _test_enum_builder(cfbe::Color::Red)
 }
 
+#[test]
+fn test_unknown_enum_value_round_trip() {
+    let unknown = cfbe::Color(7);
+    assert!(!unknown.is_known());
+    assert!(cfbe::Color::Blue.is_known());
+    assert_eq!(format!("{:?}", unknown), "Unknown(7)");
+    assert_eq!(format!("{:?}", cfbe::Color::Blue), "Blue");
+
+    let buf = Builder::new(cfbe::Bag { color: unknown }).build();
+    let decoded = decode(&buf);
+    assert_eq!(decoded.color, unknown);
+    assert_eq!(Builder::new(decoded).build(), buf);
+
+    // The value is only accepted by `verify_root` with `accept_unknown_enum_values`, since the
+    // flatc reader cannot represent it, and the checked accessor still rejects it.
+    assert_eq!(
+        get_root::<fbe::Bag>(&buf).map(|_| ()),
+        Err(Error::UnknownEnumValue)
+    );
+    assert_eq!(
+        accept_unknown().get_root::<fbe::Bag>(&buf).map(|_| ()),
+        Err(Error::UnknownEnumValue)
+    );
+    let root = get_lazy_root::<fbe::Bag>(&buf).unwrap();
+    assert_eq!(root.try_color().map(|_| ()), Err(Error::UnknownEnumValue));
+}
+
 proptest! {
     #[test]
     fn proptest_enum_builder(color in arb_color()) {
         _test_enum_builder(color);
     }
+
+    #[test]
+    fn proptest_enum_round_trip(color: i8) {
+        let buf = Builder::new(cfbe::Bag { color: cfbe::Color(color) }).build();
+        prop_assert_eq!(Builder::new(decode(&buf)).build(), buf);
+    }
 }
@@ -2,14 +2,13 @@ pub mod common;
 
 use cfb::builder::Builder;
 use proptest::prelude::*;
-use std::mem::transmute;
 
 use common::enum_vector_builder::example as cfbe;
 use common::enum_vector_generated::example as fbe;
 
 prop_compose! {
     fn arb_color()(color in 0i8..3i8) -> cfbe::Color {
-        unsafe { transmute(color) }
+        cfbe::Color(color)
     }
 }
 
@@ -21,7 +20,7 @@ fn _test_enum_vector_builder(colors: Vec<cfbe::Color>) {
 
     let root = flatbuffers::get_root::<fbe::Bag>(&buf[..]);
     assert_eq!(
-        colors.iter().map(|c| *c as i8).collect::<Vec<_>>(),
+        colors.iter().map(|c| c.0).collect::<Vec<_>>(),
         root.colors()
             .as_ref()
             .map(common::collect_flatbuffers_vector)
@@ -0,0 +1,126 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use cfb::strategy::Config;
+use common::evolution_v1_generated::example as v1;
+use common::evolution_v2_builder::example as cfbe;
+use common::evolution_v2_generated::example as v2;
+use flatbuffers::FlatBufferBuilder;
+use flatbuffers_verifier::{get_root, Error, Options};
+use proptest::prelude::*;
+
+const STRICT: Options = Options {
+    reject_deprecated_fields: false,
+    reject_unknown_fields: true,
+    reject_shared_objects: false,
+    max_work: None,
+    reject_default_scalars: false,
+    accept_unknown_enum_values: false,
+};
+
+/// Builds a `Hero` of the newer schema with flatc.
+fn hero_v2(hp: u32, mp: u32, skills: &[&str]) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let name = fbb.create_string("hero");
+    let skills = if skills.is_empty() {
+        None
+    } else {
+        Some(fbb.create_vector_of_strings(skills))
+    };
+    let root = v2::Hero::create(
+        &mut fbb,
+        &v2::HeroArgs {
+            name: Some(name),
+            hp,
+            mp,
+            skills,
+        },
+    );
+    fbb.finish_minimal(root);
+    fbb.finished_data().to_vec()
+}
+
+fn _test_evolution(hero: cfbe::Hero) {
+    let buf = Builder::new(hero.clone()).build();
+
+    // The older schema reads the fields it knows and skips the others.
+    let root = get_root::<v1::Hero>(&buf).expect("verified");
+    assert_eq!(hero.name, root.name().unwrap_or_default());
+    assert_eq!(hero.hp, root.hp());
+
+    let has_unknown_fields = hero.mp != 0 || !hero.skills.is_empty();
+    assert_eq!(
+        STRICT.get_root::<v1::Hero>(&buf).map(|_| ()),
+        if has_unknown_fields {
+            Err(Error::UnknownField)
+        } else {
+            Ok(())
+        }
+    );
+    assert!(STRICT.get_root::<v2::Hero>(&buf).is_ok());
+}
+
+#[test]
+fn test_evolution_lenient() {
+    for buf in [hero_v2(10, 20, &["fly", "swim"]), hero_v2(10, 0, &[])] {
+        let root = get_root::<v1::Hero>(&buf).expect("verified");
+        assert_eq!(Some("hero"), root.name());
+        assert_eq!(10, root.hp());
+    }
+}
+
+#[test]
+fn test_evolution_strict() {
+    for buf in [hero_v2(10, 20, &[]), hero_v2(0, 0, &["fly"])] {
+        assert_eq!(
+            STRICT.get_root::<v1::Hero>(&buf).map(|_| ()),
+            Err(Error::UnknownField)
+        );
+        assert!(STRICT.get_root::<v2::Hero>(&buf).is_ok());
+    }
+
+    // flatc omits the trailing absent fields from the vtable, so the buffer looks like an old one.
+    assert!(STRICT.get_root::<v1::Hero>(&hero_v2(10, 0, &[])).is_ok());
+}
+
+#[test]
+fn test_evolution_unknown_field_bounds() {
+    let mut buf = hero_v2(10, 20, &["fly"]);
+    let root = flatbuffers::get_root::<v2::Hero>(&buf);
+    let soffset = flatbuffers::read_scalar_at::<i32>(&buf, root._tab.loc);
+    let vtable_loc = (root._tab.loc as i64 - soffset as i64) as usize;
+    let inline_size = flatbuffers::read_scalar_at::<u16>(&buf, vtable_loc + 2);
+
+    // Points the unknown field `skills` at the end of the table.
+    let skills_loc = vtable_loc + v2::Hero::VT_SKILLS as usize;
+    buf[skills_loc..skills_loc + 2].copy_from_slice(&inline_size.to_le_bytes());
+    for options in [Options::default(), STRICT] {
+        assert_eq!(
+            options.get_root::<v1::Hero>(&buf).map(|_| ()),
+            Err(Error::OutOfBounds)
+        );
+    }
+}
+
+#[test]
+fn test_evolution_builder() {
+    _test_evolution(cfbe::Hero {
+        name: "hero".to_string(),
+        hp: 10,
+        mp: 20,
+        skills: vec!["fly".to_string()],
+    });
+    _test_evolution(cfbe::Hero {
+        name: "hero".to_string(),
+        hp: 10,
+        ..Default::default()
+    });
+    _test_evolution(cfbe::Hero::default());
+}
+
+proptest! {
+    #[test]
+    fn proptest_evolution(hero in any_with::<cfbe::Hero>(Config::default())) {
+        _test_evolution(hero);
+    }
+}
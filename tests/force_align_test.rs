@@ -0,0 +1,153 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use cfb::scalar::Scalar;
+use cfb::strategy::Config;
+use common::force_align_builder::example as cfbe;
+use common::force_align_generated::example as fbe;
+use flatbuffers_verifier::{get_root, verify_buffer_alignment, AlignedBuf, Error};
+use proptest::prelude::*;
+use std::mem::{align_of, size_of};
+
+fn vec4(x: f32, y: f32, z: f32, w: f32) -> cfbe::Vec4 {
+    cfbe::Vec4 { x, y, z, w }
+}
+
+fn assert_vec4(expected: &cfbe::Vec4, vec4: &fbe::Vec4) {
+    // Compares the bits so NaN generated by proptest round-trips too, once made canonical.
+    let expected = (*expected).canonicalize();
+    assert_eq!(
+        [expected.x, expected.y, expected.z, expected.w].map(f32::to_bits),
+        [vec4.x(), vec4.y(), vec4.z(), vec4.w()].map(f32::to_bits)
+    );
+}
+
+/// Returns the position of `slice` in `buf`, or 0 for a missing vector.
+fn position<T>(buf: &[u8], slice: &[T]) -> usize {
+    if slice.is_empty() {
+        return 0;
+    }
+    slice.as_ptr() as usize - buf.as_ptr() as usize
+}
+
+fn _test_force_align_builder(block: cfbe::Block) {
+    let buf = Builder::new(block.clone()).build();
+    assert!(get_root::<fbe::Block>(&buf).is_ok());
+
+    // The reader dereferences the 16-byte aligned structs in place, so it needs an aligned copy.
+    let buf = AlignedBuf::new(&buf, 32);
+    let root = get_root::<fbe::Block>(&buf).expect("verified");
+
+    if block.position.is_present() {
+        assert_vec4(&block.position, root.position().unwrap());
+    } else {
+        assert!(root.position().is_none());
+    }
+
+    let bytes = root.bytes().unwrap_or_default();
+    assert_eq!(block.bytes, bytes);
+    assert_eq!(position(&buf, bytes) % 32, 0);
+
+    let flags = root.flags().unwrap_or_default();
+    assert_eq!(block.flags, flags);
+    assert_eq!(position(&buf, flags) % 16, 0);
+
+    let points = root.points().unwrap_or_default();
+    assert_eq!(block.points.len(), points.len());
+    assert_eq!(position(&buf, points) % 16, 0);
+    for (expected, point) in block.points.iter().zip(points) {
+        assert_vec4(expected, point);
+    }
+
+    match &block.header {
+        Some(expected) => {
+            let nested = root.header().unwrap();
+            assert_eq!(position(&buf, nested) % 16, 0);
+            let header = get_root::<fbe::Header>(nested).expect("verified");
+            assert_eq!(expected.number, header.number());
+        }
+        None => assert!(root.header().is_none()),
+    }
+}
+
+#[test]
+fn test_force_align_layout() {
+    assert_eq!(size_of::<cfbe::Vec4>(), 16);
+    assert_eq!(align_of::<cfbe::Vec4>(), 16);
+    assert_eq!(align_of::<cfbe::Vec4>(), align_of::<fbe::Vec4>());
+}
+
+#[test]
+fn test_force_align_builder() {
+    _test_force_align_builder(cfbe::Block {
+        position: vec4(1.0, 2.0, 3.0, 4.0),
+        bytes: vec![1, 2, 3],
+        flags: vec![true, false],
+        points: vec![vec4(0.5, 0.0, -1.0, 0.0), vec4(0.0, 0.0, 0.0, 1.0)],
+        header: Some(cfbe::Header { number: 5 }),
+    });
+    _test_force_align_builder(cfbe::Block {
+        bytes: vec![1],
+        ..Default::default()
+    });
+    _test_force_align_builder(cfbe::Block::default());
+}
+
+#[test]
+fn test_force_align_buffer_alignment() {
+    let buf = Builder::new(cfbe::Block {
+        bytes: vec![1, 2, 3],
+        ..Default::default()
+    })
+    .build();
+
+    // The bytes are 32-byte aligned within the buffer, which the verifier checks wherever the
+    // buffer is. Reading them in place also needs the buffer itself to be aligned.
+    let shifted = AlignedBuf::new(&[&[0u8; 16][..], &buf].concat(), 32);
+    assert!(get_root::<fbe::Block>(&shifted[16..]).is_ok());
+    assert_eq!(
+        verify_buffer_alignment(&shifted[16..], 32),
+        Err(Error::Misaligned)
+    );
+    assert_eq!(
+        verify_buffer_alignment(&AlignedBuf::new(&buf, 32), 32),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_force_align_verifier_rejects_non_canonical_floats_in_structs() {
+    let buf = Builder::new(cfbe::Block {
+        position: vec4(1.0, 2.0, 3.0, 4.0),
+        points: vec![vec4(5.0, 6.0, 7.0, 8.0)],
+        ..Default::default()
+    })
+    .build();
+    let aligned = AlignedBuf::new(&buf, 32);
+    let root = get_root::<fbe::Block>(&aligned).expect("verified");
+    let position_loc = position(&aligned, std::slice::from_ref(root.position().unwrap()));
+    let points_loc = position(&aligned, root.points().unwrap());
+
+    // Negative zero in `position.y`, then a NaN with payload in `points[0].w`.
+    for loc in [position_loc + 4, points_loc + 12] {
+        let mut mutant = buf.clone();
+        mutant[loc..loc + 4].copy_from_slice(&(-0.0f32).to_le_bytes());
+        assert_eq!(
+            get_root::<fbe::Block>(&mutant).map(|_| ()),
+            Err(Error::NonCanonicalFloat)
+        );
+
+        let mut mutant = buf.clone();
+        mutant[loc..loc + 4].copy_from_slice(&0x7fc0_0001u32.to_le_bytes());
+        let mutant = AlignedBuf::new(&mutant, 32);
+        let root = flatbuffers::get_root::<fbe::Block>(&mutant);
+        assert!(root.try_position().is_err() || root.try_points().is_err());
+    }
+}
+
+proptest! {
+    #[test]
+    fn proptest_force_align_builder(block in any_with::<cfbe::Block>(Config::default())) {
+        _test_force_align_builder(block);
+    }
+}
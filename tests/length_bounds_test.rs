@@ -0,0 +1,127 @@
+pub mod common;
+
+use cfb::builder::{BuildError, Builder, LengthError};
+use cfb::strategy::Config;
+use common::length_bounds_builder::example as cfbe;
+use common::length_bounds_generated::example as fbe;
+use flatbuffers::FlatBufferBuilder;
+use flatbuffers_verifier::{get_root, salvage_root, Error};
+use proptest::prelude::*;
+
+fn script(code_hash: &str, args: &[&str]) -> cfbe::Script {
+    cfbe::Script {
+        code_hash: code_hash.to_string(),
+        args: args.iter().map(|arg| arg.to_string()).collect(),
+        hash_type: 1,
+    }
+}
+
+/// Builds a `Script` with flatc, which ignores the length bounds.
+fn flatc_script(code_hash: Option<&str>, args: &[&str]) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let code_hash = code_hash.map(|code_hash| fbb.create_string(code_hash));
+    let args = fbb.create_vector_of_strings(args);
+    let root = fbe::Script::create(
+        &mut fbb,
+        &fbe::ScriptArgs {
+            code_hash,
+            args: Some(args),
+            hash_type: 1,
+        },
+    );
+    fbb.finish_minimal(root);
+    fbb.finished_data().to_vec()
+}
+
+#[test]
+fn test_length_bounds_builder() {
+    for (code_hash, args) in [("a", &[][..]), ("12345678", &["a", "b", "c"][..])] {
+        let buf = Builder::new(script(code_hash, args))
+            .try_build()
+            .expect("within bounds");
+        let root = get_root::<fbe::Script>(&buf).expect("verified");
+        assert_eq!(Some(code_hash), root.code_hash());
+    }
+
+    assert_eq!(
+        Err(BuildError::Length(LengthError {
+            field: "Script.code_hash",
+            len: 0,
+            min: 1,
+            max: 8,
+        })),
+        Builder::new(script("", &[])).try_build()
+    );
+    assert_eq!(
+        Err(BuildError::Length(LengthError {
+            field: "Script.code_hash",
+            len: 9,
+            min: 1,
+            max: 8,
+        })),
+        Builder::new(script("123456789", &[])).try_build()
+    );
+    let error = Builder::new(script("a", &["a", "b", "c", "d"]))
+        .try_build()
+        .unwrap_err();
+    assert_eq!("Script.args has length 4, more than 3", error.to_string());
+}
+
+#[test]
+#[should_panic(expected = "Script.code_hash has length 0, less than 1")]
+fn test_length_bounds_build_panics() {
+    Builder::new(script("", &[])).build();
+}
+
+#[test]
+fn test_length_bounds_verifier() {
+    assert!(get_root::<fbe::Script>(&flatc_script(Some("a"), &["a", "b", "c"])).is_ok());
+
+    assert_eq!(
+        get_root::<fbe::Script>(&flatc_script(Some("123456789"), &[])).map(|_| ()),
+        Err(Error::LengthOutOfBounds {
+            field: "Script.code_hash",
+            len: 9,
+            min: 1,
+            max: 8,
+        })
+    );
+    // An absent field has length 0.
+    assert_eq!(
+        get_root::<fbe::Script>(&flatc_script(None, &[]))
+            .unwrap_err()
+            .to_string(),
+        "Script.code_hash has length 0, less than 1"
+    );
+    assert_eq!(
+        get_root::<fbe::Script>(&flatc_script(Some("a"), &["a", "b", "c", "d"]))
+            .unwrap_err()
+            .to_string(),
+        "Script.args has length 4, more than 3"
+    );
+}
+
+#[test]
+fn test_length_bounds_salvage() {
+    let buf = flatc_script(Some(""), &["a", "b", "c", "d"]);
+    let errors: Vec<String> = salvage_root::<fbe::Script>(&buf)
+        .violations
+        .iter()
+        .map(|violation| violation.error.to_string())
+        .collect();
+    assert_eq!(
+        vec![
+            "Script.code_hash has length 0, less than 1",
+            "Script.args has length 4, more than 3",
+        ],
+        errors
+    );
+}
+
+proptest! {
+    #[test]
+    fn proptest_length_bounds(script in any_with::<cfbe::Script>(Config::default())) {
+        let buf = Builder::new(script).try_build().expect("within bounds");
+        prop_assert!(get_root::<fbe::Script>(&buf).is_ok());
+    }
+}
@@ -0,0 +1,1024 @@
+//! Mutation fuzzing of the generated verifiers.
+//!
+//! Valid buffers are built from the generated proptest strategies of every test schema, then
+//! mutated by flipping, overwriting, truncating and splicing bytes. The verifier must never panic
+//! on a mutant, and every mutant it accepts must be fully traversable with the flatc readers.
+//! A deterministic RNG is used so the corpus is the same on every run.
+pub mod common;
+
+use cfb::builder::{Builder, Component};
+use cfb::strategy::Config;
+use flatbuffers::{Follow, Vector};
+use flatbuffers_verifier::{self as verifier, get_lazy_root, get_root, salvage_root, Verify};
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::{TestRng, TestRunner};
+use std::hint::black_box;
+use std::panic::{self, RefUnwindSafe};
+
+const GENERATED_SEEDS: usize = 16;
+const MUTANTS_PER_SEED: usize = 1024;
+
+/// Reads every field reachable from a root with the flatc readers.
+trait Walk {
+    fn walk(&self);
+
+    /// Reads every field reachable from a root with the checked `try_` accessors.
+    fn try_walk(&self) -> verifier::Result {
+        self.walk();
+        Ok(())
+    }
+}
+
+macro_rules! walk_scalars {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Walk for $ty {
+                fn walk(&self) {
+                    black_box(*self);
+                }
+            }
+        )*
+    };
+}
+
+/// Walks enums with the flatc name lookup, which panics on undeclared values.
+macro_rules! walk_enums {
+    ($($ty:ty => $name:ident),* $(,)?) => {
+        $(
+            impl Walk for $ty {
+                fn walk(&self) {
+                    black_box($name(*self));
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! walk_tables {
+    ($($ty:ident { $($field:ident: $try_field:ident),* $(,)? })*) => {
+        $(
+            impl<'a> Walk for $ty<'a> {
+                fn walk(&self) {
+                    $(self.$field().walk();)*
+                }
+
+                fn try_walk(&self) -> verifier::Result {
+                    $(self.$try_field()?.try_walk()?;)*
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! walk_union {
+    ($union_type:expr, $union:expr, $enum:ident { $($variant:ident),* $(,)? }) => {
+        $union_type.walk();
+        if let Some(table) = $union {
+            match $union_type {
+                $($enum::$variant => $variant::init_from_table(table).walk(),)*
+                _ => {}
+            }
+        }
+    };
+}
+
+walk_scalars!(bool, i16, i64, u8, u16, u32, u64, f32);
+
+impl<T: Copy> Walk for &T {
+    fn walk(&self) {
+        black_box(**self);
+    }
+}
+
+impl<T: Copy> Walk for &[T] {
+    fn walk(&self) {
+        for element in self.iter() {
+            black_box(*element);
+        }
+    }
+}
+
+impl Walk for &str {
+    fn walk(&self) {
+        black_box(self.chars().count());
+    }
+}
+
+impl<T: Walk> Walk for Option<T> {
+    fn walk(&self) {
+        if let Some(value) = self {
+            value.walk();
+        }
+    }
+
+    fn try_walk(&self) -> verifier::Result {
+        match self {
+            Some(value) => value.try_walk(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a, T: Follow<'a> + 'a> Walk for Vector<'a, T>
+where
+    T::Inner: Walk,
+{
+    fn walk(&self) {
+        for i in 0..self.len() {
+            self.get(i).walk();
+        }
+    }
+
+    fn try_walk(&self) -> verifier::Result {
+        for i in 0..self.len() {
+            self.get(i).try_walk()?;
+        }
+        Ok(())
+    }
+}
+
+fn interesting_u32(rng: &mut TestRng, len: usize) -> u32 {
+    let len = len as u32;
+    match rng.gen_range(0, 10) {
+        0 => 0,
+        1 => 1,
+        2 => 4,
+        3 => len,
+        4 => len.wrapping_sub(4),
+        5 => i32::MAX as u32,
+        6 => i32::MIN as u32,
+        7 => u32::MAX,
+        8 => u32::MAX - 3,
+        _ => rng.gen_range(0, 64),
+    }
+}
+
+fn mutate(rng: &mut TestRng, seeds: &[Vec<u8>], seed: &[u8]) -> Vec<u8> {
+    let mut buf = seed.to_vec();
+
+    for _ in 0..rng.gen_range(1, 4) {
+        let len = buf.len();
+        match rng.gen_range(0, 7) {
+            // Flip a bit.
+            0 if len > 0 => {
+                let pos = rng.gen_range(0, len);
+                buf[pos] ^= 1 << rng.gen_range(0, 8);
+            }
+            // Overwrite a byte.
+            1 if len > 0 => {
+                let pos = rng.gen_range(0, len);
+                buf[pos] = [0u8, 1, 2, 0x7f, 0x80, 0xff][rng.gen_range(0, 6)];
+            }
+            // Overwrite a u32, which is likely an offset or a length when aligned.
+            2 if len >= 4 => {
+                let mut pos = rng.gen_range(0, len - 3);
+                if rng.gen() {
+                    pos &= !3;
+                }
+                let value = interesting_u32(rng, len);
+                buf[pos..pos + 4].copy_from_slice(&value.to_le_bytes());
+            }
+            // Truncate.
+            3 => {
+                buf.truncate(rng.gen_range(0, len + 1));
+            }
+            // Splice in a chunk of another seed.
+            4 => {
+                let other = &seeds[rng.gen_range(0, seeds.len())];
+                if !other.is_empty() && len > 0 {
+                    let from = rng.gen_range(0, other.len());
+                    let chunk_len = rng.gen_range(1, other.len() - from + 1);
+                    let at = rng.gen_range(0, len);
+                    let end = (at + chunk_len).min(len);
+                    buf.splice(at..end, other[from..from + chunk_len].iter().cloned());
+                }
+            }
+            // Insert bytes.
+            5 => {
+                let at = rng.gen_range(0, len + 1);
+                let count = rng.gen_range(1, 9);
+                buf.splice(at..at, std::iter::repeat(0).take(count));
+            }
+            // Remove bytes.
+            _ if len > 0 => {
+                let at = rng.gen_range(0, len);
+                let end = (at + rng.gen_range(1, 9)).min(len);
+                buf.drain(at..end);
+            }
+            _ => {}
+        }
+    }
+
+    buf
+}
+
+/// Verifies `buf` and walks it if it is accepted, returning whether it was accepted.
+///
+/// The buffer is also walked lazily with the checked accessors, which must succeed on every buffer
+/// the verifier accepts and must not panic on the others, and salvaged, which must find violations
+/// exactly in the buffers the verifier rejects.
+fn check<'a, T>(buf: &'a [u8]) -> bool
+where
+    T: Follow<'a> + 'a,
+    T::Inner: Verify + Walk,
+{
+    let lazy = get_lazy_root::<T>(buf).and_then(|root| root.try_walk());
+    let salvaged = salvage_root::<T>(buf);
+    if let Some(root) = &salvaged.root {
+        let _ = root.try_walk();
+    }
+    assert_eq!(
+        salvaged.violations.is_empty(),
+        get_root::<T>(buf).is_ok(),
+        "salvaging disagrees with the verifier: {:?}",
+        salvaged.violations
+    );
+    match get_root::<T>(buf) {
+        Ok(root) => {
+            root.walk();
+            assert_eq!(lazy, Ok(()), "checked accessors reject a verified buffer");
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+/// Runs the mutation fuzzer over buffers built from `T` plus the fixed `corpus`.
+///
+/// `check` verifies and walks a buffer, see [`check`].
+fn fuzz<T, F>(corpus: &[&[u8]], check: F)
+where
+    T: Arbitrary<Parameters = Config> + Component<'static> + 'static,
+    F: Fn(&[u8]) -> bool + RefUnwindSafe,
+{
+    let mut runner = TestRunner::deterministic();
+    let strategy = any_with::<T>(Config::default());
+
+    let mut seeds: Vec<Vec<u8>> = corpus.iter().map(|buf| buf.to_vec()).collect();
+    for _ in 0..GENERATED_SEEDS {
+        let root = strategy
+            .new_tree(&mut runner)
+            .expect("generate seed")
+            .current();
+        seeds.push(Builder::new(root).build());
+    }
+    for seed in &seeds {
+        assert!(check(seed), "seed is rejected: {}", common::hex(seed));
+    }
+
+    for seed in &seeds {
+        for _ in 0..MUTANTS_PER_SEED {
+            let mutant = mutate(runner.rng(), &seeds, seed);
+            if panic::catch_unwind(|| check(&mutant)).is_err() {
+                panic!("panicked on mutant: {}", common::hex(&mutant));
+            }
+        }
+    }
+}
+
+mod bit_flags {
+    use super::*;
+    use common::bit_flags_builder::example as cfbe;
+    use common::bit_flags_generated::example::*;
+
+    impl Walk for Permission {
+        fn walk(&self) {
+            assert!(Permission::all().contains(*self), "undeclared flags");
+        }
+    }
+
+    walk_tables! {
+        File { permission: try_permission, permissions: try_permissions }
+    }
+
+    #[test]
+    fn test_mutation_bit_flags() {
+        fuzz::<cfbe::File, _>(&[], |buf| check::<File>(buf));
+    }
+}
+
+mod ckb {
+    use super::*;
+    use common::ckb_builder::ckb::protocol as cfbc;
+    use common::ckb_generated::ckb::protocol::*;
+
+    walk_enums!(
+        SyncPayload => enum_name_sync_payload,
+        RelayPayload => enum_name_relay_payload,
+    );
+
+    walk_tables! {
+        Bytes { seq: try_seq }
+        GetHeaders {
+            version: try_version,
+            block_locator_hashes: try_block_locator_hashes,
+            hash_stop: try_hash_stop,
+        }
+        GetBlocks { block_hashes: try_block_hashes }
+        Headers { headers: try_headers }
+        Header {
+            version: try_version,
+            parent_hash: try_parent_hash,
+            timestamp: try_timestamp,
+            number: try_number,
+            txs_commit: try_txs_commit,
+            txs_proposal: try_txs_proposal,
+            difficulty: try_difficulty,
+            nonce: try_nonce,
+            proof: try_proof,
+            cellbase_id: try_cellbase_id,
+            uncles_hash: try_uncles_hash,
+            uncles_count: try_uncles_count,
+        }
+        Block {
+            header: try_header,
+            uncles: try_uncles,
+            commit_transactions: try_commit_transactions,
+            proposal_transactions: try_proposal_transactions,
+        }
+        UncleBlock {
+            header: try_header,
+            cellbase: try_cellbase,
+            proposal_transactions: try_proposal_transactions,
+        }
+        Transaction {
+            version: try_version,
+            deps: try_deps,
+            inputs: try_inputs,
+            outputs: try_outputs,
+            embeds: try_embeds,
+        }
+        OutPoint { hash: try_hash, index: try_index }
+        CellInput { hash: try_hash, index: try_index, args: try_args }
+        CellOutput { capacity: try_capacity, data: try_data, lock: try_lock, type_: try_type_ }
+        Script { version: try_version, args: try_args, binary_hash: try_binary_hash }
+        CompactBlock {
+            header: try_header,
+            nonce: try_nonce,
+            short_ids: try_short_ids,
+            prefilled_transactions: try_prefilled_transactions,
+            uncles: try_uncles,
+            proposal_transactions: try_proposal_transactions,
+        }
+        IndexTransaction { index: try_index, transaction: try_transaction }
+        ValidTransaction { cycles: try_cycles, transaction: try_transaction }
+        GetBlockTransactions { hash: try_hash, indexes: try_indexes }
+        BlockTransactions { hash: try_hash, transactions: try_transactions }
+        GetBlockProposal {
+            block_number: try_block_number,
+            proposal_transactions: try_proposal_transactions,
+        }
+        BlockProposal { transactions: try_transactions }
+        SetFilter { filter: try_filter, num_hashes: try_num_hashes, hash_seed: try_hash_seed }
+        AddFilter { filter: try_filter }
+        ClearFilter {}
+        FilteredBlock { header: try_header, transactions: try_transactions, proof: try_proof }
+        MerkleProof { indices: try_indices, lemmas: try_lemmas }
+        TimeMessage { payload: try_payload }
+        Time { timestamp: try_timestamp }
+    }
+
+    impl<'a> Walk for SyncMessage<'a> {
+        fn walk(&self) {
+            walk_union!(
+                self.payload_type(),
+                self.payload(),
+                SyncPayload {
+                    GetHeaders,
+                    Headers,
+                    GetBlocks,
+                    Block,
+                    SetFilter,
+                    AddFilter,
+                    ClearFilter,
+                    FilteredBlock,
+                }
+            );
+        }
+
+        fn try_walk(&self) -> verifier::Result {
+            self.try_payload_as_get_headers()?.try_walk()?;
+            self.try_payload_as_headers()?.try_walk()?;
+            self.try_payload_as_get_blocks()?.try_walk()?;
+            self.try_payload_as_block()?.try_walk()?;
+            self.try_payload_as_set_filter()?.try_walk()?;
+            self.try_payload_as_add_filter()?.try_walk()?;
+            self.try_payload_as_clear_filter()?.try_walk()?;
+            self.try_payload_as_filtered_block()?.try_walk()
+        }
+    }
+
+    impl<'a> Walk for RelayMessage<'a> {
+        fn walk(&self) {
+            walk_union!(
+                self.payload_type(),
+                self.payload(),
+                RelayPayload {
+                    CompactBlock,
+                    ValidTransaction,
+                    GetBlockTransactions,
+                    BlockTransactions,
+                    GetBlockProposal,
+                    BlockProposal,
+                }
+            );
+        }
+
+        fn try_walk(&self) -> verifier::Result {
+            self.try_payload_as_compact_block()?.try_walk()?;
+            self.try_payload_as_valid_transaction()?.try_walk()?;
+            self.try_payload_as_get_block_transactions()?.try_walk()?;
+            self.try_payload_as_block_transactions()?.try_walk()?;
+            self.try_payload_as_get_block_proposal()?.try_walk()?;
+            self.try_payload_as_block_proposal()?.try_walk()
+        }
+    }
+
+    #[test]
+    fn test_mutation_sync_message() {
+        fuzz::<cfbc::SyncMessage, _>(&[], |buf| check::<SyncMessage>(buf));
+    }
+
+    #[test]
+    fn test_mutation_relay_message() {
+        fuzz::<cfbc::RelayMessage, _>(&[], |buf| check::<RelayMessage>(buf));
+    }
+
+    #[test]
+    fn test_mutation_time_message() {
+        fuzz::<cfbc::TimeMessage, _>(&[], |buf| check::<TimeMessage>(buf));
+    }
+}
+
+mod data_alignment {
+    use super::*;
+    use common::data_alignment_builder as cfbe;
+    use common::data_alignment_generated::*;
+
+    walk_tables! {
+        T1 { f1: try_f1, s1: try_s1, f2: try_f2, s2: try_s2, f3: try_f3, s3: try_s3, f4: try_f4 }
+        T2 { f1: try_f1 }
+    }
+
+    #[test]
+    fn test_mutation_data_alignment() {
+        fuzz::<cfbe::T1, _>(&[include_bytes!("common/data_alignment.bin")], |buf| {
+            check::<T1>(buf)
+        });
+    }
+}
+
+mod data_order {
+    use super::*;
+    use common::data_order_builder as cfbe;
+    use common::data_order_generated::*;
+
+    walk_tables! {
+        Monster { name: try_name, stat: try_stat, loots: try_loots }
+        Stat { hp: try_hp, mp: try_mp }
+        Item { name: try_name }
+    }
+
+    #[test]
+    fn test_mutation_data_order() {
+        fuzz::<cfbe::Monster, _>(&[include_bytes!("common/data_order.bin")], |buf| {
+            check::<Monster>(buf)
+        });
+    }
+}
+
+mod default_value {
+    use super::*;
+    use common::default_value_builder::example as cfbe;
+    use common::default_value_generated::example::*;
+
+    walk_enums!(Color => enum_name_color);
+
+    walk_tables! {
+        Hero {
+            hp: try_hp,
+            mp: try_mp,
+            speed: try_speed,
+            alive: try_alive,
+            color: try_color,
+            exp: try_exp,
+            name: try_name,
+        }
+    }
+
+    #[test]
+    fn test_mutation_default_value() {
+        fuzz::<cfbe::Hero, _>(&[], |buf| check::<Hero>(buf));
+    }
+}
+
+mod deprecated {
+    use super::*;
+    use common::deprecated_builder::example as cfbe;
+    use common::deprecated_generated::example::*;
+
+    walk_tables! {
+        Hero { hp: try_hp, mp: try_mp }
+    }
+
+    #[test]
+    fn test_mutation_deprecated() {
+        fuzz::<cfbe::Hero, _>(&[], |buf| check::<Hero>(buf));
+    }
+}
+
+mod enum_ {
+    use super::*;
+    use common::enum_builder::example as cfbe;
+    use common::enum_generated::example::*;
+
+    walk_enums!(Color => enum_name_color);
+
+    walk_tables! {
+        Bag { color: try_color }
+    }
+
+    #[test]
+    fn test_mutation_enum() {
+        fuzz::<cfbe::Bag, _>(&[], |buf| check::<Bag>(buf));
+    }
+}
+
+mod enum_vector {
+    use super::*;
+    use common::enum_vector_builder::example as cfbe;
+    use common::enum_vector_generated::example::*;
+
+    walk_enums!(Color => enum_name_color);
+
+    walk_tables! {
+        Bag { colors: try_colors }
+    }
+
+    #[test]
+    fn test_mutation_enum_vector() {
+        fuzz::<cfbe::Bag, _>(&[], |buf| check::<Bag>(buf));
+    }
+}
+
+mod evolution {
+    use super::*;
+    use common::evolution_v1_generated::example::*;
+    use common::evolution_v2_builder::example as cfbe;
+
+    walk_tables! {
+        Hero { name: try_name, hp: try_hp }
+    }
+
+    #[test]
+    fn test_mutation_evolution() {
+        // Buffers of the newer schema are walked with the older one, which skips the new fields.
+        fuzz::<cfbe::Hero, _>(&[], |buf| check::<Hero>(buf));
+    }
+}
+
+mod force_align {
+    use super::*;
+    use common::force_align_builder::example as cfbe;
+    use common::force_align_generated::example::*;
+    use verifier::AlignedBuf;
+
+    walk_tables! {
+        Header { number: try_number }
+    }
+
+    impl<'a> Walk for Block<'a> {
+        fn walk(&self) {
+            self.position().walk();
+            self.bytes().walk();
+            self.flags().walk();
+            self.points().walk();
+            self.header().walk();
+            if let Some(header) = self.header().and_then(|buf| get_root::<Header>(buf).ok()) {
+                header.walk();
+            }
+        }
+
+        fn try_walk(&self) -> verifier::Result {
+            self.try_position()?.try_walk()?;
+            self.try_bytes()?.try_walk()?;
+            self.try_flags()?.try_walk()?;
+            self.try_points()?.try_walk()?;
+            if let Some(buf) = self.try_header()? {
+                // The nested buffer is verified separately from the block.
+                check::<Header>(buf);
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_mutation_force_align() {
+        // Mutants are copied to a 32-byte aligned address, since the reader dereferences the
+        // 16-byte aligned structs in place and the allocator may return less.
+        fuzz::<cfbe::Block, _>(&[], |buf| check::<Block>(&AlignedBuf::new(buf, 32)));
+    }
+}
+
+mod nested_buffer {
+    use super::*;
+    use common::nested_buffer_builder as cfbe;
+    use common::nested_buffer_generated::*;
+    use verifier::AlignedBuf;
+
+    walk_tables! {
+        Header { number: try_number }
+    }
+
+    impl<'a> Walk for Block<'a> {
+        fn walk(&self) {
+            self.header().walk();
+            if let Some(buf) = self.header() {
+                // The nested buffer is only 4-byte aligned, and the reader reads `number` in place.
+                let buf = AlignedBuf::new(buf, 8);
+                if let Ok(header) = get_root::<Header>(&buf) {
+                    header.walk();
+                }
+            }
+        }
+
+        fn try_walk(&self) -> verifier::Result {
+            if let Some(buf) = self.try_header()? {
+                // The nested buffer is verified separately from the block.
+                check::<Header>(&AlignedBuf::new(buf, 8));
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_mutation_nested_buffer() {
+        fuzz::<cfbe::Block, _>(&[], |buf| check::<Block>(buf));
+    }
+}
+
+mod offset64 {
+    use super::*;
+    use common::offset64_builder::example as cfbe;
+    use common::offset64_generated::example::*;
+
+    walk_tables! {
+        Snapshot { name: try_name, blocks: try_blocks, points: try_points, ids: try_ids }
+    }
+
+    #[test]
+    fn test_mutation_offset64() {
+        fuzz::<cfbe::Snapshot, _>(&[], |buf| check::<Snapshot>(buf));
+    }
+}
+
+mod optional {
+    use super::*;
+    use common::optional_builder::example as cfbe;
+    use common::optional_generated::example::*;
+
+    walk_enums!(Color => enum_name_color);
+
+    walk_tables! {
+        Hero {
+            hp: try_hp,
+            alive: try_alive,
+            color: try_color,
+            hash: try_hash,
+            checksum: try_checksum,
+        }
+    }
+
+    #[test]
+    fn test_mutation_optional() {
+        fuzz::<cfbe::Hero, _>(&[], |buf| check::<Hero>(buf));
+    }
+}
+
+mod scalar_vector {
+    use super::*;
+    use common::scalar_vector_builder::example as cfbe;
+    use common::scalar_vector_generated::example::*;
+
+    walk_tables! {
+        Sensor { readings: try_readings }
+    }
+
+    #[test]
+    fn test_mutation_scalar_vector() {
+        fuzz::<cfbe::Sensor, _>(&[], |buf| check::<Sensor>(buf));
+    }
+}
+
+mod scalars_with_different_size {
+    use super::*;
+    use common::scalars_with_different_size_builder::example as cfbe;
+    use common::scalars_with_different_size_generated::example::*;
+
+    walk_tables! {
+        Account { year: try_year, balance: try_balance }
+    }
+
+    #[test]
+    fn test_mutation_scalars_with_different_size() {
+        fuzz::<cfbe::Account, _>(&[], |buf| check::<Account>(buf));
+    }
+}
+
+mod scalars_with_same_size {
+    use super::*;
+    use common::scalars_with_same_size_builder::example as cfbe;
+    use common::scalars_with_same_size_generated::example::*;
+
+    walk_tables! {
+        Point { x: try_x, y: try_y }
+    }
+
+    #[test]
+    fn test_mutation_scalars_with_same_size() {
+        fuzz::<cfbe::Point, _>(&[], |buf| check::<Point>(buf));
+    }
+}
+
+mod sorted_vector {
+    use super::*;
+    use common::sorted_vector_builder::example as cfbe;
+    use common::sorted_vector_generated::example::*;
+
+    walk_tables! {
+        Monster { name: try_name, hp: try_hp }
+        Stat { id: try_id, value: try_value }
+        Index { monsters: try_monsters, stats: try_stats }
+    }
+
+    #[test]
+    fn test_mutation_sorted_vector() {
+        fuzz::<cfbe::Index, _>(&[], |buf| check::<Index>(buf));
+    }
+}
+
+mod string {
+    use super::*;
+    use common::string_builder::example as cfbe;
+    use common::string_generated::example::*;
+
+    walk_tables! {
+        Author { name: try_name }
+    }
+
+    #[test]
+    fn test_mutation_string() {
+        fuzz::<cfbe::Author, _>(&[], |buf| check::<Author>(buf));
+    }
+}
+
+mod string_vector {
+    use super::*;
+    use common::string_vector_builder::example as cfbe;
+    use common::string_vector_generated::example::*;
+
+    walk_tables! {
+        Text { lines: try_lines }
+    }
+
+    #[test]
+    fn test_mutation_string_vector() {
+        fuzz::<cfbe::Text, _>(&[], |buf| check::<Text>(buf));
+    }
+}
+
+mod struct_ {
+    use super::*;
+    use common::struct_builder::example as cfbe;
+    use common::struct_generated::example::*;
+
+    walk_tables! {
+        Point { position: try_position }
+    }
+
+    #[test]
+    fn test_mutation_struct() {
+        fuzz::<cfbe::Point, _>(&[], |buf| check::<Point>(buf));
+    }
+}
+
+mod struct_array {
+    use super::*;
+    use common::struct_array_builder::example as cfbe;
+    use common::struct_array_generated::example::*;
+
+    walk_tables! {
+        Header { block: try_block, blocks: try_blocks, signature: try_signature }
+    }
+
+    #[test]
+    fn test_mutation_struct_array() {
+        fuzz::<cfbe::Header, _>(&[], |buf| check::<Header>(buf));
+    }
+}
+
+mod struct_vector {
+    use super::*;
+    use common::struct_vector_builder::example as cfbe;
+    use common::struct_vector_generated::example::*;
+
+    walk_tables! {
+        Hero { stats: try_stats }
+    }
+
+    #[test]
+    fn test_mutation_struct_vector() {
+        fuzz::<cfbe::Hero, _>(&[], |buf| check::<Hero>(buf));
+    }
+}
+
+mod table_field {
+    use super::*;
+    use common::table_field_builder::example as cfbe;
+    use common::table_field_generated::example::*;
+
+    walk_tables! {
+        Stat { hp: try_hp }
+        Hero { stat: try_stat }
+    }
+
+    #[test]
+    fn test_mutation_table_field() {
+        fuzz::<cfbe::Hero, _>(&[], |buf| check::<Hero>(buf));
+    }
+}
+
+mod table_fields_order {
+    use super::*;
+    use common::table_fields_order_builder as cfbe;
+    use common::table_fields_order_generated::*;
+
+    walk_enums!(Color => enum_name_color, Result => enum_name_result);
+
+    walk_tables! {
+        Ok { value: try_value }
+        Err { reason: try_reason }
+    }
+
+    impl<'a> Walk for T<'a> {
+        fn walk(&self) {
+            self.a_ubyte().walk();
+            self.complex().walk();
+            self.a_uint32().walk();
+            walk_union!(self.result_type(), self.result(), Result { Ok, Err });
+            self.a_uint64().walk();
+            self.uint16_array().walk();
+            self.color().walk();
+        }
+
+        fn try_walk(&self) -> verifier::Result {
+            self.try_a_ubyte()?.try_walk()?;
+            self.try_complex()?.try_walk()?;
+            self.try_a_uint32()?.try_walk()?;
+            self.try_result_as_ok()?.try_walk()?;
+            self.try_result_as_err()?.try_walk()?;
+            self.try_a_uint64()?.try_walk()?;
+            self.try_uint16_array()?.try_walk()?;
+            self.try_color()?.try_walk()
+        }
+    }
+
+    #[test]
+    fn test_mutation_table_fields_order() {
+        fuzz::<cfbe::T, _>(&[include_bytes!("common/table_fields_order.bin")], |buf| {
+            check::<T>(buf)
+        });
+    }
+}
+
+mod table_vector {
+    use super::*;
+    use common::table_vector_builder::example as cfbe;
+    use common::table_vector_generated::example::*;
+
+    walk_tables! {
+        Stat { hp: try_hp }
+        Hero { stats: try_stats }
+    }
+
+    #[test]
+    fn test_mutation_table_vector() {
+        fuzz::<cfbe::Hero, _>(&[], |buf| check::<Hero>(buf));
+    }
+}
+
+mod union {
+    use super::*;
+    use common::union_builder::example as cfbe;
+    use common::union_generated::example::*;
+
+    walk_enums!(Role => enum_name_role);
+
+    walk_tables! {
+        Hero { hp: try_hp }
+        Monster { hp: try_hp }
+    }
+
+    impl<'a> Walk for Player<'a> {
+        fn walk(&self) {
+            walk_union!(self.role_type(), self.role(), Role { Hero, Monster });
+        }
+
+        fn try_walk(&self) -> verifier::Result {
+            self.try_role_as_hero()?.try_walk()?;
+            self.try_role_as_monster()?.try_walk()
+        }
+    }
+
+    #[test]
+    fn test_mutation_union() {
+        fuzz::<cfbe::Player, _>(&[], |buf| check::<Player>(buf));
+    }
+}
+
+mod union_variants {
+    use super::*;
+    use common::union_variants_builder::example as cfbe;
+    use common::union_variants_generated::example::*;
+
+    walk_enums!(Role => enum_name_role);
+
+    walk_tables! {
+        Hero { hp: try_hp }
+    }
+
+    fn walk_role(role_type: Role, table: flatbuffers::Table) {
+        role_type.walk();
+        match role_type {
+            Role::Hero => Hero::init_from_table(table).walk(),
+            Role::Point => <&Point>::follow(table.buf, table.loc).walk(),
+            Role::Name => <&str>::follow(table.buf, table.loc).walk(),
+            _ => {}
+        }
+    }
+
+    impl<'a> Walk for Player<'a> {
+        fn walk(&self) {
+            match self.role() {
+                Some(table) => walk_role(self.role_type(), table),
+                None => self.role_type().walk(),
+            }
+            if let (Some(types), Some(roles)) = (self.roles_type(), self.roles()) {
+                for i in 0..types.len().min(roles.len()) {
+                    walk_role(types.get(i), roles.get(i));
+                }
+            }
+        }
+
+        fn try_walk(&self) -> verifier::Result {
+            self.try_role_as_hero()?.try_walk()?;
+            self.try_role_as_point()?.try_walk()?;
+            self.try_role_as_name()?.try_walk()?;
+            self.try_roles_type()?.try_walk()?;
+            self.try_roles()?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_mutation_union_variants() {
+        fuzz::<cfbe::Player, _>(&[], |buf| check::<Player>(buf));
+    }
+}
+
+mod union_vector {
+    use super::*;
+    use common::union_vector_builder::example as cfbe;
+    use common::union_vector_generated::example::*;
+
+    walk_enums!(Role => enum_name_role);
+
+    walk_tables! {
+        Hero { hp: try_hp }
+        Monster { hp: try_hp }
+    }
+
+    impl<'a> Walk for Party<'a> {
+        fn walk(&self) {
+            if let (Some(types), Some(members)) = (self.members_type(), self.members()) {
+                for i in 0..types.len().min(members.len()) {
+                    walk_union!(types.get(i), Some(members.get(i)), Role { Hero, Monster });
+                }
+            }
+        }
+
+        fn try_walk(&self) -> verifier::Result {
+            // The members can only be read as tables once their tags are known to match.
+            self.try_members_type()?.try_walk()?;
+            self.try_members()?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_mutation_union_vector() {
+        fuzz::<cfbe::Party, _>(&[], |buf| check::<Party>(buf));
+    }
+}
@@ -13,9 +13,9 @@ fn _test_nested_buffer_builder(number: Option<u64>) {
     .build();
 
     let root = flatbuffers::get_root::<fbe::Block>(&buf[..]);
-    if number.is_some() {
+    if let Some(number) = number {
         let header = flatbuffers::get_root::<fbe::Header>(root.header().unwrap());
-        assert_eq!(number.unwrap(), header.number());
+        assert_eq!(number, header.number());
     } else {
         assert!(root.header().is_none());
     }
@@ -32,4 +32,9 @@ proptest! {
     fn proptest_nested_buffer_builder(hp: Option<u64>) {
         _test_nested_buffer_builder(hp);
     }
+
+    #[test]
+    fn proptest_nested_buffer_builder_arbitrary(block in any::<cfbe::Block>()) {
+        _test_nested_buffer_builder(block.header.map(|header| header.number));
+    }
 }
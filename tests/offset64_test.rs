@@ -0,0 +1,145 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use cfb::strategy::Config;
+use common::offset64_builder::example as cfbe;
+use common::offset64_generated::example as fbe;
+use flatbuffers_verifier::{get_root, Error};
+use proptest::prelude::*;
+
+/// Returns the position of `slice` in `buf`, or 0 for a missing vector.
+fn position<T>(buf: &[u8], slice: &[T]) -> usize {
+    if slice.is_empty() {
+        return 0;
+    }
+    slice.as_ptr() as usize - buf.as_ptr() as usize
+}
+
+fn _test_offset64_builder(snapshot: cfbe::Snapshot) {
+    let buf = Builder::new(snapshot.clone()).build();
+    let root = get_root::<fbe::Snapshot>(&buf).expect("verified");
+
+    assert_eq!(snapshot.name, root.name().unwrap_or_default());
+    assert_eq!(snapshot.blocks, root.blocks().unwrap_or_default());
+    let points = root.points().unwrap_or_default();
+    assert_eq!(snapshot.points.len(), points.len());
+    for (expected, point) in snapshot.points.iter().zip(points) {
+        assert_eq!((expected.x, expected.y), (point.x(), point.y()));
+    }
+    let ids = root
+        .ids()
+        .map_or(vec![], |ids| common::collect_flatbuffers_vector(&ids));
+    assert_eq!(snapshot.ids, ids);
+
+    // The vectors referenced by 64-bit offsets are written last, in the order of the fields.
+    let blocks = root.blocks().unwrap_or_default();
+    let mut end = buf.len();
+    if !points.is_empty() {
+        assert_eq!(position(&buf, points) + points.len() * 8, end);
+        end = position(&buf, points);
+    }
+    if !blocks.is_empty() {
+        assert!(position(&buf, blocks) + blocks.len() <= end);
+        end = position(&buf, blocks);
+    }
+    if let Some(name) = root.name() {
+        assert!(position(&buf, name.as_bytes()) < end);
+    }
+}
+
+#[test]
+fn test_offset64_layout() {
+    let buf = Builder::new(cfbe::Snapshot {
+        name: "a".to_string(),
+        blocks: vec![1, 2, 3],
+        points: vec![cfbe::Point { x: 1, y: 2 }],
+        ids: vec![7],
+    })
+    .build();
+
+    let expected: Vec<u8> = [
+        // root uoffset
+        &20u32.to_le_bytes()[..],
+        // vtable
+        &[12, 0, 28, 0, 20, 0, 4, 0, 12, 0, 24, 0],
+        // padding so the 64-bit offsets are aligned to 8
+        &[0u8; 4],
+        // table
+        &16i32.to_le_bytes(),
+        // blocks: uoffset64
+        &40u64.to_le_bytes(),
+        // points: uoffset64
+        &40u64.to_le_bytes(),
+        // name: uoffset
+        &8u32.to_le_bytes(),
+        // ids: uoffset
+        &12u32.to_le_bytes(),
+        // name
+        &1u32.to_le_bytes(),
+        b"a\0",
+        &[0u8; 2],
+        // ids
+        &1u32.to_le_bytes(),
+        &7u32.to_le_bytes(),
+        // blocks
+        &3u32.to_le_bytes(),
+        &[1, 2, 3],
+        &[0u8; 1],
+        // points
+        &1u32.to_le_bytes(),
+        &1i32.to_le_bytes(),
+        &2i32.to_le_bytes(),
+    ]
+    .concat();
+    assert_eq!(expected, buf);
+}
+
+#[test]
+fn test_offset64_builder() {
+    _test_offset64_builder(cfbe::Snapshot {
+        name: "snapshot".to_string(),
+        blocks: vec![1, 2, 3],
+        points: vec![cfbe::Point { x: 1, y: -1 }, cfbe::Point { x: 0, y: 2 }],
+        ids: vec![1, 2],
+    });
+    _test_offset64_builder(cfbe::Snapshot {
+        points: vec![cfbe::Point { x: 3, y: 4 }],
+        ..Default::default()
+    });
+    _test_offset64_builder(cfbe::Snapshot::default());
+}
+
+#[test]
+fn test_offset64_verifier() {
+    let mut buf = Builder::new(cfbe::Snapshot {
+        blocks: vec![1, 2, 3],
+        ..Default::default()
+    })
+    .build();
+    let root = flatbuffers::get_root::<fbe::Snapshot>(&buf);
+    let offset_loc = root._tab.loc + root._tab.vtable().get(fbe::Snapshot::VT_BLOCKS) as usize;
+    assert_eq!(offset_loc % 8, 0);
+
+    // The offset is 64-bit, so the high bytes must be checked too.
+    let offset = flatbuffers::read_scalar_at::<u64>(&buf, offset_loc);
+    buf[offset_loc..offset_loc + 8].copy_from_slice(&(offset | 1 << 32).to_le_bytes());
+    assert_eq!(
+        get_root::<fbe::Snapshot>(&buf).map(|_| ()),
+        Err(Error::OutOfBounds)
+    );
+    let root = flatbuffers::get_root::<fbe::Snapshot>(&buf);
+    assert_eq!(root.try_blocks(), Err(Error::OutOfBounds));
+
+    buf[offset_loc..offset_loc + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert_eq!(
+        get_root::<fbe::Snapshot>(&buf).map(|_| ()),
+        Err(Error::OutOfBounds)
+    );
+}
+
+proptest! {
+    #[test]
+    fn proptest_offset64_builder(snapshot in any_with::<cfbe::Snapshot>(Config::default())) {
+        _test_offset64_builder(snapshot);
+    }
+}
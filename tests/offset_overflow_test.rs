@@ -21,3 +21,80 @@ fn test_zero_root_offset() {
     let hero = get_root::<reader::Hero>(&buf);
     assert_eq!(hero, Err(Error::OutOfBounds));
 }
+
+fn table_with_vector(len: u32) -> Vec<u8> {
+    [
+        le!(12u32),
+        // vtable
+        le!(6u16),
+        le!(8u16),
+        le!(4u16),
+        // padding
+        le!(0u16),
+        // table
+        le!(8i32),
+        le!(4u32),
+        // vector
+        le!(len),
+        le!(0u64),
+    ]
+    .concat()
+}
+
+#[test]
+fn test_scalar_vector_len_overflow() {
+    use common::scalar_vector_generated::example::Sensor;
+
+    // `len * 4` overflows a 32-bit usize.
+    for len in &[u32::MAX, u32::MAX / 4 + 1] {
+        let buf = table_with_vector(*len);
+        let root = get_root::<Sensor>(&buf);
+        assert_eq!(root, Err(Error::OutOfBounds));
+    }
+}
+
+#[test]
+fn test_struct_vector_len_overflow() {
+    use common::struct_vector_generated::example::Hero;
+
+    // Stat is 8 bytes, `len * 8` overflows a 32-bit usize.
+    for len in &[u32::MAX, u32::MAX / 8 + 1] {
+        let buf = table_with_vector(*len);
+        let root = get_root::<Hero>(&buf);
+        assert_eq!(root, Err(Error::OutOfBounds));
+    }
+}
+
+#[test]
+fn test_reference_vector_len_overflow() {
+    use common::string_vector_generated::example::Text;
+    use common::table_vector_generated::example::Hero;
+
+    for len in &[u32::MAX, u32::MAX / 4 + 1] {
+        let buf = table_with_vector(*len);
+        assert_eq!(get_root::<Text>(&buf), Err(Error::OutOfBounds));
+        assert_eq!(get_root::<Hero>(&buf), Err(Error::OutOfBounds));
+    }
+}
+
+#[test]
+fn test_string_len_overflow() {
+    use common::string_generated::example::Author;
+
+    for len in &[u32::MAX, u32::MAX - 4] {
+        let buf = table_with_vector(*len);
+        let root = get_root::<Author>(&buf);
+        assert_eq!(root, Err(Error::OutOfBounds));
+    }
+}
+
+#[test]
+fn test_uoffset_overflow() {
+    use common::scalar_vector_generated::example::Sensor;
+
+    let mut buf = table_with_vector(0);
+    // Replace the offset to the vector.
+    buf[16..20].copy_from_slice(&u32::MAX.to_le_bytes());
+    let root = get_root::<Sensor>(&buf);
+    assert_eq!(root, Err(Error::OutOfBounds));
+}
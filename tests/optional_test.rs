@@ -0,0 +1,68 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use cfb::strategy::Config;
+use common::optional_builder::example as cfbe;
+use common::optional_generated::example as fbe;
+use flatbuffers_verifier::get_root;
+use proptest::prelude::*;
+
+fn hash(hash: &cfbe::Hash) -> fbe::Hash {
+    fbe::Hash::new(hash.a, hash.b)
+}
+
+fn _test_optional_builder(hero: cfbe::Hero) {
+    let buf = Builder::new(hero.clone()).build();
+    let root = get_root::<fbe::Hero>(&buf).expect("verified");
+
+    assert_eq!(hero.hp, root.hp());
+    assert_eq!(hero.alive, root.alive());
+    assert_eq!(hero.color.map(|c| c.0), root.color().map(|c| c as i8));
+    assert_eq!(hero.hash.as_ref().map(hash), root.hash().cloned());
+    if hero.checksum.is_present() {
+        assert_eq!(Some(hash(&hero.checksum)), root.checksum().cloned());
+    } else {
+        assert_eq!(None, root.checksum());
+    }
+}
+
+#[test]
+fn test_optional_builder_absent() {
+    let buf = Builder::new(cfbe::Hero::default()).build();
+    let root = get_root::<fbe::Hero>(&buf).expect("verified");
+
+    assert_eq!(root.hp(), None);
+    assert_eq!(root.alive(), None);
+    assert!(root.color().is_none());
+    assert_eq!(root.hash(), None);
+    assert_eq!(root.checksum(), None);
+}
+
+#[test]
+fn test_optional_builder_present_zeros() {
+    let hero = cfbe::Hero {
+        hp: Some(0),
+        alive: Some(false),
+        color: Some(cfbe::Color::Red),
+        hash: Some(cfbe::Hash::default()),
+        checksum: cfbe::Hash::default(),
+    };
+    let buf = Builder::new(hero.clone()).build();
+    let root = get_root::<fbe::Hero>(&buf).expect("verified");
+
+    assert_eq!(root.hp(), Some(0));
+    assert_eq!(root.alive(), Some(false));
+    assert_eq!(root.color().map(|c| c as i8), Some(0));
+    assert_eq!(root.hash(), Some(&fbe::Hash::new(0, 0)));
+    // The zeroed struct without `cfb_optional` is still omitted.
+    assert_eq!(root.checksum(), None);
+
+    _test_optional_builder(hero);
+}
+
+proptest! {
+    #[test]
+    fn proptest_optional_builder(hero in any_with::<cfbe::Hero>(Config::default())) {
+        _test_optional_builder(hero);
+    }
+}
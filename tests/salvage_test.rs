@@ -0,0 +1,150 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use common::data_order_builder as do_cfbe;
+use common::data_order_generated as do_fbe;
+use common::default_value_builder::example as cfbe;
+use common::default_value_generated::example as fbe;
+use flatbuffers_verifier::{get_root, salvage_root, Error, Options, Violation};
+
+/// Returns the location of the field `field` of the table `tab`.
+fn field_loc(tab: &flatbuffers::Table, field: flatbuffers::VOffsetT) -> usize {
+    tab.loc + tab.vtable().get(field) as usize
+}
+
+/// Returns the position of `bytes` in `buf`.
+fn position(buf: &[u8], bytes: &[u8]) -> usize {
+    bytes.as_ptr() as usize - buf.as_ptr() as usize
+}
+
+#[test]
+fn test_salvage_collects_every_violation() {
+    let mut buf = Builder::new(cfbe::Hero {
+        hp: 10,
+        alive: false,
+        color: cfbe::Color::Red,
+        name: "hero".to_string(),
+        ..Default::default()
+    })
+    .build();
+    assert!(salvage_root::<fbe::Hero>(&buf).violations.is_empty());
+
+    let root = flatbuffers::get_root::<fbe::Hero>(&buf);
+    let table_loc = root._tab.loc;
+    let alive_loc = field_loc(&root._tab, fbe::Hero::VT_ALIVE);
+    let color_loc = field_loc(&root._tab, fbe::Hero::VT_COLOR);
+    let name_loc = position(&buf, root.name().unwrap().as_bytes());
+    buf[alive_loc] = 2;
+    buf[color_loc] = 7;
+    buf[name_loc] = 0xff;
+
+    assert_eq!(
+        get_root::<fbe::Hero>(&buf).map(|_| ()),
+        Err(Error::InvalidBool)
+    );
+    let salvaged = salvage_root::<fbe::Hero>(&buf);
+    assert_eq!(
+        vec![
+            Violation {
+                table_loc,
+                field: Some(fbe::Hero::VT_ALIVE),
+                error: Error::InvalidBool,
+            },
+            Violation {
+                table_loc,
+                field: Some(fbe::Hero::VT_COLOR),
+                error: Error::UnknownEnumValue,
+            },
+            Violation {
+                table_loc,
+                field: Some(fbe::Hero::VT_NAME),
+                error: Error::NonUtf8String,
+            },
+        ],
+        salvaged.violations
+    );
+
+    // The valid fields are still readable with the checked accessors.
+    let root = salvaged.root.expect("root");
+    assert_eq!(Ok(10), root.try_hp());
+    assert_eq!(Err(Error::InvalidBool), root.try_alive());
+    assert_eq!(Err(Error::NonUtf8String), root.try_name());
+}
+
+#[test]
+fn test_salvage_nested_table() {
+    let mut buf = Builder::new(do_cfbe::Monster {
+        name: "orc".to_string(),
+        stat: None,
+        loots: vec![
+            do_cfbe::Item {
+                name: "sword".to_string(),
+            },
+            do_cfbe::Item {
+                name: "shield".to_string(),
+            },
+        ],
+    })
+    .build();
+
+    let root = flatbuffers::get_root::<do_fbe::Monster>(&buf);
+    let shield = root.loots().unwrap().get(1);
+    let shield_loc = shield._tab.loc;
+    let name_loc = position(&buf, shield.name().unwrap().as_bytes());
+    buf[name_loc] = 0xff;
+
+    let salvaged = salvage_root::<do_fbe::Monster>(&buf);
+    assert_eq!(
+        vec![Violation {
+            table_loc: shield_loc,
+            field: Some(do_fbe::Item::VT_NAME),
+            error: Error::NonUtf8String,
+        }],
+        salvaged.violations
+    );
+    let root = salvaged.root.expect("root");
+    assert_eq!(Ok(Some("orc")), root.try_name());
+}
+
+#[test]
+fn test_salvage_invalid_root() {
+    let salvaged = salvage_root::<fbe::Hero>(&[0, 0]);
+    assert!(salvaged.root.is_none());
+    assert_eq!(
+        vec![Violation {
+            table_loc: 0,
+            field: None,
+            error: Error::OutOfBounds,
+        }],
+        salvaged.violations
+    );
+
+    // The root offset points beyond the buffer, so the root table cannot be verified at all.
+    let buf = 8u32.to_le_bytes();
+    let salvaged = salvage_root::<fbe::Hero>(&buf);
+    assert!(salvaged.root.is_some());
+    assert_eq!(
+        vec![Violation {
+            table_loc: 8,
+            field: None,
+            error: Error::OutOfBounds,
+        }],
+        salvaged.violations
+    );
+}
+
+#[test]
+fn test_salvage_stops_at_work_budget() {
+    let buf = Builder::new(cfbe::Hero {
+        name: "hero".to_string(),
+        ..Default::default()
+    })
+    .build();
+    let options = Options {
+        max_work: Some(1),
+        ..Default::default()
+    };
+    let salvaged = options.salvage_root::<fbe::Hero>(&buf);
+    assert_eq!(1, salvaged.violations.len());
+    assert_eq!(Error::WorkBudgetExceeded, salvaged.violations[0].error);
+}
@@ -0,0 +1,146 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use cfb::strategy::Config;
+use common::string_vector_generated::example as sv;
+use common::table_vector_builder::example as cfbe;
+use common::table_vector_generated::example as tv;
+use common::union_variants_builder::example as uv_cfbe;
+use common::union_variants_generated::example as uv;
+use flatbuffers::FlatBufferBuilder;
+use flatbuffers_verifier::{get_root, Error, Options};
+use proptest::prelude::*;
+
+const NO_SHARING: Options = Options {
+    reject_deprecated_fields: false,
+    reject_unknown_fields: false,
+    reject_shared_objects: true,
+    max_work: None,
+    reject_default_scalars: false,
+    accept_unknown_enum_values: false,
+};
+
+/// Builds a `Hero` whose `stats` references the same `Stat` `len` times with flatc.
+fn hero_with_shared_stat(len: usize) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let stat = tv::Stat::create(&mut fbb, &tv::StatArgs { hp: 1 });
+    let stats = fbb.create_vector(&vec![stat; len]);
+    let root = tv::Hero::create(&mut fbb, &tv::HeroArgs { stats: Some(stats) });
+    fbb.finish_minimal(root);
+    fbb.finished_data().to_vec()
+}
+
+/// Builds a `Text` with flatc and returns the locations of the offsets in `lines`.
+fn text(lines: &[&str]) -> (Vec<u8>, Vec<usize>) {
+    let mut fbb = FlatBufferBuilder::new();
+    let lines = fbb.create_vector_of_strings(lines);
+    let root = sv::Text::create(&mut fbb, &sv::TextArgs { lines: Some(lines) });
+    fbb.finish_minimal(root);
+    let buf = fbb.finished_data().to_vec();
+
+    let root = flatbuffers::get_root::<sv::Text>(&buf);
+    let offset_loc = root._tab.loc + root._tab.vtable().get(sv::Text::VT_LINES) as usize;
+    let lines_loc = offset_loc + flatbuffers::read_scalar_at::<u32>(&buf, offset_loc) as usize;
+    let offset_locs = (0..root.lines().unwrap().len())
+        .map(|i| lines_loc + flatbuffers::SIZE_UOFFSET * (i + 1))
+        .collect();
+    (buf, offset_locs)
+}
+
+/// Points the uoffset at `offset_loc` to `target`.
+fn set_uoffset(buf: &mut [u8], offset_loc: usize, target: usize) {
+    let offset = (target - offset_loc) as u32;
+    buf[offset_loc..offset_loc + 4].copy_from_slice(&offset.to_le_bytes());
+}
+
+#[test]
+fn test_shared_table() {
+    let buf = hero_with_shared_stat(3);
+    assert!(get_root::<tv::Hero>(&buf).is_ok());
+    assert_eq!(
+        NO_SHARING.get_root::<tv::Hero>(&buf).map(|_| ()),
+        Err(Error::SharedObject)
+    );
+}
+
+#[test]
+fn test_shared_table_work_budget() {
+    let buf = hero_with_shared_stat(10_000);
+    assert!(get_root::<tv::Hero>(&buf).is_ok());
+
+    // Each visit to the shared table is charged, so the work exceeds the buffer length.
+    let options = Options {
+        max_work: Some(buf.len()),
+        ..Default::default()
+    };
+    assert_eq!(
+        options.get_root::<tv::Hero>(&buf).map(|_| ()),
+        Err(Error::WorkBudgetExceeded)
+    );
+}
+
+#[test]
+fn test_aliased_strings() {
+    let (mut buf, offset_locs) = text(&["ab", "cd"]);
+    assert!(NO_SHARING.get_root::<sv::Text>(&buf).is_ok());
+
+    let first = offset_locs[0] + flatbuffers::read_scalar_at::<u32>(&buf, offset_locs[0]) as usize;
+    set_uoffset(&mut buf, offset_locs[1], first);
+    let root = get_root::<sv::Text>(&buf).expect("verified");
+    assert_eq!(Some("ab"), root.lines().map(|lines| lines.get(1)));
+    assert_eq!(
+        NO_SHARING.get_root::<sv::Text>(&buf).map(|_| ()),
+        Err(Error::SharedObject)
+    );
+}
+
+#[test]
+fn test_overlapping_strings() {
+    // The content of the first string is also a string of length 1.
+    let (mut buf, offset_locs) = text(&["\u{1}\0\0\0x", "cd"]);
+    let first = offset_locs[0] + flatbuffers::read_scalar_at::<u32>(&buf, offset_locs[0]) as usize;
+    set_uoffset(&mut buf, offset_locs[1], first + flatbuffers::SIZE_UOFFSET);
+    let root = get_root::<sv::Text>(&buf).expect("verified");
+    assert_eq!(Some("x"), root.lines().map(|lines| lines.get(1)));
+    assert_eq!(
+        NO_SHARING.get_root::<sv::Text>(&buf).map(|_| ()),
+        Err(Error::OverlappingObjects)
+    );
+}
+
+fn _test_canonical_buffer_is_not_shared(buf: &[u8]) {
+    let options = Options {
+        max_work: Some(buf.len()),
+        ..NO_SHARING
+    };
+    assert!(options.get_root::<tv::Hero>(buf).is_ok());
+}
+
+#[test]
+fn test_builder_does_not_share_objects() {
+    let stat = cfbe::Stat { hp: 1 };
+    let buf = Builder::new(cfbe::Hero {
+        stats: vec![stat.clone(), stat.clone(), stat],
+    })
+    .build();
+    _test_canonical_buffer_is_not_shared(&buf);
+}
+
+proptest! {
+    #[test]
+    fn proptest_builder_does_not_share_objects(hero in any_with::<cfbe::Hero>(Config::default())) {
+        _test_canonical_buffer_is_not_shared(&Builder::new(hero).build());
+    }
+
+    #[test]
+    fn proptest_builder_does_not_share_union_variants(
+        player in any_with::<uv_cfbe::Player>(Config::default())
+    ) {
+        let buf = Builder::new(player).build();
+        let options = Options {
+            max_work: Some(buf.len()),
+            ..NO_SHARING
+        };
+        prop_assert!(options.get_root::<uv::Player>(&buf).is_ok());
+    }
+}
@@ -0,0 +1,186 @@
+pub mod common;
+
+use cfb::builder::{BuildError, Builder};
+use cfb::strategy::Config;
+use common::sorted_vector_builder::example as cfbe;
+use common::sorted_vector_generated::example as fbe;
+use flatbuffers::FlatBufferBuilder;
+use flatbuffers_verifier::{get_root, AlignedBuf, Error};
+use proptest::prelude::*;
+
+fn monster(name: &str, hp: u32) -> cfbe::Monster {
+    cfbe::Monster {
+        name: name.to_owned(),
+        hp,
+    }
+}
+
+fn stat(id: u32, value: i64) -> cfbe::Stat {
+    cfbe::Stat { id, value }
+}
+
+/// Builds an `Index` with the stats in the given order, bypassing the sorting in the builder.
+fn index_with_stats(ids: &[u32]) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let stats: Vec<_> = ids
+        .iter()
+        .map(|id| fbe::Stat::create(&mut fbb, &fbe::StatArgs { id: *id, value: 0 }))
+        .collect();
+    let stats = fbb.create_vector(&stats);
+    let root = fbe::Index::create(
+        &mut fbb,
+        &fbe::IndexArgs {
+            stats: Some(stats),
+            ..Default::default()
+        },
+    );
+    fbb.finish_minimal(root);
+    fbb.finished_data().to_vec()
+}
+
+fn _test_sorted_vector_builder(index: cfbe::Index) {
+    let buf = Builder::new(index.clone()).build();
+    let root = get_root::<fbe::Index>(&buf).expect("verified");
+
+    let mut monsters = index.monsters.clone();
+    monsters.sort_by(cfbe::Monster::cmp_by_key);
+    let read: Vec<_> = root
+        .monsters()
+        .map(|vec| {
+            common::collect_flatbuffers_vector(&vec)
+                .into_iter()
+                .map(|m| monster(m.name(), m.hp()))
+                .collect()
+        })
+        .unwrap_or_default();
+    assert_eq!(monsters, read);
+
+    if let Some(vec) = root.monsters() {
+        for m in &monsters {
+            let found = fbe::Monster::lookup_by_key(vec, &m.name).expect("found");
+            assert_eq!(found.hp(), m.hp);
+        }
+    }
+    if let Some(vec) = root.stats() {
+        for s in &index.stats {
+            let found = fbe::Stat::lookup_by_key(vec, s.id).expect("found");
+            assert_eq!(found.value(), s.value);
+        }
+    }
+}
+
+#[test]
+fn test_sorted_vector_builder_sorts_by_key() {
+    let index = cfbe::Index {
+        monsters: vec![monster("orc", 1), monster("elf", 2), monster("", 3)],
+        stats: vec![stat(3, 30), stat(1, 10), stat(2, 20)],
+    };
+    let buf = Builder::new(index.clone()).build();
+    let root = get_root::<fbe::Index>(&buf).expect("verified");
+
+    let names: Vec<_> = common::collect_flatbuffers_vector(&root.monsters().unwrap())
+        .into_iter()
+        .map(|m| m.name())
+        .collect();
+    assert_eq!(names, vec!["", "elf", "orc"]);
+    let ids: Vec<_> = common::collect_flatbuffers_vector(&root.stats().unwrap())
+        .into_iter()
+        .map(|s| s.id())
+        .collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+
+    let stats = root.stats().unwrap();
+    assert!(fbe::Stat::lookup_by_key(stats, 0).is_none());
+    assert!(fbe::Stat::lookup_by_key(stats, 4).is_none());
+    let monsters = root.monsters().unwrap();
+    assert!(fbe::Monster::lookup_by_key(monsters, "goblin").is_none());
+
+    // The encoding does not depend on the insertion order.
+    let mut reversed = index.clone();
+    reversed.monsters.reverse();
+    reversed.stats.reverse();
+    assert_eq!(buf, Builder::new(reversed).build());
+
+    _test_sorted_vector_builder(index);
+}
+
+#[test]
+fn test_sorted_vector_builder_rejects_duplicate_keys() {
+    let index = cfbe::Index {
+        monsters: vec![monster("orc", 1), monster("elf", 2), monster("orc", 3)],
+        stats: vec![],
+    };
+    assert_eq!(
+        Err(BuildError::DuplicateKey {
+            field: "Index.monsters"
+        }),
+        Builder::new(&index).try_build()
+    );
+
+    let index = cfbe::Index {
+        monsters: vec![],
+        stats: vec![stat(2, 20), stat(2, 30)],
+    };
+    let error = Builder::new(index).try_build().unwrap_err();
+    assert_eq!("Index.stats has duplicate keys", error.to_string());
+}
+
+#[test]
+fn test_sorted_vector_verifier() {
+    assert!(get_root::<fbe::Index>(&index_with_stats(&[1, 2, 3])).is_ok());
+    assert_eq!(
+        get_root::<fbe::Index>(&index_with_stats(&[1, 3, 2])).map(|_| ()),
+        Err(Error::UnsortedKeys)
+    );
+    assert_eq!(
+        get_root::<fbe::Index>(&index_with_stats(&[1, 2, 2])).map(|_| ()),
+        Err(Error::DuplicateKey)
+    );
+}
+
+#[test]
+fn test_required_key_is_missing() {
+    let mut fbb = FlatBufferBuilder::new();
+    let start = fbb.start_table();
+    fbb.push_slot::<u32>(fbe::Monster::VT_HP, 1, 0);
+    let monster = fbb.end_table(start);
+    let monsters =
+        fbb.create_vector(&[flatbuffers::WIPOffset::<fbe::Monster>::new(monster.value())]);
+    let root = fbe::Index::create(
+        &mut fbb,
+        &fbe::IndexArgs {
+            monsters: Some(monsters),
+            ..Default::default()
+        },
+    );
+    fbb.finish_minimal(root);
+
+    assert_eq!(
+        get_root::<fbe::Index>(fbb.finished_data()).map(|_| ()),
+        Err(Error::MissingRequiredField)
+    );
+}
+
+proptest! {
+    #[test]
+    fn proptest_sorted_vector_builder(index in any_with::<cfbe::Index>(Config::default())) {
+        _test_sorted_vector_builder(index);
+    }
+}
+
+#[test]
+fn test_sorted_vector_verifier_needs_aligned_buffer_for_keys() {
+    let buf = Builder::new(cfbe::Index {
+        monsters: vec![],
+        stats: vec![stat(1, 0), stat(2, 0)],
+    })
+    .build();
+
+    // The keys are compared with the reader, which cannot read a buffer at an odd address.
+    let framed = [&[0u8][..], &buf].concat();
+    assert_eq!(
+        get_root::<fbe::Index>(&framed[1..]).map(|_| ()),
+        Err(Error::Misaligned)
+    );
+    assert!(get_root::<fbe::Index>(&AlignedBuf::new(&framed[1..], 8)).is_ok());
+}
@@ -6,13 +6,15 @@ use flatbuffers_verifier::{get_root, Error};
 #[test]
 fn test_string_uoffset_out_of_bounds() {
     let buf = [
-        le!(10u32),
+        le!(12u32),
         // vtable
         le!(6u16),
         le!(8u16),
         le!(4u16),
+        // padding
+        le!(0u16),
         // author
-        le!(6i32),
+        le!(8i32),
         le!(4u32),
     ]
     .concat();
@@ -23,13 +25,15 @@ fn test_string_uoffset_out_of_bounds() {
 #[test]
 fn test_string_len_out_of_bounds() {
     let buf = [
-        le!(10u32),
+        le!(12u32),
         // vtable
         le!(6u16),
         le!(8u16),
         le!(4u16),
+        // padding
+        le!(0u16),
         // author
-        le!(6i32),
+        le!(8i32),
         le!(4u32),
         // name
         le!(0u16),
@@ -42,13 +46,15 @@ fn test_string_len_out_of_bounds() {
 #[test]
 fn test_string_content_out_of_bounds() {
     let buf = [
-        le!(10u32),
+        le!(12u32),
         // vtable
         le!(6u16),
         le!(8u16),
         le!(4u16),
+        // padding
+        le!(0u16),
         // author
-        le!(6i32),
+        le!(8i32),
         le!(4u32),
         // name
         le!(4u32),
@@ -62,13 +68,15 @@ fn test_string_content_out_of_bounds() {
 #[test]
 fn test_string_not_terminated_with_null() {
     let buf = [
-        le!(10u32),
+        le!(12u32),
         // vtable
         le!(6u16),
         le!(8u16),
         le!(4u16),
+        // padding
+        le!(0u16),
         // author
-        le!(6i32),
+        le!(8i32),
         le!(4u32),
         // name
         le!(4u32),
@@ -0,0 +1,168 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use cfb::strategy::Config;
+use common::struct_array_builder::example as cfbe;
+use common::struct_array_generated::example as fbe;
+use flatbuffers::FlatBufferBuilder;
+use flatbuffers_verifier::{get_root, Error};
+use proptest::prelude::*;
+use std::mem::{align_of, size_of};
+
+fn block(version: u16) -> cfbe::Block {
+    let mut hash = [0u8; 32];
+    for (i, b) in hash.iter_mut().enumerate() {
+        *b = i as u8 ^ version as u8;
+    }
+    cfbe::Block {
+        version,
+        numbers: [1, u32::MAX, version as u32],
+        hash,
+        corners: [
+            cfbe::Point { x: -1, y: 2 },
+            cfbe::Point {
+                x: version as i16,
+                y: i16::MIN,
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+fn assert_block(expected: &cfbe::Block, block: &fbe::Block) {
+    assert_eq!(expected.version, block.version());
+    assert_eq!(expected.numbers, block.numbers());
+    assert_eq!(&expected.hash, block.hash());
+    for (expected, corner) in expected.corners.iter().zip(block.corners()) {
+        assert_eq!((expected.x, expected.y), (corner.x(), corner.y()));
+    }
+}
+
+fn _test_struct_array_builder(header: cfbe::Header) {
+    let buf = Builder::new(header.clone()).build();
+    let root = get_root::<fbe::Header>(&buf).expect("verified");
+
+    if header.block.is_present() {
+        assert_block(&header.block, root.block().unwrap());
+    } else {
+        assert!(root.block().is_none());
+    }
+    let blocks = root.blocks().unwrap_or_default();
+    assert_eq!(header.blocks.len(), blocks.len());
+    for (expected, block) in header.blocks.iter().zip(blocks) {
+        assert_block(expected, block);
+    }
+    if header.signature.is_present() {
+        assert_eq!(&header.signature.data, root.signature().unwrap().data());
+    } else {
+        assert!(root.signature().is_none());
+    }
+}
+
+#[test]
+fn test_struct_array_layout() {
+    assert_eq!(size_of::<cfbe::Block>(), 56);
+    assert_eq!(align_of::<cfbe::Block>(), 4);
+    assert_eq!(size_of::<cfbe::Block>(), size_of::<fbe::Block>());
+
+    assert!(!cfbe::Block::default().is_present());
+    let mut hash_only = cfbe::Block::default();
+    hash_only.hash[31] = 1;
+    assert!(hash_only.is_present());
+    let mut corner_only = cfbe::Block::default();
+    corner_only.corners[1].y = 1;
+    assert!(corner_only.is_present());
+}
+
+#[test]
+fn test_struct_array_builder() {
+    _test_struct_array_builder(cfbe::Header {
+        block: block(7),
+        blocks: vec![block(1), block(2)],
+        ..Default::default()
+    });
+    _test_struct_array_builder(cfbe::Header::default());
+}
+
+#[test]
+fn test_struct_array_longer_than_32() {
+    assert_eq!(size_of::<cfbe::Signature>(), 65);
+    assert!(!cfbe::Signature::default().is_present());
+
+    let mut data = [0u8; 65];
+    data[64] = 1;
+    let signature = cfbe::Signature { data };
+    _test_struct_array_builder(cfbe::Header {
+        signature,
+        ..Default::default()
+    });
+
+    let mut fbb = FlatBufferBuilder::new();
+    let root = fbe::Header::create(
+        &mut fbb,
+        &fbe::HeaderArgs {
+            signature: Some(&fbe::Signature::new(&data)),
+            ..Default::default()
+        },
+    );
+    fbb.finish_minimal(root);
+    let root = get_root::<fbe::Header>(fbb.finished_data()).expect("verified");
+    assert_eq!(&data, root.signature().unwrap().data());
+}
+
+#[test]
+fn test_struct_array_matches_flatc() {
+    let expected = block(3);
+    let mut fbb = FlatBufferBuilder::new();
+    let corners = [
+        fbe::Point::new(expected.corners[0].x, expected.corners[0].y),
+        fbe::Point::new(expected.corners[1].x, expected.corners[1].y),
+    ];
+    let block = fbe::Block::new(
+        expected.version,
+        &expected.numbers,
+        &expected.hash,
+        &corners,
+    );
+    let root = fbe::Header::create(
+        &mut fbb,
+        &fbe::HeaderArgs {
+            block: Some(&block),
+            ..Default::default()
+        },
+    );
+    fbb.finish_minimal(root);
+
+    let root = get_root::<fbe::Header>(fbb.finished_data()).expect("verified");
+    assert_block(&expected, root.block().unwrap());
+
+    // The vtables are laid out differently, but the struct is the same.
+    let buf = Builder::new(cfbe::Header {
+        block: expected,
+        ..Default::default()
+    })
+    .build();
+    let flatc_buf = fbb.finished_data();
+    assert_eq!(buf[buf.len() - 56..], flatc_buf[flatc_buf.len() - 56..]);
+}
+
+#[test]
+fn test_struct_array_out_of_bounds() {
+    // The vector of blocks is the last component written by the builder.
+    let buf = Builder::new(cfbe::Header {
+        blocks: vec![block(1)],
+        ..Default::default()
+    })
+    .build();
+    assert_eq!(
+        get_root::<fbe::Header>(&buf[..buf.len() - 4]).map(|_| ()),
+        Err(Error::OutOfBounds)
+    );
+}
+
+proptest! {
+    #[test]
+    fn proptest_struct_array_builder(header in any_with::<cfbe::Header>(Config::default())) {
+        _test_struct_array_builder(header);
+    }
+}
@@ -35,13 +35,15 @@ fn test_table_body_out_of_bounds() {
 fn test_table_fields_offset_out_of_bounds() {
     {
         let buf = [
-            le!(10u32),
+            le!(12u32),
             // vtable
             le!(6u16),
             le!(6u16),
             le!(6u16),
+            // padding
+            le!(0u16),
             // tab
-            le!(6i32),
+            le!(8i32),
             le!(0u16),
         ]
         .concat();
@@ -51,13 +53,15 @@ fn test_table_fields_offset_out_of_bounds() {
 
     {
         let buf = [
-            le!(10u32),
+            le!(12u32),
             // vtable
             le!(6u16),
             le!(6u16),
             le!(4u16),
+            // padding
+            le!(0u16),
             // tab
-            le!(6i32),
+            le!(8i32),
             le!(0u16),
         ]
         .concat();
@@ -69,20 +73,24 @@ fn test_table_fields_offset_out_of_bounds() {
 #[test]
 fn test_nested_table_field_out_of_bounds() {
     let buf = [
-        le!(10u32),
+        le!(12u32),
         // vtable
         le!(6u16),
         le!(8u16),
         le!(4u16),
+        // padding
+        le!(0u16),
         // hero
-        le!(6i32),
-        le!(4u32),
+        le!(8i32),
+        le!(12u32),
         // vtable
         le!(6u16),
         le!(8u16),
         le!(6u16),
+        // padding
+        le!(0u16),
         // stat
-        le!(6i32),
+        le!(8i32),
         le!(8u32),
     ]
     .concat();
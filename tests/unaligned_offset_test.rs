@@ -0,0 +1,122 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use common::table_field_builder::example as cfbe;
+use common::table_field_generated::example as reader;
+use common::union_generated::example::Player;
+use flatbuffers_verifier::{get_root, verify_buffer_alignment, AlignedBuf, Error};
+use std::panic;
+
+// The flatbuffers reader dereferences offsets in place, which panics on misaligned locations in
+// debug builds. The verifier must not, wherever the offsets of a malicious buffer are.
+
+fn assert_verify_does_not_panic<'a, T>(buf: &'a [u8])
+where
+    T: flatbuffers::Follow<'a> + 'a,
+    T::Inner: flatbuffers_verifier::Verify,
+{
+    assert!(panic::catch_unwind(|| get_root::<T>(buf).map(|_| ())).is_ok());
+}
+
+#[test]
+fn test_misaligned_table() {
+    let buf = [
+        le!(6u32),
+        // padding
+        le!(0u16),
+        // tab
+        le!(-4i32),
+        // vtable
+        le!(4u16),
+        le!(4u16),
+    ]
+    .concat();
+    assert_verify_does_not_panic::<reader::Stat>(&buf);
+}
+
+#[test]
+fn test_misaligned_vtable() {
+    let buf = [
+        le!(4u32),
+        // tab
+        le!(-5i32),
+        // padding
+        le!(0u8),
+        // vtable
+        le!(4u16),
+        le!(4u16),
+    ]
+    .concat();
+    assert_verify_does_not_panic::<reader::Stat>(&buf);
+}
+
+#[test]
+fn test_misaligned_table_field_offset() {
+    let buf = [
+        le!(12u32),
+        // vtable
+        le!(6u16),
+        le!(10u16),
+        le!(6u16),
+        // padding
+        le!(0u16),
+        // hero
+        le!(8i32),
+        le!(0u16),
+        le!(6u32),
+        le!(0u16),
+        // stat
+        le!(-4i32),
+        // vtable
+        le!(4u16),
+        le!(4u16),
+    ]
+    .concat();
+    assert_verify_does_not_panic::<reader::Hero>(&buf);
+}
+
+#[test]
+fn test_misaligned_union_offset() {
+    let buf = [
+        le!(12u32),
+        // vtable
+        le!(8u16),
+        le!(10u16),
+        le!(4u16),
+        le!(6u16),
+        // player
+        le!(8i32),
+        le!(1u8),
+        le!(0u8),
+        le!(6u32),
+        le!(0u16),
+        // hero
+        le!(-4i32),
+        // vtable
+        le!(4u16),
+        le!(4u16),
+    ]
+    .concat();
+    assert_verify_does_not_panic::<Player>(&buf);
+}
+
+#[test]
+fn test_misaligned_buffer() {
+    let buf = Builder::new(cfbe::Hero {
+        stat: Some(cfbe::Stat { hp: 1 }),
+    })
+    .build();
+
+    // A valid buffer sliced at an odd address is accepted, since the alignments are relative to
+    // its start, but the reader can only read an aligned copy of it.
+    let framed = [&[0u8][..], &buf].concat();
+    assert!(get_root::<reader::Hero>(&framed[1..]).is_ok());
+    assert_eq!(
+        verify_buffer_alignment(&framed[1..], 4),
+        Err(Error::Misaligned)
+    );
+    let copied = AlignedBuf::new(&framed[1..], 4);
+    assert_eq!(verify_buffer_alignment(&copied, 4), Ok(()));
+    let hero = get_root::<reader::Hero>(&copied).unwrap();
+    assert_eq!(hero.stat().unwrap().hp(), 1);
+}
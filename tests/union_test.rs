@@ -6,13 +6,6 @@ use proptest::prelude::*;
 use common::union_builder::example as cfbe;
 use common::union_generated::example as fbe;
 
-fn arb_role() -> impl Strategy<Value = cfbe::Role> {
-    prop_oneof![
-        any::<u32>().prop_map(|hp| cfbe::Role::Hero(cfbe::Hero { hp })),
-        any::<u32>().prop_map(|hp| cfbe::Role::Monster(cfbe::Monster { hp })),
-    ]
-}
-
 fn _test_union_builder(role: Option<cfbe::Role>) {
     let buf = Builder::new(cfbe::Player { role: role.clone() }).build();
     let root = flatbuffers::get_root::<fbe::Player>(&buf[..]);
@@ -33,6 +26,9 @@ fn _test_union_builder(role: Option<cfbe::Role>) {
             assert!(monster.is_some());
             assert_eq!(hp, monster.unwrap().hp());
         }
+        Some(cfbe::Role::Unknown(_)) => {
+            unreachable!("the strategies only generate declared variants")
+        }
         None => assert!(root.role().is_none()),
     }
 }
@@ -44,7 +40,7 @@ fn test_union_builder() {
 
 proptest! {
     #[test]
-    fn proptest_union_builder(role in prop::option::of(arb_role())) {
+    fn proptest_union_builder(role in prop::option::of(any::<cfbe::Role>())) {
         _test_union_builder(role);
     }
 }
@@ -0,0 +1,247 @@
+pub mod common;
+
+use cfb::builder::{BuildError, Builder};
+use cfb::strategy::Config;
+use common::union_variants_builder::example as cfbe;
+use common::union_variants_generated::example as fbe;
+use flatbuffers::FlatBufferBuilder;
+use flatbuffers::Follow;
+use flatbuffers_verifier::{get_lazy_root, get_root, Error, Options};
+use proptest::prelude::*;
+
+fn point(x: i16, y: i32) -> cfbe::Point {
+    cfbe::Point {
+        x,
+        y,
+        ..Default::default()
+    }
+}
+
+/// Reads a union value as the variant named by its tag.
+fn assert_variant(role: &cfbe::Role, role_type: fbe::Role, table: flatbuffers::Table) {
+    assert_eq!(role.union_type(), role_type as u8);
+    match role {
+        cfbe::Role::Hero(hero) => {
+            assert_eq!(hero.hp, fbe::Hero::init_from_table(table).hp());
+        }
+        cfbe::Role::Point(p) => {
+            let read = <&fbe::Point>::follow(table.buf, table.loc);
+            assert_eq!((p.x, p.y), (read.x(), read.y()));
+        }
+        cfbe::Role::Name(name) => {
+            assert_eq!(name, <&str>::follow(table.buf, table.loc));
+        }
+        cfbe::Role::Unknown(_) => unreachable!("the strategies only generate declared variants"),
+    }
+}
+
+fn _test_union_variants_builder(player: cfbe::Player) {
+    let buf = Builder::new(player.clone()).build();
+    let root = get_root::<fbe::Player>(&buf).expect("verified");
+
+    match &player.role {
+        Some(role) => assert_variant(role, root.role_type(), root.role().unwrap()),
+        None => assert!(root.role().is_none()),
+    }
+
+    let types = root
+        .roles_type()
+        .map_or(vec![], |types| common::collect_flatbuffers_vector(&types));
+    let roles = root
+        .roles()
+        .map_or(vec![], |roles| common::collect_flatbuffers_vector(&roles));
+    assert_eq!(types.len(), player.roles.len());
+    assert_eq!(roles.len(), player.roles.len());
+    for ((role, role_type), table) in player.roles.iter().zip(types).zip(roles) {
+        assert_variant(role, role_type, table);
+    }
+}
+
+/// Replaces the first occurrence of `from` in `buf` with `to`.
+fn replace(buf: &mut [u8], from: &[u8], to: &[u8]) {
+    let pos = buf
+        .windows(from.len())
+        .position(|w| w == from)
+        .expect("found");
+    buf[pos..pos + to.len()].copy_from_slice(to);
+}
+
+#[test]
+fn test_union_variants_builder() {
+    _test_union_variants_builder(cfbe::Player {
+        role: Some(cfbe::Role::Point(point(1, -2))),
+        roles: vec![
+            cfbe::Role::Name("orc".to_owned()),
+            cfbe::Role::Hero(cfbe::Hero { hp: 3 }),
+            cfbe::Role::Point(point(-4, 5)),
+        ],
+    });
+    _test_union_variants_builder(cfbe::Player {
+        role: Some(cfbe::Role::Name(String::new())),
+        roles: vec![],
+    });
+}
+
+#[test]
+fn test_union_variants_accessors() {
+    let buf = Builder::new(cfbe::Player {
+        role: Some(cfbe::Role::Point(point(1, -2))),
+        roles: vec![],
+    })
+    .build();
+    let root = get_root::<fbe::Player>(&buf).expect("verified");
+    assert!(root.try_role_as_hero().unwrap().is_none());
+    assert!(root.try_role_as_name().unwrap().is_none());
+    assert_eq!(root.try_role_as_point().unwrap().unwrap().y(), -2);
+
+    let buf = Builder::new(cfbe::Player {
+        role: Some(cfbe::Role::Name("orc".to_owned())),
+        roles: vec![],
+    })
+    .build();
+    let root = get_root::<fbe::Player>(&buf).expect("verified");
+    assert_eq!(root.try_role_as_name(), Ok(Some("orc")));
+}
+
+#[test]
+fn test_union_struct_out_of_bounds() {
+    // The struct is the last component written by the builder.
+    let buf = Builder::new(cfbe::Player {
+        role: Some(cfbe::Role::Point(point(1, -2))),
+        roles: vec![],
+    })
+    .build();
+    assert_eq!(
+        get_root::<fbe::Player>(&buf[..buf.len() - 1]).map(|_| ()),
+        Err(Error::OutOfBounds)
+    );
+}
+
+#[test]
+fn test_union_string_is_verified() {
+    for player in [
+        cfbe::Player {
+            role: Some(cfbe::Role::Name("orc".to_owned())),
+            roles: vec![],
+        },
+        cfbe::Player {
+            role: None,
+            roles: vec![cfbe::Role::Name("orc".to_owned())],
+        },
+    ] {
+        let mut buf = Builder::new(player).build();
+        replace(&mut buf, b"orc\0", &[0xff]);
+        assert_eq!(
+            get_root::<fbe::Player>(&buf).map(|_| ()),
+            Err(Error::NonUtf8String)
+        );
+    }
+}
+
+fn accept_unknown() -> Options {
+    Options {
+        accept_unknown_enum_values: true,
+        ..Default::default()
+    }
+}
+
+fn decode_role(union_type: u8, table: flatbuffers::Table) -> cfbe::Role {
+    match union_type {
+        1 => cfbe::Role::Hero(cfbe::Hero {
+            hp: fbe::Hero::init_from_table(table).hp(),
+        }),
+        2 => {
+            let read = <&fbe::Point>::follow(table.buf, table.loc);
+            cfbe::Role::Point(point(read.x(), read.y()))
+        }
+        3 => cfbe::Role::Name(<&str>::follow(table.buf, table.loc).to_owned()),
+        _ => cfbe::Role::Unknown(union_type),
+    }
+}
+
+/// Verifies a player which may come from a newer peer, and decodes the union types from the raw
+/// values, since the flatc reader cannot represent unknown variants.
+fn decode(buf: &[u8]) -> cfbe::Player {
+    accept_unknown()
+        .verify_root::<fbe::Player>(buf)
+        .expect("verified");
+    let root = get_lazy_root::<fbe::Player>(buf).unwrap();
+    let types = root
+        .raw_roles_type()
+        .map_or(vec![], |types| common::collect_flatbuffers_vector(&types));
+    let roles = root
+        .roles()
+        .map_or(vec![], |roles| common::collect_flatbuffers_vector(&roles));
+    cfbe::Player {
+        role: root
+            .role()
+            .map(|table| decode_role(root.raw_role_type(), table)),
+        roles: types
+            .into_iter()
+            .zip(roles)
+            .map(|(union_type, table)| decode_role(union_type, table))
+            .collect(),
+    }
+}
+
+#[test]
+fn test_unknown_union_variant_decode() {
+    // A newer peer writes the variant `Mage { mp: uint32 }` with flatc.
+    let mut fbb = FlatBufferBuilder::new();
+    let start = fbb.start_table();
+    fbb.push_slot_always::<u32>(4, 100);
+    let mage = fbb.end_table(start);
+    let start = fbb.start_table();
+    fbb.push_slot_always(fbe::Player::VT_ROLE, mage);
+    fbb.push_slot_always::<u8>(fbe::Player::VT_ROLE_TYPE, 4);
+    let root = fbb.end_table(start);
+    fbb.finish_minimal(flatbuffers::WIPOffset::<fbe::Player>::new(root.value()));
+    let buf = fbb.finished_data().to_vec();
+    assert_eq!(
+        get_root::<fbe::Player>(&buf).map(|_| ()),
+        Err(Error::UnknownEnumValue)
+    );
+
+    // The value of an unknown variant is only checked to be within the buffer, and its fields
+    // cannot be copied without its schema, so it is not written back.
+    let decoded = decode(&buf);
+    assert_eq!(decoded.role, Some(cfbe::Role::Unknown(4)));
+    assert_eq!(
+        Builder::new(&decoded).try_build(),
+        Err(BuildError::UnknownUnionVariant { union_type: 4 })
+    );
+
+    // The checked accessors still reject the tag, which the flatc reader cannot represent.
+    let root = get_lazy_root::<fbe::Player>(&buf).unwrap();
+    assert_eq!(root.try_role_type(), Err(Error::UnknownEnumValue));
+    assert_eq!(
+        root.try_role_as_hero().map(|_| ()),
+        Err(Error::UnknownEnumValue)
+    );
+}
+
+#[test]
+fn test_unknown_union_variant_build() {
+    // Neither undeclared union types nor `NONE` and the declared ones have a value to write.
+    for union_type in &[0, 1, 5] {
+        let player = cfbe::Player {
+            role: None,
+            roles: vec![
+                cfbe::Role::Hero(cfbe::Hero { hp: 3 }),
+                cfbe::Role::Unknown(*union_type),
+            ],
+        };
+        let expected = Err(BuildError::UnknownUnionVariant {
+            union_type: *union_type,
+        });
+        assert_eq!(Builder::new(&player).try_build(), expected);
+        assert_eq!(Builder::new(player).try_build(), expected);
+    }
+}
+
+proptest! {
+    #[test]
+    fn proptest_union_variants_builder(player in any_with::<cfbe::Player>(Config::default())) {
+        _test_union_variants_builder(player);
+    }
+}
@@ -0,0 +1,112 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use cfb::strategy::Config;
+use common::union_vector_builder::example as cfbe;
+use common::union_vector_generated::example as fbe;
+use flatbuffers::FlatBufferBuilder;
+use flatbuffers_verifier::{get_root, Error};
+use proptest::prelude::*;
+
+/// Builds a `Party` whose members are heroes with the given type tags, so the tags and the values
+/// can disagree.
+fn party(types: Option<&[fbe::Role]>, heroes: Option<usize>) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let members = heroes.map(|len| {
+        let members: Vec<_> = (0..len)
+            .map(|hp| {
+                fbe::Hero::create(&mut fbb, &fbe::HeroArgs { hp: hp as u32 }).as_union_value()
+            })
+            .collect();
+        fbb.create_vector(&members)
+    });
+    let members_type = types.map(|types| fbb.create_vector(types));
+    let root = fbe::Party::create(
+        &mut fbb,
+        &fbe::PartyArgs {
+            members_type,
+            members,
+        },
+    );
+    fbb.finish_minimal(root);
+    fbb.finished_data().to_vec()
+}
+
+fn _test_union_vector_builder(party: cfbe::Party) {
+    let buf = Builder::new(party.clone()).build();
+    let root = get_root::<fbe::Party>(&buf).expect("verified");
+
+    let types = root.members_type().map_or(vec![], |types| {
+        common::collect_flatbuffers_vector(&types)
+            .into_iter()
+            .map(|t| t as u8)
+            .collect()
+    });
+    let members = root.members().map_or(vec![], |members| {
+        common::collect_flatbuffers_vector(&members)
+    });
+    assert_eq!(types.len(), party.members.len());
+    assert_eq!(members.len(), party.members.len());
+
+    for ((member, union_type), table) in party.members.iter().zip(types).zip(members) {
+        assert_eq!(member.union_type(), union_type);
+        match member {
+            cfbe::Role::Hero(hero) => {
+                assert_eq!(hero.hp, fbe::Hero::init_from_table(table).hp());
+            }
+            cfbe::Role::Monster(monster) => {
+                assert_eq!(monster.hp, fbe::Monster::init_from_table(table).hp());
+            }
+            cfbe::Role::Unknown(_) => {
+                unreachable!("the strategies only generate declared variants")
+            }
+        }
+    }
+}
+
+#[test]
+fn test_union_vector_builder() {
+    _test_union_vector_builder(cfbe::Party {
+        members: vec![
+            cfbe::Role::Monster(cfbe::Monster { hp: 1 }),
+            cfbe::Role::Hero(cfbe::Hero { hp: 2 }),
+            cfbe::Role::Hero(cfbe::Hero { hp: 0 }),
+        ],
+    });
+}
+
+#[test]
+fn test_union_vector_builder_omits_empty_vectors() {
+    let buf = Builder::new(cfbe::Party::default()).build();
+    let root = get_root::<fbe::Party>(&buf).expect("verified");
+    assert!(root.members_type().is_none());
+    assert!(root.members().is_none());
+}
+
+#[test]
+fn test_union_vector_verifier() {
+    let hero = fbe::Role::Hero;
+    assert!(get_root::<fbe::Party>(&party(Some(&[hero, hero]), Some(2))).is_ok());
+    assert!(get_root::<fbe::Party>(&party(None, None)).is_ok());
+    assert!(get_root::<fbe::Party>(&party(Some(&[]), Some(0))).is_ok());
+
+    for (types, heroes) in [
+        (Some(&[hero][..]), Some(2)),
+        (Some(&[hero, hero][..]), Some(1)),
+        (None, Some(1)),
+        (Some(&[hero][..]), None),
+        (Some(&[hero, fbe::Role::NONE][..]), Some(2)),
+    ] {
+        assert_eq!(
+            get_root::<fbe::Party>(&party(types, heroes)).map(|_| ()),
+            Err(Error::UnmatchedUnion)
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn proptest_union_vector_builder(party in any_with::<cfbe::Party>(Config::default())) {
+        _test_union_vector_builder(party);
+    }
+}
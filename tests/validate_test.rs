@@ -0,0 +1,179 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use common::validate_builder::{Item, Monster, Rarity, Stat};
+use common::validate_generated as fbe;
+use common::validate_rules::{MAX_LOOTS, VALIDATED_ITEMS};
+use flatbuffers_verifier::{get_root, salvage_root, Error, Options, PathSegment};
+
+fn item(name: &str) -> Item {
+    Item {
+        name: name.to_string(),
+        rarity: Rarity::Common,
+    }
+}
+
+fn validated_items() -> usize {
+    VALIDATED_ITEMS.with(|validated| validated.get())
+}
+
+fn monster(hp: u32, mp: u32, loots: &[&str]) -> Vec<u8> {
+    Builder::new(Monster {
+        name: "monster".to_string(),
+        stat: Some(Stat { hp, mp }),
+        loots: loots.iter().map(|name| item(name)).collect(),
+    })
+    .build()
+}
+
+/// Returns the path and the message of the `Error::Invalid` returned by the verification.
+fn invalid(buf: &[u8]) -> (String, String) {
+    match get_root::<fbe::Monster>(buf).map(|_| ()) {
+        Err(Error::Invalid { path, message }) => (path.to_string(), message),
+        result => panic!("expected Error::Invalid, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_validate_valid() {
+    let buf = monster(10, 5, &["sword", "shield"]);
+    let root = get_root::<fbe::Monster>(&buf).expect("verified");
+    assert_eq!(Some("monster"), root.name());
+}
+
+#[test]
+fn test_validate_table_field() {
+    let buf = monster(3, 5, &[]);
+    assert_eq!(
+        ("$.stat".to_string(), "mp 5 exceeds hp 3".to_string()),
+        invalid(&buf)
+    );
+    assert_eq!(
+        "$.stat: mp 5 exceeds hp 3",
+        get_root::<fbe::Monster>(&buf).unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn test_validate_vector_element() {
+    let buf = monster(10, 5, &["sword", "", "shield"]);
+    match get_root::<fbe::Monster>(&buf).map(|_| ()) {
+        Err(Error::Invalid { path, .. }) => assert_eq!(
+            &[PathSegment::Field("loots"), PathSegment::Index(1)],
+            path.segments()
+        ),
+        result => panic!("expected Error::Invalid, got {:?}", result),
+    }
+    assert_eq!(
+        ("$.loots[1]".to_string(), "item has no name".to_string()),
+        invalid(&buf)
+    );
+}
+
+#[test]
+fn test_validate_root() {
+    let loots = vec!["loot"; MAX_LOOTS + 1];
+    assert_eq!(
+        ("$".to_string(), "4 loots exceed the limit 3".to_string()),
+        invalid(&monster(10, 5, &loots))
+    );
+}
+
+#[test]
+fn test_validate_after_nested_tables() {
+    // The nested tables are validated before the table referencing them.
+    let loots = vec![""; MAX_LOOTS + 1];
+    assert_eq!("$.loots[0]", invalid(&monster(10, 5, &loots)).0);
+}
+
+#[test]
+fn test_salvage_validate() {
+    let loots = vec!["", "loot", "", "loot"];
+    let buf = monster(3, 5, &loots);
+    let salvaged = salvage_root::<fbe::Monster>(&buf);
+    let errors: Vec<String> = salvaged
+        .violations
+        .iter()
+        .map(|violation| violation.error.to_string())
+        .collect();
+    assert_eq!(
+        vec![
+            "$.stat: mp 5 exceeds hp 3",
+            "$.loots[0]: item has no name",
+            "$.loots[2]: item has no name",
+            "$: 4 loots exceed the limit 3",
+        ],
+        errors
+    );
+}
+
+#[test]
+fn test_salvage_skips_validate_of_malformed_tables() {
+    let mut buf = monster(10, 5, &["\u{7f}bad"; MAX_LOOTS + 1]);
+    let pos = buf
+        .windows(4)
+        .position(|window| window == b"\x7fbad")
+        .expect("item name");
+    buf[pos] = 0xff;
+
+    // Neither the malformed item nor the monster referencing it is validated.
+    let salvaged = salvage_root::<fbe::Monster>(&buf);
+    let errors: Vec<Error> = salvaged
+        .violations
+        .into_iter()
+        .map(|violation| violation.error)
+        .collect();
+    assert_eq!(vec![Error::NonUtf8String], errors);
+}
+
+#[test]
+fn test_validate_rejects_unknown_enum_values() {
+    let accept_unknown = Options {
+        accept_unknown_enum_values: true,
+        ..Default::default()
+    };
+    let buf = Builder::new(Monster {
+        name: "monster".to_string(),
+        stat: None,
+        loots: vec![Item {
+            name: "sword".to_string(),
+            rarity: Rarity(7),
+        }],
+    })
+    .build();
+
+    // `validate` would read the rarity with the flatc reader, which cannot represent it, so the
+    // item is rejected without being validated.
+    let validated = validated_items();
+    assert_eq!(
+        accept_unknown.verify_root::<fbe::Monster>(&buf),
+        Err(Error::UnknownEnumValue)
+    );
+    assert_eq!(
+        accept_unknown.get_root::<fbe::Monster>(&buf).map(|_| ()),
+        Err(Error::UnknownEnumValue)
+    );
+    // The violation of the item also keeps the monster from being validated.
+    let salvaged = accept_unknown.salvage_root::<fbe::Monster>(&buf);
+    assert_eq!(
+        vec![Error::UnknownEnumValue],
+        salvaged
+            .violations
+            .into_iter()
+            .map(|violation| violation.error)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(validated, validated_items());
+
+    let buf = Builder::new(Monster {
+        name: "monster".to_string(),
+        stat: None,
+        loots: vec![Item {
+            name: "sword".to_string(),
+            rarity: Rarity::Rare,
+        }],
+    })
+    .build();
+    assert_eq!(accept_unknown.verify_root::<fbe::Monster>(&buf), Ok(()));
+    assert_eq!(validated + 1, validated_items());
+}
@@ -7,13 +7,15 @@ mod scalar_vector_test {
     #[test]
     fn test_scalar_vector_uoffset_out_of_bounds() {
         let buf = [
-            le!(10u32),
+            le!(12u32),
             // vtable
             le!(6u16),
             le!(8u16),
             le!(4u16),
+            // padding
+            le!(0u16),
             // sensor
-            le!(6i32),
+            le!(8i32),
             le!(4u32),
         ]
         .concat();
@@ -24,13 +26,15 @@ mod scalar_vector_test {
     #[test]
     fn test_scalar_vector_len_out_of_bounds() {
         let buf = [
-            le!(10u32),
+            le!(12u32),
             // vtable
             le!(6u16),
             le!(8u16),
             le!(4u16),
+            // padding
+            le!(0u16),
             // sensor
-            le!(6i32),
+            le!(8i32),
             le!(4u32),
             // readings
             le!(0u16),
@@ -43,13 +47,15 @@ mod scalar_vector_test {
     #[test]
     fn test_scalar_vector_content_out_of_bounds() {
         let buf = [
-            le!(10u32),
+            le!(12u32),
             // vtable
             le!(6u16),
             le!(8u16),
             le!(4u16),
+            // padding
+            le!(0u16),
             // sensor
-            le!(6i32),
+            le!(8i32),
             le!(4u32),
             // readings
             le!(2u32),
@@ -68,13 +74,15 @@ mod string_vector_test {
     #[test]
     fn test_string_vector_uoffset_out_of_bounds() {
         let buf = [
-            le!(10u32),
+            le!(12u32),
             // vtable
             le!(6u16),
             le!(8u16),
             le!(4u16),
+            // padding
+            le!(0u16),
             // text
-            le!(6i32),
+            le!(8i32),
             le!(4u32),
         ]
         .concat();
@@ -85,13 +93,15 @@ mod string_vector_test {
     #[test]
     fn test_string_vector_len_out_of_bounds() {
         let buf = [
-            le!(10u32),
+            le!(12u32),
             // vtable
             le!(6u16),
             le!(8u16),
             le!(4u16),
+            // padding
+            le!(0u16),
             // text
-            le!(6i32),
+            le!(8i32),
             le!(4u32),
             // lines
             le!(0u16),
@@ -104,13 +114,15 @@ mod string_vector_test {
     #[test]
     fn test_string_vector_content_out_of_bounds() {
         let buf = [
-            le!(10u32),
+            le!(12u32),
             // vtable
             le!(6u16),
             le!(8u16),
             le!(4u16),
+            // padding
+            le!(0u16),
             // text
-            le!(6i32),
+            le!(8i32),
             le!(4u32),
             // lines
             le!(2u32),
@@ -124,13 +136,15 @@ mod string_vector_test {
     #[test]
     fn test_string_vector_element_out_of_bounds() {
         let buf = [
-            le!(10u32),
+            le!(12u32),
             // vtable
             le!(6u16),
             le!(8u16),
             le!(4u16),
+            // padding
+            le!(0u16),
             // text
-            le!(6i32),
+            le!(8i32),
             le!(4u32),
             // lines
             le!(1u32),
@@ -152,13 +166,15 @@ mod table_vector_test {
     #[test]
     fn test_table_vector_uoffset_out_of_bounds() {
         let buf = [
-            le!(10u32),
+            le!(12u32),
             // vtable
             le!(6u16),
             le!(8u16),
             le!(4u16),
+            // padding
+            le!(0u16),
             // hero
-            le!(6i32),
+            le!(8i32),
             le!(4u32),
         ]
         .concat();
@@ -169,13 +185,15 @@ mod table_vector_test {
     #[test]
     fn test_table_vector_len_out_of_bounds() {
         let buf = [
-            le!(10u32),
+            le!(12u32),
             // vtable
             le!(6u16),
             le!(8u16),
             le!(4u16),
+            // padding
+            le!(0u16),
             // hero
-            le!(6i32),
+            le!(8i32),
             le!(4u32),
             // stats
             le!(0u16),
@@ -188,13 +206,15 @@ mod table_vector_test {
     #[test]
     fn test_table_vector_content_out_of_bounds() {
         let buf = [
-            le!(10u32),
+            le!(12u32),
             // vtable
             le!(6u16),
             le!(8u16),
             le!(4u16),
+            // padding
+            le!(0u16),
             // hero
-            le!(6i32),
+            le!(8i32),
             le!(4u32),
             // stats
             le!(2u32),
@@ -208,19 +228,21 @@ mod table_vector_test {
     #[test]
     fn test_table_vector_element_out_of_bounds() {
         let buf = [
-            le!(10u32),
+            le!(12u32),
             // vtable
             le!(6u16),
             le!(8u16),
             le!(4u16),
+            // padding
+            le!(0u16),
             // hero
-            le!(6i32),
+            le!(8i32),
             le!(4u32),
             // stats
             le!(1u32),
             le!(4u32),
             // stats[0]
-            le!(22u32),
+            le!(24u32),
             le!(4u16),
         ]
         .concat();
@@ -0,0 +1,106 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use common::string_vector_builder::example as sv_cfbe;
+use common::string_vector_generated::example as sv;
+use common::table_vector_builder::example as cfbe;
+use common::table_vector_generated::example as fbe;
+use flatbuffers::FlatBufferBuilder;
+use flatbuffers_verifier::{get_root_with_report, Error, Options, VerificationReport};
+
+fn hero(hps: &[u32]) -> Vec<u8> {
+    Builder::new(cfbe::Hero {
+        stats: hps.iter().map(|hp| cfbe::Stat { hp: *hp }).collect(),
+    })
+    .build()
+}
+
+#[test]
+fn test_report_table_vector() {
+    let buf = hero(&[1, 2]);
+    let (root, report) = get_root_with_report::<fbe::Hero>(&buf).expect("verified");
+    assert_eq!(2, root.stats().map_or(0, |stats| stats.len()));
+
+    // `Hero` and `Stat` have the same vtable, and only the 2 bytes after it are padding.
+    assert_eq!(
+        VerificationReport {
+            tables: 3,
+            vtables: 1,
+            strings: 0,
+            vectors: 1,
+            elements: 2,
+            max_vector_len: 2,
+            max_depth: 2,
+            covered_bytes: 46,
+            buffer_len: 48,
+        },
+        report
+    );
+    assert_eq!(2, report.uncovered_bytes());
+}
+
+#[test]
+fn test_report_string_vector() {
+    let buf = Builder::new(sv_cfbe::Text {
+        lines: vec!["a".to_string(), "bcd".to_string()],
+    })
+    .build();
+    let (_, report) = get_root_with_report::<sv::Text>(&buf).expect("verified");
+
+    // The padding follows the vtable and the string "a".
+    assert_eq!(
+        VerificationReport {
+            tables: 1,
+            vtables: 1,
+            strings: 2,
+            vectors: 1,
+            elements: 2,
+            max_vector_len: 2,
+            max_depth: 1,
+            covered_bytes: 44,
+            buffer_len: 48,
+        },
+        report
+    );
+}
+
+#[test]
+fn test_report_unreferenced_bytes() {
+    let mut buf = hero(&[1]);
+    let (_, report) = get_root_with_report::<fbe::Hero>(&buf).expect("verified");
+    buf.extend_from_slice(&[0u8; 8]);
+    let (_, padded_report) = get_root_with_report::<fbe::Hero>(&buf).expect("verified");
+
+    assert_eq!(report.covered_bytes, padded_report.covered_bytes);
+    assert_eq!(
+        report.uncovered_bytes() + 8,
+        padded_report.uncovered_bytes()
+    );
+}
+
+#[test]
+fn test_report_shared_table() {
+    let mut fbb = FlatBufferBuilder::new();
+    let stat = fbe::Stat::create(&mut fbb, &fbe::StatArgs { hp: 1 });
+    let stats = fbb.create_vector(&[stat, stat, stat]);
+    let root = fbe::Hero::create(&mut fbb, &fbe::HeroArgs { stats: Some(stats) });
+    fbb.finish_minimal(root);
+    let buf = fbb.finished_data();
+
+    // The shared table is counted on every visit, but its bytes are covered once.
+    let (_, report) = get_root_with_report::<fbe::Hero>(buf).expect("verified");
+    assert_eq!(4, report.tables);
+    assert_eq!(3, report.elements);
+    assert_eq!(buf.len(), report.buffer_len);
+
+    let options = Options {
+        reject_shared_objects: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        options
+            .get_root_with_report::<fbe::Hero>(buf)
+            .map(|(_, report)| report),
+        Err(Error::SharedObject)
+    );
+}
@@ -0,0 +1,92 @@
+pub mod common;
+
+use cfb::builder::Builder;
+use common::data_order_builder as cfbe;
+use common::data_order_generated::Monster;
+use flatbuffers_verifier::{
+    get_verified_root, get_verified_size_prefixed_root, Error, Options, Verified, VerifiedBuf,
+};
+use std::thread;
+
+fn monster() -> Vec<u8> {
+    Builder::new(cfbe::Monster {
+        name: "orc".to_owned(),
+        stat: Some(cfbe::Stat { hp: 10, mp: 20 }),
+        loots: vec![],
+    })
+    .build()
+}
+
+fn name<'a>(monster: Verified<'a, Monster<'a>>) -> Option<&'a str> {
+    monster.name()
+}
+
+#[test]
+fn test_get_verified_root() {
+    let buf = monster();
+    let root = get_verified_root::<Monster>(&buf).expect("verified");
+    assert_eq!(root.buf(), &buf[..]);
+    assert_eq!(name(root), Some("orc"));
+    assert_eq!(root.into_inner().stat().map(|stat| stat.hp()), Some(10));
+}
+
+#[test]
+fn test_get_verified_root_rejects_invalid_buffer() {
+    let mut buf = monster();
+    buf.truncate(buf.len() - 4);
+    assert_eq!(get_verified_root::<Monster>(&buf), Err(Error::OutOfBounds));
+}
+
+#[test]
+fn test_get_verified_size_prefixed_root() {
+    let buf = monster();
+    let prefixed = [le!(buf.len() as u32), &buf[..]].concat();
+    let root = get_verified_size_prefixed_root::<Monster>(&prefixed).expect("verified");
+    assert_eq!(root.buf(), &prefixed[..]);
+    assert_eq!(name(root), Some("orc"));
+}
+
+#[test]
+fn test_verified_buf() {
+    let buf = VerifiedBuf::<Monster<'static>>::new(monster()).expect("verified");
+    assert_eq!(name(buf.root()), Some("orc"));
+    assert_eq!(buf.as_bytes(), &monster()[..]);
+    assert_eq!(buf.into_bytes(), monster());
+}
+
+#[test]
+fn test_verified_buf_returns_rejected_buffer() {
+    let mut buf = monster();
+    buf.truncate(buf.len() - 4);
+    let (rejected, err) = VerifiedBuf::<Monster<'static>>::new(buf.clone()).unwrap_err();
+    assert_eq!(rejected, buf);
+    assert_eq!(err, Error::OutOfBounds);
+}
+
+#[test]
+fn test_verified_buf_with_options() {
+    let options = Options {
+        max_work: Some(8),
+        ..Default::default()
+    };
+    let (rejected, err) =
+        VerifiedBuf::<Monster<'static>>::with_options(monster(), &options).unwrap_err();
+    assert_eq!(rejected, monster());
+    assert_eq!(err, Error::WorkBudgetExceeded);
+
+    let buf = VerifiedBuf::<Monster<'static>>::with_options(monster(), &Options::default())
+        .expect("verified");
+    assert_eq!(name(buf.root()), Some("orc"));
+}
+
+#[test]
+fn test_verified_buf_across_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<VerifiedBuf<Monster<'static>>>();
+
+    let buf = VerifiedBuf::<Monster<'static>>::new(monster()).expect("verified");
+    let hp = thread::spawn(move || buf.root().stat().map(|stat| stat.hp()))
+        .join()
+        .unwrap();
+    assert_eq!(hp, Some(10));
+}